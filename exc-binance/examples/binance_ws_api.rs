@@ -57,7 +57,6 @@ async fn main() -> anyhow::Result<()> {
                 while let Some(trade) = stream.next().await {
                     match trade {
                         Ok(trade) => {
-                            counter += 1;
                             tracing::info!("[{count}]trade={trade:?}");
                         }
                         Err(err) => {
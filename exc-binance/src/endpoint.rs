@@ -1,15 +1,19 @@
 use std::time::Duration;
 
-use exc_core::{transport::http::endpoint::Endpoint as HttpEndpoint, Exc};
+use exc_core::{
+    transport::http::endpoint::Endpoint as HttpEndpoint, util::clock_sync::Clock,
+    weight::RateLimiter, Exc,
+};
 use tower::{buffer::Buffer, ready_cache::ReadyCache, util::Either, ServiceBuilder};
 
 use crate::{
     http::{
         layer::BinanceRestApiLayer,
+        limit,
         request::{RestEndpoint, SpotOptions},
     },
     service::{Binance, BinanceInner, HTTP_KEY, WS_KEY},
-    types::key::BinanceKey,
+    types::key::{BinanceKey, DEFAULT_RECV_WINDOW},
     websocket::{endpoint::WsEndpoint, BinanceWebsocketApi},
     Request,
 };
@@ -22,6 +26,9 @@ pub struct Endpoint {
     pub(crate) http: (RestEndpoint, HttpEndpoint),
     pub(crate) ws: WsEndpoint,
     buffer: usize,
+    clock: Clock,
+    recv_window: Duration,
+    limiter: RateLimiter,
 }
 
 impl Endpoint {
@@ -32,6 +39,9 @@ impl Endpoint {
             http: (RestEndpoint::UsdMarginFutures, HttpEndpoint::default()),
             ws: BinanceWebsocketApi::usd_margin_futures(),
             buffer: CAP,
+            clock: Clock::default(),
+            recv_window: Duration::from_millis(DEFAULT_RECV_WINDOW as u64),
+            limiter: limit::rate_limiter(&RestEndpoint::UsdMarginFutures),
         }
     }
 
@@ -42,6 +52,9 @@ impl Endpoint {
             http: (RestEndpoint::Spot(options), HttpEndpoint::default()),
             ws: BinanceWebsocketApi::spot(),
             buffer: CAP,
+            clock: Clock::default(),
+            recv_window: Duration::from_millis(DEFAULT_RECV_WINDOW as u64),
+            limiter: limit::rate_limiter(&RestEndpoint::Spot(options)),
         }
     }
 
@@ -52,6 +65,9 @@ impl Endpoint {
             http: (RestEndpoint::EuropeanOptions, HttpEndpoint::default()),
             ws: BinanceWebsocketApi::european_options(),
             buffer: CAP,
+            clock: Clock::default(),
+            recv_window: Duration::from_millis(DEFAULT_RECV_WINDOW as u64),
+            limiter: limit::rate_limiter(&RestEndpoint::EuropeanOptions),
         }
     }
 
@@ -91,12 +107,41 @@ impl Endpoint {
         self
     }
 
+    /// Set the rate limiter of ws requests
+    /// (see [`rate_limiter`](crate::websocket::limit::rate_limiter)).
+    pub fn ws_rate_limiter(&mut self, limiter: RateLimiter) -> &mut Self {
+        self.ws.rate_limiter(limiter);
+        self
+    }
+
     /// Private mode.
     pub fn private(&mut self, key: BinanceKey) -> &mut Self {
         self.key = Some(key);
         self
     }
 
+    /// Set the clock used for signing, which can be kept in sync with the server
+    /// by the [`ClockSyncLayer`](exc_core::util::clock_sync::ClockSyncLayer).
+    pub fn clock(&mut self, clock: Clock) -> &mut Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Set the recv window of the signed requests (default to 5s).
+    pub fn recv_window(&mut self, recv_window: Duration) -> &mut Self {
+        self.recv_window = recv_window;
+        self
+    }
+
+    /// Set the rate limiter of the rest requests (default to the limits of the endpoint,
+    /// see [`rate_limiter`](crate::http::limit::rate_limiter)).
+    ///
+    /// Share the same limiter between the endpoints using the same IP and account.
+    pub fn rate_limiter(&mut self, limiter: RateLimiter) -> &mut Self {
+        self.limiter = limiter;
+        self
+    }
+
     /// Set buffer capacity.
     pub fn buffer(&mut self, capacity: usize) -> &mut Self {
         self.buffer = capacity;
@@ -110,7 +155,10 @@ impl Endpoint {
 
     /// Connect to the binance service.
     pub fn connect(&self) -> Binance {
-        let mut layer = BinanceRestApiLayer::new(self.http.0);
+        let mut layer = BinanceRestApiLayer::new(self.http.0)
+            .clock(self.clock.clone())
+            .recv_window(self.recv_window.as_millis() as i64)
+            .rate_limiter(self.limiter.clone());
         if let Some(key) = self.key.as_ref() {
             layer = layer.key(key.clone());
         }
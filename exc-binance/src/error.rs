@@ -2,7 +2,10 @@ use anyhow::anyhow;
 use exc_core::ExchangeError;
 use thiserror::Error;
 
-use crate::{http::error::RestError, websocket::error::WsError};
+use crate::{
+    http::error::{api_error, RestError},
+    websocket::error::WsError,
+};
 
 /// All errors in [`exc-binance`]
 #[derive(Debug, Error)]
@@ -28,9 +31,12 @@ impl From<Error> for ExchangeError {
             Error::WrongResponseType => Self::Other(anyhow!("wrong response type")),
             Error::Rest(err) => match err {
                 RestError::Http(_) | RestError::Hyper(_) => Self::Unavailable(err.into()),
+                RestError::Api(code, msg) => Self::ApiError(api_error(code, msg)),
                 RestError::Exchange(err) => err,
+                RestError::UnsupportedEndpoint(err) => Self::Unsupported(err),
                 _ => Self::Other(err.into()),
             },
+            Error::Ws(WsError::Exchange(err)) => err,
             Error::Ws(err) => match &err {
                 WsError::ListenKeyExpired(_)
                 | WsError::StreamSubscribed(_)
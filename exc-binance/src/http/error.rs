@@ -1,11 +1,13 @@
-use exc_core::ExchangeError;
+use std::time::Duration;
+
+use exc_core::{retry::RetryableError, ApiError, ApiErrorKind, ExchangeError};
 use thiserror::Error;
 
 /// Rest API Errors.
 #[derive(Debug, Error)]
 pub enum RestError {
     /// API error message.
-    #[error("api: code={0} msg={0}")]
+    #[error("api: code={0} msg={1}")]
     Api(i64, String),
     /// Http errors.
     #[error("http: {0}")]
@@ -69,10 +71,75 @@ pub enum RestError {
 impl RestError {
     /// Is temp.
     pub fn is_temporary(&self) -> bool {
+        match self {
+            Self::Exchange(err) => err.is_temporary(),
+            Self::Api(code, _) => *code == -1016,
+            _ => false,
+        }
+    }
+}
+
+/// The name of the exchange in the [`ApiError`]s.
+const VENUE: &str = "binance";
+
+/// Map the error code (and message) of Binance into an [`ApiError`].
+pub fn api_error(code: i64, msg: String) -> ApiError {
+    let kind = match code {
+        -2018 | -2019 => ApiErrorKind::InsufficientBalance,
+        -1013 if msg.contains("PRICE_FILTER") || msg.contains("PERCENT_PRICE") => {
+            ApiErrorKind::InvalidPrice
+        }
+        -4014 | -4016 | -4024 => ApiErrorKind::InvalidPrice,
+        -5022 => ApiErrorKind::PostOnlyWouldTake,
+        -2011 | -2013 => ApiErrorKind::OrderNotFound,
+        -4116 => ApiErrorKind::DuplicateClientId,
+        -1021 => ApiErrorKind::TimestampDrift,
+        -1002 | -1022 | -2014 | -2015 => ApiErrorKind::PermissionDenied,
+        -1016 => ApiErrorKind::Maintenance,
+        // Spot reports the rejections of new orders with the same code.
+        -2010 if msg.contains("insufficient balance") => ApiErrorKind::InsufficientBalance,
+        -2010 if msg.contains("immediately match and take") => ApiErrorKind::PostOnlyWouldTake,
+        -2010 if msg.contains("Duplicate order") => ApiErrorKind::DuplicateClientId,
+        _ => ApiErrorKind::Other,
+    };
+    ApiError::new(VENUE, code, msg, kind)
+}
+
+impl RetryableError for RestError {
+    fn is_temporary(&self) -> bool {
+        RestError::is_temporary(self)
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
         if let Self::Exchange(err) = self {
-            err.is_temporary()
+            err.retry_after()
         } else {
-            false
+            None
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_error() {
+        let err = ExchangeError::from(crate::Error::from(RestError::Api(
+            -2010,
+            "Order would immediately match and take.".to_string(),
+        )));
+        assert_eq!(err.api_error_kind(), Some(ApiErrorKind::PostOnlyWouldTake));
+        let err = api_error(-2019, "Margin is insufficient.".to_string());
+        assert_eq!(err.kind, ApiErrorKind::InsufficientBalance);
+        assert_eq!(err.code, "-2019");
+        assert_eq!(
+            RestError::Api(
+                -1021,
+                "Timestamp for this request is outside of the recvWindow.".to_string()
+            )
+            .to_string(),
+            "api: code=-1021 msg=Timestamp for this request is outside of the recvWindow."
+        );
+    }
+}
@@ -1,24 +1,28 @@
-use exc_core::retry::RetryPolicy;
+use exc_core::retry::Backoff;
+use exc_core::util::clock_sync::Clock;
+use exc_core::weight::RateLimiter;
 use futures::future::BoxFuture;
 use futures::{FutureExt, TryFutureExt};
 use tower::retry::Retry;
 
-use crate::types::key::BinanceKey;
+use crate::types::key::{BinanceKey, DEFAULT_RECV_WINDOW};
 
 use super::error::RestError;
+use super::limit;
 use super::request::{Payload, Rest, RestEndpoint, RestRequest};
 use super::response::{Data, RestResponse};
 use std::task::{Context, Poll};
 use tower::{Layer, Service, ServiceBuilder};
 
-type Policy = RetryPolicy<RestRequest<Payload>, RestResponse<Data>, fn(&RestError) -> bool>;
-
 /// Binance rest api layer.
 #[derive(Clone)]
 pub struct BinanceRestApiLayer {
-    retry: Policy,
+    retry: Backoff,
     endpoint: RestEndpoint,
     key: Option<BinanceKey>,
+    clock: Clock,
+    recv_window: i64,
+    limiter: RateLimiter,
 }
 
 impl BinanceRestApiLayer {
@@ -31,8 +35,11 @@ impl BinanceRestApiLayer {
     pub fn new(endpoint: RestEndpoint) -> Self {
         Self {
             endpoint,
-            retry: RetryPolicy::default().retry_on(RestError::is_temporary),
+            retry: Backoff::default(),
             key: None,
+            clock: Clock::default(),
+            recv_window: DEFAULT_RECV_WINDOW,
+            limiter: RateLimiter::default(),
         }
     }
 
@@ -41,19 +48,50 @@ impl BinanceRestApiLayer {
         self.key = Some(key);
         self
     }
+
+    /// Set the clock used for signing.
+    pub fn clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Set the recv window (in millis) of the signed requests.
+    pub fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = recv_window;
+        self
+    }
+
+    /// Set the retry policy of the requests.
+    /// Only the temporary errors of the idempotent requests are retried.
+    pub fn retry(mut self, retry: Backoff) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Set the rate limiter acquiring the weights of the requests,
+    /// which is corrected with the usage reported in the response headers.
+    /// See [`limit::rate_limiter`] for the default limits of the endpoint.
+    pub fn rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.limiter = limiter;
+        self
+    }
 }
 
 impl<S> Layer<S> for BinanceRestApiLayer {
     type Service = BinanceRestApi<S>;
 
     fn layer(&self, http: S) -> Self::Service {
-        let inner = ServiceBuilder::default()
-            .retry(self.retry)
-            .service(BinanceRestApiInner {
-                http,
-                endpoint: self.endpoint,
-                key: self.key.clone(),
-            });
+        let inner =
+            ServiceBuilder::default()
+                .retry(self.retry.clone())
+                .service(BinanceRestApiInner {
+                    http,
+                    endpoint: self.endpoint,
+                    key: self.key.clone(),
+                    clock: self.clock.clone(),
+                    recv_window: self.recv_window,
+                    limiter: self.limiter.clone(),
+                });
         BinanceRestApi { inner }
     }
 }
@@ -64,12 +102,52 @@ pub struct BinanceRestApiInner<S> {
     endpoint: RestEndpoint,
     http: S,
     key: Option<BinanceKey>,
+    clock: Clock,
+    recv_window: i64,
+    limiter: RateLimiter,
+}
+
+impl<S> BinanceRestApiInner<S>
+where
+    S: Service<http::Request<hyper::Body>, Response = http::Response<hyper::Body>>,
+    S::Future: Send + 'static,
+    S::Error: 'static,
+    RestError: From<S::Error>,
+{
+    fn send<R: Rest>(
+        &mut self,
+        req: RestRequest<R>,
+    ) -> BoxFuture<'static, Result<RestResponse<Data>, RestError>> {
+        let payload = req.payload().to_payload();
+        match req.to_http(
+            &self.endpoint,
+            self.key.as_ref(),
+            &self.clock,
+            self.recv_window,
+        ) {
+            Ok(req) => {
+                tracing::trace!("sent http request: {}", req.uri());
+                let limiter = self.limiter.clone();
+                self.http
+                    .call(req)
+                    .map_err(RestError::from)
+                    .and_then(move |resp| {
+                        limit::record_usage(&limiter, resp.headers());
+                        RestResponse::from_http(resp)
+                    })
+                    .map_ok(move |resp| resp.map(|data| payload.select(data)))
+                    .boxed()
+            }
+            Err(err) => futures::future::ready(Err(err)).boxed(),
+        }
+    }
 }
 
 impl<S, R> Service<RestRequest<R>> for BinanceRestApiInner<S>
 where
     R: Rest,
     S: Service<http::Request<hyper::Body>, Response = http::Response<hyper::Body>>,
+    S: Clone + Send + 'static,
     S::Future: Send + 'static,
     S::Error: 'static,
     RestError: From<S::Error>,
@@ -83,16 +161,21 @@ where
     }
 
     fn call(&mut self, req: RestRequest<R>) -> Self::Future {
-        match req.to_http(&self.endpoint, self.key.as_ref()) {
-            Ok(req) => {
-                tracing::trace!("sent http request: {}", req.uri());
-                self.http
-                    .call(req)
-                    .map_err(RestError::from)
-                    .and_then(RestResponse::from_http)
-                    .boxed()
+        // Acquire the weight before signing, since the wait may exceed the recv window.
+        let weight = req.payload().weight(&self.endpoint);
+        match self.limiter.try_acquire(&weight) {
+            Ok(None) => self.send(req),
+            Ok(Some(_)) => {
+                let clone = self.clone();
+                let mut inner = std::mem::replace(self, clone);
+                async move {
+                    inner.limiter.clone().acquire(&weight).await?;
+                    futures::future::poll_fn(|cx| inner.http.poll_ready(cx)).await?;
+                    inner.send(req).await
+                }
+                .boxed()
             }
-            Err(err) => futures::future::ready(Err(err)).boxed(),
+            Err(err) => futures::future::ready(Err(err.into())).boxed(),
         }
     }
 }
@@ -100,7 +183,7 @@ where
 /// Binance rest api service.
 #[derive(Clone)]
 pub struct BinanceRestApi<S> {
-    inner: Retry<Policy, BinanceRestApiInner<S>>,
+    inner: Retry<Backoff, BinanceRestApiInner<S>>,
 }
 
 impl<S> Service<RestRequest<Payload>> for BinanceRestApi<S>
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use exc_core::weight::{RateLimiter, Weight};
+use http::HeaderMap;
+
+use super::request::RestEndpoint;
+
+/// The bucket of the request weight (per IP).
+pub const REQUEST_WEIGHT: &str = "request_weight";
+
+/// The bucket of the orders placed in 10 seconds (per account).
+pub const ORDERS_10S: &str = "orders_10s";
+
+/// The bucket of the orders placed in a minute (per account, futures and options).
+pub const ORDERS_1M: &str = "orders_1m";
+
+/// The bucket of the orders placed in a day (per account, spot).
+pub const ORDERS_1D: &str = "orders_1d";
+
+const MINUTE: Duration = Duration::from_secs(60);
+
+/// The headers reporting the usage of the buckets.
+const USAGE_HEADERS: [(&str, &str); 4] = [
+    ("x-mbx-used-weight-1m", REQUEST_WEIGHT),
+    ("x-mbx-order-count-10s", ORDERS_10S),
+    ("x-mbx-order-count-1m", ORDERS_1M),
+    ("x-mbx-order-count-1d", ORDERS_1D),
+];
+
+/// Create a rate limiter with the default limits of the endpoint.
+///
+/// Share it between the services using the same IP and account to keep
+/// them from being banned together.
+pub fn rate_limiter(endpoint: &RestEndpoint) -> RateLimiter {
+    match endpoint {
+        RestEndpoint::Spot(_) => RateLimiter::new()
+            .with_bucket(REQUEST_WEIGHT, 6000, MINUTE)
+            .with_bucket(ORDERS_10S, 100, Duration::from_secs(10))
+            .with_bucket(ORDERS_1D, 200_000, Duration::from_secs(86400)),
+        RestEndpoint::UsdMarginFutures => RateLimiter::new()
+            .with_bucket(REQUEST_WEIGHT, 2400, MINUTE)
+            .with_bucket(ORDERS_10S, 300, Duration::from_secs(10))
+            .with_bucket(ORDERS_1M, 1200, MINUTE),
+        RestEndpoint::EuropeanOptions => RateLimiter::new()
+            .with_bucket(REQUEST_WEIGHT, 400, MINUTE)
+            .with_bucket(ORDERS_10S, 100, Duration::from_secs(10))
+            .with_bucket(ORDERS_1M, 1200, MINUTE),
+    }
+}
+
+/// The weight of placing (or amending) `orders` orders in a request of the given weight.
+pub(crate) fn order_weight(weight: u64, orders: u64) -> Weight {
+    Weight::bucket(REQUEST_WEIGHT, weight)
+        .with(ORDERS_10S, orders)
+        .with(ORDERS_1M, orders)
+        .with(ORDERS_1D, orders)
+}
+
+/// Record the usage reported in the response headers.
+pub(crate) fn record_usage(limiter: &RateLimiter, headers: &HeaderMap) {
+    for (header, bucket) in USAGE_HEADERS {
+        let used = headers
+            .get(header)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok());
+        if let Some(used) = used {
+            limiter.record_usage(bucket, used);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::request::{trading::ListOpenOrders, Rest, SpotOptions};
+
+    #[test]
+    fn test_record_usage() {
+        let limiter = rate_limiter(&RestEndpoint::Spot(SpotOptions::default()));
+        let mut headers = HeaderMap::new();
+        headers.insert("X-MBX-USED-WEIGHT-1M", "5990".parse().unwrap());
+        headers.insert("X-MBX-ORDER-COUNT-10S", "3".parse().unwrap());
+        record_usage(&limiter, &headers);
+        assert_eq!(limiter.usage(REQUEST_WEIGHT), Some(5990));
+        assert_eq!(limiter.usage(ORDERS_10S), Some(3));
+        assert!(limiter.try_acquire(&order_weight(1, 1)).unwrap().is_none());
+        assert!(limiter
+            .try_acquire(&Weight::bucket(REQUEST_WEIGHT, 20))
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn test_open_orders_weight() {
+        let spot = RestEndpoint::Spot(SpotOptions::default());
+        let futures = RestEndpoint::UsdMarginFutures;
+        let one = ListOpenOrders {
+            symbol: Some("BTCUSDT".to_string()),
+        };
+        let all = ListOpenOrders { symbol: None };
+        assert_eq!(one.weight(&spot), Weight::bucket(REQUEST_WEIGHT, 6));
+        assert_eq!(all.weight(&spot), Weight::bucket(REQUEST_WEIGHT, 80));
+        assert_eq!(one.weight(&futures), Weight::bucket(REQUEST_WEIGHT, 1));
+        assert_eq!(all.weight(&futures), Weight::bucket(REQUEST_WEIGHT, 40));
+    }
+}
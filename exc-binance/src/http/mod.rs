@@ -4,6 +4,9 @@ pub mod error;
 /// Binance rest api layer.
 pub mod layer;
 
+/// Rate limits.
+pub mod limit;
+
 /// Rest requests.
 pub mod request;
 
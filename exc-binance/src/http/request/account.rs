@@ -1,6 +1,7 @@
+use exc_core::weight::Weight;
 use serde::Serialize;
 
-use crate::http::error::RestError;
+use crate::http::{error::RestError, limit::REQUEST_WEIGHT};
 
 use super::{Rest, RestEndpoint};
 
@@ -258,3 +259,100 @@ impl Rest for GetSubAccountFuturesPositions {
         super::Payload::new(self.clone())
     }
 }
+
+/// Get balances of the account.
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetBalances {
+    /// Omit zero balances (spot only).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub omit_zero_balances: Option<bool>,
+}
+
+impl Rest for GetBalances {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v2/balance".to_string()),
+            RestEndpoint::Spot(_options) => Ok("/api/v3/account".to_string()),
+            RestEndpoint::EuropeanOptions => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`GetBalances` is not available on `binance-e`"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        match endpoint {
+            RestEndpoint::Spot(_) => Ok(serde_json::to_value(self)?),
+            _ => Ok(serde_json::json!({})),
+        }
+    }
+
+    fn weight(&self, endpoint: &RestEndpoint) -> Weight {
+        match endpoint {
+            RestEndpoint::Spot(_) => Weight::bucket(REQUEST_WEIGHT, 20),
+            _ => Weight::bucket(REQUEST_WEIGHT, 5),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Get position information (USD-M futures only).
+#[derive(Debug, Clone, Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPositionRisk {
+    /// Symbol.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+}
+
+impl Rest for GetPositionRisk {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v2/positionRisk".to_string()),
+            RestEndpoint::Spot(_) | RestEndpoint::EuropeanOptions => {
+                Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                    "`GetPositionRisk` only available on `binance-u`"
+                )))
+            }
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn weight(&self, _endpoint: &RestEndpoint) -> Weight {
+        Weight::bucket(REQUEST_WEIGHT, 5)
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
@@ -0,0 +1,82 @@
+use exc_core::weight::Weight;
+use serde::Serialize;
+
+use super::{Rest, RestEndpoint, RestError};
+use crate::http::limit::REQUEST_WEIGHT;
+
+const SPOT_LIMITS: [usize; 8] = [5, 10, 20, 50, 100, 500, 1000, 5000];
+const FUTURES_LIMITS: [usize; 7] = [5, 10, 20, 50, 100, 500, 1000];
+
+/// Get order book snapshot.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDepth {
+    /// Symbol.
+    pub symbol: String,
+    /// Limit.
+    /// Will be rounded up to the nearest valid limit of the endpoint.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl GetDepth {
+    fn with_valid_limit(&self, limits: &[usize]) -> Self {
+        let limit = self.limit.map(|limit| {
+            limits
+                .iter()
+                .copied()
+                .find(|valid| *valid >= limit)
+                .or_else(|| limits.last().copied())
+                .unwrap_or(limit)
+        });
+        Self {
+            symbol: self.symbol.clone(),
+            limit,
+        }
+    }
+}
+
+impl Rest for GetDepth {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => {
+                let qs = serde_urlencoded::to_string(self.with_valid_limit(&FUTURES_LIMITS))?;
+                Ok(format!("/fapi/v1/depth?{qs}"))
+            }
+            RestEndpoint::Spot(_) => {
+                let qs = serde_urlencoded::to_string(self.with_valid_limit(&SPOT_LIMITS))?;
+                Ok(format!("/api/v3/depth?{qs}"))
+            }
+            RestEndpoint::EuropeanOptions => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`GetDepth` is not available on `binance-e`"
+            ))),
+        }
+    }
+
+    fn weight(&self, endpoint: &RestEndpoint) -> Weight {
+        let weight = match endpoint {
+            RestEndpoint::UsdMarginFutures => match self.limit.unwrap_or(500) {
+                0..=50 => 2,
+                51..=100 => 5,
+                101..=500 => 10,
+                _ => 20,
+            },
+            RestEndpoint::Spot(_) => match self.limit.unwrap_or(100) {
+                0..=100 => 5,
+                101..=500 => 25,
+                501..=1000 => 50,
+                _ => 250,
+            },
+            RestEndpoint::EuropeanOptions => 1,
+        };
+        Weight::bucket(REQUEST_WEIGHT, weight)
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
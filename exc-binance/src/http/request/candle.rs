@@ -1,6 +1,8 @@
+use exc_core::weight::Weight;
 use serde::Serialize;
 
 use super::{Rest, RestEndpoint, RestError};
+use crate::http::limit::REQUEST_WEIGHT;
 
 /// Intervals.
 #[derive(Debug, Clone, Copy, Serialize)]
@@ -52,6 +54,29 @@ pub enum Interval {
     Mon1,
 }
 
+impl Interval {
+    /// Get the name of the interval.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::M1 => "1m",
+            Self::M3 => "3m",
+            Self::M5 => "5m",
+            Self::M15 => "15m",
+            Self::M30 => "30m",
+            Self::H1 => "1h",
+            Self::H2 => "2h",
+            Self::H4 => "4h",
+            Self::H6 => "6h",
+            Self::H8 => "8h",
+            Self::H12 => "12h",
+            Self::D1 => "1d",
+            Self::D3 => "3d",
+            Self::W1 => "1w",
+            Self::Mon1 => "1M",
+        }
+    }
+}
+
 /// Query candles.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -85,6 +110,20 @@ impl Rest for QueryCandles {
         }
     }
 
+    fn weight(&self, endpoint: &RestEndpoint) -> Weight {
+        let weight = match endpoint {
+            RestEndpoint::UsdMarginFutures => match self.limit.unwrap_or(500) {
+                0..=99 => 1,
+                100..=499 => 2,
+                500..=1000 => 5,
+                _ => 10,
+            },
+            RestEndpoint::Spot(_) => 2,
+            RestEndpoint::EuropeanOptions => 1,
+        };
+        Weight::bucket(REQUEST_WEIGHT, weight)
+    }
+
     fn to_payload(&self) -> super::Payload {
         super::Payload::new(self.clone())
     }
@@ -0,0 +1,45 @@
+use serde::Serialize;
+
+use crate::http::error::RestError;
+
+use super::{Rest, RestEndpoint};
+
+/// Get the trading fee rates (commission rates) of a symbol.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCommissionRate {
+    /// Symbol.
+    pub symbol: String,
+}
+
+impl Rest for GetCommissionRate {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::Spot(_options) => Ok("/sapi/v1/asset/tradeFee".to_string()),
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/commissionRate".to_string()),
+            RestEndpoint::EuropeanOptions => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`GetCommissionRate` is not available on `binance-e`"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
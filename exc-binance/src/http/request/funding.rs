@@ -0,0 +1,72 @@
+use serde::Serialize;
+
+use super::{Rest, RestEndpoint, RestError};
+
+/// Query funding rate history.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryFundingRates {
+    /// Symbol.
+    pub symbol: String,
+    /// Start time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// End time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Limit (max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Rest for QueryFundingRates {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => {
+                let qs = serde_urlencoded::to_string(self)?;
+                Ok(format!("/fapi/v1/fundingRate?{qs}"))
+            }
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`QueryFundingRates` only available on `binance-u`"
+            ))),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Get mark price and funding rate.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPremiumIndex {
+    /// Symbol.
+    pub symbol: String,
+}
+
+impl Rest for GetPremiumIndex {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => {
+                let qs = serde_urlencoded::to_string(self)?;
+                Ok(format!("/fapi/v1/premiumIndex?{qs}"))
+            }
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`GetPremiumIndex` only available on `binance-u`"
+            ))),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
@@ -1,4 +1,6 @@
-use crate::http::error::RestError;
+use exc_core::weight::Weight;
+
+use crate::http::{error::RestError, limit::REQUEST_WEIGHT};
 
 use super::{Rest, RestEndpoint};
 
@@ -19,6 +21,13 @@ impl Rest for ExchangeInfo {
         }
     }
 
+    fn weight(&self, endpoint: &RestEndpoint) -> Weight {
+        match endpoint {
+            RestEndpoint::Spot(_) => Weight::bucket(REQUEST_WEIGHT, 20),
+            _ => Weight::bucket(REQUEST_WEIGHT, 1),
+        }
+    }
+
     fn to_payload(&self) -> super::Payload {
         super::Payload::new(*self)
     }
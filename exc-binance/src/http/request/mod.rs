@@ -2,9 +2,14 @@ use std::fmt;
 
 use http::{HeaderValue, Method, Request};
 
+use exc_core::{util::clock_sync::Clock, weight::Weight};
+
 use crate::types::key::BinanceKey;
 
-use super::error::RestError;
+use super::{
+    error::RestError,
+    response::{Data, RestResponse},
+};
 
 /// Utils.
 pub mod utils;
@@ -24,14 +29,49 @@ pub mod trading;
 /// Account.
 pub mod account;
 
+/// Book.
+pub mod book;
+
+/// Funding.
+pub mod funding;
+
+/// Open interest.
+pub mod open_interest;
+
+/// Trade.
+pub mod trade;
+
+/// Fee rates.
+pub mod fee;
+
+/// Leverage and margin configuration.
+pub mod leverage;
+
+/// Transfer.
+pub mod transfer;
+
+/// Capital (deposits and withdrawals).
+pub mod capital;
+
 pub use self::{
     account::{
-        GetSubAccountAssets, GetSubAccountFutures, GetSubAccountFuturesPositions,
-        GetSubAccountMargin, ListSubAccounts,
+        GetBalances, GetPositionRisk, GetSubAccountAssets, GetSubAccountFutures,
+        GetSubAccountFuturesPositions, GetSubAccountMargin, ListSubAccounts,
     },
+    book::GetDepth,
     candle::{Interval, QueryCandles},
+    capital::{DepositHistory, WithdrawHistory},
+    fee::GetCommissionRate,
+    funding::{GetPremiumIndex, QueryFundingRates},
     instrument::ExchangeInfo,
+    leverage::{ChangeLeverage, ChangeMarginType, ChangePositionMode},
     listen_key::{CurrentListenKey, DeleteListenKey},
+    open_interest::QueryOpenInterestHist,
+    trade::{QueryAggTrades, QueryHistoricalTrades},
+    transfer::{
+        QuerySubAccountUniversalTransfer, QueryUniversalTransfer, SubAccountUniversalTransfer,
+        UniversalTransfer,
+    },
 };
 
 /// Rest payload.
@@ -66,6 +106,22 @@ pub trait Rest: Send + Sync + 'static {
         Ok(serde_json::json!({}))
     }
 
+    /// Select the part of the response data asked for,
+    /// for the apis that cannot filter it by themselves.
+    fn select(&self, data: Data) -> Data {
+        data
+    }
+
+    /// The weight of the request in the rate limits of the endpoint.
+    fn weight(&self, _endpoint: &RestEndpoint) -> Weight {
+        Weight::bucket(super::limit::REQUEST_WEIGHT, 1)
+    }
+
+    /// Whether sending the request more than once has the same effect as sending it once.
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
     /// Clone.
     fn to_payload(&self) -> Payload;
 }
@@ -122,6 +178,18 @@ impl Rest for Payload {
         self.inner.serialize(endpoint)
     }
 
+    fn select(&self, data: Data) -> Data {
+        self.inner.select(data)
+    }
+
+    fn weight(&self, endpoint: &RestEndpoint) -> Weight {
+        self.inner.weight(endpoint)
+    }
+
+    fn is_idempotent(&self) -> bool {
+        self.inner.is_idempotent()
+    }
+
     fn to_payload(&self) -> Payload {
         self.clone()
     }
@@ -211,17 +279,23 @@ impl RestRequest<Payload> {
 }
 
 impl<T: Rest> RestRequest<T> {
+    pub(crate) fn payload(&self) -> &T {
+        &self.payload
+    }
+
     pub(crate) fn to_http(
         &self,
         endpoint: &RestEndpoint,
         key: Option<&BinanceKey>,
+        clock: &Clock,
+        recv_window: i64,
     ) -> Result<Request<hyper::Body>, RestError> {
         let mut uri = format!("{}{}", endpoint.host(), self.payload.to_path(endpoint)?);
         tracing::trace!("building http request: uri={uri}");
         let value = self.payload.serialize(endpoint)?;
         let body = if self.payload.need_sign() {
             if let Some(key) = key.as_ref() {
-                let value = key.sign(value)?;
+                let value = key.sign_with(value, clock, recv_window)?;
                 let s = serde_urlencoded::to_string(value)?;
                 tracing::trace!("params: {s}");
                 match self.payload.method(endpoint)? {
@@ -263,6 +337,14 @@ impl<T: Rest> RestRequest<T> {
     }
 }
 
+impl exc_core::Request for RestRequest<Payload> {
+    type Response = RestResponse<Data>;
+
+    fn is_idempotent(&self) -> bool {
+        self.payload.is_idempotent()
+    }
+}
+
 impl<T: Rest> From<T> for RestRequest<T> {
     fn from(payload: T) -> Self {
         Self { payload }
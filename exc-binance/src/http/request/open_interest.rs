@@ -0,0 +1,44 @@
+use serde::Serialize;
+
+use super::{Interval, Rest, RestEndpoint, RestError};
+
+/// Query open interest history.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryOpenInterestHist {
+    /// Symbol.
+    pub symbol: String,
+    /// Period (`5m` to `1d`).
+    pub period: Interval,
+    /// Start time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// End time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Limit (max 500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Rest for QueryOpenInterestHist {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => {
+                let qs = serde_urlencoded::to_string(self)?;
+                Ok(format!("/futures/data/openInterestHist?{qs}"))
+            }
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`QueryOpenInterestHist` only available on `binance-u`"
+            ))),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
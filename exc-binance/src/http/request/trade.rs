@@ -0,0 +1,84 @@
+use serde::Serialize;
+
+use super::{Rest, RestEndpoint, RestError};
+
+/// Query aggregate trades.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryAggTrades {
+    /// Symbol.
+    pub symbol: String,
+    /// Return the aggregate trades from this id (inclusive).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<i64>,
+    /// Start time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// End time (must be within an hour of the start time).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Limit (max 1000).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Rest for QueryAggTrades {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        let qs = serde_urlencoded::to_string(self)?;
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok(format!("/fapi/v1/aggTrades?{qs}")),
+            RestEndpoint::Spot(_) => Ok(format!("/api/v3/aggTrades?{qs}")),
+            RestEndpoint::EuropeanOptions => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`QueryAggTrades` is not available on `binance-o`"
+            ))),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Query historical trades (by id).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryHistoricalTrades {
+    /// Symbol.
+    pub symbol: String,
+    /// Return the trades from this id (inclusive),
+    /// the most recent trades are returned if it is not provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_id: Option<i64>,
+    /// Limit (max 1000 for spot, 500 for futures).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Rest for QueryHistoricalTrades {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        let qs = serde_urlencoded::to_string(self)?;
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok(format!("/fapi/v1/historicalTrades?{qs}")),
+            RestEndpoint::Spot(_) => Ok(format!("/api/v3/historicalTrades?{qs}")),
+            RestEndpoint::EuropeanOptions => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`QueryHistoricalTrades` is not available on `binance-o`"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
@@ -1,10 +1,11 @@
-use exc_core::types;
+use exc_core::{types, weight::Weight, ExchangeError};
 use rust_decimal::Decimal;
 use serde::Serialize;
 
 use crate::{
     http::{
         error::RestError,
+        limit::order_weight,
         request::{Payload, Rest, RestEndpoint},
     },
     types::trading::{OrderSide, TimeInForce},
@@ -70,6 +71,16 @@ impl<'a> TryFrom<&'a types::PlaceOrder> for PlaceOrder {
         } else {
             OrderSide::Sell
         };
+        if let Some(mode) = req.opts.margin_mode() {
+            return Err(RestError::Exchange(ExchangeError::Unsupported(
+                anyhow::anyhow!("margin mode `{mode:?}` is not available on `binance-e`"),
+            )));
+        }
+        if req.opts.position_side() != types::PositionSide::Net {
+            return Err(RestError::Exchange(ExchangeError::Unsupported(
+                anyhow::anyhow!("position sides are not available on `binance-e`"),
+            )));
+        }
         let (order_type, price, tif, post_only) = match place.kind {
             types::OrderKind::Market => (OrderType::Market, None, None, None),
             types::OrderKind::Limit(price, tif) => {
@@ -86,12 +97,17 @@ impl<'a> TryFrom<&'a types::PlaceOrder> for PlaceOrder {
                 Some(TimeInForce::Gtc),
                 Some(true),
             ),
+            kind => {
+                return Err(RestError::Exchange(ExchangeError::Unsupported(
+                    anyhow::anyhow!("`{kind:?}` orders are not available on `binance-e`"),
+                )));
+            }
         };
         Ok(Self {
             symbol: req.opts.instrument().to_uppercase(),
             side,
             order_type,
-            reduce_only: None,
+            reduce_only: req.opts.reduce_only().then_some(true),
             quantity: place.size.abs(),
             price,
             client_order_id: req.opts.client_id().map(|s| s.to_string()),
@@ -129,6 +145,14 @@ impl Rest for PlaceOrder {
         Ok(serde_json::to_value(self)?)
     }
 
+    fn weight(&self, _endpoint: &RestEndpoint) -> Weight {
+        order_weight(1, 1)
+    }
+
+    fn is_idempotent(&self) -> bool {
+        self.client_order_id.is_some()
+    }
+
     fn to_payload(&self) -> Payload {
         Payload::new(self.clone())
     }
@@ -1,6 +1,8 @@
 use self::spot::SideEffect;
+use exc_core::{types::MarginMode, weight::Weight, ExchangeError};
 
 use super::{MarginOp, Rest, RestEndpoint, RestError};
+use crate::http::limit::{order_weight, REQUEST_WEIGHT};
 use serde::Serialize;
 
 /// Usd-Margin futures.
@@ -29,7 +31,20 @@ impl PlaceOrder {
             )),
             RestEndpoint::Spot(options) => {
                 let mut req = spot::PlaceOrder::try_from(&self.inner)?;
+                let margin_mode = self.inner.opts.margin_mode();
                 if let Some(margin) = options.margin.as_ref() {
+                    req.is_isolated = match margin_mode {
+                        None => None,
+                        Some(MarginMode::Cross) => Some("FALSE".to_string()),
+                        Some(MarginMode::Isolated) => Some("TRUE".to_string()),
+                        Some(MarginMode::Cash) => {
+                            return Err(RestError::Exchange(ExchangeError::Unsupported(
+                                anyhow::anyhow!(
+                                    "cannot place cash orders with the margin endpoint"
+                                ),
+                            )));
+                        }
+                    };
                     let margin = if self.inner.place.size.is_sign_positive() {
                         margin.buy.as_ref()
                     } else {
@@ -46,6 +61,10 @@ impl PlaceOrder {
                             req.side_effect_type = None;
                         }
                     }
+                } else if matches!(margin_mode, Some(MarginMode::Cross | MarginMode::Isolated)) {
+                    return Err(RestError::Exchange(ExchangeError::Unsupported(
+                        anyhow::anyhow!("margin orders need the margin endpoint to be enabled"),
+                    )));
                 }
                 Ok(PlaceOrderKind::Spot(req))
             }
@@ -106,6 +125,14 @@ impl Rest for PlaceOrder {
         Ok(serde_json::to_value(self.dispatch(endpoint)?)?)
     }
 
+    fn weight(&self, _endpoint: &RestEndpoint) -> Weight {
+        order_weight(1, 1)
+    }
+
+    fn is_idempotent(&self) -> bool {
+        self.inner.opts.client_id().is_some()
+    }
+
     fn to_payload(&self) -> super::Payload {
         super::Payload::new(self.clone())
     }
@@ -245,3 +272,274 @@ impl Rest for GetOrder {
         super::Payload::new(self.clone())
     }
 }
+
+/// Cancel all open orders of a symbol.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllOrders {
+    /// Symbol.
+    pub symbol: String,
+}
+
+impl Rest for CancelAllOrders {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::DELETE)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/allOpenOrders".to_string()),
+            RestEndpoint::EuropeanOptions => Ok("/eapi/v1/allOpenOrders".to_string()),
+            RestEndpoint::Spot(options) => {
+                if options.margin.is_some() {
+                    Ok("/sapi/v1/margin/openOrders".to_string())
+                } else {
+                    Ok("/api/v3/openOrders".to_string())
+                }
+            }
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// List open orders.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListOpenOrders {
+    /// Symbol (`None` for all symbols).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+}
+
+impl Rest for ListOpenOrders {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/openOrders".to_string()),
+            RestEndpoint::EuropeanOptions => Ok("/eapi/v1/openOrders".to_string()),
+            RestEndpoint::Spot(options) => {
+                if options.margin.is_some() {
+                    Ok("/sapi/v1/margin/openOrders".to_string())
+                } else {
+                    Ok("/api/v3/openOrders".to_string())
+                }
+            }
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn weight(&self, endpoint: &RestEndpoint) -> Weight {
+        let weight = match (endpoint, self.symbol.is_some()) {
+            (RestEndpoint::Spot(_), true) => 6,
+            (RestEndpoint::Spot(_), false) => 80,
+            (_, true) => 1,
+            (_, false) => 40,
+        };
+        Weight::bucket(REQUEST_WEIGHT, weight)
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Query all orders (open or finished) of a symbol.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllOrders {
+    /// Symbol.
+    pub symbol: String,
+    /// Return the orders with order id greater than or equal to it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
+    /// Start time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// End time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Limit.
+    pub limit: usize,
+}
+
+impl Rest for AllOrders {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/allOrders".to_string()),
+            RestEndpoint::Spot(options) => {
+                if options.margin.is_some() {
+                    Ok("/sapi/v1/margin/allOrders".to_string())
+                } else {
+                    Ok("/api/v3/allOrders".to_string())
+                }
+            }
+            RestEndpoint::EuropeanOptions => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`QueryOrderHistory` is not available on `binance-e`"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn weight(&self, endpoint: &RestEndpoint) -> Weight {
+        match endpoint {
+            RestEndpoint::Spot(_) => Weight::bucket(REQUEST_WEIGHT, 20),
+            _ => Weight::bucket(REQUEST_WEIGHT, 5),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Query the trades (fills) of the account of a symbol.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MyTrades {
+    /// Symbol.
+    pub symbol: String,
+    /// Start time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    /// End time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    /// Limit.
+    pub limit: usize,
+}
+
+impl Rest for MyTrades {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/userTrades".to_string()),
+            RestEndpoint::Spot(options) => {
+                if options.margin.is_some() {
+                    Ok("/sapi/v1/margin/myTrades".to_string())
+                } else {
+                    Ok("/api/v3/myTrades".to_string())
+                }
+            }
+            RestEndpoint::EuropeanOptions => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`QueryFills` is not available on `binance-e`"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn weight(&self, endpoint: &RestEndpoint) -> Weight {
+        match endpoint {
+            RestEndpoint::Spot(_) => Weight::bucket(REQUEST_WEIGHT, 20),
+            _ => Weight::bucket(REQUEST_WEIGHT, 5),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::request::SpotOptions;
+    use exc_core::types::{Place, PlaceOrderOptions};
+    use rust_decimal_macros::dec;
+
+    fn place(
+        opts: &PlaceOrderOptions,
+        endpoint: &RestEndpoint,
+    ) -> Result<serde_json::Value, RestError> {
+        let req = PlaceOrder {
+            inner: Place::with_size(dec!(1))
+                .limit(dec!(100))
+                .into_request(opts),
+        };
+        req.serialize(endpoint)
+    }
+
+    #[test]
+    fn test_margin_mode() -> anyhow::Result<()> {
+        let margin = RestEndpoint::Spot(SpotOptions::with_margin(Some(MarginOp::Loan), None));
+        let mut opts = PlaceOrderOptions::new("btcusdt");
+        let body = place(&opts, &margin)?;
+        assert!(body.get("isIsolated").is_none());
+        assert_eq!(body["sideEffectType"], "MARGIN_BUY");
+        opts.with_margin_mode(MarginMode::Isolated);
+        assert_eq!(place(&opts, &margin)?["isIsolated"], "TRUE");
+        opts.with_margin_mode(MarginMode::Cross);
+        assert_eq!(place(&opts, &margin)?["isIsolated"], "FALSE");
+
+        // Margin orders need the margin endpoint.
+        let spot = RestEndpoint::Spot(SpotOptions::default());
+        assert!(matches!(
+            place(&opts, &spot),
+            Err(RestError::Exchange(ExchangeError::Unsupported(_)))
+        ));
+        opts.with_margin_mode(MarginMode::Cash);
+        assert!(place(&opts, &spot)?.get("isIsolated").is_none());
+        assert!(matches!(
+            place(&opts, &margin),
+            Err(RestError::Exchange(ExchangeError::Unsupported(_)))
+        ));
+        Ok(())
+    }
+}
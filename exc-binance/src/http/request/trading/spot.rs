@@ -1,4 +1,4 @@
-use exc_core::types;
+use exc_core::{types, ExchangeError};
 use rust_decimal::Decimal;
 use serde::Serialize;
 
@@ -54,9 +54,18 @@ pub struct PlaceOrder {
     /// Price.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub price: Option<Decimal>,
+    /// Stop price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_price: Option<Decimal>,
+    /// Trailing delta (in BIPS).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_delta: Option<Decimal>,
     /// Side effect (for margin).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub side_effect_type: Option<SideEffect>,
+    /// Is isolated margin (`TRUE` or `FALSE`, for margin).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_isolated: Option<String>,
     /// Client id.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub new_client_order_id: Option<String>,
@@ -65,6 +74,16 @@ pub struct PlaceOrder {
     pub new_order_resp_type: Option<RespType>,
 }
 
+fn last_price_trigger(trigger: &types::Trigger) -> Result<(), RestError> {
+    if trigger.source == types::TriggerSource::Last {
+        Ok(())
+    } else {
+        Err(RestError::Exchange(ExchangeError::Unsupported(
+            anyhow::anyhow!("only triggers on the last price are supported by binance spot"),
+        )))
+    }
+}
+
 impl<'a> TryFrom<&'a exc_core::types::PlaceOrder> for PlaceOrder {
     type Error = RestError;
 
@@ -77,6 +96,15 @@ impl<'a> TryFrom<&'a exc_core::types::PlaceOrder> for PlaceOrder {
         } else {
             OrderSide::Sell
         };
+        if req.opts.reduce_only() || req.opts.position_side() != types::PositionSide::Net {
+            return Err(RestError::Exchange(ExchangeError::Unsupported(
+                anyhow::anyhow!(
+                    "reduce-only orders and position sides are not available on binance spot"
+                ),
+            )));
+        }
+        let mut stop_price = None;
+        let mut trailing_delta = None;
         let (order_type, price, tif) = match place.kind {
             types::OrderKind::Market => (OrderType::Market, None, None),
             types::OrderKind::Limit(price, tif) => {
@@ -88,6 +116,37 @@ impl<'a> TryFrom<&'a exc_core::types::PlaceOrder> for PlaceOrder {
                 (OrderType::Limit, Some(price), tif)
             }
             types::OrderKind::PostOnly(price) => (OrderType::LimitMaker, Some(price), None),
+            types::OrderKind::StopMarket(trigger) | types::OrderKind::TakeProfitMarket(trigger) => {
+                last_price_trigger(&trigger)?;
+                stop_price = Some(trigger.price);
+                let order_type = if matches!(place.kind, types::OrderKind::StopMarket(_)) {
+                    OrderType::StopLoss
+                } else {
+                    OrderType::TakeProfit
+                };
+                (order_type, None, None)
+            }
+            types::OrderKind::StopLimit(trigger, price)
+            | types::OrderKind::TakeProfitLimit(trigger, price) => {
+                last_price_trigger(&trigger)?;
+                stop_price = Some(trigger.price);
+                let order_type = if matches!(place.kind, types::OrderKind::StopLimit(..)) {
+                    OrderType::StopLossLimit
+                } else {
+                    OrderType::TakeProfitLimit
+                };
+                (order_type, Some(price), Some(TimeInForce::Gtc))
+            }
+            types::OrderKind::TrailingStop(trailing) => {
+                if trailing.activation.is_some() || trailing.source != types::TriggerSource::Last {
+                    return Err(RestError::Exchange(ExchangeError::Unsupported(anyhow::anyhow!(
+                        "only trailing stops on the last price without activation are supported by binance spot"
+                    ))));
+                }
+                // The trailing delta is in BIPS.
+                trailing_delta = Some((trailing.callback_rate * Decimal::from(10_000)).round());
+                (OrderType::StopLoss, None, None)
+            }
         };
         Ok(Self {
             symbol: req.opts.instrument().to_uppercase(),
@@ -96,10 +155,69 @@ impl<'a> TryFrom<&'a exc_core::types::PlaceOrder> for PlaceOrder {
             quantity: Some(place.size.abs()),
             quote_order_qty: None,
             price,
+            stop_price,
+            trailing_delta,
             new_client_order_id: req.opts.client_id().map(|s| s.to_string()),
             time_in_force: tif,
             side_effect_type: None,
+            is_isolated: None,
             new_order_resp_type: Some(RespType::Ack),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exc_core::types::{Place, PlaceOrderOptions, Trigger, TriggerSource};
+    use rust_decimal_macros::dec;
+    use serde_json::{json, Value};
+
+    fn place(place: Place) -> Result<Value, RestError> {
+        let req = place.into_request(&PlaceOrderOptions::new("btcusdt"));
+        Ok(serde_json::to_value(PlaceOrder::try_from(&req)?)?)
+    }
+
+    #[test]
+    fn test_conditional_orders() -> anyhow::Result<()> {
+        let trigger = Trigger::new(dec!(90));
+        let body = place(Place::with_size(dec!(-1)).stop_market(trigger))?;
+        assert_eq!(body["symbol"], "BTCUSDT");
+        assert_eq!(body["side"], "SELL");
+        assert_eq!(body["type"], "STOP_LOSS");
+        assert_eq!(body["stopPrice"], json!(dec!(90)));
+        assert!(body.get("price").is_none());
+
+        let body = place(Place::with_size(dec!(-1)).stop_limit(trigger, dec!(89)))?;
+        assert_eq!(body["type"], "STOP_LOSS_LIMIT");
+        assert_eq!(body["price"], json!(dec!(89)));
+        assert_eq!(body["timeInForce"], "GTC");
+
+        let trigger = Trigger::new(dec!(110));
+        let body = place(Place::with_size(dec!(1)).take_profit_market(trigger))?;
+        assert_eq!(body["side"], "BUY");
+        assert_eq!(body["type"], "TAKE_PROFIT");
+        assert_eq!(body["stopPrice"], json!(dec!(110)));
+
+        let body = place(Place::with_size(dec!(1)).take_profit_limit(trigger, dec!(111)))?;
+        assert_eq!(body["type"], "TAKE_PROFIT_LIMIT");
+        assert_eq!(body["price"], json!(dec!(111)));
+
+        // The trailing delta is in BIPS.
+        let body = place(Place::with_size(dec!(-1)).trailing_stop(dec!(0.015), None))?;
+        assert_eq!(body["type"], "STOP_LOSS");
+        assert_eq!(body["trailingDelta"], json!(dec!(150)));
+        assert!(body.get("stopPrice").is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_conditional_orders() {
+        let mark = Trigger::new(dec!(90)).with_source(TriggerSource::Mark);
+        assert!(place(Place::with_size(dec!(-1)).stop_market(mark)).is_err());
+        assert!(place(Place::with_size(dec!(1)).take_profit_limit(mark, dec!(91))).is_err());
+        assert!(
+            place(Place::with_size(dec!(-1)).trailing_stop(dec!(0.01), Some(dec!(100)))).is_err()
+        );
+    }
+}
@@ -1,13 +1,14 @@
-use exc_core::types;
+use exc_core::{types, weight::Weight, ExchangeError};
 use rust_decimal::Decimal;
 use serde::Serialize;
 
 use crate::{
     http::{
         error::RestError,
+        limit::order_weight,
         request::{Payload, Rest, RestEndpoint},
     },
-    types::trading::{OrderSide, OrderType, PositionSide, TimeInForce},
+    types::trading::{self, OrderSide, OrderType, PositionSide, TimeInForce},
 };
 
 pub use super::RespType;
@@ -26,7 +27,11 @@ pub struct PlaceOrder {
     #[serde(rename = "type")]
     pub order_type: OrderType,
     /// Reduce only.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// It is serialized as a string since `batchOrders` does not accept json booleans.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_bool_as_str"
+    )]
     pub reduce_only: Option<bool>,
     /// Quantity.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -63,6 +68,16 @@ pub struct PlaceOrder {
     pub new_order_resp_type: Option<RespType>,
 }
 
+fn serialize_bool_as_str<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match value {
+        Some(value) => serializer.serialize_str(if *value { "true" } else { "false" }),
+        None => serializer.serialize_none(),
+    }
+}
+
 impl<'a> TryFrom<&'a types::PlaceOrder> for PlaceOrder {
     type Error = RestError;
 
@@ -75,6 +90,28 @@ impl<'a> TryFrom<&'a types::PlaceOrder> for PlaceOrder {
         } else {
             OrderSide::Sell
         };
+        if let Some(mode) = req.opts.margin_mode() {
+            return Err(RestError::Exchange(ExchangeError::Unsupported(anyhow::anyhow!(
+                "the margin mode of `binance-u` is set per symbol instead of per order, got `{mode:?}`"
+            ))));
+        }
+        let position_side = match req.opts.position_side() {
+            types::PositionSide::Net => PositionSide::Both,
+            types::PositionSide::Long => PositionSide::Long,
+            types::PositionSide::Short => PositionSide::Short,
+        };
+        let reduce_only = req.opts.reduce_only();
+        if reduce_only && !matches!(position_side, PositionSide::Both) {
+            return Err(RestError::Exchange(ExchangeError::Unsupported(
+                anyhow::anyhow!(
+                    "reduce-only orders are not available in hedge mode on `binance-u`"
+                ),
+            )));
+        }
+        let mut stop_price = None;
+        let mut activation_price = None;
+        let mut callback_rate = None;
+        let mut working_type = None;
         let (order_type, price, tif) = match place.kind {
             types::OrderKind::Market => (OrderType::Market, None, None),
             types::OrderKind::Limit(price, tif) => {
@@ -88,22 +125,50 @@ impl<'a> TryFrom<&'a types::PlaceOrder> for PlaceOrder {
             types::OrderKind::PostOnly(price) => {
                 (OrderType::Limit, Some(price), Some(TimeInForce::Gtx))
             }
+            types::OrderKind::StopMarket(trigger) | types::OrderKind::TakeProfitMarket(trigger) => {
+                stop_price = Some(trigger.price);
+                working_type = Some(trading::working_type(trigger.source)?.to_string());
+                let order_type = if matches!(place.kind, types::OrderKind::StopMarket(_)) {
+                    OrderType::StopMarket
+                } else {
+                    OrderType::TakeProfitMarket
+                };
+                (order_type, None, None)
+            }
+            types::OrderKind::StopLimit(trigger, price)
+            | types::OrderKind::TakeProfitLimit(trigger, price) => {
+                stop_price = Some(trigger.price);
+                working_type = Some(trading::working_type(trigger.source)?.to_string());
+                let order_type = if matches!(place.kind, types::OrderKind::StopLimit(..)) {
+                    OrderType::Stop
+                } else {
+                    OrderType::TakeProfit
+                };
+                (order_type, Some(price), Some(TimeInForce::Gtc))
+            }
+            types::OrderKind::TrailingStop(trailing) => {
+                // The callback rate is in percentage.
+                callback_rate = Some((trailing.callback_rate * Decimal::ONE_HUNDRED).normalize());
+                activation_price = trailing.activation;
+                working_type = Some(trading::working_type(trailing.source)?.to_string());
+                (OrderType::TrailingStopMarket, None, None)
+            }
         };
         Ok(Self {
             symbol: req.opts.instrument().to_uppercase(),
             side,
-            position_side: PositionSide::Both,
+            position_side,
             order_type,
-            reduce_only: None,
+            reduce_only: reduce_only.then_some(true),
             quantity: Some(place.size.abs()),
             price,
             new_client_order_id: req.opts.client_id().map(|s| s.to_string()),
-            stop_price: None,
+            stop_price,
             close_position: None,
-            activation_price: None,
-            callback_rate: None,
+            activation_price,
+            callback_rate,
             time_in_force: tif,
-            working_type: None,
+            working_type,
             price_protect: None,
             new_order_resp_type: None,
         })
@@ -136,7 +201,399 @@ impl Rest for PlaceOrder {
         Ok(serde_json::to_value(self)?)
     }
 
+    fn weight(&self, _endpoint: &RestEndpoint) -> Weight {
+        order_weight(1, 1)
+    }
+
+    fn is_idempotent(&self) -> bool {
+        self.new_client_order_id.is_some()
+    }
+
+    fn to_payload(&self) -> Payload {
+        Payload::new(self.clone())
+    }
+}
+
+/// Amend (modify) order.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendOrder {
+    /// Symbol.
+    pub symbol: String,
+    /// Order id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
+    /// Client id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orig_client_order_id: Option<String>,
+    /// Side.
+    pub side: OrderSide,
+    /// Quantity.
+    pub quantity: Decimal,
+    /// Price.
+    pub price: Decimal,
+}
+
+impl<'a> TryFrom<&'a types::AmendOrder> for AmendOrder {
+    type Error = RestError;
+
+    fn try_from(req: &'a types::AmendOrder) -> Result<Self, Self::Error> {
+        let side = if req.new_size.is_zero() {
+            return Err(RestError::PlaceZeroSize);
+        } else if req.new_size.is_sign_positive() {
+            OrderSide::Buy
+        } else {
+            OrderSide::Sell
+        };
+        Ok(Self {
+            symbol: req.instrument.to_uppercase(),
+            order_id: None,
+            orig_client_order_id: Some(req.id.as_str().to_string()),
+            side,
+            quantity: req.new_size.abs(),
+            price: req.new_price,
+        })
+    }
+}
+
+impl Rest for AmendOrder {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::PUT)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/order".to_string()),
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`AmendOrder` only available on `binance-u`"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn weight(&self, _endpoint: &RestEndpoint) -> Weight {
+        order_weight(1, 1)
+    }
+
     fn to_payload(&self) -> Payload {
         Payload::new(self.clone())
     }
 }
+
+/// The maximum number of orders to place in a batch,
+/// larger batches are rejected with [`ExchangeError::Unsupported`].
+const MAX_BATCH_ORDERS: usize = 5;
+
+/// The maximum number of orders to cancel in a batch,
+/// larger batches are rejected with [`ExchangeError::Unsupported`].
+const MAX_BATCH_CANCEL_ORDERS: usize = 10;
+
+fn check_batch_size(len: usize, max: usize) -> Result<(), RestError> {
+    if len == 0 {
+        Err(RestError::Exchange(ExchangeError::Other(anyhow::anyhow!(
+            "empty batch"
+        ))))
+    } else if len > max {
+        Err(RestError::Exchange(ExchangeError::Unsupported(anyhow::anyhow!(
+            "too many orders in a batch: {len} > {max}, split the batch or send the orders one by one"
+        ))))
+    } else {
+        Ok(())
+    }
+}
+
+/// Place a batch of orders.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaceOrders {
+    /// Orders in json.
+    pub batch_orders: String,
+}
+
+impl<'a> TryFrom<&'a types::PlaceOrders> for PlaceOrders {
+    type Error = RestError;
+
+    fn try_from(req: &'a types::PlaceOrders) -> Result<Self, Self::Error> {
+        check_batch_size(req.0.len(), MAX_BATCH_ORDERS)?;
+        let orders = req
+            .0
+            .iter()
+            .map(PlaceOrder::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            batch_orders: serde_json::to_string(&orders)?,
+        })
+    }
+}
+
+impl Rest for PlaceOrders {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::POST)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/batchOrders".to_string()),
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`PlaceOrders` only available on `binance-u`"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn weight(&self, _endpoint: &RestEndpoint) -> Weight {
+        let orders = serde_json::from_str::<Vec<serde_json::Value>>(&self.batch_orders)
+            .map(|orders| orders.len() as u64)
+            .unwrap_or(MAX_BATCH_ORDERS as u64);
+        order_weight(5, orders)
+    }
+
+    fn is_idempotent(&self) -> bool {
+        serde_json::from_str::<Vec<serde_json::Value>>(&self.batch_orders)
+            .map(|orders| {
+                orders
+                    .iter()
+                    .all(|order| order.get("newClientOrderId").is_some())
+            })
+            .unwrap_or(false)
+    }
+
+    fn to_payload(&self) -> Payload {
+        Payload::new(self.clone())
+    }
+}
+
+/// Cancel a batch of orders of the same symbol.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelOrders {
+    /// Symbol.
+    pub symbol: String,
+    /// Client ids in json.
+    pub orig_client_order_id_list: String,
+}
+
+impl<'a> TryFrom<&'a types::CancelOrders> for CancelOrders {
+    type Error = RestError;
+
+    fn try_from(req: &'a types::CancelOrders) -> Result<Self, Self::Error> {
+        check_batch_size(req.0.len(), MAX_BATCH_CANCEL_ORDERS)?;
+        let symbol = req.0[0].instrument.to_uppercase();
+        let mut ids = Vec::with_capacity(req.0.len());
+        for order in req.0.iter() {
+            if !order.instrument.eq_ignore_ascii_case(&symbol) {
+                return Err(RestError::Exchange(ExchangeError::Other(anyhow::anyhow!(
+                    "all orders in a batch cancellation must be of the same instrument"
+                ))));
+            }
+            ids.push(order.id.as_str());
+        }
+        Ok(Self {
+            symbol,
+            orig_client_order_id_list: serde_json::to_string(&ids)?,
+        })
+    }
+}
+
+impl Rest for CancelOrders {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::DELETE)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/batchOrders".to_string()),
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`CancelOrders` only available on `binance-u`"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn to_payload(&self) -> Payload {
+        Payload::new(self.clone())
+    }
+}
+
+/// Cancel all open orders of a symbol after the countdown.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CountdownCancelAll {
+    /// Symbol.
+    pub symbol: String,
+    /// Countdown time in milliseconds (`0` to disarm).
+    pub countdown_time: u64,
+}
+
+impl Rest for CountdownCancelAll {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::POST)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/countdownCancelAll".to_string()),
+            _ => Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                "`CancelAllAfter` only available on `binance-u`"
+            ))),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn to_payload(&self) -> Payload {
+        Payload::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exc_core::types::{Place, PlaceOrderOptions, Trigger, TriggerSource};
+    use rust_decimal_macros::dec;
+    use serde_json::{json, Value};
+
+    fn place(place: Place) -> Result<Value, RestError> {
+        let req = place.into_request(&PlaceOrderOptions::new("btcusdt"));
+        Ok(serde_json::to_value(PlaceOrder::try_from(&req)?)?)
+    }
+
+    #[test]
+    fn test_conditional_orders() -> anyhow::Result<()> {
+        let trigger = Trigger::new(dec!(90)).with_source(TriggerSource::Mark);
+        let body = place(Place::with_size(dec!(-1)).stop_market(trigger))?;
+        assert_eq!(body["symbol"], "BTCUSDT");
+        assert_eq!(body["side"], "SELL");
+        assert_eq!(body["type"], "STOP_MARKET");
+        assert_eq!(body["stopPrice"], json!(dec!(90)));
+        assert_eq!(body["workingType"], "MARK_PRICE");
+
+        let body = place(Place::with_size(dec!(-1)).stop_limit(trigger, dec!(89)))?;
+        assert_eq!(body["type"], "STOP");
+        assert_eq!(body["price"], json!(dec!(89)));
+        assert_eq!(body["timeInForce"], "GTC");
+
+        let trigger = Trigger::new(dec!(110));
+        let body = place(Place::with_size(dec!(1)).take_profit_market(trigger))?;
+        assert_eq!(body["side"], "BUY");
+        assert_eq!(body["type"], "TAKE_PROFIT_MARKET");
+        assert_eq!(body["workingType"], "CONTRACT_PRICE");
+
+        let body = place(Place::with_size(dec!(1)).take_profit_limit(trigger, dec!(111)))?;
+        assert_eq!(body["type"], "TAKE_PROFIT");
+        assert_eq!(body["stopPrice"], json!(dec!(110)));
+        assert_eq!(body["price"], json!(dec!(111)));
+
+        // The callback rate is in percentage.
+        let body = place(Place::with_size(dec!(-1)).trailing_stop(dec!(0.015), Some(dec!(100))))?;
+        assert_eq!(body["type"], "TRAILING_STOP_MARKET");
+        assert_eq!(body["callbackRate"], json!(dec!(1.5)));
+        assert_eq!(body["activationPrice"], json!(dec!(100)));
+        assert_eq!(body["workingType"], "CONTRACT_PRICE");
+
+        let index = Trigger::new(dec!(90)).with_source(TriggerSource::Index);
+        assert!(place(Place::with_size(dec!(-1)).stop_market(index)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_weight() -> anyhow::Result<()> {
+        let opts = PlaceOrderOptions::new("btcusdt");
+        let orders = types::PlaceOrders::new(
+            [dec!(1), dec!(2), dec!(3)].map(|size| Place::with_size(size).into_request(&opts)),
+        );
+        let req = PlaceOrders::try_from(&orders)?;
+        assert_eq!(
+            req.weight(&RestEndpoint::UsdMarginFutures),
+            order_weight(5, 3)
+        );
+
+        // Larger batches must be split by the caller.
+        let orders =
+            types::PlaceOrders::new((0..6).map(|_| Place::with_size(dec!(1)).into_request(&opts)));
+        assert!(matches!(
+            PlaceOrders::try_from(&orders),
+            Err(RestError::Exchange(ExchangeError::Unsupported(_)))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_position_options() -> anyhow::Result<()> {
+        let mut opts = PlaceOrderOptions::new("btcusdt");
+        opts.with_reduce_only(true);
+        let req = Place::with_size(dec!(-1)).into_request(&opts);
+        let body = serde_json::to_value(PlaceOrder::try_from(&req)?)?;
+        assert_eq!(body["positionSide"], "BOTH");
+        // `batchOrders` only accepts the string-encoded booleans.
+        assert_eq!(body["reduceOnly"], "true");
+        let batch = PlaceOrders::try_from(&types::PlaceOrders::new([req]))?;
+        assert!(batch.batch_orders.contains(r#""reduceOnly":"true""#));
+
+        opts.with_reduce_only(false)
+            .with_position_side(types::PositionSide::Long);
+        let req = Place::with_size(dec!(1)).into_request(&opts);
+        let body = serde_json::to_value(PlaceOrder::try_from(&req)?)?;
+        assert_eq!(body["positionSide"], "LONG");
+        assert!(body.get("reduceOnly").is_none());
+
+        opts.with_reduce_only(true);
+        let req = Place::with_size(dec!(1)).into_request(&opts);
+        assert!(matches!(
+            PlaceOrder::try_from(&req),
+            Err(RestError::Exchange(ExchangeError::Unsupported(_)))
+        ));
+
+        let mut opts = PlaceOrderOptions::new("btcusdt");
+        opts.with_margin_mode(types::MarginMode::Isolated);
+        let req = Place::with_size(dec!(1)).into_request(&opts);
+        assert!(matches!(
+            PlaceOrder::try_from(&req),
+            Err(RestError::Exchange(ExchangeError::Unsupported(_)))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,252 @@
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+use crate::http::{error::RestError, response::Data};
+
+use super::{Rest, RestEndpoint};
+
+/// Universal transfer between the wallets of the account.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransfer {
+    /// Transfer type, e.g. `MAIN_UMFUTURE`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Asset.
+    pub asset: String,
+    /// Amount.
+    pub amount: Decimal,
+}
+
+impl Rest for UniversalTransfer {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::POST)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures | RestEndpoint::EuropeanOptions => {
+                Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                    "`UniversalTransfer` only available on `binance-s`"
+                )))
+            }
+            RestEndpoint::Spot(_options) => Ok("/sapi/v1/asset/transfer".to_string()),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Query the universal transfer history.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryUniversalTransfer {
+    /// Transfer type, e.g. `MAIN_UMFUTURE`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Page size (max 100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<usize>,
+    /// Only keep the transfer of the given id in the response.
+    #[serde(skip)]
+    pub tran_id: Option<i64>,
+}
+
+impl Rest for QueryUniversalTransfer {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures | RestEndpoint::EuropeanOptions => {
+                Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                    "`QueryUniversalTransfer` only available on `binance-s`"
+                )))
+            }
+            RestEndpoint::Spot(_options) => Ok("/sapi/v1/asset/transfer".to_string()),
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn select(&self, data: Data) -> Data {
+        match (data, self.tran_id) {
+            (Data::UniversalTransferHistory(mut history), Some(id)) => {
+                history.rows.retain(|row| row.tran_id == id);
+                Data::UniversalTransferHistory(history)
+            }
+            (data, _) => data,
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Account type of the sub-account universal transfer.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccountType {
+    /// Spot.
+    Spot,
+    /// USDT-margin futures.
+    UsdtFuture,
+    /// Coin-margin futures.
+    CoinFuture,
+    /// Margin.
+    Margin,
+    /// Isolated margin.
+    IsolatedMargin,
+}
+
+/// Universal transfer involving sub-accounts (must be sent by the master account).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountUniversalTransfer {
+    /// Email of the sender (the master account if not set).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_email: Option<String>,
+    /// Email of the receiver (the master account if not set).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_email: Option<String>,
+    /// Account type of the sender.
+    pub from_account_type: AccountType,
+    /// Account type of the receiver.
+    pub to_account_type: AccountType,
+    /// Asset.
+    pub asset: String,
+    /// Amount.
+    pub amount: Decimal,
+}
+
+impl Rest for SubAccountUniversalTransfer {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::POST)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures | RestEndpoint::EuropeanOptions => {
+                Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                    "`SubAccountUniversalTransfer` only available on `binance-s`"
+                )))
+            }
+            RestEndpoint::Spot(_options) => {
+                Ok("/sapi/v1/sub-account/universalTransfer".to_string())
+            }
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
+
+/// Query the universal transfer history involving sub-accounts.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuerySubAccountUniversalTransfer {
+    /// Email of the sender.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_email: Option<String>,
+    /// Email of the receiver.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_email: Option<String>,
+    /// Limit (max 500).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    /// Only keep the transfer of the given id in the response.
+    #[serde(skip)]
+    pub tran_id: Option<i64>,
+}
+
+impl Rest for QuerySubAccountUniversalTransfer {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::UsdMarginFutures | RestEndpoint::EuropeanOptions => {
+                Err(RestError::UnsupportedEndpoint(anyhow::anyhow!(
+                    "`QuerySubAccountUniversalTransfer` only available on `binance-s`"
+                )))
+            }
+            RestEndpoint::Spot(_options) => {
+                Ok("/sapi/v1/sub-account/universalTransfer".to_string())
+            }
+        }
+    }
+
+    fn need_apikey(&self) -> bool {
+        true
+    }
+
+    fn need_sign(&self) -> bool {
+        true
+    }
+
+    fn serialize(&self, _endpoint: &RestEndpoint) -> Result<serde_json::Value, RestError> {
+        Ok(serde_json::to_value(self)?)
+    }
+
+    fn select(&self, data: Data) -> Data {
+        match (data, self.tran_id) {
+            (Data::SubAccountTransferHistory(mut history), Some(id)) => {
+                history.result.retain(|row| row.tran_id == id);
+                Data::SubAccountTransferHistory(history)
+            }
+            (data, _) => data,
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(self.clone())
+    }
+}
@@ -21,3 +21,25 @@ impl Rest for Ping {
         super::Payload::new(*self)
     }
 }
+
+/// Get the server time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ServerTime;
+
+impl Rest for ServerTime {
+    fn method(&self, _endpoint: &RestEndpoint) -> Result<http::Method, RestError> {
+        Ok(http::Method::GET)
+    }
+
+    fn to_path(&self, endpoint: &RestEndpoint) -> Result<String, RestError> {
+        match endpoint {
+            RestEndpoint::Spot(_) => Ok("/api/v3/time".to_string()),
+            RestEndpoint::UsdMarginFutures => Ok("/fapi/v1/time".to_string()),
+            RestEndpoint::EuropeanOptions => Ok("/eapi/v1/time".to_string()),
+        }
+    }
+
+    fn to_payload(&self) -> super::Payload {
+        super::Payload::new(*self)
+    }
+}
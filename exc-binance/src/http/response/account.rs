@@ -1,7 +1,11 @@
+use exc_core::Asset;
 use rust_decimal::Decimal;
 use serde::Deserialize;
 
-use crate::http::error::RestError;
+use crate::{
+    http::error::RestError,
+    types::trading::{MarginType, PositionSide},
+};
 
 use super::Data;
 
@@ -35,6 +39,7 @@ impl TryFrom<Data> for SubAccounts {
     fn try_from(value: Data) -> Result<Self, Self::Error> {
         match value {
             Data::SubAccounts(data) => Ok(data),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
             _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
                 "{value:?}"
             ))),
@@ -70,6 +75,7 @@ impl TryFrom<Data> for SubAccountBalances {
     fn try_from(value: Data) -> Result<Self, Self::Error> {
         match value {
             Data::SubAccountBalances(data) => Ok(data),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
             _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
                 "{value:?}"
             ))),
@@ -232,6 +238,7 @@ impl TryFrom<Data> for SubAccountFutures {
     fn try_from(value: Data) -> Result<Self, Self::Error> {
         match value {
             Data::SubAccountFutures(data) => Ok(data),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
             _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
                 "{value:?}"
             ))),
@@ -307,6 +314,134 @@ impl TryFrom<Data> for SubAccountFuturesPositions {
     fn try_from(value: Data) -> Result<Self, Self::Error> {
         match value {
             Data::SubAccountFuturesPositions(data) => Ok(data),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// Balance of an asset in the spot account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotBalance {
+    /// Asset.
+    pub asset: Asset,
+    /// Free.
+    pub free: Decimal,
+    /// Locked.
+    pub locked: Decimal,
+}
+
+/// Spot account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotAccount {
+    /// Can trade?
+    pub can_trade: bool,
+    /// Can withdraw?
+    pub can_withdraw: bool,
+    /// Can deposit?
+    pub can_deposit: bool,
+    /// Update time.
+    pub update_time: i64,
+    /// Account type.
+    pub account_type: String,
+    /// Balances.
+    pub balances: Vec<SpotBalance>,
+}
+
+/// Balance of an asset in the USD-M futures account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesBalance {
+    /// Account alias.
+    pub account_alias: String,
+    /// Asset.
+    pub asset: Asset,
+    /// Wallet balance.
+    pub balance: Decimal,
+    /// Cross wallet balance.
+    pub cross_wallet_balance: Decimal,
+    /// Unrealized profit of crossed positions.
+    pub cross_un_pnl: Decimal,
+    /// Available balance.
+    pub available_balance: Decimal,
+    /// Max withdraw amount.
+    pub max_withdraw_amount: Decimal,
+    /// Update time.
+    pub update_time: i64,
+}
+
+/// Balances of the account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Balances {
+    /// USD-M futures.
+    UsdMarginFutures(Vec<FuturesBalance>),
+    /// Spot.
+    Spot(SpotAccount),
+}
+
+impl TryFrom<Data> for Balances {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::Balances(data) => Ok(data),
+            _ if value.is_empty_list() => Ok(Self::UsdMarginFutures(vec![])),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// Position information of USD-M futures.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionRisk {
+    /// Symbol.
+    pub symbol: String,
+    /// Position amount.
+    pub position_amt: Decimal,
+    /// Entry price.
+    pub entry_price: Decimal,
+    /// Mark price.
+    pub mark_price: Decimal,
+    /// Unrealized profit.
+    pub un_realized_profit: Decimal,
+    /// Liquidation price.
+    pub liquidation_price: Decimal,
+    /// Leverage.
+    pub leverage: Decimal,
+    /// Margin type.
+    pub margin_type: MarginType,
+    /// Isolated margin.
+    pub isolated_margin: Decimal,
+    /// Position side.
+    pub position_side: PositionSide,
+    /// Notional.
+    pub notional: Decimal,
+    /// Update time.
+    pub update_time: i64,
+}
+
+/// Positions.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(transparent)]
+pub struct Positions(pub Vec<PositionRisk>);
+
+impl TryFrom<Data> for Positions {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::Positions(data) => Ok(data),
+            _ if value.is_empty_list() => Ok(Self(vec![])),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
             _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
                 "{value:?}"
             ))),
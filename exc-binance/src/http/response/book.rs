@@ -0,0 +1,51 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http::error::RestError;
+
+use super::Data;
+
+/// Order book snapshot.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderBook {
+    /// Last update id.
+    pub last_update_id: u64,
+    /// Message output time (only for futures).
+    #[serde(rename = "E")]
+    pub event_ts: Option<i64>,
+    /// Transaction time (only for futures).
+    #[serde(rename = "T")]
+    pub trade_ts: Option<i64>,
+    /// Bids.
+    pub bids: Vec<(Decimal, Decimal)>,
+    /// Asks.
+    pub asks: Vec<(Decimal, Decimal)>,
+}
+
+impl TryFrom<Data> for OrderBook {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::OrderBook(book) => Ok(book),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_book_error() -> anyhow::Result<()> {
+        let data = serde_json::from_str::<Data>(r#"{"code":-1121,"msg":"Invalid symbol."}"#)?;
+        let err = OrderBook::try_from(data).unwrap_err();
+        assert!(matches!(err, RestError::Api(-1121, ref msg) if msg == "Invalid symbol."));
+        Ok(())
+    }
+}
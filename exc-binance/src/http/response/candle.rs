@@ -6,6 +6,7 @@ use crate::http::error::RestError;
 use super::Data;
 
 /// Candle in list form.
+#[allow(unused)]
 #[derive(Debug, Deserialize)]
 pub struct Candle(
     /// Open time.
@@ -71,6 +72,7 @@ impl TryFrom<Data> for Vec<Candle> {
     fn try_from(value: Data) -> Result<Self, Self::Error> {
         match value {
             Data::Candles(c) => Ok(c),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
             _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
                 "{value:?}"
             ))),
@@ -93,6 +95,7 @@ impl TryFrom<Data> for CandlesKind {
         match value {
             Data::Candles(c) => Ok(Self::Candles(c)),
             Data::OptionsCandles(c) => Ok(Self::OptionsCandles(c)),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
             _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
                 "{value:?}"
             ))),
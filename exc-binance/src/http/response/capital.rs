@@ -0,0 +1,119 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http::error::RestError;
+
+use super::Data;
+
+/// Deposit record.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    /// Id.
+    pub id: String,
+    /// Amount.
+    pub amount: Decimal,
+    /// Coin.
+    pub coin: String,
+    /// Network.
+    pub network: String,
+    /// Status (`0` pending, `6` credited but cannot withdraw, `7` wrong deposit,
+    /// `8` waiting user confirm, `1` success, `2` rejected).
+    pub status: i64,
+    /// Address.
+    pub address: String,
+    /// Transaction id.
+    #[serde(default)]
+    pub tx_id: String,
+    /// Insert time.
+    pub insert_time: i64,
+    /// Complete time.
+    #[serde(default)]
+    pub complete_time: Option<i64>,
+}
+
+/// Deposit records.
+#[derive(Debug, Clone)]
+pub struct Deposits(pub Vec<DepositRecord>);
+
+impl TryFrom<Data> for Deposits {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::Deposits(records) => Ok(Self(records)),
+            _ if value.is_empty_list() => Ok(Self(vec![])),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// Withdraw record.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecord {
+    /// Id.
+    pub id: String,
+    /// Amount.
+    pub amount: Decimal,
+    /// Transaction fee.
+    pub transaction_fee: Decimal,
+    /// Coin.
+    pub coin: String,
+    /// Network.
+    pub network: String,
+    /// Status (`0` email sent, `1` cancelled, `2` awaiting approval, `3` rejected,
+    /// `4` processing, `5` failure, `6` completed).
+    pub status: i64,
+    /// Address.
+    pub address: String,
+    /// Transaction id.
+    #[serde(default)]
+    pub tx_id: String,
+    /// Apply time (`YYYY-MM-DD HH:MM:SS` in UTC).
+    pub apply_time: String,
+    /// Complete time (`YYYY-MM-DD HH:MM:SS` in UTC).
+    #[serde(default)]
+    pub complete_time: Option<String>,
+}
+
+/// Withdraw records.
+#[derive(Debug, Clone)]
+pub struct Withdrawals(pub Vec<WithdrawRecord>);
+
+impl TryFrom<Data> for Withdrawals {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::Withdrawals(records) => Ok(Self(records)),
+            _ if value.is_empty_list() => Ok(Self(vec![])),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capital_data() -> anyhow::Result<()> {
+        let deposits = r#"[{"id":"769800519366885376","amount":"0.001","coin":"BNB","network":"BNB","status":1,"address":"bnb136ns6lfw4zs5hg4n85vdthaad7hq5m4gtkgf23","addressTag":"101764890","txId":"98A3EA560C6B3336D348B6C83F0F95ECE4F1F5919E94BD006E5BF3BF264FACFC","insertTime":1661493146000,"completeTime":1661493146000,"transferType":0,"confirmTimes":"1/1","unlockConfirm":0,"walletType":0}]"#;
+        let Deposits(deposits) = Deposits::try_from(serde_json::from_str::<Data>(deposits)?)?;
+        assert_eq!(deposits[0].insert_time, 1661493146000);
+        let withdrawals = r#"[{"id":"b6ae22b3aa844210a7041aee7589627c","amount":"8.91000000","transactionFee":"0.004","coin":"USDT","status":6,"address":"0x94df8b352de7f46f64b01d3666bf6e936e44ce60","txId":"0xb5ef8c13b968a406cc62a93a8bd80f9e9a906ef1b3fcf20a2e48573c17659268","applyTime":"2019-10-12 11:12:02","network":"ETH","transferType":0,"info":"","confirmNo":3,"walletType":1,"txKey":"","completeTime":"2023-03-23 16:52:41"}]"#;
+        let Withdrawals(withdrawals) =
+            Withdrawals::try_from(serde_json::from_str::<Data>(withdrawals)?)?;
+        assert_eq!(withdrawals[0].apply_time, "2019-10-12 11:12:02");
+        let Deposits(empty) = Deposits::try_from(serde_json::from_str::<Data>("[]")?)?;
+        assert!(empty.is_empty());
+        Ok(())
+    }
+}
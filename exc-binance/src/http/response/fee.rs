@@ -0,0 +1,80 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http::error::RestError;
+
+use super::Data;
+
+/// Commission rate of a symbol (USD-M futures).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRate {
+    /// Symbol.
+    pub symbol: String,
+    /// Maker commission rate.
+    pub maker_commission_rate: Decimal,
+    /// Taker commission rate.
+    pub taker_commission_rate: Decimal,
+}
+
+/// Trade fee of a symbol (spot).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFee {
+    /// Symbol.
+    pub symbol: String,
+    /// Maker commission rate.
+    pub maker_commission: Decimal,
+    /// Taker commission rate.
+    pub taker_commission: Decimal,
+}
+
+/// Commission rates.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum CommissionRates {
+    /// USD-M futures.
+    UsdMarginFutures(CommissionRate),
+    /// Spot.
+    Spot(Vec<TradeFee>),
+}
+
+impl TryFrom<Data> for CommissionRates {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::CommissionRates(rates) => Ok(rates),
+            _ if value.is_empty_list() => Ok(Self::Spot(vec![])),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commission_rates_data() -> anyhow::Result<()> {
+        let futures =
+            r#"{"symbol":"BTCUSDT","makerCommissionRate":"0.0002","takerCommissionRate":"0.0004"}"#;
+        let CommissionRates::UsdMarginFutures(rate) =
+            CommissionRates::try_from(serde_json::from_str::<Data>(futures)?)?
+        else {
+            anyhow::bail!("expecting futures commission rate");
+        };
+        assert_eq!(rate.taker_commission_rate.to_string(), "0.0004");
+        let spot = r#"[{"symbol":"ADABNB","makerCommission":"0.001","takerCommission":"0.001"}]"#;
+        let CommissionRates::Spot(fees) =
+            CommissionRates::try_from(serde_json::from_str::<Data>(spot)?)?
+        else {
+            anyhow::bail!("expecting spot trade fees");
+        };
+        assert_eq!(fees[0].symbol, "ADABNB");
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http::error::RestError;
+
+use super::Data;
+
+/// Funding rate.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    /// Symbol.
+    pub symbol: String,
+    /// Funding rate.
+    pub funding_rate: Decimal,
+    /// Funding time.
+    pub funding_time: i64,
+}
+
+/// Funding rates.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FundingRates(pub Vec<FundingRate>);
+
+impl TryFrom<Data> for FundingRates {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::FundingRates(rates) => Ok(rates),
+            _ if value.is_empty_list() => Ok(Self(Vec::new())),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// Premium index (mark price and funding rate).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndex {
+    /// Symbol.
+    pub symbol: String,
+    /// Mark price.
+    pub mark_price: Decimal,
+    /// Index price.
+    pub index_price: Decimal,
+    /// The funding rate to be settled at the next funding time.
+    pub last_funding_rate: Decimal,
+    /// Next funding time.
+    pub next_funding_time: i64,
+    /// Timestamp.
+    pub time: i64,
+}
+
+impl TryFrom<Data> for PremiumIndex {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::PremiumIndex(index) => Ok(index),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
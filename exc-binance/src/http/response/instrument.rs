@@ -176,7 +176,7 @@ impl UFSymbol {
                     let date = self
                         .symbol
                         .split('_')
-                        .last()
+                        .next_back()
                         .ok_or(RestError::MissingDateForFutures)?;
                     ExcSymbol::futures_with_str(&self.base_asset, &self.quote_asset, date)
                         .ok_or(RestError::FailedToBuildExcSymbol)
@@ -341,6 +341,7 @@ impl TryFrom<Data> for ExchangeInfo {
     fn try_from(value: Data) -> Result<Self, Self::Error> {
         match value {
             Data::ExchangeInfo(v) => Ok(v),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
             _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
                 "{value:?}"
             ))),
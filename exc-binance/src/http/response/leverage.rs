@@ -0,0 +1,79 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http::error::RestError;
+
+use super::Data;
+
+/// The leverage of a symbol changed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageChanged {
+    /// Symbol.
+    pub symbol: String,
+    /// Leverage.
+    pub leverage: Decimal,
+}
+
+impl TryFrom<Data> for LeverageChanged {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::LeverageChanged(data) => Ok(data),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// No need to change the margin type.
+const MARGIN_TYPE_UNCHANGED: i64 = -4046;
+
+/// No need to change the position side.
+const POSITION_MODE_UNCHANGED: i64 = -4059;
+
+/// A configuration has been applied (or was already in place).
+#[derive(Debug, Clone, Copy)]
+pub struct Configured;
+
+impl TryFrom<Data> for Configured {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::Error(msg)
+                if matches!(
+                    msg.code,
+                    0 | 200 | MARGIN_TYPE_UNCHANGED | POSITION_MODE_UNCHANGED
+                ) =>
+            {
+                Ok(Configured)
+            }
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leverage_data() -> anyhow::Result<()> {
+        let changed = r#"{"leverage":21,"maxNotionalValue":"1000000","symbol":"BTCUSDT"}"#;
+        let changed = LeverageChanged::try_from(serde_json::from_str::<Data>(changed)?)?;
+        assert_eq!(changed.leverage, Decimal::from(21));
+        let unchanged = r#"{"code":-4046,"msg":"No need to change margin type."}"#;
+        Configured::try_from(serde_json::from_str::<Data>(unchanged)?)?;
+        let failed =
+            r#"{"code":-4047,"msg":"Margin type cannot be changed if there exists open orders."}"#;
+        assert!(Configured::try_from(serde_json::from_str::<Data>(failed)?).is_err());
+        Ok(())
+    }
+}
@@ -32,6 +32,7 @@ impl TryFrom<Data> for ListenKey {
     fn try_from(value: Data) -> Result<Self, Self::Error> {
         match value {
             Data::ListenKey(key) => Ok(key),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
             _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
                 "{value:?}"
             ))),
@@ -23,16 +23,54 @@ pub mod trading;
 /// Account.
 pub mod account;
 
+/// Book.
+pub mod book;
+
+/// Funding.
+pub mod funding;
+
+/// Open interest.
+pub mod open_interest;
+
+/// Trade.
+pub mod trade;
+
+/// Utils.
+pub mod utils;
+
+/// Fee rates.
+pub mod fee;
+
+/// Leverage and margin configuration.
+pub mod leverage;
+
+/// Transfer.
+pub mod transfer;
+
+/// Capital (deposits and withdrawals).
+pub mod capital;
+
 pub use self::{
     account::{
-        SubAccountBalances, SubAccountFutures, SubAccountFuturesPositions, SubAccountMargin,
-        SubAccounts,
+        Balances, Positions, SubAccountBalances, SubAccountFutures, SubAccountFuturesPositions,
+        SubAccountMargin, SubAccounts,
     },
+    book::OrderBook,
     candle::Candle,
+    capital::{DepositRecord, Deposits, WithdrawRecord, Withdrawals},
     error_message::ErrorMessage,
+    fee::CommissionRates,
+    funding::{FundingRates, PremiumIndex},
     instrument::{ExchangeInfo, SpotExchangeInfo, UFExchangeInfo},
+    leverage::{Configured, LeverageChanged},
     listen_key::ListenKey,
-    trading::Order,
+    open_interest::OpenInterestHistory,
+    trade::{AggTrades, HistoricalTrades},
+    trading::{AccountTrade, BatchOrderResult, BatchOrders, Order},
+    transfer::{
+        SubAccountTransferHistory, TransferRecords, TransferResult, UniversalTransferHistory,
+    },
+    utils::ServerTime,
 };
 
 /// Candles.
@@ -58,6 +96,18 @@ pub enum Data {
     Error(ErrorMessage),
     /// Order.
     Order(Order),
+    /// Account trades.
+    AccountTrades(Vec<AccountTrade>),
+    /// Orders.
+    Orders(Vec<Order>),
+    /// Batch orders.
+    BatchOrders(BatchOrders),
+    /// Balances.
+    Balances(Balances),
+    /// Positions.
+    Positions(Positions),
+    /// Order book.
+    OrderBook(OrderBook),
     /// Sub-accounts.
     SubAccounts(SubAccounts),
     /// Sub-account balances.
@@ -68,10 +118,46 @@ pub enum Data {
     SubAccountFutures(SubAccountFutures),
     /// Sub-account futures postions.
     SubAccountFuturesPositions(SubAccountFuturesPositions),
+    /// Funding rates.
+    FundingRates(FundingRates),
+    /// Premium index.
+    PremiumIndex(PremiumIndex),
+    /// Open interest history.
+    OpenInterestHistory(OpenInterestHistory),
+    /// Aggregate trades.
+    AggTrades(AggTrades),
+    /// Historical trades.
+    HistoricalTrades(HistoricalTrades),
+    /// Server time.
+    ServerTime(ServerTime),
+    /// Commission rates.
+    CommissionRates(CommissionRates),
+    /// Leverage changed.
+    LeverageChanged(LeverageChanged),
+    /// Transfer result.
+    TransferResult(TransferResult),
+    /// Universal transfer history.
+    UniversalTransferHistory(UniversalTransferHistory),
+    /// Sub-account transfer history.
+    SubAccountTransferHistory(SubAccountTransferHistory),
+    /// Deposits.
+    Deposits(Vec<DepositRecord>),
+    /// Withdrawals.
+    Withdrawals(Vec<WithdrawRecord>),
     /// Unknwon.
     Unknwon(Unknown),
 }
 
+impl Data {
+    /// Whether the data is an empty list.
+    ///
+    /// Since [`Data`] is untagged, an empty list is always parsed as [`Data::Candles`],
+    /// whatever the list the request expects.
+    pub fn is_empty_list(&self) -> bool {
+        matches!(self, Self::Candles(candles) if candles.is_empty())
+    }
+}
+
 impl TryFrom<Data> for Unknown {
     type Error = RestError;
 
@@ -92,6 +178,10 @@ pub struct RestResponse<T> {
 }
 
 impl<T> RestResponse<T> {
+    pub(crate) fn map(self, f: impl FnOnce(T) -> T) -> Self {
+        Self { data: f(self.data) }
+    }
+
     /// Into inner data.
     pub fn into_inner(self) -> T {
         self.data
@@ -111,6 +201,16 @@ impl<T> RestResponse<T> {
     {
         let status = resp.status();
         tracing::trace!("http response status: {}", resp.status());
+        let retry_after = resp
+            .headers()
+            .get(http::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(std::time::Duration::from_secs);
+        let rate_limited = |msg: anyhow::Error| match retry_after {
+            Some(after) => ExchangeError::rate_limited_with_retry_after(msg, after),
+            None => ExchangeError::RateLimited(msg),
+        };
         let bytes = hyper::body::to_bytes(resp.into_body())
             .await
             .map_err(RestError::from);
@@ -128,12 +228,12 @@ impl<T> RestResponse<T> {
                 },
             );
         let res = match status {
-            StatusCode::TOO_MANY_REQUESTS => Err(RestError::Exchange(ExchangeError::RateLimited(
-                anyhow!("too many requests"),
-            ))),
-            StatusCode::IM_A_TEAPOT => Err(RestError::Exchange(ExchangeError::RateLimited(
-                anyhow!("I'm a teapot"),
-            ))),
+            StatusCode::TOO_MANY_REQUESTS => Err(RestError::Exchange(rate_limited(anyhow!(
+                "too many requests"
+            )))),
+            StatusCode::IM_A_TEAPOT => {
+                Err(RestError::Exchange(rate_limited(anyhow!("I'm a teapot"))))
+            }
             StatusCode::SERVICE_UNAVAILABLE => Err(RestError::Exchange(match value {
                 Ok(msg) => ExchangeError::Unavailable(anyhow!("{msg}")),
                 Err(err) => ExchangeError::Unavailable(anyhow!("failed to read msg: {err}")),
@@ -0,0 +1,39 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http::error::RestError;
+
+use super::Data;
+
+/// Open interest statistics.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterestHist {
+    /// Symbol.
+    pub symbol: String,
+    /// Total open interest.
+    pub sum_open_interest: Decimal,
+    /// Total open interest value.
+    pub sum_open_interest_value: Decimal,
+    /// Timestamp.
+    pub timestamp: i64,
+}
+
+/// Open interest history.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OpenInterestHistory(pub Vec<OpenInterestHist>);
+
+impl TryFrom<Data> for OpenInterestHistory {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::OpenInterestHistory(history) => Ok(history),
+            _ if value.is_empty_list() => Ok(Self(Vec::new())),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
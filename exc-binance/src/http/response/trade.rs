@@ -0,0 +1,102 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http::error::RestError;
+
+use super::Data;
+
+/// Aggregate trade.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AggTrade {
+    /// Aggregate trade id.
+    #[serde(rename = "a")]
+    pub id: i64,
+    /// Price.
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    /// Size.
+    #[serde(rename = "q")]
+    pub size: Decimal,
+    /// First trade id.
+    #[serde(rename = "f")]
+    pub first_trade_id: i64,
+    /// Last trade id.
+    #[serde(rename = "l")]
+    pub last_trade_id: i64,
+    /// Timestamp.
+    #[serde(rename = "T")]
+    pub timestamp: i64,
+    /// Is the buyer the maker.
+    #[serde(rename = "m")]
+    pub buy_maker: bool,
+}
+
+/// Aggregate trades.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AggTrades(pub Vec<AggTrade>);
+
+impl TryFrom<Data> for AggTrades {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::AggTrades(trades) => Ok(trades),
+            _ if value.is_empty_list() => Ok(Self(Vec::new())),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// Historical trade.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoricalTrade {
+    /// Trade id.
+    pub id: i64,
+    /// Price.
+    pub price: Decimal,
+    /// Size.
+    pub qty: Decimal,
+    /// Timestamp.
+    pub time: i64,
+    /// Is the buyer the maker.
+    pub is_buyer_maker: bool,
+}
+
+/// Historical trades.
+#[derive(Debug, Clone, Deserialize)]
+pub struct HistoricalTrades(pub Vec<HistoricalTrade>);
+
+impl TryFrom<Data> for HistoricalTrades {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::HistoricalTrades(trades) => Ok(trades),
+            _ if value.is_empty_list() => Ok(Self(Vec::new())),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trades_data() -> anyhow::Result<()> {
+        let agg = r#"[{"a":26129,"p":"0.01633102","q":"4.70443515","f":27781,"l":27781,"T":1498793709153,"m":true,"M":true}]"#;
+        let trades = AggTrades::try_from(serde_json::from_str::<Data>(agg)?)?;
+        assert_eq!(trades.0[0].id, 26129);
+        let historical = r#"[{"id":28457,"price":"4.00000100","qty":"12.00000000","quoteQty":"48.000012","time":1499865549590,"isBuyerMaker":true,"isBestMatch":true}]"#;
+        let trades = HistoricalTrades::try_from(serde_json::from_str::<Data>(historical)?)?;
+        assert_eq!(trades.0[0].id, 28457);
+        Ok(())
+    }
+}
@@ -4,11 +4,11 @@ use serde::Deserialize;
 use serde_with::serde_as;
 
 use crate::{
-    http::error::RestError,
+    http::error::{api_error, RestError},
     types::trading::{OrderSide, OrderType, PositionSide, Status, TimeInForce},
 };
 
-use super::Data;
+use super::{Data, ErrorMessage};
 
 /// Order.
 #[derive(Debug, Clone, Deserialize)]
@@ -71,12 +71,128 @@ impl TryFrom<Data> for Order {
     fn try_from(value: Data) -> Result<Self, Self::Error> {
         match value {
             Data::Order(order) => Ok(order),
-            Data::Error(msg) => match msg.code {
-                -2013 => Err(RestError::Exchange(ExchangeError::OrderNotFound)),
-                _ => Err(RestError::Exchange(ExchangeError::Api(anyhow::anyhow!(
-                    "{msg:?}"
-                )))),
-            },
+            Data::Error(msg) => Err(RestError::Exchange(order_error(msg))),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+fn order_error(msg: ErrorMessage) -> ExchangeError {
+    match msg.code {
+        -2013 => ExchangeError::OrderNotFound,
+        _ => ExchangeError::ApiError(api_error(msg.code, msg.message)),
+    }
+}
+
+impl TryFrom<Data> for Vec<Order> {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::Orders(orders) => Ok(orders),
+            _ if value.is_empty_list() => Ok(Vec::new()),
+            Data::Error(msg) => Err(RestError::Exchange(order_error(msg))),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// Acknowledgement of an operation without meaningful data.
+#[derive(Debug, Clone, Copy)]
+pub struct Ack;
+
+impl TryFrom<Data> for Ack {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            // Some endpoints report success with a code of `200` or `0`.
+            Data::Error(msg) if msg.code == 200 || msg.code == 0 => Ok(Ack),
+            Data::Error(msg) => Err(RestError::Exchange(order_error(msg))),
+            _ => Ok(Ack),
+        }
+    }
+}
+
+/// The result of an order in a batch.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum BatchOrderResult {
+    /// Order.
+    Order(Order),
+    /// Error.
+    Error(ErrorMessage),
+}
+
+impl BatchOrderResult {
+    /// Convert into a result.
+    pub fn into_result(self) -> Result<Order, ExchangeError> {
+        match self {
+            BatchOrderResult::Order(order) => Ok(order),
+            BatchOrderResult::Error(msg) => Err(order_error(msg)),
+        }
+    }
+}
+
+/// Batch orders.
+pub type BatchOrders = Vec<BatchOrderResult>;
+
+impl TryFrom<Data> for BatchOrders {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::Orders(orders) => Ok(orders.into_iter().map(BatchOrderResult::Order).collect()),
+            Data::BatchOrders(orders) => Ok(orders),
+            Data::Error(msg) => Err(RestError::Exchange(order_error(msg))),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// A trade (fill) of the account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountTrade {
+    /// Symbol.
+    pub symbol: String,
+    /// Trade id.
+    pub id: i64,
+    /// Order id.
+    pub order_id: i64,
+    /// Price.
+    pub price: Decimal,
+    /// Size.
+    pub qty: Decimal,
+    /// Commission.
+    pub commission: Decimal,
+    /// Commission asset.
+    pub commission_asset: Asset,
+    /// Time.
+    pub time: i64,
+    /// Is buyer.
+    #[serde(alias = "isBuyer")]
+    pub buyer: bool,
+    /// Is maker.
+    #[serde(alias = "isMaker")]
+    pub maker: bool,
+}
+
+impl TryFrom<Data> for Vec<AccountTrade> {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::AccountTrades(trades) => Ok(trades),
+            _ if value.is_empty_list() => Ok(Vec::new()),
+            Data::Error(msg) => Err(RestError::Exchange(order_error(msg))),
             _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
                 "{value:?}"
             ))),
@@ -123,6 +239,9 @@ pub struct UsdMarginFuturesOrder {
     /// Order type.
     #[serde(rename = "type")]
     pub order_type: OrderType,
+    /// Original order type.
+    #[serde(default)]
+    pub orig_type: Option<OrderType>,
     /// Active price.
     pub activate_price: Option<Decimal>,
     /// Price rate.
@@ -185,6 +304,12 @@ pub struct SpotResult {
     pub order_type: OrderType,
     /// Order side.
     pub side: OrderSide,
+    /// Stop price.
+    #[serde(default)]
+    pub stop_price: Option<Decimal>,
+    /// Trailing delta (in BIPS).
+    #[serde(default)]
+    pub trailing_delta: Option<Decimal>,
 }
 
 /// Spot Order.
@@ -0,0 +1,107 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::http::error::RestError;
+
+use super::Data;
+
+/// Transfer result.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferResult {
+    /// Transfer id.
+    pub tran_id: i64,
+}
+
+impl TryFrom<Data> for TransferResult {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::TransferResult(result) => Ok(result),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+/// Transfer record.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferRecord {
+    /// Transfer id.
+    pub tran_id: i64,
+    /// Asset.
+    pub asset: String,
+    /// Amount.
+    pub amount: Decimal,
+    /// Status (`CONFIRMED`, `PENDING` or `FAILED` for the universal transfer,
+    /// `SUCCESS` for the sub-account universal transfer).
+    pub status: String,
+}
+
+/// Universal transfer history.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferHistory {
+    /// Total.
+    pub total: usize,
+    /// Rows (absent if empty).
+    #[serde(default)]
+    pub rows: Vec<TransferRecord>,
+}
+
+/// Universal transfer history involving sub-accounts.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferHistory {
+    /// Result.
+    pub result: Vec<TransferRecord>,
+    /// Total count.
+    pub total_count: usize,
+}
+
+/// Transfer records.
+#[derive(Debug, Clone)]
+pub struct TransferRecords(pub Vec<TransferRecord>);
+
+impl TryFrom<Data> for TransferRecords {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::UniversalTransferHistory(history) => Ok(Self(history.rows)),
+            Data::SubAccountTransferHistory(history) => Ok(Self(history.result)),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_data() -> anyhow::Result<()> {
+        let result = r#"{"tranId":13526853623}"#;
+        let result = TransferResult::try_from(serde_json::from_str::<Data>(result)?)?;
+        assert_eq!(result.tran_id, 13526853623);
+        let history = r#"{"total":1,"rows":[{"asset":"USDT","amount":"1","type":"MAIN_UMFUTURE","status":"CONFIRMED","tranId":11415955596,"timestamp":1544433328000}]}"#;
+        let TransferRecords(rows) =
+            TransferRecords::try_from(serde_json::from_str::<Data>(history)?)?;
+        assert_eq!(rows[0].status, "CONFIRMED");
+        let empty = r#"{"total":0}"#;
+        let TransferRecords(rows) =
+            TransferRecords::try_from(serde_json::from_str::<Data>(empty)?)?;
+        assert!(rows.is_empty());
+        let sub = r#"{"result":[{"tranId":92275823339,"fromEmail":"abctest@gmail.com","toEmail":"testuser@gmail.com","asset":"BNB","amount":"0.01","createTimeStamp":1640317374000,"fromAccountType":"USDT_FUTURE","toAccountType":"SPOT","status":"SUCCESS","clientTranId":"test"}],"totalCount":1}"#;
+        let TransferRecords(rows) = TransferRecords::try_from(serde_json::from_str::<Data>(sub)?)?;
+        assert_eq!(rows[0].tran_id, 92275823339);
+        Ok(())
+    }
+}
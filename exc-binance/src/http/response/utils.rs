@@ -0,0 +1,39 @@
+use serde::Deserialize;
+
+use crate::http::error::RestError;
+
+use super::Data;
+
+/// Server time.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerTime {
+    /// Server time (in millis).
+    pub server_time: i64,
+}
+
+impl TryFrom<Data> for ServerTime {
+    type Error = RestError;
+
+    fn try_from(value: Data) -> Result<Self, Self::Error> {
+        match value {
+            Data::ServerTime(time) => Ok(time),
+            Data::Error(msg) => Err(RestError::Api(msg.code, msg.message)),
+            _ => Err(RestError::UnexpectedResponseType(anyhow::anyhow!(
+                "{value:?}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_server_time_data() -> anyhow::Result<()> {
+        let data = serde_json::from_str::<Data>(r#"{"serverTime":1499827319559}"#)?;
+        assert_eq!(ServerTime::try_from(data)?.server_time, 1499827319559);
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+use exc_core::{types, Adaptor, ExchangeError, Str};
+use futures::{stream, FutureExt, StreamExt, TryStreamExt};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::{
+    http::{
+        request::{GetBalances, GetPositionRisk},
+        response::{Balances, Positions},
+    },
+    types::Name,
+    websocket::protocol::frame::account::{BalanceFrame, PositionFrame},
+    Request,
+};
+
+impl TryFrom<Balances> for types::Balances {
+    type Error = ExchangeError;
+
+    fn try_from(balances: Balances) -> Result<Self, Self::Error> {
+        match balances {
+            Balances::UsdMarginFutures(balances) => {
+                let ts = balances
+                    .iter()
+                    .map(|b| b.update_time)
+                    .max()
+                    .map(super::from_timestamp)
+                    .unwrap_or_else(|| Ok(OffsetDateTime::now_utc()))?;
+                Ok(types::Balances {
+                    ts,
+                    balances: balances
+                        .into_iter()
+                        .map(|b| {
+                            let total = b.balance.normalize();
+                            let free = b.available_balance.normalize();
+                            let balance = types::Balance {
+                                free,
+                                locked: (total - free).max(Decimal::ZERO),
+                                total,
+                            };
+                            (b.asset, balance)
+                        })
+                        .collect(),
+                })
+            }
+            Balances::Spot(account) => Ok(types::Balances {
+                ts: super::from_timestamp(account.update_time)?,
+                balances: account
+                    .balances
+                    .into_iter()
+                    .map(|b| {
+                        let balance = types::Balance::new(b.free.normalize(), b.locked.normalize());
+                        (b.asset, balance)
+                    })
+                    .collect(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<Positions> for Vec<types::Position> {
+    type Error = ExchangeError;
+
+    fn try_from(Positions(positions): Positions) -> Result<Self, Self::Error> {
+        positions
+            .into_iter()
+            .map(|p| {
+                Ok(types::Position {
+                    ts: super::from_timestamp(p.update_time)?,
+                    instrument: Str::new(p.symbol.to_lowercase()),
+                    size: p.position_amt.normalize(),
+                    entry_price: p.entry_price.normalize(),
+                    mark_price: Some(p.mark_price.normalize()),
+                    unrealized_pnl: p.un_realized_profit.normalize(),
+                    leverage: Some(p.leverage.normalize()),
+                    margin_mode: p.margin_type.into(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Adaptor<types::FetchBalances> for Request {
+    fn from_request(_req: types::FetchBalances) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(GetBalances::default()))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::FetchBalances as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move { resp.into_response::<Balances>()?.try_into() }.boxed())
+    }
+}
+
+impl Adaptor<types::SubscribeBalances> for Request {
+    fn from_request(_req: types::SubscribeBalances) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Name::account_update()))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::BalanceStream, ExchangeError> {
+        let stream = resp.into_stream::<BalanceFrame>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|update| async move { update.try_into() })
+            .boxed())
+    }
+}
+
+impl Adaptor<types::FetchPositions> for Request {
+    fn from_request(req: types::FetchPositions) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(GetPositionRisk {
+            symbol: req.instrument.map(|inst| inst.to_uppercase()),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::FetchPositions as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move { resp.into_response::<Positions>()?.try_into() }.boxed())
+    }
+}
+
+impl Adaptor<types::SubscribePositions> for Request {
+    fn from_request(req: types::SubscribePositions) -> Result<Self, ExchangeError> {
+        match req.instrument {
+            Some(inst) => Ok(Self::subscribe(
+                Name::position_update().with_inst(&inst.to_lowercase()),
+            )),
+            None => Ok(Self::subscribe(Name::position_update())),
+        }
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::PositionStream, ExchangeError> {
+        let stream = resp.into_stream::<PositionFrame>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|update| async move { Vec::<types::Position>::try_from(update) })
+            .map_ok(|positions| stream::iter(positions.into_iter().map(Ok)))
+            .try_flatten()
+            .boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::response::Data;
+    use exc_core::Asset;
+    use rust_decimal_macros::dec;
+
+    fn balances(payload: &str) -> anyhow::Result<types::Balances> {
+        let data = serde_json::from_str::<Data>(payload)?;
+        Ok(Balances::try_from(data)?.try_into()?)
+    }
+
+    #[test]
+    fn test_spot_balances() -> anyhow::Result<()> {
+        let account = r#"{"makerCommission":15,"takerCommission":15,"buyerCommission":0,"sellerCommission":0,"commissionRates":{"maker":"0.00150000","taker":"0.00150000","buyer":"0.00000000","seller":"0.00000000"},"canTrade":true,"canWithdraw":true,"canDeposit":true,"brokered":false,"requireSelfTradePrevention":false,"preventSor":false,"updateTime":1699999999000,"accountType":"SPOT","balances":[{"asset":"BTC","free":"4723846.89208129","locked":"0.00000000"},{"asset":"LTC","free":"4763368.68006011","locked":"1.00000000"}],"permissions":["SPOT"],"uid":354937868}"#;
+        let balances = balances(account)?;
+        assert_eq!(balances.ts.unix_timestamp(), 1699999999);
+        let ltc = balances.get(&"LTC".parse::<Asset>()?).unwrap();
+        assert_eq!(ltc.free, dec!(4763368.68006011));
+        assert_eq!(ltc.locked, dec!(1));
+        assert_eq!(ltc.total, dec!(4763369.68006011));
+        Ok(())
+    }
+
+    #[test]
+    fn test_futures_balances() -> anyhow::Result<()> {
+        let balance = r#"[{"accountAlias":"SgsR","asset":"USDT","balance":"122607.35137903","crossWalletBalance":"23.72469206","crossUnPnl":"0.00000000","availableBalance":"23.72469206","maxWithdrawAmount":"23.72469206","marginAvailable":true,"updateTime":1617939110373}]"#;
+        let balances = balances(balance)?;
+        assert_eq!(balances.ts.unix_timestamp(), 1617939110);
+        let usdt = balances.get(&"USDT".parse::<Asset>()?).unwrap();
+        assert_eq!(usdt.total, dec!(122607.35137903));
+        assert_eq!(usdt.free, dec!(23.72469206));
+        // The locked is the part of the wallet balance that is not available.
+        assert_eq!(usdt.locked, usdt.total - usdt.free);
+        Ok(())
+    }
+
+    #[test]
+    fn test_positions() -> anyhow::Result<()> {
+        let positions = r#"[{"symbol":"BTCUSDT","positionAmt":"-0.010","entryPrice":"22185.2","breakEvenPrice":"22176.3","markPrice":"21123.05052574","unRealizedProfit":"10.62149474","liquidationPrice":"39731.45529116","leverage":"4","maxNotionalValue":"100000000","marginType":"cross","isolatedMargin":"0.00000000","isAutoAddMargin":"false","positionSide":"BOTH","notional":"-211.23050525","isolatedWallet":"0","updateTime":1655217461579},{"symbol":"ETHUSDT","positionAmt":"0.000","entryPrice":"0.0","breakEvenPrice":"0.0","markPrice":"1561.23000000","unRealizedProfit":"0.00000000","liquidationPrice":"0","leverage":"20","maxNotionalValue":"25000000","marginType":"isolated","isolatedMargin":"0.00000000","isAutoAddMargin":"false","positionSide":"BOTH","notional":"0","isolatedWallet":"0","updateTime":0}]"#;
+        let data = serde_json::from_str::<Data>(positions)?;
+        let positions = Vec::<types::Position>::try_from(Positions::try_from(data)?)?;
+        assert_eq!(positions.len(), 2);
+        let btc = &positions[0];
+        assert_eq!(btc.ts.unix_timestamp(), 1655217461);
+        assert_eq!(btc.instrument, "btcusdt");
+        assert_eq!(btc.size, dec!(-0.01));
+        assert_eq!(btc.entry_price, dec!(22185.2));
+        assert_eq!(btc.mark_price, Some(dec!(21123.05052574)));
+        assert_eq!(btc.unrealized_pnl, dec!(10.62149474));
+        assert_eq!(btc.leverage, Some(dec!(4)));
+        assert_eq!(btc.margin_mode, types::MarginMode::Cross);
+        let eth = &positions[1];
+        assert!(eth.size.is_zero());
+        assert_eq!(eth.margin_mode, types::MarginMode::Isolated);
+
+        let data = serde_json::from_str::<Data>("[]")?;
+        assert!(Vec::<types::Position>::try_from(Positions::try_from(data)?)?.is_empty());
+        Ok(())
+    }
+}
@@ -1,8 +1,12 @@
 use exc_core::{types, Adaptor, ExchangeError};
-use futures::{StreamExt, TryStreamExt};
+use futures::{FutureExt, StreamExt, TryStreamExt};
 
 use crate::{
-    websocket::{protocol::frame::DepthFrame, request::WsRequest},
+    http::{request::GetDepth, response::OrderBook},
+    websocket::{
+        protocol::frame::{depth::Depth, DepthFrame, Name},
+        request::WsRequest,
+    },
     Request,
 };
 
@@ -19,3 +23,62 @@ impl Adaptor<types::SubscribeBidAsk> for Request {
             .boxed())
     }
 }
+
+impl Adaptor<types::FetchOrderBook> for Request {
+    fn from_request(req: types::FetchOrderBook) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(GetDepth {
+            symbol: req.instrument.to_uppercase(),
+            limit: Some(req.depth),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::FetchOrderBook as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let book = resp.into_response::<OrderBook>()?;
+            let ts = match book.trade_ts.or(book.event_ts) {
+                Some(ts) => super::from_timestamp(ts)?,
+                None => time::OffsetDateTime::now_utc(),
+            };
+            Ok(types::OrderBookUpdate {
+                ts,
+                kind: types::OrderBookUpdateKind::Snapshot,
+                first_id: None,
+                last_id: Some(book.last_update_id),
+                prev_id: None,
+                checksum: None,
+                bids: book.bids,
+                asks: book.asks,
+            })
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::SubscribeOrderBookUpdates> for Request {
+    fn from_request(req: types::SubscribeOrderBookUpdates) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Name::depth(&req.instrument, "", "100ms")))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::OrderBookUpdateStream, ExchangeError> {
+        let stream = resp.into_stream::<Depth>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|depth| async move {
+                Ok(types::OrderBookUpdate {
+                    ts: super::from_timestamp(
+                        depth.trade_timestamp.unwrap_or(depth.event_timestamp),
+                    )?,
+                    kind: types::OrderBookUpdateKind::Update,
+                    first_id: depth.first_id.map(|id| id as u64),
+                    last_id: Some(depth.id as u64),
+                    prev_id: depth.prev_id.map(|id| id as u64),
+                    checksum: None,
+                    bids: depth.bids,
+                    asks: depth.asks,
+                })
+            })
+            .boxed())
+    }
+}
@@ -0,0 +1,124 @@
+use std::ops::RangeBounds;
+
+use exc_core::{types, Adaptor, Asset, ExchangeError, Str};
+use futures::FutureExt;
+use time::{macros::format_description, OffsetDateTime, PrimitiveDateTime};
+
+use crate::{
+    http::{
+        request::{DepositHistory, WithdrawHistory},
+        response::{Deposits, Withdrawals},
+    },
+    Request,
+};
+
+/// Max number of records per request.
+const MAX_LIMIT: usize = 1000;
+
+fn parse_asset(coin: &str) -> Result<Asset, ExchangeError> {
+    coin.parse::<Asset>()
+        .map_err(|err| ExchangeError::unexpected_response_type(format!("invalid asset: {err}")))
+}
+
+fn parse_datetime(s: &str) -> Result<OffsetDateTime, ExchangeError> {
+    let format = format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    PrimitiveDateTime::parse(s, format)
+        .map(PrimitiveDateTime::assume_utc)
+        .map_err(|err| ExchangeError::Other(anyhow!("parse datetime error: {err}")))
+}
+
+fn tx_id(tx_id: String) -> Option<Str> {
+    (!tx_id.is_empty()).then(|| Str::new(tx_id))
+}
+
+impl Adaptor<types::QueryDeposits> for Request {
+    fn from_request(req: types::QueryDeposits) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(DepositHistory {
+            coin: req.query.asset.as_ref().map(|asset| asset.to_string()),
+            start_time: super::start_bound_to_timestamp(req.query.start_bound())?,
+            end_time: super::end_bound_to_timestamp(req.query.end_bound())?,
+            limit: Some(MAX_LIMIT),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::QueryDeposits as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let Deposits(records) = resp.into_response::<Deposits>()?;
+            records
+                .into_iter()
+                .map(|record| {
+                    let status = match record.status {
+                        1 | 6 => types::TransferStatus::Success,
+                        2 | 7 => types::TransferStatus::Failed,
+                        _ => types::TransferStatus::Pending,
+                    };
+                    Ok(types::FundsRecord {
+                        id: Str::new(record.id),
+                        tx_id: tx_id(record.tx_id),
+                        asset: parse_asset(&record.coin)?,
+                        network: Str::new(record.network),
+                        amount: record.amount.normalize(),
+                        fee: None,
+                        address: Str::new(record.address),
+                        status,
+                        ts: super::from_timestamp(record.insert_time)?,
+                        completed_at: record
+                            .complete_time
+                            .map(super::from_timestamp)
+                            .transpose()?,
+                    })
+                })
+                .collect()
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::QueryWithdrawals> for Request {
+    fn from_request(req: types::QueryWithdrawals) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(WithdrawHistory {
+            coin: req.query.asset.as_ref().map(|asset| asset.to_string()),
+            start_time: super::start_bound_to_timestamp(req.query.start_bound())?,
+            end_time: super::end_bound_to_timestamp(req.query.end_bound())?,
+            limit: Some(MAX_LIMIT),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::QueryWithdrawals as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let Withdrawals(records) = resp.into_response::<Withdrawals>()?;
+            records
+                .into_iter()
+                .map(|record| {
+                    let status = match record.status {
+                        6 => types::TransferStatus::Success,
+                        1 | 3 | 5 => types::TransferStatus::Failed,
+                        _ => types::TransferStatus::Pending,
+                    };
+                    Ok(types::FundsRecord {
+                        id: Str::new(record.id),
+                        tx_id: tx_id(record.tx_id),
+                        asset: parse_asset(&record.coin)?,
+                        network: Str::new(record.network),
+                        amount: record.amount.normalize(),
+                        fee: Some(record.transaction_fee.normalize()),
+                        address: Str::new(record.address),
+                        status,
+                        ts: parse_datetime(&record.apply_time)?,
+                        completed_at: record
+                            .complete_time
+                            .as_deref()
+                            .filter(|ts| !ts.is_empty())
+                            .map(parse_datetime)
+                            .transpose()?,
+                    })
+                })
+                .collect()
+        }
+        .boxed())
+    }
+}
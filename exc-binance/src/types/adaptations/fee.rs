@@ -0,0 +1,41 @@
+use exc_core::{types, Adaptor, ExchangeError, Str};
+use futures::FutureExt;
+
+use crate::{
+    http::{request::GetCommissionRate, response::CommissionRates},
+    Request,
+};
+
+impl Adaptor<types::FetchFeeRates> for Request {
+    fn from_request(req: types::FetchFeeRates) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(GetCommissionRate {
+            symbol: req.instrument.to_uppercase(),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::FetchFeeRates as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let (symbol, maker, taker) = match resp.into_response::<CommissionRates>()? {
+                CommissionRates::UsdMarginFutures(rate) => (
+                    rate.symbol,
+                    rate.maker_commission_rate,
+                    rate.taker_commission_rate,
+                ),
+                CommissionRates::Spot(fees) => {
+                    let fee = fees.into_iter().next().ok_or_else(|| {
+                        ExchangeError::unexpected_response_type("empty trade fees")
+                    })?;
+                    (fee.symbol, fee.maker_commission, fee.taker_commission)
+                }
+            };
+            Ok(types::FeeRates {
+                instrument: Str::new(symbol.to_lowercase()),
+                maker: maker.normalize(),
+                taker: taker.normalize(),
+            })
+        }
+        .boxed())
+    }
+}
@@ -0,0 +1,107 @@
+use std::ops::RangeBounds;
+
+use exc_core::{types, Adaptor, ExchangeError};
+use futures::{FutureExt, StreamExt, TryStreamExt};
+
+use crate::{
+    http::{
+        request::{GetPremiumIndex, QueryFundingRates},
+        response::{FundingRates, PremiumIndex},
+    },
+    websocket::protocol::frame::{mark_price::MarkPrice, Name},
+    Request,
+};
+
+impl Adaptor<types::QueryFirstFundingRates> for Request {
+    fn from_request(req: types::QueryFirstFundingRates) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(QueryFundingRates {
+            symbol: req.query().instrument.to_uppercase(),
+            start_time: super::start_bound_to_timestamp(req.query().start_bound())?,
+            end_time: super::end_bound_to_timestamp(req.query().end_bound())?,
+            limit: Some(req.first()),
+        }))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::FundingRateStream, ExchangeError> {
+        let rates = resp.into_response::<FundingRates>()?;
+        Ok(futures::stream::iter(rates.0.into_iter().map(|r| {
+            Ok(types::FundingRate {
+                ts: super::from_timestamp(r.funding_time)?,
+                rate: r.funding_rate.normalize(),
+            })
+        }))
+        .boxed())
+    }
+}
+
+impl Adaptor<types::FetchFundingRate> for Request {
+    fn from_request(req: types::FetchFundingRate) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(GetPremiumIndex {
+            symbol: req.instrument.to_uppercase(),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::FetchFundingRate as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let index = resp.into_response::<PremiumIndex>()?;
+            Ok(types::CurrentFundingRate {
+                ts: super::from_timestamp(index.time)?,
+                rate: index.last_funding_rate.normalize(),
+                funding_time: super::from_timestamp(index.next_funding_time)?,
+                next_rate: None,
+                next_funding_time: None,
+            })
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::SubscribeMarkPrice> for Request {
+    fn from_request(req: types::SubscribeMarkPrice) -> Result<Self, ExchangeError> {
+        Ok(Request::subscribe(Name::mark_price(
+            &req.instrument.to_lowercase(),
+        )))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::MarkPriceStream, ExchangeError> {
+        let stream = resp.into_stream::<MarkPrice>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|mark| async move {
+                Ok(types::MarkPrice {
+                    ts: super::from_timestamp(mark.event_timestamp)?,
+                    price: mark.mark_price.normalize(),
+                    index: Some(mark.index_price.normalize()),
+                })
+            })
+            .boxed())
+    }
+}
+
+impl Adaptor<types::SubscribeFundingRate> for Request {
+    fn from_request(req: types::SubscribeFundingRate) -> Result<Self, ExchangeError> {
+        Ok(Request::subscribe(Name::mark_price(
+            &req.instrument.to_lowercase(),
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<types::CurrentFundingRateStream, ExchangeError> {
+        let stream = resp.into_stream::<MarkPrice>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|mark| async move {
+                Ok(types::CurrentFundingRate {
+                    ts: super::from_timestamp(mark.event_timestamp)?,
+                    rate: mark.funding_rate.normalize(),
+                    funding_time: super::from_timestamp(mark.next_funding_time)?,
+                    next_rate: None,
+                    next_funding_time: None,
+                })
+            })
+            .boxed())
+    }
+}
@@ -0,0 +1,119 @@
+use exc_core::{types, Adaptor, ExchangeError, Str};
+use futures::FutureExt;
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::{
+    http::{
+        request::{ChangeLeverage, ChangeMarginType, ChangePositionMode, GetPositionRisk},
+        response::{Configured, LeverageChanged, Positions},
+    },
+    types::trading::MarginType,
+    Request,
+};
+
+fn margin_type(mode: types::MarginMode) -> Result<MarginType, ExchangeError> {
+    match mode {
+        types::MarginMode::Cross => Ok(MarginType::Crossed),
+        types::MarginMode::Isolated => Ok(MarginType::Isolated),
+        types::MarginMode::Cash => Err(ExchangeError::Other(anyhow::anyhow!(
+            "cash is not a margin type of binance"
+        ))),
+    }
+}
+
+impl Adaptor<types::SetLeverage> for Request {
+    fn from_request(req: types::SetLeverage) -> Result<Self, ExchangeError> {
+        let leverage = req
+            .leverage
+            .normalize()
+            .to_u32()
+            .filter(|l| req.leverage.is_integer() && *l > 0)
+            .ok_or_else(|| {
+                ExchangeError::Other(anyhow::anyhow!(
+                    "the leverage on binance must be a positive integer, got {}",
+                    req.leverage
+                ))
+            })?;
+        Ok(Self::with_rest_payload(ChangeLeverage {
+            symbol: req.instrument.to_uppercase(),
+            leverage,
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::SetLeverage as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let changed = resp.into_response::<LeverageChanged>()?;
+            Ok(types::Leverage {
+                instrument: Str::new(changed.symbol.to_lowercase()),
+                leverage: changed.leverage.normalize(),
+                margin_mode: None,
+            })
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::GetLeverage> for Request {
+    fn from_request(req: types::GetLeverage) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(GetPositionRisk {
+            symbol: Some(req.instrument.to_uppercase()),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::GetLeverage as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let Positions(positions) = resp.into_response::<Positions>()?;
+            // The positions of both sides (in the hedge mode) share the leverage.
+            let position = positions.into_iter().next().ok_or_else(|| {
+                ExchangeError::unexpected_response_type("empty position information")
+            })?;
+            Ok(types::Leverage {
+                instrument: Str::new(position.symbol.to_lowercase()),
+                leverage: position.leverage.normalize(),
+                margin_mode: Some(position.margin_type.into()),
+            })
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::SetMarginMode> for Request {
+    fn from_request(req: types::SetMarginMode) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(ChangeMarginType {
+            symbol: req.instrument.to_uppercase(),
+            margin_type: margin_type(req.margin_mode)?,
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::SetMarginMode as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.into_response::<Configured>()?;
+            Ok(())
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::SetPositionMode> for Request {
+    fn from_request(req: types::SetPositionMode) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(ChangePositionMode {
+            dual_side_position: matches!(req.mode, types::PositionMode::Hedge),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::SetPositionMode as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.into_response::<Configured>()?;
+            Ok(())
+        }
+        .boxed())
+    }
+}
@@ -3,11 +3,19 @@ use std::ops::Bound;
 use exc_core::ExchangeError;
 use time::OffsetDateTime;
 
+mod account;
 mod book;
 mod candle;
+mod capital;
+mod fee;
+mod funding;
 mod instrument;
+mod leverage;
+mod open_interest;
+mod option;
 mod trade;
 mod trading;
+mod transfer;
 mod utils;
 
 pub(crate) fn from_timestamp(ts: i64) -> Result<OffsetDateTime, ExchangeError> {
@@ -0,0 +1,93 @@
+use std::ops::RangeBounds;
+
+use exc_core::{types, Adaptor, ExchangeError, Str};
+use futures::{StreamExt, TryStreamExt};
+
+use crate::{
+    http::{
+        request::{Interval, QueryOpenInterestHist},
+        response::OpenInterestHistory,
+    },
+    websocket::protocol::frame::{force_order::ForceOrder, Name},
+    Request,
+};
+
+/// Max number of samples per request.
+const MAX_LIMIT: usize = 500;
+
+impl Adaptor<types::QueryOpenInterest> for Request {
+    fn from_request(req: types::QueryOpenInterest) -> Result<Self, ExchangeError> {
+        let period: Interval = req.period.try_into()?;
+        if matches!(
+            period,
+            Interval::M1
+                | Interval::M3
+                | Interval::H8
+                | Interval::D3
+                | Interval::W1
+                | Interval::Mon1
+        ) {
+            return Err(ExchangeError::Other(anyhow!(
+                "unsupported period for open interest: {}",
+                req.period
+            )));
+        }
+        let start_time = super::start_bound_to_timestamp(req.start_bound())?;
+        let mut end_time = super::end_bound_to_timestamp(req.end_bound())?;
+        // Binance returns the latest samples of the range, so we narrow the range
+        // to a window holding at most `MAX_LIMIT` samples to get the first ones.
+        if let (Some(start), types::PeriodKind::Duration(dur)) = (start_time, req.period.kind()) {
+            let window = start.saturating_add(dur.as_millis() as i64 * (MAX_LIMIT as i64 - 1));
+            end_time = Some(end_time.map_or(window, |end| end.min(window)));
+        }
+        Ok(Self::with_rest_payload(QueryOpenInterestHist {
+            symbol: req.instrument.to_uppercase(),
+            period,
+            start_time,
+            end_time,
+            limit: Some(MAX_LIMIT),
+        }))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::OpenInterestStream, ExchangeError> {
+        let history = resp.into_response::<OpenInterestHistory>()?;
+        Ok(futures::stream::iter(history.0.into_iter().map(|oi| {
+            Ok(types::OpenInterest {
+                ts: super::from_timestamp(oi.timestamp)?,
+                size: oi.sum_open_interest.normalize(),
+                value: Some(oi.sum_open_interest_value.normalize()),
+            })
+        }))
+        .boxed())
+    }
+}
+
+impl Adaptor<types::SubscribeLiquidations> for Request {
+    fn from_request(req: types::SubscribeLiquidations) -> Result<Self, ExchangeError> {
+        Ok(Request::subscribe(Name::force_order(
+            &req.instrument.to_lowercase(),
+        )))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::LiquidationStream, ExchangeError> {
+        let stream = resp.into_stream::<ForceOrder>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .and_then(|order| async move {
+                let order = order.order;
+                let (price, size) = if order.filled_size.is_zero() {
+                    (order.price, order.size)
+                } else {
+                    (order.avg_price, order.filled_size)
+                };
+                let size = size.normalize();
+                Ok(types::Liquidation {
+                    ts: super::from_timestamp(order.trade_timestamp)?,
+                    instrument: Str::new(order.symbol.to_lowercase()),
+                    price: price.normalize(),
+                    size: if order.is_buy() { size } else { -size },
+                })
+            })
+            .boxed())
+    }
+}
@@ -0,0 +1,93 @@
+use exc_core::{symbol::ExcSymbol, types, Adaptor, Asset, ExchangeError, Str};
+use futures::{StreamExt, TryStreamExt};
+
+use crate::{
+    websocket::protocol::frame::{
+        mark_price::{OptionsMarkPrice, OptionsMarkPrices},
+        Name,
+    },
+    Request,
+};
+
+/// Options of `binance-o` are quoted in USDT.
+const OPTIONS_QUOTE: &str = "USDT";
+
+/// Convert the symbol of an option (e.g. `BTC-240628-60000-C`) to an [`ExcSymbol`].
+fn to_exc_symbol(symbol: &str) -> Result<ExcSymbol, ExchangeError> {
+    let mut parts = symbol.split('-');
+    let base = parts.next().and_then(|base| base.parse::<Asset>().ok());
+    let quote = OPTIONS_QUOTE.parse::<Asset>().ok();
+    let date = parts.next();
+    let price = parts.next();
+    parts
+        .next()
+        .and_then(|ty| {
+            let (base, quote) = (base.as_ref()?, quote.as_ref()?);
+            match ty {
+                "C" => ExcSymbol::call_with_str(base, quote, date?, price?),
+                "P" => ExcSymbol::put_with_str(base, quote, date?, price?),
+                _ => None,
+            }
+        })
+        .ok_or_else(|| ExchangeError::Other(anyhow!("failed to build exc symbol from `{symbol}`")))
+}
+
+impl TryFrom<OptionsMarkPrice> for types::OptionGreeks {
+    type Error = ExchangeError;
+
+    fn try_from(mark: OptionsMarkPrice) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ts: super::from_timestamp(mark.event_timestamp)?,
+            symbol: to_exc_symbol(&mark.symbol)?,
+            instrument: Str::new(&mark.symbol),
+            delta: mark.delta.map(|d| d.normalize()),
+            gamma: mark.gamma.map(|d| d.normalize()),
+            vega: mark.vega.map(|d| d.normalize()),
+            theta: mark.theta.map(|d| d.normalize()),
+            mark_iv: mark.mark_iv.map(|d| d.normalize()),
+            bid_iv: mark.bid_iv.map(|d| d.normalize()),
+            ask_iv: mark.ask_iv.map(|d| d.normalize()),
+            underlying_price: mark.index_price.map(|d| d.normalize()),
+        })
+    }
+}
+
+impl Adaptor<types::SubscribeOptionGreeks> for Request {
+    fn from_request(req: types::SubscribeOptionGreeks) -> Result<Self, ExchangeError> {
+        // Accept both the underlying asset (`BTC`) and an instrument family (`BTC-USD`).
+        let underlying = req
+            .family
+            .split_once('-')
+            .map(|(base, _)| base)
+            .unwrap_or(req.family.as_str());
+        Ok(Request::subscribe(Name::mark_price(
+            &underlying.to_uppercase(),
+        )))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::OptionGreeksStream, ExchangeError> {
+        let stream = resp.into_stream::<OptionsMarkPrices>()?;
+        Ok(stream
+            .map_err(ExchangeError::from)
+            .flat_map(|marks| match marks {
+                Ok(marks) => futures::stream::iter(marks.0)
+                    .map(types::OptionGreeks::try_from)
+                    .left_stream(),
+                Err(err) => futures::stream::once(async move { Err(err) }).right_stream(),
+            })
+            .boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_options_symbol() -> anyhow::Result<()> {
+        let symbol = to_exc_symbol("BTC-240628-60000-C")?;
+        assert_eq!(symbol.to_string(), "O240628C60000:BTC-USDT");
+        assert!(to_exc_symbol("BTCUSDT").is_err());
+        Ok(())
+    }
+}
@@ -1,11 +1,20 @@
-use exc_core::{types, Adaptor, ExchangeError};
+use std::ops::{Bound, RangeBounds};
+
+use exc_core::{types, Adaptor, ExchangeError, Str};
 use futures::{StreamExt, TryStreamExt};
 
 use crate::{
+    http::{
+        request::{QueryAggTrades, QueryHistoricalTrades},
+        response::{AggTrades, HistoricalTrades},
+    },
     websocket::{protocol::frame::TradeFrame, request::WsRequest},
     Request,
 };
 
+/// The max time window (in millis) of the aggregate trades query.
+const AGG_TRADES_MAX_WINDOW: i64 = 3_600_000;
+
 impl Adaptor<types::SubscribeTrades> for Request {
     fn from_request(req: types::SubscribeTrades) -> Result<Self, ExchangeError> {
         Ok(WsRequest::dispatch_trades(req).into())
@@ -19,3 +28,119 @@ impl Adaptor<types::SubscribeTrades> for Request {
             .boxed())
     }
 }
+
+impl Adaptor<types::QueryFirstTrades> for Request {
+    fn from_request(req: types::QueryFirstTrades) -> Result<Self, ExchangeError> {
+        let symbol = req.query().instrument.to_uppercase();
+        let limit = Some(req.first().min(1000));
+        if let Some(id) = req.after_id.as_ref() {
+            let id = id
+                .parse::<i64>()
+                .map_err(|err| ExchangeError::Other(anyhow!("invalid trade id `{id}`: {err}")))?;
+            return Ok(Self::with_rest_payload(QueryAggTrades {
+                symbol,
+                from_id: Some(id + 1),
+                start_time: None,
+                end_time: None,
+                limit,
+            }));
+        }
+        let start_time = match req.query().start_bound() {
+            Bound::Included(ts) => Some(super::to_timestamp(ts)?),
+            Bound::Excluded(ts) => Some(super::to_timestamp(ts)? + 1),
+            Bound::Unbounded => None,
+        };
+        let end_time = match req.query().end_bound() {
+            Bound::Included(ts) => Some(super::to_timestamp(ts)?),
+            Bound::Excluded(ts) => Some(super::to_timestamp(ts)? - 1),
+            Bound::Unbounded => None,
+        };
+        let payload = match start_time {
+            // The end time can only be sent along with a start time less than an hour before it,
+            // otherwise the trades after the end are returned as well.
+            Some(start) => QueryAggTrades {
+                symbol,
+                from_id: None,
+                start_time: Some(start),
+                end_time: end_time.filter(|end| *end - start < AGG_TRADES_MAX_WINDOW),
+                limit,
+            },
+            None => QueryAggTrades {
+                symbol,
+                from_id: Some(0),
+                start_time: None,
+                end_time: None,
+                limit,
+            },
+        };
+        Ok(Self::with_rest_payload(payload))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::TradeHistoryStream, ExchangeError> {
+        let trades = resp.into_response::<AggTrades>()?;
+        Ok(types::TradeHistoryStream::new_forward(
+            futures::stream::iter(trades.0.into_iter().map(|trade| {
+                Ok(types::TradeRecord {
+                    id: Str::new(trade.id.to_string()),
+                    trade: types::Trade {
+                        ts: super::from_timestamp(trade.timestamp)?,
+                        price: trade.price.normalize(),
+                        size: trade.size.normalize(),
+                        buy: !trade.buy_maker,
+                    },
+                })
+            })),
+        ))
+    }
+}
+
+impl Adaptor<types::QueryLastTrades> for Request {
+    fn from_request(req: types::QueryLastTrades) -> Result<Self, ExchangeError> {
+        let symbol = req.query().instrument.to_uppercase();
+        let last = req.last().min(500);
+        let payload = match req.before_id.as_ref() {
+            Some(id) => {
+                let id = id.parse::<i64>().map_err(|err| {
+                    ExchangeError::Other(anyhow!("invalid trade id `{id}`: {err}"))
+                })?;
+                let from_id = (id - last as i64).max(0);
+                QueryHistoricalTrades {
+                    symbol,
+                    from_id: Some(from_id),
+                    limit: Some(((id - from_id) as usize).max(1)),
+                }
+            }
+            None => {
+                // The trades can only be located by id, so only the most recent ones are available.
+                if !matches!(req.query().end_bound(), Bound::Unbounded) {
+                    return Err(ExchangeError::Unavailable(anyhow!(
+                        "querying the last trades before a given time is not supported by binance"
+                    )));
+                }
+                QueryHistoricalTrades {
+                    symbol,
+                    from_id: None,
+                    limit: Some(last),
+                }
+            }
+        };
+        Ok(Self::with_rest_payload(payload))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::TradeHistoryStream, ExchangeError> {
+        let trades = resp.into_response::<HistoricalTrades>()?;
+        Ok(types::TradeHistoryStream::new_backward(
+            futures::stream::iter(trades.0.into_iter().rev().map(|trade| {
+                Ok(types::TradeRecord {
+                    id: Str::new(trade.id.to_string()),
+                    trade: types::Trade {
+                        ts: super::from_timestamp(trade.time)?,
+                        price: trade.price.normalize(),
+                        size: trade.qty.normalize(),
+                        buy: !trade.is_buyer_maker,
+                    },
+                })
+            })),
+        ))
+    }
+}
@@ -1,4 +1,7 @@
-use std::{collections::HashMap, ops::Neg};
+use std::{
+    collections::HashMap,
+    ops::{Neg, RangeBounds},
+};
 
 use exc_core::{types, Adaptor, ExchangeError, Str};
 use futures::{FutureExt, StreamExt, TryStreamExt};
@@ -7,8 +10,12 @@ use time::OffsetDateTime;
 
 use crate::{
     http::{
-        request::trading::{CancelOrder, GetOrder, GetOrderInner, PlaceOrder},
-        response::trading::Order,
+        request::trading::{
+            usd_margin_futures::{AmendOrder, CancelOrders, CountdownCancelAll, PlaceOrders},
+            AllOrders, CancelAllOrders, CancelOrder, GetOrder, GetOrderInner, ListOpenOrders,
+            MyTrades, PlaceOrder,
+        },
+        response::trading::{AccountTrade, Ack, BatchOrders, Order},
     },
     types::{
         trading::{self, OrderSide, Status, TimeInForce},
@@ -50,26 +57,39 @@ impl TryFrom<Order> for types::Order {
                         size.set_sign_positive(false);
                     }
                 }
-                let kind = match order.order_type {
-                    trading::OrderType::Limit => match order.time_in_force {
-                        TimeInForce::Gtc => types::OrderKind::Limit(
-                            order.price,
-                            types::TimeInForce::GoodTilCancelled,
-                        ),
-                        TimeInForce::Fok => {
-                            types::OrderKind::Limit(order.price, types::TimeInForce::FillOrKill)
+                // Conditional orders become market or limit orders once triggered.
+                let conditional = trading::futures_conditional_kind(
+                    order.orig_type.unwrap_or(order.order_type),
+                    order.price,
+                    order.stop_price,
+                    &order.working_type,
+                    order.activate_price,
+                    order.price_rate,
+                );
+                let kind = if let Some(kind) = conditional {
+                    kind
+                } else {
+                    match order.order_type {
+                        trading::OrderType::Limit => match order.time_in_force {
+                            TimeInForce::Gtc => types::OrderKind::Limit(
+                                order.price,
+                                types::TimeInForce::GoodTilCancelled,
+                            ),
+                            TimeInForce::Fok => {
+                                types::OrderKind::Limit(order.price, types::TimeInForce::FillOrKill)
+                            }
+                            TimeInForce::Ioc => types::OrderKind::Limit(
+                                order.price,
+                                types::TimeInForce::ImmediateOrCancel,
+                            ),
+                            TimeInForce::Gtx => types::OrderKind::PostOnly(order.price),
+                        },
+                        trading::OrderType::Market => types::OrderKind::Market,
+                        other => {
+                            return Err(ExchangeError::Other(anyhow!(
+                                "unsupported order type: {other:?}"
+                            )));
                         }
-                        TimeInForce::Ioc => types::OrderKind::Limit(
-                            order.price,
-                            types::TimeInForce::ImmediateOrCancel,
-                        ),
-                        TimeInForce::Gtx => types::OrderKind::PostOnly(order.price),
-                    },
-                    trading::OrderType::Market => types::OrderKind::Market,
-                    other => {
-                        return Err(ExchangeError::Other(anyhow!(
-                            "unsupported order type: {other:?}"
-                        )));
                     }
                 };
                 let status = match order.status {
@@ -129,11 +149,15 @@ impl TryFrom<Order> for types::Order {
                         trading::OrderType::LimitMaker => {
                             types::OrderKind::PostOnly(result.price.normalize())
                         }
-                        other => {
-                            return Err(ExchangeError::Other(anyhow!(
-                                "unsupported order type: {other:?}"
-                            )));
-                        }
+                        other => trading::spot_conditional_kind(
+                            other,
+                            result.price,
+                            result.stop_price.unwrap_or_default(),
+                            result.trailing_delta,
+                        )
+                        .ok_or_else(|| {
+                            ExchangeError::Other(anyhow!("unsupported order type: {other:?}"))
+                        })?,
                     };
                     let status = match result.status {
                         Status::New | Status::PartiallyFilled => types::OrderStatus::Pending,
@@ -295,6 +319,146 @@ impl Adaptor<types::CancelOrder> for Request {
     }
 }
 
+impl Adaptor<types::AmendOrder> for Request {
+    fn from_request(req: types::AmendOrder) -> Result<Self, ExchangeError> {
+        let req = AmendOrder::try_from(&req).map_err(crate::Error::from)?;
+        Ok(Self::with_rest_payload(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::AmendOrder as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let order = resp.into_response::<Order>()?;
+            let id = types::OrderId::from(order.client_id().to_string());
+            Ok(types::Amended {
+                ts: order
+                    .updated()
+                    .map(super::from_timestamp)
+                    .unwrap_or_else(|| Ok(OffsetDateTime::now_utc()))?,
+                id,
+                order: order
+                    .try_into()
+                    .map_err(|err| {
+                        tracing::warn!(%err, "failed to convert order");
+                    })
+                    .ok(),
+            })
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::PlaceOrders> for Request {
+    fn from_request(req: types::PlaceOrders) -> Result<Self, ExchangeError> {
+        let req = PlaceOrders::try_from(&req).map_err(crate::Error::from)?;
+        Ok(Self::with_rest_payload(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::PlaceOrders as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let orders = resp.into_response::<BatchOrders>()?;
+            Ok(orders
+                .into_iter()
+                .map(|res| {
+                    let order = res.into_result()?;
+                    let id = types::OrderId::from(order.client_id().to_string());
+                    Ok(types::Placed {
+                        ts: order
+                            .updated()
+                            .map(super::from_timestamp)
+                            .unwrap_or_else(|| Ok(OffsetDateTime::now_utc()))?,
+                        id,
+                        order: order
+                            .try_into()
+                            .map_err(|err| {
+                                tracing::warn!(%err, "failed to convert order");
+                            })
+                            .ok(),
+                    })
+                })
+                .collect())
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::CancelOrders> for Request {
+    fn from_request(req: types::CancelOrders) -> Result<Self, ExchangeError> {
+        let req = CancelOrders::try_from(&req).map_err(crate::Error::from)?;
+        Ok(Self::with_rest_payload(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::CancelOrders as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let orders = resp.into_response::<BatchOrders>()?;
+            Ok(orders
+                .into_iter()
+                .map(|res| {
+                    let order = res.into_result()?;
+                    Ok(types::Canceled {
+                        ts: order
+                            .updated()
+                            .map(super::from_timestamp)
+                            .unwrap_or_else(|| Ok(OffsetDateTime::now_utc()))?,
+                        order: Some(order.try_into()?),
+                    })
+                })
+                .collect())
+        }
+        .boxed())
+    }
+}
+
+fn required_symbol(inst: Option<&Str>, name: &str) -> Result<String, ExchangeError> {
+    inst.map(|inst| inst.to_uppercase()).ok_or_else(|| {
+        ExchangeError::Other(anyhow::anyhow!(
+            "`{name}` requires an instrument on binance"
+        ))
+    })
+}
+
+impl Adaptor<types::CancelAllOrders> for Request {
+    fn from_request(req: types::CancelAllOrders) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(CancelAllOrders {
+            symbol: required_symbol(req.instrument.as_ref(), "CancelAllOrders")?,
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::CancelAllOrders as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.into_response::<Ack>()?;
+            Ok(())
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::CancelAllAfter> for Request {
+    fn from_request(req: types::CancelAllAfter) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(CountdownCancelAll {
+            symbol: required_symbol(req.instrument.as_ref(), "CancelAllAfter")?,
+            countdown_time: req.timeout.as_millis() as u64,
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::CancelAllAfter as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.into_response::<Ack>()?;
+            Ok(())
+        }
+        .boxed())
+    }
+}
+
 impl Adaptor<types::GetOrder> for Request {
     fn from_request(req: types::GetOrder) -> Result<Self, ExchangeError> {
         Ok(Self::with_rest_payload(GetOrder {
@@ -312,14 +476,183 @@ impl Adaptor<types::GetOrder> for Request {
     ) -> Result<<types::GetOrder as exc_core::Request>::Response, ExchangeError> {
         Ok(async move {
             let order = resp.into_response::<Order>()?;
-            Ok(types::OrderUpdate {
-                ts: order
-                    .updated()
-                    .map(super::from_timestamp)
-                    .unwrap_or_else(|| Ok(OffsetDateTime::now_utc()))?,
-                order: order.try_into()?,
+            order_update(order)
+        }
+        .boxed())
+    }
+}
+
+fn order_update(order: Order) -> Result<types::OrderUpdate, ExchangeError> {
+    Ok(types::OrderUpdate {
+        ts: order
+            .updated()
+            .map(super::from_timestamp)
+            .unwrap_or_else(|| Ok(OffsetDateTime::now_utc()))?,
+        order: order.try_into()?,
+    })
+}
+
+impl Adaptor<types::Paged<types::ListOpenOrders>> for Request {
+    fn from_request(req: types::Paged<types::ListOpenOrders>) -> Result<Self, ExchangeError> {
+        Ok(Self::with_rest_payload(ListOpenOrders {
+            symbol: req.query.instrument.map(|inst| inst.to_uppercase()),
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::Paged<types::ListOpenOrders> as exc_core::Request>::Response, ExchangeError>
+    {
+        Ok(async move {
+            let orders = resp.into_response::<Vec<Order>>()?;
+            // All open orders are returned at once.
+            Ok(types::Page {
+                items: orders
+                    .into_iter()
+                    .map(order_update)
+                    .collect::<Result<_, _>>()?,
+                next: None,
             })
         }
         .boxed())
     }
 }
+
+/// The maximum number of orders in a page of order history.
+const ORDER_HISTORY_LIMIT: usize = 1000;
+
+impl Adaptor<types::Paged<types::QueryOrderHistory>> for Request {
+    fn from_request(req: types::Paged<types::QueryOrderHistory>) -> Result<Self, ExchangeError> {
+        let query = &req.query;
+        // The following pages are located by order id.
+        let (order_id, start_time) = match req.cursor {
+            Some(cursor) => {
+                let order_id = cursor.parse::<i64>().map_err(|err| {
+                    ExchangeError::Other(anyhow::anyhow!("invalid cursor: {err}"))
+                })?;
+                (Some(order_id), None)
+            }
+            None => (None, super::start_bound_to_timestamp(query.start_bound())?),
+        };
+        Ok(Self::with_rest_payload(AllOrders {
+            symbol: query.instrument.to_uppercase(),
+            order_id,
+            start_time,
+            end_time: super::end_bound_to_timestamp(query.end_bound())?,
+            limit: ORDER_HISTORY_LIMIT,
+        }))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<
+        <types::Paged<types::QueryOrderHistory> as exc_core::Request>::Response,
+        ExchangeError,
+    > {
+        Ok(async move {
+            let orders = resp.into_response::<Vec<Order>>()?;
+            let next = if orders.len() < ORDER_HISTORY_LIMIT {
+                None
+            } else {
+                orders
+                    .last()
+                    .map(|order| Str::new((order.id() + 1).to_string()))
+            };
+            Ok(types::Page {
+                items: orders
+                    .into_iter()
+                    .map(order_update)
+                    .collect::<Result<_, _>>()?,
+                next,
+            })
+        }
+        .boxed())
+    }
+}
+
+/// The maximum number of trades in a response of `myTrades`.
+const MY_TRADES_LIMIT: usize = 1000;
+
+impl TryFrom<AccountTrade> for types::Fill {
+    type Error = ExchangeError;
+
+    fn try_from(trade: AccountTrade) -> Result<Self, Self::Error> {
+        let size = if trade.buyer { trade.qty } else { -trade.qty };
+        Ok(types::Fill {
+            instrument: Str::new(trade.symbol.to_lowercase()),
+            trade_id: Str::new(trade.id.to_string()),
+            order_id: Str::new(trade.order_id.to_string()),
+            price: trade.price.normalize(),
+            size: size.normalize(),
+            fee: -trade.commission.normalize(),
+            fee_asset: Some(trade.commission_asset),
+            maker: trade.maker,
+            ts: super::from_timestamp(trade.time)?,
+        })
+    }
+}
+
+impl Adaptor<types::QueryLastFills> for Request {
+    fn from_request(req: types::QueryLastFills) -> Result<Self, ExchangeError> {
+        let query = req.query();
+        Ok(Self::with_rest_payload(MyTrades {
+            symbol: query.instrument.to_uppercase(),
+            start_time: super::start_bound_to_timestamp(query.start_bound())?,
+            end_time: super::end_bound_to_timestamp(query.end_bound())?,
+            limit: req.last().min(MY_TRADES_LIMIT),
+        }))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<types::FillStream, ExchangeError> {
+        let mut trades = resp.into_response::<Vec<AccountTrade>>()?;
+        // The trades are returned forward.
+        trades.sort_by_key(|trade| (trade.time, trade.id));
+        Ok(futures::stream::iter(trades.into_iter().rev().map(types::Fill::try_from)).boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn order(payload: &str) -> anyhow::Result<types::Order> {
+        Ok(serde_json::from_str::<Order>(payload)?.try_into()?)
+    }
+
+    #[test]
+    fn test_conditional_orders() -> anyhow::Result<()> {
+        let spot = r#"{"symbol":"BTCUSDT","orderId":28,"orderListId":-1,"clientOrderId":"6gCrw2kRUAF9CvJDGP16IP","price":"0.00000000","origQty":"1.00000000","executedQty":"0.00000000","cummulativeQuoteQty":"0.00000000","status":"NEW","timeInForce":"GTC","type":"STOP_LOSS","side":"SELL","stopPrice":"0.00000000","trailingDelta":150,"icebergQty":"0.00000000","time":1507725176595,"updateTime":1507725176595,"isWorking":false,"workingTime":-1,"origQuoteOrderQty":"0.00000000","selfTradePreventionMode":"NONE"}"#;
+        let spot = order(spot)?;
+        assert_eq!(spot.target.size, dec!(-1));
+        assert_eq!(
+            spot.target.kind,
+            types::OrderKind::TrailingStop(types::TrailingStop {
+                callback_rate: dec!(0.015),
+                activation: None,
+                source: types::TriggerSource::Last,
+            })
+        );
+
+        let futures = r#"{"avgPrice":"0.00000","clientOrderId":"abc","cumQuote":"0","executedQty":"0","orderId":1917641,"origQty":"0.40","origType":"TRAILING_STOP_MARKET","price":"0","reduceOnly":false,"side":"SELL","positionSide":"BOTH","status":"NEW","stopPrice":"9300","closePosition":false,"symbol":"BTCUSDT","time":1579276756075,"timeInForce":"GTC","type":"TRAILING_STOP_MARKET","activatePrice":"9020","priceRate":"0.3","updateTime":1579276756075,"workingType":"MARK_PRICE","priceProtect":false}"#;
+        let futures = order(futures)?;
+        assert_eq!(futures.target.size, dec!(-0.4));
+        assert_eq!(
+            futures.target.kind,
+            types::OrderKind::TrailingStop(types::TrailingStop {
+                callback_rate: dec!(0.003),
+                activation: Some(dec!(9020)),
+                source: types::TriggerSource::Mark,
+            })
+        );
+
+        // Triggered stop orders keep their original types.
+        let futures = r#"{"avgPrice":"0.00000","clientOrderId":"abc","cumQuote":"0","executedQty":"0","orderId":1917642,"origQty":"0.40","origType":"STOP","price":"8900","reduceOnly":true,"side":"SELL","positionSide":"BOTH","status":"NEW","stopPrice":"9000","closePosition":false,"symbol":"BTCUSDT","time":1579276756075,"timeInForce":"GTC","type":"LIMIT","updateTime":1579276756075,"workingType":"CONTRACT_PRICE","priceProtect":false}"#;
+        let futures = order(futures)?;
+        assert_eq!(
+            futures.target.kind,
+            types::OrderKind::StopLimit(types::Trigger::new(dec!(9000)), dec!(8900))
+        );
+        Ok(())
+    }
+}
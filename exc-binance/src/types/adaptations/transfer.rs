@@ -0,0 +1,127 @@
+use exc_core::{types, Adaptor, Asset, ExchangeError, Str};
+use futures::FutureExt;
+
+use crate::{
+    http::{
+        request::{
+            transfer::AccountType, QuerySubAccountUniversalTransfer, QueryUniversalTransfer,
+            SubAccountUniversalTransfer, UniversalTransfer,
+        },
+        response::{TransferRecords, TransferResult},
+    },
+    Request,
+};
+
+/// The wallet name used in the universal transfer types.
+fn wallet_name(kind: types::WalletKind) -> &'static str {
+    match kind {
+        types::WalletKind::Spot => "MAIN",
+        types::WalletKind::Funding => "FUNDING",
+        types::WalletKind::UsdMarginFutures => "UMFUTURE",
+        types::WalletKind::Margin => "MARGIN",
+    }
+}
+
+fn universal_transfer_type(from: &types::Wallet, to: &types::Wallet) -> String {
+    format!("{}_{}", wallet_name(from.kind), wallet_name(to.kind))
+}
+
+fn account_type(kind: types::WalletKind) -> Result<AccountType, ExchangeError> {
+    match kind {
+        types::WalletKind::Spot => Ok(AccountType::Spot),
+        types::WalletKind::UsdMarginFutures => Ok(AccountType::UsdtFuture),
+        types::WalletKind::Margin => Ok(AccountType::Margin),
+        types::WalletKind::Funding => Err(ExchangeError::Other(anyhow::anyhow!(
+            "the funding wallet is not supported by the sub-account transfer of binance"
+        ))),
+    }
+}
+
+fn is_sub_account_transfer(from: &types::Wallet, to: &types::Wallet) -> bool {
+    from.sub_account.is_some() || to.sub_account.is_some()
+}
+
+impl Adaptor<types::Transfer> for Request {
+    fn from_request(req: types::Transfer) -> Result<Self, ExchangeError> {
+        let asset = req.asset.to_string();
+        if is_sub_account_transfer(&req.from, &req.to) {
+            Ok(Self::with_rest_payload(SubAccountUniversalTransfer {
+                from_email: req.from.sub_account.as_ref().map(|id| id.to_string()),
+                to_email: req.to.sub_account.as_ref().map(|id| id.to_string()),
+                from_account_type: account_type(req.from.kind)?,
+                to_account_type: account_type(req.to.kind)?,
+                asset,
+                amount: req.amount,
+            }))
+        } else {
+            Ok(Self::with_rest_payload(UniversalTransfer {
+                kind: universal_transfer_type(&req.from, &req.to),
+                asset,
+                amount: req.amount,
+            }))
+        }
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::Transfer as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let result = resp.into_response::<TransferResult>()?;
+            Ok(Str::new(result.tran_id.to_string()))
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<types::QueryTransfer> for Request {
+    fn from_request(req: types::QueryTransfer) -> Result<Self, ExchangeError> {
+        let tran_id = req.id.parse::<i64>().map_err(|err| {
+            ExchangeError::Other(anyhow::anyhow!("invalid transfer id `{}`: {err}", req.id))
+        })?;
+        if is_sub_account_transfer(&req.from, &req.to) {
+            Ok(Self::with_rest_payload(QuerySubAccountUniversalTransfer {
+                from_email: req.from.sub_account.as_ref().map(|id| id.to_string()),
+                to_email: req.to.sub_account.as_ref().map(|id| id.to_string()),
+                limit: Some(500),
+                tran_id: Some(tran_id),
+            }))
+        } else {
+            Ok(Self::with_rest_payload(QueryUniversalTransfer {
+                kind: universal_transfer_type(&req.from, &req.to),
+                size: Some(100),
+                tran_id: Some(tran_id),
+            }))
+        }
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<types::QueryTransfer as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let TransferRecords(records) = resp.into_response::<TransferRecords>()?;
+            let record = records
+                .into_iter()
+                .next()
+                .ok_or_else(|| ExchangeError::Other(anyhow::anyhow!("transfer not found")))?;
+            let status = match record.status.as_str() {
+                "CONFIRMED" | "SUCCESS" => types::TransferStatus::Success,
+                "PENDING" | "PROCESS" => types::TransferStatus::Pending,
+                "FAILED" => types::TransferStatus::Failed,
+                status => {
+                    return Err(ExchangeError::unexpected_response_type(format!(
+                        "unknown transfer status: {status}"
+                    )))
+                }
+            };
+            Ok(types::TransferState {
+                id: Str::new(record.tran_id.to_string()),
+                asset: record.asset.parse::<Asset>().map_err(|err| {
+                    ExchangeError::unexpected_response_type(format!("invalid asset: {err}"))
+                })?,
+                amount: record.amount.normalize(),
+                status,
+            })
+        }
+        .boxed())
+    }
+}
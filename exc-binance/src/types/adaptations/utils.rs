@@ -1,6 +1,14 @@
-use exc_core::{types::utils::Reconnect, Adaptor};
+use exc_core::{
+    types::utils::{FetchServerTime, Reconnect},
+    Adaptor,
+};
+use futures::FutureExt;
 
-use crate::{websocket::request::WsRequest, Request};
+use crate::{
+    http::{request::utils::ServerTime, response},
+    websocket::request::WsRequest,
+    Request,
+};
 
 impl Adaptor<Reconnect> for Request {
     fn from_request(_req: Reconnect) -> Result<Self, exc_core::ExchangeError> {
@@ -13,3 +21,19 @@ impl Adaptor<Reconnect> for Request {
         Ok(())
     }
 }
+
+impl Adaptor<FetchServerTime> for Request {
+    fn from_request(_req: FetchServerTime) -> Result<Self, exc_core::ExchangeError> {
+        Ok(Self::with_rest_payload(ServerTime))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchServerTime as exc_core::Request>::Response, exc_core::ExchangeError> {
+        Ok(async move {
+            let time = resp.into_response::<response::ServerTime>()?;
+            super::from_timestamp(time.server_time)
+        }
+        .boxed())
+    }
+}
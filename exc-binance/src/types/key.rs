@@ -1,3 +1,4 @@
+use exc_core::util::clock_sync::Clock;
 use hmac::{Hmac, Mac};
 use serde::{Deserialize, Serialize};
 use sha2::Sha256;
@@ -17,6 +18,9 @@ pub enum SignError {
 
 type HmacSha256 = Hmac<Sha256>;
 
+/// The default recv window (in millis).
+pub const DEFAULT_RECV_WINDOW: i64 = 5000;
+
 /// Binance API Key.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BinanceKey {
@@ -31,6 +35,19 @@ impl BinanceKey {
     pub fn sign<T: Serialize>(&self, params: T) -> Result<SignedParams<T>, SignError> {
         SigningParams::now(params).signed(self)
     }
+
+    /// Sign with the time of the given clock and the given recv window (in millis).
+    pub fn sign_with<T: Serialize>(
+        &self,
+        params: T,
+        clock: &Clock,
+        recv_window: i64,
+    ) -> Result<SignedParams<T>, SignError> {
+        let now = clock.now().unix_timestamp_nanos() / 1_000_000;
+        SigningParams::with_timestamp(params, now as i64)
+            .recv_window(recv_window)
+            .signed(self)
+    }
 }
 
 /// Signing params.
@@ -47,11 +64,16 @@ impl<T> SigningParams<T> {
     fn with_timestamp(params: T, timestamp: i64) -> Self {
         Self {
             params,
-            recv_window: 5000,
+            recv_window: DEFAULT_RECV_WINDOW,
             timestamp,
         }
     }
 
+    fn recv_window(mut self, recv_window: i64) -> Self {
+        self.recv_window = recv_window;
+        self
+    }
+
     /// Sign the given params now.
     pub fn now(params: T) -> Self {
         let now = OffsetDateTime::now_utc().unix_timestamp_nanos() / 1_000_000;
@@ -19,4 +19,11 @@ pub use crate::websocket::protocol::frame::Name;
 
 impl exc_core::Request for Request {
     type Response = Response;
+
+    fn is_idempotent(&self) -> bool {
+        match self {
+            Self::Http(req) => exc_core::Request::is_idempotent(req),
+            Self::Ws(_) => true,
+        }
+    }
 }
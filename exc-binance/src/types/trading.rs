@@ -1,4 +1,5 @@
 use exc_core::{types, ExchangeError};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Order side.
@@ -23,6 +24,26 @@ pub enum PositionSide {
     Both,
 }
 
+/// Margin type.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum MarginType {
+    /// Crossed.
+    #[serde(rename = "CROSSED", alias = "cross", alias = "crossed")]
+    Crossed,
+    /// Isolated.
+    #[serde(rename = "ISOLATED", alias = "isolated")]
+    Isolated,
+}
+
+impl From<MarginType> for types::MarginMode {
+    fn from(ty: MarginType) -> Self {
+        match ty {
+            MarginType::Crossed => Self::Cross,
+            MarginType::Isolated => Self::Isolated,
+        }
+    }
+}
+
 /// Time-in-force.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -92,4 +113,193 @@ pub enum OrderType {
     TrailingStopMarket,
     /// Limit Maker.
     LimitMaker,
+    /// Stop-Loss (spot).
+    StopLoss,
+    /// Stop-Loss-Limit (spot).
+    StopLossLimit,
+    /// Take-Profit-Limit (spot).
+    TakeProfitLimit,
+}
+
+/// Convert the trigger source into the working type of usd-margin futures.
+pub(crate) fn working_type(source: types::TriggerSource) -> Result<&'static str, ExchangeError> {
+    match source {
+        types::TriggerSource::Last => Ok("CONTRACT_PRICE"),
+        types::TriggerSource::Mark => Ok("MARK_PRICE"),
+        types::TriggerSource::Index => Err(ExchangeError::Unsupported(anyhow::anyhow!(
+            "triggering by the index price is not supported by binance"
+        ))),
+    }
+}
+
+/// Convert the working type of usd-margin futures into the trigger source.
+pub(crate) fn trigger_source(working_type: &str) -> types::TriggerSource {
+    match working_type {
+        "MARK_PRICE" => types::TriggerSource::Mark,
+        _ => types::TriggerSource::Last,
+    }
+}
+
+/// The conditional order kind of usd-margin futures (`None` if it is not conditional).
+/// The callback rate is in percentage.
+pub(crate) fn futures_conditional_kind(
+    order_type: OrderType,
+    price: Decimal,
+    stop_price: Decimal,
+    working_type: &str,
+    activation: Option<Decimal>,
+    callback_rate: Option<Decimal>,
+) -> Option<types::OrderKind> {
+    let trigger =
+        types::Trigger::new(stop_price.normalize()).with_source(trigger_source(working_type));
+    let kind = match order_type {
+        OrderType::Stop => types::OrderKind::StopLimit(trigger, price.normalize()),
+        OrderType::StopMarket => types::OrderKind::StopMarket(trigger),
+        OrderType::TakeProfit => types::OrderKind::TakeProfitLimit(trigger, price.normalize()),
+        OrderType::TakeProfitMarket => types::OrderKind::TakeProfitMarket(trigger),
+        OrderType::TrailingStopMarket => types::OrderKind::TrailingStop(types::TrailingStop {
+            callback_rate: (callback_rate.unwrap_or_default() / Decimal::ONE_HUNDRED).normalize(),
+            activation: activation
+                .filter(|price| !price.is_zero())
+                .map(|price| price.normalize()),
+            source: trigger.source,
+        }),
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// The conditional order kind of spot (`None` if it is not conditional).
+/// The trailing delta is in BIPS, and the stop price of a trailing stop is its activation price.
+pub(crate) fn spot_conditional_kind(
+    order_type: OrderType,
+    price: Decimal,
+    stop_price: Decimal,
+    trailing_delta: Option<Decimal>,
+) -> Option<types::OrderKind> {
+    let trigger = types::Trigger::new(stop_price.normalize());
+    let kind = match order_type {
+        OrderType::StopLoss => match trailing_delta.filter(|delta| !delta.is_zero()) {
+            Some(delta) => types::OrderKind::TrailingStop(types::TrailingStop {
+                callback_rate: (delta / Decimal::from(10_000)).normalize(),
+                activation: (!stop_price.is_zero()).then(|| stop_price.normalize()),
+                source: types::TriggerSource::Last,
+            }),
+            None => types::OrderKind::StopMarket(trigger),
+        },
+        OrderType::StopLossLimit => types::OrderKind::StopLimit(trigger, price.normalize()),
+        OrderType::TakeProfit => types::OrderKind::TakeProfitMarket(trigger),
+        OrderType::TakeProfitLimit => types::OrderKind::TakeProfitLimit(trigger, price.normalize()),
+        _ => return None,
+    };
+    Some(kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_working_type() {
+        for source in [types::TriggerSource::Last, types::TriggerSource::Mark] {
+            assert_eq!(trigger_source(working_type(source).unwrap()), source);
+        }
+        assert!(working_type(types::TriggerSource::Index).is_err());
+    }
+
+    #[test]
+    fn test_futures_conditional_kind() {
+        let kind = |ty, working_type, activation, rate| {
+            futures_conditional_kind(ty, dec!(89.0), dec!(90.0), working_type, activation, rate)
+        };
+        let mark = types::Trigger::new(dec!(90)).with_source(types::TriggerSource::Mark);
+        let last = types::Trigger::new(dec!(90));
+        assert_eq!(
+            kind(OrderType::StopMarket, "MARK_PRICE", None, None),
+            Some(types::OrderKind::StopMarket(mark))
+        );
+        assert_eq!(
+            kind(OrderType::Stop, "CONTRACT_PRICE", None, None),
+            Some(types::OrderKind::StopLimit(last, dec!(89)))
+        );
+        assert_eq!(
+            kind(OrderType::TakeProfitMarket, "CONTRACT_PRICE", None, None),
+            Some(types::OrderKind::TakeProfitMarket(last))
+        );
+        assert_eq!(
+            kind(OrderType::TakeProfit, "MARK_PRICE", None, None),
+            Some(types::OrderKind::TakeProfitLimit(mark, dec!(89)))
+        );
+        // The callback rate is in percentage, and a zero activation price means no activation.
+        assert_eq!(
+            kind(
+                OrderType::TrailingStopMarket,
+                "CONTRACT_PRICE",
+                Some(dec!(0)),
+                Some(dec!(1.5))
+            ),
+            Some(types::OrderKind::TrailingStop(types::TrailingStop {
+                callback_rate: dec!(0.015),
+                activation: None,
+                source: types::TriggerSource::Last,
+            }))
+        );
+        assert_eq!(
+            kind(
+                OrderType::TrailingStopMarket,
+                "MARK_PRICE",
+                Some(dec!(100)),
+                Some(dec!(1))
+            ),
+            Some(types::OrderKind::TrailingStop(types::TrailingStop {
+                callback_rate: dec!(0.01),
+                activation: Some(dec!(100)),
+                source: types::TriggerSource::Mark,
+            }))
+        );
+        assert_eq!(kind(OrderType::Limit, "CONTRACT_PRICE", None, None), None);
+    }
+
+    #[test]
+    fn test_spot_conditional_kind() {
+        let trigger = types::Trigger::new(dec!(90));
+        assert_eq!(
+            spot_conditional_kind(OrderType::StopLoss, dec!(0), dec!(90.0), None),
+            Some(types::OrderKind::StopMarket(trigger))
+        );
+        assert_eq!(
+            spot_conditional_kind(OrderType::StopLossLimit, dec!(89.0), dec!(90.0), None),
+            Some(types::OrderKind::StopLimit(trigger, dec!(89)))
+        );
+        assert_eq!(
+            spot_conditional_kind(OrderType::TakeProfit, dec!(0), dec!(90.0), None),
+            Some(types::OrderKind::TakeProfitMarket(trigger))
+        );
+        assert_eq!(
+            spot_conditional_kind(OrderType::TakeProfitLimit, dec!(89.0), dec!(90.0), None),
+            Some(types::OrderKind::TakeProfitLimit(trigger, dec!(89)))
+        );
+        // The trailing delta is in BIPS.
+        assert_eq!(
+            spot_conditional_kind(OrderType::StopLoss, dec!(0), dec!(0), Some(dec!(150))),
+            Some(types::OrderKind::TrailingStop(types::TrailingStop {
+                callback_rate: dec!(0.015),
+                activation: None,
+                source: types::TriggerSource::Last,
+            }))
+        );
+        assert_eq!(
+            spot_conditional_kind(OrderType::StopLoss, dec!(0), dec!(100), Some(dec!(100))),
+            Some(types::OrderKind::TrailingStop(types::TrailingStop {
+                callback_rate: dec!(0.01),
+                activation: Some(dec!(100)),
+                source: types::TriggerSource::Last,
+            }))
+        );
+        assert_eq!(
+            spot_conditional_kind(OrderType::Market, dec!(0), dec!(0), None),
+            None
+        );
+    }
 }
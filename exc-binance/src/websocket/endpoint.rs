@@ -1,12 +1,18 @@
 use std::{collections::HashSet, time::Duration};
 
-use tower::ServiceBuilder;
+use exc_core::weight::RateLimiter;
 use tower::{reconnect::Reconnect, ServiceExt};
 
 use crate::types::Name;
 
 use super::connect::{BinanceWsConnect, BinanceWsHost, BinanceWsTarget, Http};
-use super::{error::WsError, protocol::WsClient, request::WsRequest, BinanceWebsocketApi};
+use super::{
+    error::WsError,
+    limit::{self, WsRateLimit},
+    protocol::WsClient,
+    request::WsRequest,
+    BinanceWebsocketApi,
+};
 
 const DEFAULT_KEEP_ALIVE_TIMEOUT: Duration = Duration::from_secs(30);
 const DEFAULT_STREAM_TIMEOUT: Duration = Duration::from_secs(30);
@@ -22,7 +28,7 @@ pub struct WsEndpoint {
     listen_key_retry: Option<usize>,
     listen_key_refresh_interval: Option<Duration>,
     listen_key_stop_refreshing_after: Option<Duration>,
-    rate_limit: (u64, Duration),
+    limiter: RateLimiter,
 }
 
 impl WsEndpoint {
@@ -40,7 +46,7 @@ impl WsEndpoint {
             listen_key_retry: None,
             listen_key_refresh_interval: None,
             listen_key_stop_refreshing_after: None,
-            rate_limit: DEFAULT_RATE_LIMIT,
+            limiter: limit::rate_limiter(DEFAULT_RATE_LIMIT.0, DEFAULT_RATE_LIMIT.1),
         }
     }
 
@@ -90,7 +96,14 @@ impl WsEndpoint {
 
     /// Set rate-limit of the websocket request.
     pub fn rate_limit(&mut self, num: u64, per: Duration) -> &mut Self {
-        self.rate_limit = (num, per);
+        self.limiter = limit::rate_limiter(num, per);
+        self
+    }
+
+    /// Set the rate limiter of the websocket requests, which costs `1`
+    /// of the [`WS_REQUESTS`](limit::WS_REQUESTS) bucket for each request.
+    pub fn rate_limiter(&mut self, limiter: RateLimiter) -> &mut Self {
+        self.limiter = limiter;
         self
     }
 
@@ -116,10 +129,7 @@ impl WsEndpoint {
                 Ok(err) => *err,
                 Err(err) => WsError::UnknownConnection(err),
             });
-        let svc = ServiceBuilder::default()
-            .rate_limit(self.rate_limit.0, self.rate_limit.1)
-            .service(connection)
-            .boxed();
+        let svc = WsRateLimit::new(connection, self.limiter.clone()).boxed();
         BinanceWebsocketApi { svc }
     }
 }
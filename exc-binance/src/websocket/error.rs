@@ -1,3 +1,4 @@
+use exc_core::ExchangeError;
 use thiserror::Error;
 use tower::BoxError;
 
@@ -17,9 +18,9 @@ pub enum WsError {
     /// Transport is borken.
     #[error("transport is broken")]
     TransportIsBoken,
-    /// Websocket errors.
+    /// Websocket errors (boxed to keep the results small).
     #[error("websocket: {0}")]
-    Websocket(#[from] tokio_tungstenite::tungstenite::Error),
+    Websocket(Box<tokio_tungstenite::tungstenite::Error>),
     /// Remote close.
     #[error("websocket: remote close (received a close frame)")]
     RemoteClose,
@@ -53,7 +54,16 @@ pub enum WsError {
     /// Login error.
     #[error("login: {0}")]
     Login(#[from] RestError),
+    /// Standard exchange errors.
+    #[error("exchange: {0}")]
+    Exchange(#[from] ExchangeError),
     /// Listen key is expired.
     #[error("listen key is expired: at={0}")]
     ListenKeyExpired(i64),
 }
+
+impl From<tokio_tungstenite::tungstenite::Error> for WsError {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        Self::Websocket(Box::new(err))
+    }
+}
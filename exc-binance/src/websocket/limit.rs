@@ -0,0 +1,89 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use exc_core::weight::{RateLimiter, Weight};
+use futures::ready;
+use tokio::time::Sleep;
+use tower::Service;
+
+use super::error::WsError;
+
+/// The bucket of the websocket requests (per connection).
+pub const WS_REQUESTS: &str = "ws_requests";
+
+/// Create a rate limiter allowing `num` websocket requests in every `per`
+/// (default to `2` per second).
+pub fn rate_limiter(num: u64, per: Duration) -> RateLimiter {
+    RateLimiter::new().with_bucket(WS_REQUESTS, num, per)
+}
+
+/// Acquire `1` of the [`WS_REQUESTS`] bucket before each request.
+pub(crate) struct WsRateLimit<S> {
+    svc: S,
+    limiter: RateLimiter,
+    acquired: bool,
+    sleep: Option<Pin<Box<Sleep>>>,
+}
+
+impl<S> WsRateLimit<S> {
+    pub(crate) fn new(svc: S, limiter: RateLimiter) -> Self {
+        Self {
+            svc,
+            limiter,
+            acquired: false,
+            sleep: None,
+        }
+    }
+}
+
+impl<S, R> Service<R> for WsRateLimit<S>
+where
+    S: Service<R, Error = WsError>,
+{
+    type Response = S::Response;
+    type Error = WsError;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        while !self.acquired {
+            if let Some(sleep) = self.sleep.as_mut() {
+                ready!(sleep.as_mut().poll(cx));
+                self.sleep = None;
+            }
+            match self.limiter.try_acquire(&Weight::bucket(WS_REQUESTS, 1))? {
+                None => self.acquired = true,
+                Some(wait) => self.sleep = Some(Box::pin(tokio::time::sleep(wait))),
+            }
+        }
+        self.svc.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        self.acquired = false;
+        self.svc.call(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_ws_rate_limit() -> anyhow::Result<()> {
+        let limiter = rate_limiter(2, Duration::from_secs(1));
+        let svc = tower::service_fn(|_: ()| async { Ok::<_, WsError>(()) });
+        let mut svc = WsRateLimit::new(svc, limiter.clone());
+        limiter.record_usage(WS_REQUESTS, 2);
+        let start = tokio::time::Instant::now();
+        (&mut svc).oneshot(()).await?;
+        // Wait for the next window.
+        assert!(start.elapsed() <= Duration::from_secs(1));
+        assert_eq!(limiter.usage(WS_REQUESTS), Some(1));
+        Ok(())
+    }
+}
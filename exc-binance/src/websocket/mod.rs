@@ -13,6 +13,9 @@ pub mod response;
 /// Binance websocket endpoint.
 pub mod endpoint;
 
+/// Binance websocket rate limit.
+pub mod limit;
+
 pub(crate) mod connect;
 
 use std::task::{Context, Poll};
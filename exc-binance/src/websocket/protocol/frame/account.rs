@@ -6,7 +6,7 @@ use rust_decimal::Decimal;
 use serde::Deserialize;
 
 use crate::{
-    types::trading::{OrderSide, PositionSide, Status, TimeInForce},
+    types::trading::{self, MarginType, OrderSide, PositionSide, Status, TimeInForce},
     websocket::error::WsError,
 };
 
@@ -38,6 +38,148 @@ pub enum AccountEvent {
     /// Order update (for spot).
     #[serde(rename = "executionReport")]
     ExecutionReport(ExecutionReport),
+    /// Account update.
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    AccountUpdate {
+        /// Event timestamp.
+        #[serde(rename = "E")]
+        event_ts: i64,
+        /// Trade timestamp.
+        #[serde(rename = "T")]
+        trade_ts: i64,
+        /// Account.
+        #[serde(rename = "a")]
+        account: AccountUpdate,
+    },
+    /// Account update (for spot).
+    #[serde(rename = "outboundAccountPosition")]
+    OutboundAccountPosition(OutboundAccountPosition),
+}
+
+/// Account update.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountUpdate {
+    /// Reason.
+    #[serde(rename = "m")]
+    pub reason: String,
+    /// Balances.
+    #[serde(rename = "B", default)]
+    pub balances: Vec<AccountUpdateBalance>,
+    /// Positions.
+    #[serde(rename = "P", default)]
+    pub positions: Vec<AccountUpdatePosition>,
+    /// Instrument of the positions (only set for the updates broken down by instrument).
+    #[serde(skip)]
+    pub(crate) inst: Option<String>,
+}
+
+impl AccountUpdate {
+    /// Whether this is a position-only update.
+    pub fn is_position_update(&self) -> bool {
+        self.balances.is_empty() && !self.positions.is_empty()
+    }
+}
+
+/// Position in account update.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountUpdatePosition {
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: Str,
+    /// Position amount.
+    #[serde(rename = "pa")]
+    pub position_amount: Decimal,
+    /// Entry price.
+    #[serde(rename = "ep")]
+    pub entry_price: Decimal,
+    /// Accumulated realized (pre-fee).
+    #[serde(rename = "cr")]
+    pub accumulated_realized: Decimal,
+    /// Unrealized PnL.
+    #[serde(rename = "up")]
+    pub unrealized_pnl: Decimal,
+    /// Margin type.
+    #[serde(rename = "mt")]
+    pub margin_type: MarginType,
+    /// Isolated wallet (if isolated position).
+    #[serde(rename = "iw")]
+    pub isolated_wallet: Decimal,
+    /// Position side.
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+}
+
+/// Balance in account update.
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountUpdateBalance {
+    /// Asset.
+    #[serde(rename = "a")]
+    pub asset: Asset,
+    /// Wallet balance.
+    #[serde(rename = "wb")]
+    pub wallet_balance: Decimal,
+    /// Cross wallet balance.
+    #[serde(rename = "cw")]
+    pub cross_wallet_balance: Decimal,
+    /// Balance change (except PnL and commission).
+    #[serde(rename = "bc")]
+    pub balance_change: Decimal,
+}
+
+/// Account update (for spot).
+#[derive(Debug, Clone, Deserialize)]
+pub struct OutboundAccountPosition {
+    /// Event timestamp.
+    #[serde(rename = "E")]
+    pub event_ts: i64,
+    /// Update timestamp.
+    #[serde(rename = "u")]
+    pub update_ts: i64,
+    /// Balances.
+    #[serde(rename = "B")]
+    pub balances: Vec<SpotBalance>,
+}
+
+/// Balance in account update (for spot).
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpotBalance {
+    /// Asset.
+    #[serde(rename = "a")]
+    pub asset: Asset,
+    /// Free.
+    #[serde(rename = "f")]
+    pub free: Decimal,
+    /// Locked.
+    #[serde(rename = "l")]
+    pub locked: Decimal,
+}
+
+/// Position Update Frame.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum PositionFrame {
+    /// USD-M Futures.
+    UsdMarginFutures {
+        /// Event timestamp.
+        event_ts: i64,
+        /// Positions.
+        positions: Vec<AccountUpdatePosition>,
+    },
+}
+
+/// Balance Update Frame.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum BalanceFrame {
+    /// USD-M Futures.
+    UsdMarginFutures {
+        /// Event timestamp.
+        event_ts: i64,
+        /// Account update.
+        update: AccountUpdate,
+    },
+    /// Spot.
+    Spot(OutboundAccountPosition),
 }
 
 /// Order Update Frame.
@@ -68,6 +210,39 @@ pub enum OrderType {
     Liquidation,
     /// Limit maker.
     LimitMaker,
+    /// Stop market.
+    StopMarket,
+    /// Take profit market.
+    TakeProfitMarket,
+    /// Trailing stop market.
+    TrailingStopMarket,
+    /// Stop loss (spot).
+    StopLoss,
+    /// Stop loss limit (spot).
+    StopLossLimit,
+    /// Take profit limit (spot).
+    TakeProfitLimit,
+}
+
+impl OrderType {
+    /// Convert into the order type of rest api (`None` for liquidation).
+    fn rest(self) -> Option<trading::OrderType> {
+        let ty = match self {
+            Self::Market => trading::OrderType::Market,
+            Self::Limit => trading::OrderType::Limit,
+            Self::Stop => trading::OrderType::Stop,
+            Self::TakeProfit => trading::OrderType::TakeProfit,
+            Self::Liquidation => return None,
+            Self::LimitMaker => trading::OrderType::LimitMaker,
+            Self::StopMarket => trading::OrderType::StopMarket,
+            Self::TakeProfitMarket => trading::OrderType::TakeProfitMarket,
+            Self::TrailingStopMarket => trading::OrderType::TrailingStopMarket,
+            Self::StopLoss => trading::OrderType::StopLoss,
+            Self::StopLossLimit => trading::OrderType::StopLossLimit,
+            Self::TakeProfitLimit => trading::OrderType::TakeProfitLimit,
+        };
+        Some(ty)
+    }
 }
 
 /// Update kind.
@@ -214,6 +389,12 @@ pub struct ExecutionReport {
     /// Price. (FIXME: should this to be optional?)
     #[serde(rename = "p")]
     pub price: Decimal,
+    /// Stop price.
+    #[serde(rename = "P", default)]
+    pub stop_price: Decimal,
+    /// Trailing delta (in BIPS).
+    #[serde(rename = "d", default)]
+    pub trailing_delta: Option<Decimal>,
     /// Update kind.
     #[serde(rename = "x")]
     pub kind: UpdateKind,
@@ -375,6 +556,13 @@ impl Nameable for AccountEvent {
                 Name::order_trade_update(&order.symbol.to_lowercase())
             }
             Self::ExecutionReport(r) => Name::order_trade_update(&r.symbol.to_lowercase()),
+            Self::AccountUpdate { account, .. } if account.is_position_update() => {
+                match account.inst.as_ref() {
+                    Some(inst) => Name::position_update().with_inst(inst),
+                    None => Name::position_update(),
+                }
+            }
+            Self::AccountUpdate { .. } | Self::OutboundAccountPosition(_) => Name::account_update(),
         }
     }
 }
@@ -423,33 +611,153 @@ impl TryFrom<StreamFrame> for OrderUpdateFrame {
     }
 }
 
+impl TryFrom<StreamFrame> for BalanceFrame {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        match frame.data {
+            StreamFrameKind::AccountEvent(e) => match e {
+                AccountEvent::AccountUpdate {
+                    event_ts, account, ..
+                } => Ok(Self::UsdMarginFutures {
+                    event_ts,
+                    update: account,
+                }),
+                AccountEvent::OutboundAccountPosition(p) => Ok(Self::Spot(p)),
+                e => Err(WsError::UnexpectedFrame(anyhow::anyhow!("{e:?}"))),
+            },
+            e => Err(WsError::UnexpectedFrame(anyhow::anyhow!("{e:?}"))),
+        }
+    }
+}
+
+impl TryFrom<StreamFrame> for PositionFrame {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        match frame.data {
+            StreamFrameKind::AccountEvent(AccountEvent::AccountUpdate {
+                event_ts,
+                account,
+                ..
+            }) => Ok(Self::UsdMarginFutures {
+                event_ts,
+                positions: account.positions,
+            }),
+            e => Err(WsError::UnexpectedFrame(anyhow::anyhow!("{e:?}"))),
+        }
+    }
+}
+
+impl TryFrom<PositionFrame> for Vec<types::Position> {
+    type Error = ExchangeError;
+
+    fn try_from(value: PositionFrame) -> Result<Self, Self::Error> {
+        match value {
+            PositionFrame::UsdMarginFutures {
+                event_ts,
+                positions,
+            } => {
+                let ts = crate::types::adaptations::from_timestamp(event_ts)?;
+                Ok(positions
+                    .into_iter()
+                    .map(|p| types::Position {
+                        ts,
+                        instrument: Str::new(p.symbol.to_lowercase()),
+                        size: p.position_amount.normalize(),
+                        entry_price: p.entry_price.normalize(),
+                        mark_price: None,
+                        unrealized_pnl: p.unrealized_pnl.normalize(),
+                        leverage: None,
+                        margin_mode: p.margin_type.into(),
+                    })
+                    .collect())
+            }
+        }
+    }
+}
+
+impl TryFrom<BalanceFrame> for types::Balances {
+    type Error = ExchangeError;
+
+    fn try_from(value: BalanceFrame) -> Result<Self, Self::Error> {
+        match value {
+            BalanceFrame::UsdMarginFutures { event_ts, update } => Ok(types::Balances {
+                ts: crate::types::adaptations::from_timestamp(event_ts)?,
+                balances: update
+                    .balances
+                    .into_iter()
+                    .map(|b| {
+                        let total = b.wallet_balance.normalize();
+                        let free = b.cross_wallet_balance.normalize();
+                        let balance = types::Balance {
+                            free,
+                            locked: (total - free).max(Decimal::ZERO),
+                            total,
+                        };
+                        (b.asset, balance)
+                    })
+                    .collect(),
+            }),
+            BalanceFrame::Spot(p) => Ok(types::Balances {
+                ts: crate::types::adaptations::from_timestamp(p.update_ts)?,
+                balances: p
+                    .balances
+                    .into_iter()
+                    .map(|b| {
+                        let balance = types::Balance::new(b.free.normalize(), b.locked.normalize());
+                        (b.asset, balance)
+                    })
+                    .collect(),
+            }),
+        }
+    }
+}
+
 impl TryFrom<OrderUpdateFrame> for types::OrderUpdate {
     type Error = ExchangeError;
 
     fn try_from(value: OrderUpdateFrame) -> Result<Self, Self::Error> {
         match value {
             OrderUpdateFrame::UsdMarginFutures(update) => {
-                let kind = match update.order_type {
-                    OrderType::Limit => match update.time_in_force {
-                        TimeInForce::Gtc => types::OrderKind::Limit(
-                            update.price.normalize(),
-                            types::TimeInForce::GoodTilCancelled,
-                        ),
-                        TimeInForce::Fok => types::OrderKind::Limit(
-                            update.price.normalize(),
-                            types::TimeInForce::FillOrKill,
-                        ),
-                        TimeInForce::Ioc => types::OrderKind::Limit(
-                            update.price.normalize(),
-                            types::TimeInForce::ImmediateOrCancel,
-                        ),
-                        TimeInForce::Gtx => types::OrderKind::PostOnly(update.price.normalize()),
-                    },
-                    OrderType::Market => types::OrderKind::Market,
-                    other => {
-                        return Err(ExchangeError::Other(anyhow!(
-                            "unsupported order type: {other:?}"
-                        )));
+                // Conditional orders become market or limit orders once triggered.
+                let conditional = update.original_order_type.rest().and_then(|ty| {
+                    trading::futures_conditional_kind(
+                        ty,
+                        update.price,
+                        update.trigger_price,
+                        &update.trigger_type,
+                        update.active_price,
+                        update.cr,
+                    )
+                });
+                let kind = if let Some(kind) = conditional {
+                    kind
+                } else {
+                    match update.order_type {
+                        OrderType::Limit => match update.time_in_force {
+                            TimeInForce::Gtc => types::OrderKind::Limit(
+                                update.price.normalize(),
+                                types::TimeInForce::GoodTilCancelled,
+                            ),
+                            TimeInForce::Fok => types::OrderKind::Limit(
+                                update.price.normalize(),
+                                types::TimeInForce::FillOrKill,
+                            ),
+                            TimeInForce::Ioc => types::OrderKind::Limit(
+                                update.price.normalize(),
+                                types::TimeInForce::ImmediateOrCancel,
+                            ),
+                            TimeInForce::Gtx => {
+                                types::OrderKind::PostOnly(update.price.normalize())
+                            }
+                        },
+                        OrderType::Market => types::OrderKind::Market,
+                        other => {
+                            return Err(ExchangeError::Other(anyhow!(
+                                "unsupported order type: {other:?}"
+                            )));
+                        }
                     }
                 };
                 let mut filled = update.filled_size.abs().normalize();
@@ -524,11 +832,19 @@ impl TryFrom<OrderUpdateFrame> for types::OrderUpdate {
                     },
                     OrderType::Market => types::OrderKind::Market,
                     OrderType::LimitMaker => types::OrderKind::PostOnly(update.price.normalize()),
-                    other => {
-                        return Err(ExchangeError::Other(anyhow!(
-                            "unsupported order type: {other:?}"
-                        )));
-                    }
+                    other => other
+                        .rest()
+                        .and_then(|ty| {
+                            trading::spot_conditional_kind(
+                                ty,
+                                update.price,
+                                update.stop_price,
+                                update.trailing_delta,
+                            )
+                        })
+                        .ok_or_else(|| {
+                            ExchangeError::Other(anyhow!("unsupported order type: {other:?}"))
+                        })?,
                 };
                 let mut filled = update.filled_size.abs().normalize();
                 let mut size = update.size.abs().normalize();
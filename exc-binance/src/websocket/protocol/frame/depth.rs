@@ -17,12 +17,18 @@ pub struct Depth {
     /// Symbol.
     #[serde(rename = "s")]
     pub symbol: String,
-    /// Trade time.
-    #[serde(rename = "T")]
-    pub trade_timestamp: i64,
+    /// Trade time (not provided by spot).
+    #[serde(rename = "T", default)]
+    pub trade_timestamp: Option<i64>,
+    /// First update ID in event (only for diff depth streams).
+    #[serde(rename = "U", default)]
+    pub first_id: Option<usize>,
     /// Update ID.
     #[serde(rename = "u")]
     pub id: usize,
+    /// Final update ID of the previous event (only for futures diff depth streams).
+    #[serde(rename = "pu", default)]
+    pub prev_id: Option<usize>,
     /// Bids.
     #[serde(rename = "b")]
     pub bids: Vec<(Decimal, Decimal)>,
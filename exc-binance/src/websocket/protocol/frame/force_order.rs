@@ -0,0 +1,91 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::websocket::error::WsError;
+
+use super::{Name, Nameable, StreamFrame, StreamFrameKind};
+
+/// # Example
+/// A [`ForceOrder`] in JSON format:
+/// ```json
+/// {
+///     "e": "forceOrder",              // Event Type
+///     "E": 1568014460893,             // Event Time
+///     "o": {
+///         "s": "BTCUSDT",             // Symbol
+///         "S": "SELL",                // Side
+///         "o": "LIMIT",               // Order Type
+///         "f": "IOC",                 // Time in Force
+///         "q": "0.014",               // Original Quantity
+///         "p": "9910",                // Price
+///         "ap": "9910",               // Average Price
+///         "X": "FILLED",              // Order Status
+///         "l": "0.014",               // Order Last Filled Quantity
+///         "z": "0.014",               // Order Filled Accumulated Quantity
+///         "T": 1568014460893          // Order Trade Time
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct ForceOrder {
+    /// Event type.
+    #[serde(rename = "e")]
+    pub event: String,
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_timestamp: i64,
+    /// Order.
+    #[serde(rename = "o")]
+    pub order: ForceOrderData,
+}
+
+/// Liquidation order.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ForceOrderData {
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Side.
+    #[serde(rename = "S")]
+    pub side: String,
+    /// Original quantity.
+    #[serde(rename = "q")]
+    pub size: Decimal,
+    /// Price.
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    /// Average price.
+    #[serde(rename = "ap")]
+    pub avg_price: Decimal,
+    /// Filled accumulated quantity.
+    #[serde(rename = "z")]
+    pub filled_size: Decimal,
+    /// Trade time.
+    #[serde(rename = "T")]
+    pub trade_timestamp: i64,
+}
+
+impl ForceOrderData {
+    /// Is buy.
+    pub fn is_buy(&self) -> bool {
+        self.side.eq_ignore_ascii_case("buy")
+    }
+}
+
+impl Nameable for ForceOrder {
+    fn to_name(&self) -> Name {
+        Name::force_order(&self.order.symbol.to_lowercase())
+    }
+}
+
+impl TryFrom<StreamFrame> for ForceOrder {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        if let StreamFrameKind::ForceOrder(order) = frame.data {
+            Ok(order)
+        } else {
+            Err(WsError::UnexpectedFrame(anyhow::anyhow!("{frame:?}")))
+        }
+    }
+}
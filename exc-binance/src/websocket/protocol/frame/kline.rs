@@ -0,0 +1,103 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::websocket::error::WsError;
+
+use super::{Name, Nameable, StreamFrame, StreamFrameKind};
+
+/// # Example
+/// A [`Kline`] in JSON format:
+/// ```json
+/// {
+///     "e": "kline",     // Event type
+///     "E": 123456789,   // Event time
+///     "s": "BNBBTC",    // Symbol
+///     "k": {
+///         "t": 123400000, // Kline start time
+///         "T": 123460000, // Kline close time
+///         "s": "BNBBTC",  // Symbol
+///         "i": "1m",      // Interval
+///         "f": 100,       // First trade ID
+///         "L": 200,       // Last trade ID
+///         "o": "0.0010",  // Open price
+///         "c": "0.0020",  // Close price
+///         "h": "0.0025",  // High price
+///         "l": "0.0015",  // Low price
+///         "v": "1000",    // Base asset volume
+///         "n": 100,       // Number of trades
+///         "x": false,     // Is this kline closed?
+///         "q": "1.0000",  // Quote asset volume
+///         "V": "500",     // Taker buy base asset volume
+///         "Q": "0.500",   // Taker buy quote asset volume
+///         "B": "123456"   // Ignore
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct Kline {
+    /// Event type.
+    #[serde(rename = "e")]
+    pub event: String,
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_timestamp: i64,
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Kline.
+    #[serde(rename = "k")]
+    pub kline: KlineData,
+}
+
+/// Kline data.
+#[derive(Debug, Clone, Deserialize)]
+pub struct KlineData {
+    /// Start time.
+    #[serde(rename = "t")]
+    pub start_timestamp: i64,
+    /// Close time.
+    #[serde(rename = "T")]
+    pub close_timestamp: i64,
+    /// Interval.
+    #[serde(rename = "i")]
+    pub interval: String,
+    /// Open price.
+    #[serde(rename = "o")]
+    pub open: Decimal,
+    /// High price.
+    #[serde(rename = "h")]
+    pub high: Decimal,
+    /// Low price.
+    #[serde(rename = "l")]
+    pub low: Decimal,
+    /// Close price.
+    #[serde(rename = "c")]
+    pub close: Decimal,
+    /// Base asset volume.
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+    /// Is this kline closed.
+    #[serde(rename = "x")]
+    pub closed: bool,
+}
+
+impl Nameable for Kline {
+    fn to_name(&self) -> Name {
+        Name {
+            inst: Some(self.symbol.to_lowercase()),
+            channel: format!("{}_{}", self.event, self.kline.interval),
+        }
+    }
+}
+
+impl TryFrom<StreamFrame> for Kline {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        if let StreamFrameKind::Kline(kline) = frame.data {
+            Ok(kline)
+        } else {
+            Err(WsError::UnexpectedFrame(anyhow::anyhow!("{frame:?}")))
+        }
+    }
+}
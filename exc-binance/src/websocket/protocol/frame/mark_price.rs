@@ -0,0 +1,139 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+use crate::websocket::error::WsError;
+
+use super::{Name, Nameable, StreamFrame, StreamFrameKind};
+
+/// # Example
+/// A [`MarkPrice`] in JSON format:
+/// ```json
+/// {
+///     "e": "markPriceUpdate",     // Event type
+///     "E": 1562305380000,         // Event time
+///     "s": "BTCUSDT",             // Symbol
+///     "p": "11794.15000000",      // Mark price
+///     "i": "11784.62659091",      // Index price
+///     "P": "11784.25641265",      // Estimated Settle Price
+///     "r": "0.00038167",          // Funding rate
+///     "T": 1562306400000          // Next funding time
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarkPrice {
+    /// Event type.
+    #[serde(rename = "e")]
+    pub event: String,
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_timestamp: i64,
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Mark price.
+    #[serde(rename = "p")]
+    pub mark_price: Decimal,
+    /// Index price.
+    #[serde(rename = "i")]
+    pub index_price: Decimal,
+    /// Funding rate.
+    #[serde(rename = "r")]
+    pub funding_rate: Decimal,
+    /// Next funding time.
+    #[serde(rename = "T")]
+    pub next_funding_time: i64,
+}
+
+impl Nameable for MarkPrice {
+    fn to_name(&self) -> Name {
+        Name::mark_price(&self.symbol.to_lowercase())
+    }
+}
+
+impl TryFrom<StreamFrame> for MarkPrice {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        if let StreamFrameKind::MarkPrice(mark) = frame.data {
+            Ok(mark)
+        } else {
+            Err(WsError::UnexpectedFrame(anyhow::anyhow!("{frame:?}")))
+        }
+    }
+}
+
+/// Mark price (and greeks) of an option,
+/// pushed in a list with all the options of the underlying (the `<underlying>@markPrice` stream of `binance-o`).
+/// # Example
+/// A [`OptionsMarkPrice`] in JSON format:
+/// ```json
+/// {
+///     "e": "markPrice",           // Event type
+///     "E": 1663684594227,         // Event time
+///     "s": "ETH-220930-1500-C",   // Symbol
+///     "mp": "30.3",               // Mark price
+///     "i": "1294.5",              // Index price
+///     "b": "0.58",                // Bid IV
+///     "a": "0.63",                // Ask IV
+///     "vo": "0.61",               // Mark IV
+///     "d": "0.52",                // Delta
+///     "t": "-1.98",               // Theta
+///     "g": "0.0021",              // Gamma
+///     "v": "0.93"                 // Vega
+/// }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct OptionsMarkPrice {
+    /// Event type.
+    #[serde(rename = "e")]
+    pub event: String,
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_timestamp: i64,
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Mark price.
+    #[serde(rename = "mp")]
+    pub mark_price: Decimal,
+    /// Index price.
+    #[serde(rename = "i", default)]
+    pub index_price: Option<Decimal>,
+    /// Implied volatility of the best bid.
+    #[serde(rename = "b", default)]
+    pub bid_iv: Option<Decimal>,
+    /// Implied volatility of the best ask.
+    #[serde(rename = "a", default)]
+    pub ask_iv: Option<Decimal>,
+    /// Implied volatility of the mark price.
+    #[serde(rename = "vo", default)]
+    pub mark_iv: Option<Decimal>,
+    /// Delta.
+    #[serde(rename = "d", default)]
+    pub delta: Option<Decimal>,
+    /// Theta.
+    #[serde(rename = "t", default)]
+    pub theta: Option<Decimal>,
+    /// Gamma.
+    #[serde(rename = "g", default)]
+    pub gamma: Option<Decimal>,
+    /// Vega.
+    #[serde(rename = "v", default)]
+    pub vega: Option<Decimal>,
+}
+
+/// The mark prices of the options of an underlying.
+#[derive(Debug, Clone, Deserialize)]
+pub struct OptionsMarkPrices(pub Vec<OptionsMarkPrice>);
+
+impl TryFrom<StreamFrame> for OptionsMarkPrices {
+    type Error = WsError;
+
+    fn try_from(frame: StreamFrame) -> Result<Self, Self::Error> {
+        if let StreamFrameKind::OptionsMarkPrices(marks) = frame.data {
+            Ok(marks)
+        } else {
+            Err(WsError::UnexpectedFrame(anyhow::anyhow!("{frame:?}")))
+        }
+    }
+}
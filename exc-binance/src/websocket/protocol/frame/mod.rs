@@ -17,6 +17,15 @@ pub mod agg_trade;
 /// Trade.
 pub mod trade;
 
+/// Kline.
+pub mod kline;
+
+/// Mark price.
+pub mod mark_price;
+
+/// Liquidation order.
+pub mod force_order;
+
 /// Book ticker.
 pub mod book_ticker;
 
@@ -74,6 +83,30 @@ impl Name {
         }
     }
 
+    /// Kline
+    pub fn kline(inst: &str, interval: &str) -> Self {
+        Self {
+            inst: Some(inst.to_string()),
+            channel: format!("kline_{interval}"),
+        }
+    }
+
+    /// Mark price
+    pub fn mark_price(inst: &str) -> Self {
+        Self {
+            inst: Some(inst.to_string()),
+            channel: "markPrice".to_string(),
+        }
+    }
+
+    /// Liquidation order
+    pub fn force_order(inst: &str) -> Self {
+        Self {
+            inst: Some(inst.to_string()),
+            channel: "forceOrder".to_string(),
+        }
+    }
+
     /// Book ticker
     pub fn book_ticker(inst: &str) -> Self {
         Self {
@@ -99,6 +132,16 @@ impl Name {
     pub fn order_trade_update(inst: &str) -> Self {
         Self::new("orderTradeUpdate").with_inst(inst)
     }
+
+    /// Account update.
+    pub fn account_update() -> Self {
+        Self::new("accountUpdate")
+    }
+
+    /// Position update.
+    pub fn position_update() -> Self {
+        Self::new("positionUpdate")
+    }
 }
 
 impl fmt::Display for Name {
@@ -209,6 +252,60 @@ impl ServerFrame {
                         })
                         .collect()
                 }
+                StreamFrameKind::AccountEvent(AccountEvent::AccountUpdate { account, .. })
+                    if !account.positions.is_empty() =>
+                {
+                    let Self::Stream(f) = self else {
+                        unreachable!()
+                    };
+                    let StreamFrameKind::AccountEvent(AccountEvent::AccountUpdate {
+                        event_ts,
+                        trade_ts,
+                        account,
+                    }) = f.data
+                    else {
+                        unreachable!()
+                    };
+                    // Break down into a balance update, a position update
+                    // and the position updates of each instrument.
+                    let mut updates = Vec::with_capacity(account.positions.len() + 2);
+                    if !account.balances.is_empty() {
+                        updates.push(account::AccountUpdate {
+                            reason: account.reason.clone(),
+                            balances: account.balances,
+                            positions: Vec::new(),
+                            inst: None,
+                        });
+                    }
+                    for position in account.positions.iter() {
+                        updates.push(account::AccountUpdate {
+                            reason: account.reason.clone(),
+                            balances: Vec::new(),
+                            positions: vec![position.clone()],
+                            inst: Some(position.symbol.to_lowercase()),
+                        });
+                    }
+                    updates.push(account::AccountUpdate {
+                        reason: account.reason,
+                        balances: Vec::new(),
+                        positions: account.positions,
+                        inst: None,
+                    });
+                    let stream = f.stream;
+                    updates
+                        .into_iter()
+                        .map(|account| {
+                            Self::Stream(StreamFrame {
+                                stream: stream.clone(),
+                                data: StreamFrameKind::AccountEvent(AccountEvent::AccountUpdate {
+                                    event_ts,
+                                    trade_ts,
+                                    account,
+                                }),
+                            })
+                        })
+                        .collect()
+                }
                 _ => vec![self],
             },
         }
@@ -228,6 +325,10 @@ pub trait Nameable {
 pub enum StreamFrameKind {
     /// Aggregate trade.
     AggTrade(AggTrade),
+    /// Kline.
+    Kline(kline::Kline),
+    /// Mark price.
+    MarkPrice(mark_price::MarkPrice),
     /// Trade.
     Trade(trade::Trade),
     /// Book ticker.
@@ -236,10 +337,14 @@ pub enum StreamFrameKind {
     Depth(depth::Depth),
     /// Account event.
     AccountEvent(AccountEvent),
+    /// Liquidation order.
+    ForceOrder(force_order::ForceOrder),
     /// Options Order Update.
     OptionsOrder(account::OptionsOrder),
     /// Options Order Trade Update.
     OptionsOrderUpdate(account::OptionsOrderUpdate),
+    /// Options mark prices.
+    OptionsMarkPrices(mark_price::OptionsMarkPrices),
     /// Unknwon.
     Unknwon(serde_json::Value),
 }
@@ -258,9 +363,11 @@ impl StreamFrame {
     pub fn to_name(&self) -> Option<Name> {
         match &self.data {
             StreamFrameKind::AggTrade(f) => Some(f.to_name()),
+            StreamFrameKind::Kline(f) => Some(f.to_name()),
+            StreamFrameKind::MarkPrice(f) => Some(f.to_name()),
             StreamFrameKind::Trade(f) => Some(f.to_name()),
             StreamFrameKind::BookTicker(f) => Some(f.to_name()),
-            StreamFrameKind::Depth(_) => {
+            StreamFrameKind::Depth(_) | StreamFrameKind::OptionsMarkPrices(_) => {
                 let (inst, channel) = self.stream.split_once('@')?;
                 Some(Name {
                     inst: Some(inst.to_string()),
@@ -268,6 +375,7 @@ impl StreamFrame {
                 })
             }
             StreamFrameKind::AccountEvent(e) => Some(e.to_name()),
+            StreamFrameKind::ForceOrder(f) => Some(f.to_name()),
             StreamFrameKind::OptionsOrder(e) => Some(e.to_name()),
             StreamFrameKind::OptionsOrderUpdate(_) => None,
             StreamFrameKind::Unknwon(_) => {
@@ -372,7 +480,9 @@ impl TryFrom<DepthFrame> for exc_core::types::BidAsk {
                 ask: Some((t.ask.normalize(), t.ask_size.normalize())),
             }),
             DepthFrame::Depth(t) => Ok(exc_core::types::BidAsk {
-                ts: crate::types::adaptations::from_timestamp(t.trade_timestamp)?,
+                ts: crate::types::adaptations::from_timestamp(
+                    t.trade_timestamp.unwrap_or(t.event_timestamp),
+                )?,
                 bid: t.bids.first().map(|b| (b.0.normalize(), b.1.normalize())),
                 ask: t.asks.first().map(|a| (a.0.normalize(), a.1.normalize())),
             }),
@@ -426,6 +536,119 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_kline_frame() -> anyhow::Result<()> {
+        let msg = r#"{"stream":"btcusdt@kline_1m","data":{"e":"kline","E":1672531230000,"s":"BTCUSDT","k":{"t":1672531200000,"T":1672531259999,"s":"BTCUSDT","i":"1m","f":100,"L":200,"o":"16500.1","c":"16510.2","h":"16520.0","l":"16490.5","v":"12.5","n":100,"x":false,"q":"206250.0","V":"6.0","Q":"99000.0","B":"0"}}}"#;
+        let super::ServerFrame::Stream(frame) = serde_json::from_str(msg)? else {
+            anyhow::bail!("not a stream frame");
+        };
+        assert_eq!(
+            frame.to_name().map(|name| name.to_string()).as_deref(),
+            Some("btcusdt@kline_1m")
+        );
+        let kline = super::kline::Kline::try_from(frame)?;
+        assert!(!kline.kline.closed);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mark_price_frame() -> anyhow::Result<()> {
+        let msg = r#"{"stream":"btcusdt@markPrice","data":{"e":"markPriceUpdate","E":1562305380000,"s":"BTCUSDT","p":"11794.15000000","i":"11784.62659091","P":"11784.25641265","r":"0.00038167","T":1562306400000}}"#;
+        let super::ServerFrame::Stream(frame) = serde_json::from_str(msg)? else {
+            anyhow::bail!("not a stream frame");
+        };
+        assert_eq!(
+            frame.to_name().map(|name| name.to_string()).as_deref(),
+            Some("btcusdt@markPrice")
+        );
+        let mark = super::mark_price::MarkPrice::try_from(frame)?;
+        assert_eq!(mark.next_funding_time, 1562306400000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_options_mark_price_frame() -> anyhow::Result<()> {
+        let msg = r#"{"stream":"ETH@markPrice","data":[{"e":"markPrice","E":1663684594227,"s":"ETH-220930-1500-C","mp":"30.3","i":"1294.5","d":"0.52","vo":"0.61"},{"e":"markPrice","E":1663684594228,"s":"ETH-220930-1500-P","mp":"8.1"}]}"#;
+        let super::ServerFrame::Stream(frame) = serde_json::from_str(msg)? else {
+            anyhow::bail!("not a stream frame");
+        };
+        assert_eq!(
+            frame.to_name().map(|name| name.to_string()).as_deref(),
+            Some("ETH@markPrice")
+        );
+        let marks = super::mark_price::OptionsMarkPrices::try_from(frame)?;
+        assert_eq!(marks.0.len(), 2);
+        assert!(marks.0[0].delta.is_some());
+        assert!(marks.0[1].delta.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_force_order_frame() -> anyhow::Result<()> {
+        let msg = r#"{"stream":"btcusdt@forceOrder","data":{"e":"forceOrder","E":1568014460893,"o":{"s":"BTCUSDT","S":"SELL","o":"LIMIT","f":"IOC","q":"0.014","p":"9910","ap":"9910","X":"FILLED","l":"0.014","z":"0.014","T":1568014460893}}}"#;
+        let super::ServerFrame::Stream(frame) = serde_json::from_str(msg)? else {
+            anyhow::bail!("not a stream frame");
+        };
+        assert_eq!(
+            frame.to_name().map(|name| name.to_string()).as_deref(),
+            Some("btcusdt@forceOrder")
+        );
+        let order = super::force_order::ForceOrder::try_from(frame)?;
+        assert!(!order.order.is_buy());
+        Ok(())
+    }
+
+    #[test]
+    fn test_balance_frame() -> anyhow::Result<()> {
+        use exc_core::{types::Balances, Asset};
+        use rust_decimal_macros::dec;
+
+        let msg = r#"{"stream":"listenKey","data":{"e":"ACCOUNT_UPDATE","E":1564745798939,"T":1564745798938,"a":{"m":"ORDER","B":[{"a":"USDT","wb":"122624.12345678","cw":"100.12345678","bc":"50.12345678"}],"P":[]}}}"#;
+        let super::ServerFrame::Stream(frame) = serde_json::from_str(msg)? else {
+            anyhow::bail!("not a stream frame");
+        };
+        let balances = Balances::try_from(super::account::BalanceFrame::try_from(frame)?)?;
+        let usdt = balances.get(&"USDT".parse::<Asset>()?).unwrap();
+        assert_eq!(usdt.total, dec!(122624.12345678));
+        assert_eq!(usdt.free, dec!(100.12345678));
+        assert_eq!(usdt.locked, usdt.total - usdt.free);
+
+        let msg = r#"{"stream":"listenKey","data":{"e":"outboundAccountPosition","E":1564034571105,"u":1564034571073,"B":[{"a":"ETH","f":"10000.000000","l":"1.000000"}]}}"#;
+        let super::ServerFrame::Stream(frame) = serde_json::from_str(msg)? else {
+            anyhow::bail!("not a stream frame");
+        };
+        let balances = Balances::try_from(super::account::BalanceFrame::try_from(frame)?)?;
+        assert_eq!(balances.ts.unix_timestamp(), 1564034571);
+        let eth = balances.get(&"ETH".parse::<Asset>()?).unwrap();
+        assert_eq!(eth.free, dec!(10000));
+        assert_eq!(eth.locked, dec!(1));
+        assert_eq!(eth.total, dec!(10001));
+        Ok(())
+    }
+
+    #[test]
+    fn test_position_frame() -> anyhow::Result<()> {
+        use exc_core::types::{MarginMode, Position};
+        use rust_decimal_macros::dec;
+
+        let msg = r#"{"stream":"listenKey","data":{"e":"ACCOUNT_UPDATE","E":1564745798939,"T":1564745798938,"a":{"m":"ORDER","B":[],"P":[{"s":"BTCUSDT","pa":"0","ep":"0.00000","bep":"0","cr":"200","up":"0","mt":"isolated","iw":"0.00000000","ps":"BOTH"},{"s":"BTCUSDT","pa":"-20","ep":"6563.66500","bep":"6563.6","cr":"0","up":"2850.21200","mt":"cross","iw":"0","ps":"BOTH"}]}}}"#;
+        let super::ServerFrame::Stream(frame) = serde_json::from_str(msg)? else {
+            anyhow::bail!("not a stream frame");
+        };
+        let positions = Vec::<Position>::try_from(super::account::PositionFrame::try_from(frame)?)?;
+        assert_eq!(positions.len(), 2);
+        assert!(positions[0].size.is_zero());
+        assert_eq!(positions[0].margin_mode, MarginMode::Isolated);
+        let btc = &positions[1];
+        assert_eq!(btc.ts.unix_timestamp(), 1564745798);
+        assert_eq!(btc.instrument, "btcusdt");
+        assert_eq!(btc.size, dec!(-20));
+        assert_eq!(btc.entry_price, dec!(6563.665));
+        assert_eq!(btc.unrealized_pnl, dec!(2850.212));
+        assert_eq!(btc.margin_mode, MarginMode::Cross);
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_book_ticker() -> anyhow::Result<()> {
         let mut api = Binance::usd_margin_futures().connect();
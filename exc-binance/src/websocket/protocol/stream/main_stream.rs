@@ -29,6 +29,8 @@ impl MainStream {
 }
 
 impl MainStream {
+    /// Give the frame back if there is no main stream for the name.
+    #[allow(clippy::result_large_err)]
     pub(super) fn try_publish(
         &self,
         name: &Name,
@@ -15,6 +15,17 @@ pub use exc_types as types;
 /// Retry utils.
 pub use exc_service::retry;
 
+#[cfg(feature = "limit")]
+/// Weighted rate limit.
+pub use exc_service::weight;
+
+/// Circuit breaker.
+pub use exc_service::breaker;
+
+#[cfg(feature = "metrics")]
+/// Metrics and tracing.
+pub use exc_service::metrics;
+
 /// Utils for creating [`ExcService`](exc_service::ExcService).
 pub mod util;
 
@@ -25,6 +36,10 @@ pub use self::service::{
     traits::{AsService, IntoService},
     Adaptor, Exc, ExcLayer, ExcService, ExcServiceExt, IntoExc, Request,
 };
-pub use exc_service::{self as service, error::InstrumentError, ExchangeError, SendExcService};
+pub use exc_service::{
+    self as service,
+    error::{ApiError, ApiErrorKind, InstrumentError},
+    ExchangeError, SendExcService,
+};
 
 pub use positions::prelude::{Asset, Instrument, ParseAssetError, ParseSymbolError, Str, Symbol};
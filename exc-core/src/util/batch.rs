@@ -0,0 +1,337 @@
+use std::task::{Context, Poll};
+
+use exc_service::{ExcService, ExchangeError, Request};
+use exc_types::{CancelOrder, CancelOrders, PlaceOrder, PlaceOrders};
+use futures::{
+    future::BoxFuture,
+    stream::{FuturesOrdered, StreamExt},
+    FutureExt, TryFutureExt,
+};
+use tower::{Layer, Service, ServiceExt};
+
+/// Fan-out batch layer.
+#[derive(Debug, Default)]
+pub struct FanOutBatchLayer;
+
+impl<S> Layer<S> for FanOutBatchLayer {
+    type Service = FanOutBatch<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FanOutBatch(inner)
+    }
+}
+
+/// Fan-out batch service.
+///
+/// Handle batch requests by sending the orders one by one through the inner service,
+/// for the exchanges lacking native batching. Every order waits for the inner service
+/// to be ready, so the rate limits of the inner service are honored,
+/// while the responses are awaited concurrently.
+#[derive(Debug, Clone, Copy)]
+pub struct FanOutBatch<S>(S);
+
+fn fan_out<S, R, T>(
+    svc: &mut S,
+    reqs: Vec<R>,
+) -> BoxFuture<'static, Result<Vec<Result<T, ExchangeError>>, ExchangeError>>
+where
+    S: Clone + Send + 'static,
+    S: ExcService<R>,
+    S::Future: Send,
+    R: Request<Response = BoxFuture<'static, Result<T, ExchangeError>>> + Send + 'static,
+    T: Send + 'static,
+{
+    let mut reqs = reqs.into_iter();
+    let mut results = FuturesOrdered::<BoxFuture<'static, Result<T, ExchangeError>>>::new();
+    // The first request uses the readiness reserved by `poll_ready`.
+    if let Some(req) = reqs.next() {
+        results.push_back(ExcService::<R>::call(svc, req).try_flatten().boxed());
+    }
+    let mut svc = svc.clone();
+    async move {
+        for req in reqs {
+            let fut = Service::<R>::call(svc.as_service().ready().await?, req);
+            results.push_back(fut.try_flatten().boxed());
+        }
+        Ok(results.collect().await)
+    }
+    .boxed()
+}
+
+impl<S> Service<PlaceOrders> for FanOutBatch<S>
+where
+    S: Clone + Send + 'static,
+    S: ExcService<PlaceOrder>,
+    <S as ExcService<PlaceOrder>>::Future: Send,
+{
+    type Response = <PlaceOrders as Request>::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Service::<PlaceOrder>::poll_ready(&mut self.0.as_service(), cx)
+    }
+
+    fn call(&mut self, req: PlaceOrders) -> Self::Future {
+        let placed = fan_out(&mut self.0, req.0);
+        futures::future::ready(Ok(placed)).boxed()
+    }
+}
+
+impl<S> Service<CancelOrders> for FanOutBatch<S>
+where
+    S: Clone + Send + 'static,
+    S: ExcService<CancelOrder>,
+    <S as ExcService<CancelOrder>>::Future: Send,
+{
+    type Response = <CancelOrders as Request>::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Service::<CancelOrder>::poll_ready(&mut self.0.as_service(), cx)
+    }
+
+    fn call(&mut self, req: CancelOrders) -> Self::Future {
+        let canceled = fan_out(&mut self.0, req.0);
+        futures::future::ready(Ok(canceled)).boxed()
+    }
+}
+
+/// Chunked batch layer.
+#[derive(Debug, Clone, Copy)]
+pub struct ChunkedBatchLayer {
+    place: usize,
+    cancel: usize,
+}
+
+impl ChunkedBatchLayer {
+    /// Split the batches into chunks of at most `place` orders to place
+    /// and `cancel` orders to cancel.
+    ///
+    /// # Panics
+    /// Panic if any of the sizes is zero.
+    pub fn new(place: usize, cancel: usize) -> Self {
+        assert!(place > 0 && cancel > 0, "the batch sizes must be positive");
+        Self { place, cancel }
+    }
+}
+
+impl<S> Layer<S> for ChunkedBatchLayer {
+    type Service = ChunkedBatch<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ChunkedBatch {
+            inner,
+            place: self.place,
+            cancel: self.cancel,
+        }
+    }
+}
+
+/// Chunked batch service.
+///
+/// Split the batches exceeding the batch size limits of the exchange into several
+/// batches sent one after another through the inner service, and concatenate
+/// the results in the same order as the requests. Once the first batch has been sent,
+/// the failure of a later batch is reported for each of its orders instead.
+#[derive(Debug, Clone, Copy)]
+pub struct ChunkedBatch<S> {
+    inner: S,
+    place: usize,
+    cancel: usize,
+}
+
+fn chunked<S, R, T, U>(
+    svc: &mut S,
+    reqs: Vec<T>,
+    size: usize,
+    batch: fn(Vec<T>) -> R,
+) -> BoxFuture<'static, Result<Vec<Result<U, ExchangeError>>, ExchangeError>>
+where
+    S: Clone + Send + 'static,
+    S: ExcService<R>,
+    S::Future: Send,
+    R: Request<Response = BoxFuture<'static, Result<Vec<Result<U, ExchangeError>>, ExchangeError>>>
+        + Send
+        + 'static,
+    T: Send + 'static,
+    U: Send + 'static,
+{
+    let mut reqs = reqs.into_iter().peekable();
+    let mut chunks = Vec::new();
+    while reqs.peek().is_some() {
+        chunks.push(reqs.by_ref().take(size).collect::<Vec<_>>());
+    }
+    let mut chunks = chunks.into_iter();
+    // The first batch uses the readiness reserved by `poll_ready`.
+    let first = chunks
+        .next()
+        .map(|chunk| ExcService::<R>::call(svc, batch(chunk)).try_flatten());
+    let mut svc = svc.clone();
+    async move {
+        let mut results = match first {
+            Some(fut) => fut.await?,
+            None => return Ok(Vec::new()),
+        };
+        for chunk in chunks {
+            let len = chunk.len();
+            let res = match svc.as_service().ready().await {
+                Ok(svc) => Service::<R>::call(svc, batch(chunk)).try_flatten().await,
+                Err(err) => Err(err),
+            };
+            match res {
+                Ok(res) => results.extend(res),
+                Err(err) => {
+                    let err = err.to_string();
+                    results.extend((0..len).map(|_| {
+                        Err(ExchangeError::Other(anyhow::anyhow!(
+                            "the batch of the order failed: {err}"
+                        )))
+                    }));
+                }
+            }
+        }
+        Ok(results)
+    }
+    .boxed()
+}
+
+impl<S> Service<PlaceOrders> for ChunkedBatch<S>
+where
+    S: Clone + Send + 'static,
+    S: ExcService<PlaceOrders>,
+    <S as ExcService<PlaceOrders>>::Future: Send,
+{
+    type Response = <PlaceOrders as Request>::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Service::<PlaceOrders>::poll_ready(&mut self.inner.as_service(), cx)
+    }
+
+    fn call(&mut self, req: PlaceOrders) -> Self::Future {
+        let placed = chunked(&mut self.inner, req.0, self.place, PlaceOrders);
+        futures::future::ready(Ok(placed)).boxed()
+    }
+}
+
+impl<S> Service<CancelOrders> for ChunkedBatch<S>
+where
+    S: Clone + Send + 'static,
+    S: ExcService<CancelOrders>,
+    <S as ExcService<CancelOrders>>::Future: Send,
+{
+    type Response = <CancelOrders as Request>::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Service::<CancelOrders>::poll_ready(&mut self.inner.as_service(), cx)
+    }
+
+    fn call(&mut self, req: CancelOrders) -> Self::Future {
+        let canceled = chunked(&mut self.inner, req.0, self.cancel, CancelOrders);
+        futures::future::ready(Ok(canceled)).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exc_types::{Place, PlaceOrderOptions, Placed};
+    use rust_decimal_macros::dec;
+    use time::OffsetDateTime;
+
+    #[test]
+    fn test_fan_out_place_orders() {
+        let inner = tower::service_fn(|req: PlaceOrder| async move {
+            let placed = if req.place.size.is_sign_positive() {
+                Ok(Placed {
+                    id: req.opts.client_id().unwrap().to_string().into(),
+                    order: None,
+                    ts: OffsetDateTime::UNIX_EPOCH,
+                })
+            } else {
+                Err(ExchangeError::Other(anyhow::anyhow!("rejected")))
+            };
+            Ok::<_, ExchangeError>(futures::future::ready(placed).boxed())
+        });
+        let mut svc = FanOutBatchLayer.layer(inner);
+        let orders = [dec!(1), dec!(-1), dec!(2)]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, size)| {
+                PlaceOrder::new(
+                    Place::with_size(size).limit(dec!(100)),
+                    PlaceOrderOptions::new("BTC-USDT").with_client_id(Some(idx.to_string())),
+                )
+            })
+            .collect::<Vec<_>>();
+        let results = futures::executor::block_on(async move {
+            Service::<PlaceOrders>::call(svc.ready().await?, PlaceOrders(orders))
+                .await?
+                .await
+        })
+        .unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().id.as_str(), "0");
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_ref().unwrap().id.as_str(), "2");
+    }
+
+    #[test]
+    fn test_chunked_place_orders() {
+        let batches = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let inner = {
+            let batches = batches.clone();
+            tower::service_fn(move |req: PlaceOrders| {
+                batches.lock().unwrap().push(req.0.len());
+                let placed = if req.0.iter().any(|req| req.place.size.is_sign_negative()) {
+                    Err(ExchangeError::Other(anyhow::anyhow!("rejected")))
+                } else {
+                    Ok(req
+                        .0
+                        .into_iter()
+                        .map(|req| {
+                            Ok(Placed {
+                                id: req.opts.client_id().unwrap().to_string().into(),
+                                order: None,
+                                ts: OffsetDateTime::UNIX_EPOCH,
+                            })
+                        })
+                        .collect::<Vec<_>>())
+                };
+                futures::future::ready(Ok::<_, ExchangeError>(
+                    futures::future::ready(placed).boxed(),
+                ))
+            })
+        };
+        let mut svc = ChunkedBatchLayer::new(2, 2).layer(inner);
+        let orders = [dec!(1), dec!(1), dec!(1), dec!(-1), dec!(1)]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, size)| {
+                PlaceOrder::new(
+                    Place::with_size(size).limit(dec!(100)),
+                    PlaceOrderOptions::new("BTC-USDT").with_client_id(Some(idx.to_string())),
+                )
+            })
+            .collect::<Vec<_>>();
+        let results = futures::executor::block_on(async move {
+            Service::<PlaceOrders>::call(svc.ready().await?, PlaceOrders(orders))
+                .await?
+                .await
+        })
+        .unwrap();
+        assert_eq!(*batches.lock().unwrap(), [2, 2, 1]);
+        assert_eq!(results.len(), 5);
+        assert_eq!(results[0].as_ref().unwrap().id.as_str(), "0");
+        assert_eq!(results[1].as_ref().unwrap().id.as_str(), "1");
+        // The second batch is rejected as a whole.
+        assert!(results[2].is_err());
+        assert!(results[3].is_err());
+        assert_eq!(results[4].as_ref().unwrap().id.as_str(), "4");
+    }
+}
@@ -0,0 +1,306 @@
+use std::task::{Context, Poll};
+
+use exc_service::{ExcService, ExchangeError, Request};
+use exc_types::{AmendOrder, Amended, CancelOrder, GetOrder, OrderKind, Place, PlaceOrder};
+use futures::{future::BoxFuture, FutureExt};
+use tower::{Layer, Service, ServiceExt};
+
+/// Cancel-replace layer.
+#[derive(Debug, Default)]
+pub struct CancelReplaceLayer;
+
+impl<S> Layer<S> for CancelReplaceLayer {
+    type Service = CancelReplace<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CancelReplace(inner)
+    }
+}
+
+/// Cancel-replace service.
+///
+/// Amend an order by cancelling it and placing a new one with the same kind,
+/// for the exchanges lacking native amendment. The order loses its queue priority,
+/// and the id of the new order is returned.
+///
+/// The new order is placed with the options of the original order, which must be
+/// set with [`AmendOrder::with_options`] since they cannot be fetched from the exchange.
+#[derive(Debug, Clone, Copy)]
+pub struct CancelReplace<S>(S);
+
+impl<S> Service<AmendOrder> for CancelReplace<S>
+where
+    S: Clone + Send + 'static,
+    S: ExcService<GetOrder>,
+    S: ExcService<CancelOrder>,
+    S: ExcService<PlaceOrder>,
+    <S as ExcService<GetOrder>>::Future: Send,
+    <S as ExcService<CancelOrder>>::Future: Send,
+    <S as ExcService<PlaceOrder>>::Future: Send,
+{
+    type Response = <AmendOrder as Request>::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Service::<GetOrder>::poll_ready(&mut self.0.as_service(), cx)
+    }
+
+    fn call(&mut self, req: AmendOrder) -> Self::Future {
+        let Some(opts) = req.opts.clone() else {
+            return futures::future::ready(Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                "cannot cancel-replace an order without the options it was placed with"
+            ))))
+            .boxed();
+        };
+        if opts.instrument() != req.instrument.as_str() {
+            return futures::future::ready(Err(ExchangeError::Other(anyhow::anyhow!(
+                "the options are for `{}` instead of `{}`",
+                opts.instrument(),
+                req.instrument
+            ))))
+            .boxed();
+        }
+        let current = Service::<GetOrder>::call(
+            &mut self.0.as_service(),
+            GetOrder {
+                instrument: req.instrument.clone(),
+                id: req.id.clone(),
+            },
+        );
+        let mut svc = self.0.clone();
+        let amended = async move {
+            let mut order = current.await?.await?.order;
+            let kind = match order.target.kind {
+                OrderKind::Limit(_, tif) => OrderKind::Limit(req.new_price, tif),
+                OrderKind::PostOnly(_) => OrderKind::PostOnly(req.new_price),
+                OrderKind::StopLimit(trigger, _) => OrderKind::StopLimit(trigger, req.new_price),
+                OrderKind::TakeProfitLimit(trigger, _) => {
+                    OrderKind::TakeProfitLimit(trigger, req.new_price)
+                }
+                OrderKind::Market
+                | OrderKind::StopMarket(_)
+                | OrderKind::TakeProfitMarket(_)
+                | OrderKind::TrailingStop(_) => {
+                    return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                        "cannot amend a market or trigger-market order"
+                    )));
+                }
+            };
+            if order.target.size.is_sign_positive() != req.new_size.is_sign_positive() {
+                return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                    "cannot change the side of an order"
+                )));
+            }
+            let canceled = Service::<CancelOrder>::call(
+                svc.as_service().ready().await?,
+                CancelOrder {
+                    instrument: req.instrument.clone(),
+                    id: req.id,
+                },
+            )
+            .await?
+            .await?;
+            if let Some(canceled) = canceled.order {
+                order = canceled;
+            }
+            let remaining = req.new_size.abs() - order.state.filled.abs();
+            if remaining.is_sign_negative() || remaining.is_zero() {
+                return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                    "the order has been canceled, and its filled size is not less than the new size"
+                )));
+            }
+            let size = if req.new_size.is_sign_positive() {
+                remaining
+            } else {
+                -remaining
+            };
+            let placed = Service::<PlaceOrder>::call(
+                svc.as_service().ready().await?,
+                PlaceOrder {
+                    place: Place { size, kind },
+                    opts,
+                },
+            )
+            .await?
+            .await?;
+            Ok(Amended {
+                id: placed.id,
+                order: placed.order,
+                ts: placed.ts,
+            })
+        };
+        futures::future::ready(Ok(amended.boxed())).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exc_types::{Canceled, Order, OrderState, OrderUpdate, PlaceOrderOptions, Placed};
+    use futures::future::{ready, Ready};
+    use rust_decimal_macros::dec;
+    use std::sync::{Arc, Mutex};
+    use time::OffsetDateTime;
+
+    type Reply<T> = Ready<Result<BoxFuture<'static, Result<T, ExchangeError>>, ExchangeError>>;
+
+    fn reply<T: Send + 'static>(res: Result<T, ExchangeError>) -> Reply<T> {
+        ready(Ok(ready(res).boxed()))
+    }
+
+    fn rejected() -> ExchangeError {
+        ExchangeError::Other(anyhow::anyhow!("rejected"))
+    }
+
+    /// An exchange with a partially filled buy order `1` of size `2`.
+    #[derive(Debug, Clone, Default)]
+    struct Exchange {
+        cancel_fails: bool,
+        place_fails: bool,
+        canceled: Arc<Mutex<Vec<CancelOrder>>>,
+        placed: Arc<Mutex<Vec<PlaceOrder>>>,
+    }
+
+    impl Exchange {
+        fn order() -> Order {
+            let mut order = Order::new(
+                "1".to_string().into(),
+                Place::with_size(dec!(2)).limit(dec!(100)),
+            );
+            order.with_state(OrderState {
+                filled: dec!(0.5),
+                ..Default::default()
+            });
+            order
+        }
+    }
+
+    impl Service<GetOrder> for Exchange {
+        type Response = BoxFuture<'static, Result<OrderUpdate, ExchangeError>>;
+        type Error = ExchangeError;
+        type Future = Reply<OrderUpdate>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _req: GetOrder) -> Self::Future {
+            reply(Ok(OrderUpdate {
+                ts: OffsetDateTime::UNIX_EPOCH,
+                order: Self::order(),
+            }))
+        }
+    }
+
+    impl Service<CancelOrder> for Exchange {
+        type Response = BoxFuture<'static, Result<Canceled, ExchangeError>>;
+        type Error = ExchangeError;
+        type Future = Reply<Canceled>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: CancelOrder) -> Self::Future {
+            if self.cancel_fails {
+                return reply(Err(rejected()));
+            }
+            self.canceled.lock().unwrap().push(req);
+            reply(Ok(Canceled {
+                order: Some(Self::order()),
+                ts: OffsetDateTime::UNIX_EPOCH,
+            }))
+        }
+    }
+
+    impl Service<PlaceOrder> for Exchange {
+        type Response = BoxFuture<'static, Result<Placed, ExchangeError>>;
+        type Error = ExchangeError;
+        type Future = Reply<Placed>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: PlaceOrder) -> Self::Future {
+            if self.place_fails {
+                return reply(Err(rejected()));
+            }
+            self.placed.lock().unwrap().push(req);
+            reply(Ok(Placed {
+                id: "2".to_string().into(),
+                order: None,
+                ts: OffsetDateTime::UNIX_EPOCH,
+            }))
+        }
+    }
+
+    fn amend(exchange: &Exchange, req: AmendOrder) -> Result<Amended, ExchangeError> {
+        let svc = CancelReplaceLayer.layer(exchange.clone());
+        futures::executor::block_on(async move { svc.oneshot(req).await?.await })
+    }
+
+    fn options() -> PlaceOrderOptions {
+        let mut opts = PlaceOrderOptions::new("BTC-USDT-SWAP");
+        opts.with_client_id(Some("replaced"))
+            .with_reduce_only(true)
+            .with_margin_mode(exc_types::MarginMode::Isolated)
+            .insert("tdMode", "isolated");
+        opts
+    }
+
+    #[test]
+    fn test_keep_options() {
+        let exchange = Exchange::default();
+        let req = AmendOrder::new("BTC-USDT-SWAP", "1".to_string().into(), dec!(99), dec!(3))
+            .with_options(&options());
+        let amended = amend(&exchange, req).unwrap();
+        assert_eq!(amended.id.as_str(), "2");
+        let placed = exchange.placed.lock().unwrap();
+        assert_eq!(placed.len(), 1);
+        // The filled part is not placed again.
+        assert_eq!(placed[0].place, Place::with_size(dec!(2.5)).limit(dec!(99)));
+        assert_eq!(placed[0].opts.client_id(), Some("replaced"));
+        assert!(placed[0].opts.reduce_only());
+        assert_eq!(
+            placed[0].opts.margin_mode(),
+            Some(exc_types::MarginMode::Isolated)
+        );
+        assert_eq!(placed[0].opts.custom().get("tdMode").unwrap(), "isolated");
+
+        // The options are required.
+        let req = AmendOrder::new("BTC-USDT-SWAP", "1".to_string().into(), dec!(99), dec!(3));
+        assert!(matches!(
+            amend(&exchange, req),
+            Err(ExchangeError::Unsupported(_))
+        ));
+        assert_eq!(exchange.canceled.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_failed_cancel() {
+        let exchange = Exchange {
+            cancel_fails: true,
+            ..Default::default()
+        };
+        let req = AmendOrder::new("BTC-USDT-SWAP", "1".to_string().into(), dec!(99), dec!(3))
+            .with_options(&options());
+        assert!(amend(&exchange, req).is_err());
+        assert!(exchange.placed.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_failed_place() {
+        let exchange = Exchange {
+            place_fails: true,
+            ..Default::default()
+        };
+        let req = AmendOrder::new("BTC-USDT-SWAP", "1".to_string().into(), dec!(99), dec!(3))
+            .with_options(&options());
+        assert!(amend(&exchange, req).is_err());
+        // The order stays canceled.
+        assert_eq!(exchange.canceled.lock().unwrap().len(), 1);
+        assert!(exchange.placed.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,335 @@
+use std::{
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
+};
+
+use exc_service::{ExcService, ExchangeError, Request};
+use exc_types::utils::FetchServerTime;
+use futures::{future::BoxFuture, FutureExt};
+use time::{Duration, OffsetDateTime};
+use tower::{Layer, Service, ServiceExt};
+
+const NEVER: i64 = i64::MIN;
+
+#[derive(Debug)]
+struct ClockState {
+    /// Offset of the server clock (in millis).
+    offset: AtomicI64,
+    /// The local time of the latest synchronization (in millis).
+    synced_at: AtomicI64,
+}
+
+impl Default for ClockState {
+    fn default() -> Self {
+        Self {
+            offset: AtomicI64::new(0),
+            synced_at: AtomicI64::new(NEVER),
+        }
+    }
+}
+
+fn millis(ts: OffsetDateTime) -> i64 {
+    (ts.unix_timestamp_nanos() / 1_000_000) as i64
+}
+
+/// A shared clock tracking the offset of the exchange server clock from the local clock.
+///
+/// The signers read the (corrected) current time from it,
+/// while [`ClockSync`] keeps the offset up to date.
+#[derive(Debug, Clone, Default)]
+pub struct Clock {
+    state: Arc<ClockState>,
+}
+
+impl Clock {
+    /// Create a new clock without offset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the offset of the server clock.
+    pub fn offset(&self) -> Duration {
+        Duration::milliseconds(self.state.offset.load(Ordering::Relaxed))
+    }
+
+    /// Set the offset of the server clock.
+    pub fn set_offset(&self, offset: Duration) {
+        self.state
+            .offset
+            .store(offset.whole_milliseconds() as i64, Ordering::Relaxed);
+    }
+
+    /// The current time of the server clock.
+    pub fn now(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc() + self.offset()
+    }
+
+    /// Update the offset with a measurement: the server time received
+    /// for a request sent at `sent` and answered at `received` (both local).
+    pub fn record(&self, sent: OffsetDateTime, server: OffsetDateTime, received: OffsetDateTime) {
+        let local = sent + (received - sent) / 2;
+        let offset = server - local;
+        tracing::debug!("clock offset: {offset} (rtt={})", received - sent);
+        self.set_offset(offset);
+    }
+
+    /// Returns the time of the last synchronization if a synchronization is due,
+    /// and marks it as started (at `now`) to keep the others from starting.
+    fn start_sync(&self, interval: Duration, now: i64) -> Option<i64> {
+        let last = self.state.synced_at.load(Ordering::Relaxed);
+        if last != NEVER && now.saturating_sub(last) < interval.whole_milliseconds() as i64 {
+            return None;
+        }
+        self.state
+            .synced_at
+            .compare_exchange(last, now, Ordering::AcqRel, Ordering::Relaxed)
+            .ok()
+    }
+
+    /// Roll back the mark of a failed synchronization started at `started`,
+    /// so that the next request retries it.
+    fn fail_sync(&self, started: i64, last: i64) {
+        let _ = self.state.synced_at.compare_exchange(
+            started,
+            last,
+            Ordering::AcqRel,
+            Ordering::Relaxed,
+        );
+    }
+}
+
+/// Clock sync layer.
+#[derive(Debug, Clone)]
+pub struct ClockSyncLayer {
+    clock: Clock,
+    interval: Duration,
+}
+
+impl ClockSyncLayer {
+    /// Create a new clock sync layer that measures the offset of the server clock
+    /// every `interval` (checked on requests) and stores it to `clock`.
+    pub fn new(clock: Clock, interval: std::time::Duration) -> Self {
+        Self {
+            clock,
+            interval: Duration::try_from(interval).unwrap_or(Duration::MAX),
+        }
+    }
+}
+
+impl<S> Layer<S> for ClockSyncLayer {
+    type Service = ClockSync<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ClockSync {
+            svc: inner,
+            clock: self.clock.clone(),
+            interval: self.interval,
+        }
+    }
+}
+
+/// Clock sync.
+///
+/// Fetch the server time alongside the request when a synchronization is due,
+/// and record the offset to the [`Clock`]. The request is sent right away
+/// with the current offset, except for the first synchronization which it waits for
+/// since the offset is unknown until then. Failures of the synchronization are
+/// only logged, and the synchronization is retried on the next request.
+#[derive(Debug, Clone)]
+pub struct ClockSync<S> {
+    svc: S,
+    clock: Clock,
+    interval: Duration,
+}
+
+impl<S> ClockSync<S> {
+    /// Get the clock.
+    pub fn clock(&self) -> &Clock {
+        &self.clock
+    }
+}
+
+async fn sync<S>(svc: &mut S, clock: &Clock) -> Result<(), ExchangeError>
+where
+    S: ExcService<FetchServerTime>,
+{
+    let sent = OffsetDateTime::now_utc();
+    let server = ServiceExt::<FetchServerTime>::oneshot(
+        ExcService::<FetchServerTime>::as_service(svc),
+        FetchServerTime,
+    )
+    .await?
+    .await?;
+    clock.record(sent, server, OffsetDateTime::now_utc());
+    Ok(())
+}
+
+impl<S, R> Service<R> for ClockSync<S>
+where
+    R: Request + Send + 'static,
+    R::Response: Send,
+    S: ExcService<R> + ExcService<FetchServerTime> + Clone + Send + 'static,
+    <S as ExcService<R>>::Future: Send,
+    <S as ExcService<FetchServerTime>>::Future: Send,
+{
+    type Response = R::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        ExcService::<R>::poll_ready(&mut self.svc, cx)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        let started = millis(OffsetDateTime::now_utc());
+        let Some(last) = self.clock.start_sync(self.interval, started) else {
+            return ExcService::<R>::call(&mut self.svc, req).boxed();
+        };
+        let synced = {
+            let mut svc = self.svc.clone();
+            let clock = self.clock.clone();
+            async move {
+                if let Err(err) = sync(&mut svc, &clock).await {
+                    tracing::warn!("failed to sync clock: {err}");
+                    clock.fail_sync(started, last);
+                }
+            }
+        };
+        if last == NEVER {
+            let clone = self.svc.clone();
+            let mut svc = std::mem::replace(&mut self.svc, clone);
+            async move {
+                synced.await;
+                ServiceExt::<R>::oneshot(ExcService::<R>::as_service(&mut svc), req).await
+            }
+            .boxed()
+        } else {
+            let res = ExcService::<R>::call(&mut self.svc, req);
+            async move { futures::future::join(synced, res).await.1 }.boxed()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicBool;
+
+    #[derive(Debug)]
+    struct Ping;
+
+    impl Request for Ping {
+        type Response = ();
+    }
+
+    /// A server whose clock is ahead of the local one.
+    #[derive(Debug, Clone, Default)]
+    struct Server {
+        fetched: Arc<AtomicI64>,
+        down: Arc<AtomicBool>,
+        calls: Arc<std::sync::Mutex<Vec<&'static str>>>,
+    }
+
+    impl Service<FetchServerTime> for Server {
+        type Response = BoxFuture<'static, Result<OffsetDateTime, ExchangeError>>;
+        type Error = ExchangeError;
+        type Future = futures::future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _req: FetchServerTime) -> Self::Future {
+            self.fetched.fetch_add(1, Ordering::Relaxed);
+            self.calls.lock().unwrap().push("time");
+            if self.down.load(Ordering::Relaxed) {
+                return futures::future::ready(Err(ExchangeError::Unavailable(anyhow::anyhow!(
+                    "server is down"
+                ))));
+            }
+            let now = OffsetDateTime::now_utc() + Duration::minutes(1);
+            futures::future::ready(Ok(futures::future::ready(Ok(now)).boxed()))
+        }
+    }
+
+    impl Service<Ping> for Server {
+        type Response = ();
+        type Error = ExchangeError;
+        type Future = futures::future::Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _req: Ping) -> Self::Future {
+            self.calls.lock().unwrap().push("ping");
+            futures::future::ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn test_clock_sync() {
+        let server = Server::default();
+        let clock = Clock::new();
+        let mut svc = ClockSyncLayer::new(clock.clone(), std::time::Duration::from_secs(60))
+            .layer(server.clone());
+        futures::executor::block_on(async {
+            for _ in 0..3 {
+                ServiceExt::<Ping>::oneshot(&mut svc, Ping).await.unwrap();
+            }
+        });
+        assert_eq!(server.fetched.load(Ordering::Relaxed), 1);
+        let offset = clock.offset();
+        assert!(
+            offset > Duration::seconds(59) && offset <= Duration::minutes(1),
+            "{offset}"
+        );
+    }
+
+    #[test]
+    fn test_failed_clock_sync() {
+        let server = Server::default();
+        server.down.store(true, Ordering::Relaxed);
+        let clock = Clock::new();
+        let mut svc = ClockSyncLayer::new(clock.clone(), std::time::Duration::from_secs(60))
+            .layer(server.clone());
+        futures::executor::block_on(async {
+            for _ in 0..2 {
+                ServiceExt::<Ping>::oneshot(&mut svc, Ping).await.unwrap();
+            }
+        });
+        // The failed synchronizations are retried on the next requests.
+        assert_eq!(server.fetched.load(Ordering::Relaxed), 2);
+        assert_eq!(clock.offset(), Duration::ZERO);
+        server.down.store(false, Ordering::Relaxed);
+        futures::executor::block_on(async {
+            for _ in 0..2 {
+                ServiceExt::<Ping>::oneshot(&mut svc, Ping).await.unwrap();
+            }
+        });
+        assert_eq!(server.fetched.load(Ordering::Relaxed), 3);
+        assert!(clock.offset() > Duration::seconds(59));
+    }
+
+    #[test]
+    fn test_concurrent_clock_sync() {
+        let server = Server::default();
+        let clock = Clock::new();
+        let mut svc =
+            ClockSyncLayer::new(clock.clone(), std::time::Duration::ZERO).layer(server.clone());
+        futures::executor::block_on(async {
+            for _ in 0..2 {
+                ServiceExt::<Ping>::oneshot(&mut svc, Ping).await.unwrap();
+            }
+        });
+        // Only the first request waits for the synchronization.
+        assert_eq!(
+            *server.calls.lock().unwrap(),
+            ["time", "ping", "ping", "time"]
+        );
+        assert!(clock.offset() > Duration::seconds(59));
+    }
+}
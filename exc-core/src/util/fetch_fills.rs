@@ -0,0 +1,180 @@
+use std::{
+    collections::HashSet,
+    num::NonZeroUsize,
+    ops::Bound,
+    task::{Context, Poll},
+};
+
+use async_stream::try_stream;
+use exc_service::{ExcService, ExchangeError};
+use exc_types::{FillStream, QueryFills, QueryLastFills, Str};
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use tower::{Layer, Service, ServiceExt};
+
+/// Fetch fills backward layer.
+#[derive(Debug, Clone, Copy)]
+pub struct FetchFillsBackwardLayer {
+    limit: NonZeroUsize,
+}
+
+impl FetchFillsBackwardLayer {
+    /// Create a new fetch fills backward layer.
+    /// # Panic
+    /// Panic if `limit` is zero.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit: NonZeroUsize::new(limit).unwrap(),
+        }
+    }
+}
+
+impl<S> Layer<S> for FetchFillsBackwardLayer {
+    type Service = FetchFillsBackward<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FetchFillsBackward {
+            svc: inner,
+            limit: self.limit,
+        }
+    }
+}
+
+/// Fetch fills backward.
+///
+/// Query the last fills of the range repeatedly, moving the end of the range
+/// to the oldest fill received. The fills sharing the timestamp of the boundary
+/// are deduplicated by their trade ids, so there must be fewer than `limit` fills
+/// sharing a timestamp.
+#[derive(Debug, Clone)]
+pub struct FetchFillsBackward<S> {
+    svc: S,
+    limit: NonZeroUsize,
+}
+
+impl<S> Service<QueryFills> for FetchFillsBackward<S>
+where
+    S: ExcService<QueryLastFills> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = FillStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, query: QueryFills) -> Self::Future {
+        let limit = self.limit.get();
+        let mut query = QueryLastFills { query, last: limit };
+        let mut svc = self.svc.clone();
+        let stream = try_stream! {
+            // The trade ids of the fills received at the boundary.
+            let mut boundary = None;
+            let mut seen = HashSet::<Str>::new();
+            loop {
+                tracing::trace!("query fills: {query}");
+                if query.query().is_empty() {
+                    break;
+                }
+                let mut stream = Service::call(svc.as_service().ready().await?, query.clone()).await?;
+                let mut count = 0;
+                let mut fresh = 0;
+                let mut oldest = None;
+                let mut at_oldest = HashSet::new();
+                while let Some(fill) = stream.next().await {
+                    let fill = fill?;
+                    count += 1;
+                    if oldest != Some(fill.ts) {
+                        oldest = Some(fill.ts);
+                        at_oldest.clear();
+                    }
+                    at_oldest.insert(fill.trade_id.clone());
+                    if boundary == Some(fill.ts) && seen.contains(&fill.trade_id) {
+                        continue;
+                    }
+                    fresh += 1;
+                    yield fill;
+                }
+                let Some(oldest) = oldest else {
+                    break;
+                };
+                if fresh == 0 {
+                    if count >= limit {
+                        Err(ExchangeError::Other(anyhow::anyhow!(
+                            "too many fills at {oldest}, try a larger limit"
+                        )))?;
+                    }
+                    break;
+                }
+                if boundary == Some(oldest) {
+                    seen.extend(at_oldest);
+                } else {
+                    boundary = Some(oldest);
+                    seen = at_oldest;
+                }
+                query.query.end = Bound::Included(oldest);
+            }
+        };
+        futures::future::ready(Ok(stream.boxed())).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exc_types::Fill;
+    use futures::TryStreamExt;
+    use rust_decimal::Decimal;
+    use std::ops::RangeBounds;
+    use time::{macros::datetime, OffsetDateTime};
+
+    fn fill(id: usize, ts: OffsetDateTime) -> Fill {
+        Fill {
+            instrument: Str::new_inline("BTC-USDT"),
+            trade_id: Str::new(id.to_string()),
+            order_id: Str::new_inline("1"),
+            price: Decimal::ONE,
+            size: Decimal::ONE,
+            fee: Decimal::ZERO,
+            fee_asset: None,
+            maker: false,
+            ts,
+        }
+    }
+
+    #[test]
+    fn test_fetch_fills_backward() {
+        let t0 = datetime!(2024-01-01 00:00 UTC);
+        let t1 = datetime!(2024-01-01 00:01 UTC);
+        let t2 = datetime!(2024-01-01 00:02 UTC);
+        // Newest first, with fills sharing timestamps across pages.
+        let fills = [
+            fill(5, t2),
+            fill(4, t1),
+            fill(3, t1),
+            fill(2, t0),
+            fill(1, t0),
+        ];
+        let svc = tower::service_fn(move |req: QueryLastFills| {
+            let page = fills
+                .iter()
+                .filter(|f| req.query.contains(&f.ts))
+                .take(req.last)
+                .cloned()
+                .map(Ok)
+                .collect::<Vec<_>>();
+            async move { Ok::<_, ExchangeError>(futures::stream::iter(page).boxed()) }
+        });
+        let mut fetch = FetchFillsBackwardLayer::new(3).layer(svc);
+        let ids = futures::executor::block_on(async {
+            Service::call(&mut fetch, QueryFills::new("BTC-USDT", ..))
+                .await?
+                .map_ok(|f| f.trade_id)
+                .try_collect::<Vec<_>>()
+                .await
+        })
+        .unwrap();
+        assert_eq!(ids, ["5", "4", "3", "2", "1"]);
+    }
+}
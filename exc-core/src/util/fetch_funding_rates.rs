@@ -0,0 +1,144 @@
+use std::{
+    num::NonZeroUsize,
+    ops::Bound,
+    task::{Context, Poll},
+};
+
+use async_stream::try_stream;
+use exc_service::{ExcService, ExchangeError};
+use exc_types::{FundingRateStream, QueryFirstFundingRates, QueryFundingRates};
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use tower::{Layer, Service, ServiceExt};
+
+/// Fetch funding rates forward layer.
+#[derive(Debug, Clone, Copy)]
+pub struct FetchFundingRatesForwardLayer {
+    limit: NonZeroUsize,
+}
+
+impl FetchFundingRatesForwardLayer {
+    /// Create a new fetch funding rates forward layer.
+    /// # Panic
+    /// Panic if `limit` is zero.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit: NonZeroUsize::new(limit).unwrap(),
+        }
+    }
+}
+
+impl<S> Layer<S> for FetchFundingRatesForwardLayer {
+    type Service = FetchFundingRatesForward<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FetchFundingRatesForward {
+            svc: inner,
+            limit: self.limit,
+        }
+    }
+}
+
+/// Fetch funding rates forward.
+///
+/// Query the first funding rates of the range repeatedly,
+/// moving the start of the range past the latest funding rate received.
+#[derive(Debug, Clone)]
+pub struct FetchFundingRatesForward<S> {
+    svc: S,
+    limit: NonZeroUsize,
+}
+
+impl<S> Service<QueryFundingRates> for FetchFundingRatesForward<S>
+where
+    S: ExcService<QueryFirstFundingRates> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = FundingRateStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, query: QueryFundingRates) -> Self::Future {
+        let mut query = QueryFirstFundingRates {
+            query,
+            first: self.limit.get(),
+        };
+        let mut svc = self.svc.clone();
+        let stream = try_stream! {
+            loop {
+                tracing::trace!("query funding rates: {query}");
+                if query.query().is_empty() {
+                    break;
+                }
+                let mut stream = Service::call(svc.as_service().ready().await?, query.clone()).await?;
+                let mut next = None;
+                while let Some(rate) = stream.next().await {
+                    let rate = rate?;
+                    next = Some(rate.ts);
+                    yield rate;
+                }
+                if let Some(next) = next {
+                    query.query.start = Bound::Excluded(next);
+                } else {
+                    break;
+                }
+            }
+        };
+        futures::future::ready(Ok(stream.boxed())).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exc_types::FundingRate;
+    use futures::TryStreamExt;
+    use rust_decimal::Decimal;
+    use std::ops::RangeBounds;
+    use time::{macros::datetime, Duration};
+
+    #[test]
+    fn test_fetch_funding_rates_forward() {
+        let t0 = datetime!(2024-01-01 00:00 UTC);
+        let rates = (0..5)
+            .map(|idx| FundingRate {
+                ts: t0 + Duration::hours(8 * idx),
+                rate: Decimal::new(idx, 4),
+            })
+            .collect::<Vec<_>>();
+        let svc = tower::service_fn(move |req: QueryFirstFundingRates| {
+            let page = rates
+                .iter()
+                .filter(|r| req.query.contains(&r.ts))
+                .take(req.first)
+                .cloned()
+                .map(Ok)
+                .collect::<Vec<_>>();
+            async move { Ok::<_, ExchangeError>(futures::stream::iter(page).boxed()) }
+        });
+        let mut fetch = FetchFundingRatesForwardLayer::new(2).layer(svc);
+        let rates = futures::executor::block_on(async {
+            Service::call(
+                &mut fetch,
+                QueryFundingRates::new("BTC-USDT-SWAP", t0 + Duration::hours(8)..),
+            )
+            .await?
+            .map_ok(|r| r.rate)
+            .try_collect::<Vec<_>>()
+            .await
+        })
+        .unwrap();
+        assert_eq!(
+            rates,
+            [
+                Decimal::new(1, 4),
+                Decimal::new(2, 4),
+                Decimal::new(3, 4),
+                Decimal::new(4, 4)
+            ]
+        );
+    }
+}
@@ -0,0 +1,370 @@
+use std::{
+    collections::HashSet,
+    num::NonZeroUsize,
+    ops::{Bound, RangeBounds},
+    task::{Context, Poll},
+};
+
+use async_stream::try_stream;
+use exc_service::{ExcService, ExchangeError};
+use exc_types::{QueryFirstTrades, QueryLastTrades, QueryTrades, Str, TradeHistoryStream};
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use time::OffsetDateTime;
+use tower::{Layer, Service, ServiceExt};
+
+/// The trades received at the boundary of the pages.
+#[derive(Default)]
+struct Boundary {
+    ts: Option<OffsetDateTime>,
+    seen: HashSet<Str>,
+}
+
+impl Boundary {
+    fn contains(&self, ts: &OffsetDateTime, id: &Str) -> bool {
+        self.ts.as_ref() == Some(ts) && self.seen.contains(id)
+    }
+
+    fn update(&mut self, ts: OffsetDateTime, ids: HashSet<Str>) {
+        if self.ts == Some(ts) {
+            self.seen.extend(ids);
+        } else {
+            self.ts = Some(ts);
+            self.seen = ids;
+        }
+    }
+}
+
+/// Fetch trades forward layer.
+#[derive(Debug, Clone, Copy)]
+pub struct FetchTradesForwardLayer {
+    limit: NonZeroUsize,
+}
+
+impl FetchTradesForwardLayer {
+    /// Create a new fetch trades forward layer.
+    /// # Panic
+    /// Panic if `limit` is zero.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit: NonZeroUsize::new(limit).unwrap(),
+        }
+    }
+}
+
+impl<S> Layer<S> for FetchTradesForwardLayer {
+    type Service = FetchTradesForward<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FetchTradesForward {
+            svc: inner,
+            limit: self.limit,
+        }
+    }
+}
+
+/// Fetch trades forward.
+///
+/// Query the first trades of the range repeatedly, moving the start of the range
+/// to the latest trade received (and asking for the trades after its id).
+/// The trades sharing the timestamp of the boundary are deduplicated by their ids,
+/// so without paging by id there must be fewer than `limit` trades sharing a timestamp.
+#[derive(Debug, Clone)]
+pub struct FetchTradesForward<S> {
+    svc: S,
+    limit: NonZeroUsize,
+}
+
+impl<S> Service<QueryTrades> for FetchTradesForward<S>
+where
+    S: ExcService<QueryFirstTrades> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = TradeHistoryStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, query: QueryTrades) -> Self::Future {
+        let limit = self.limit.get();
+        let end = query.end;
+        let mut query = QueryFirstTrades {
+            query,
+            first: limit,
+            after_id: None,
+        };
+        let mut svc = self.svc.clone();
+        let stream = try_stream! {
+            let mut boundary = Boundary::default();
+            'outer: loop {
+                tracing::trace!("query trades: {query}");
+                if query.query().is_empty() {
+                    break;
+                }
+                let mut stream = Service::call(svc.as_service().ready().await?, query.clone()).await?;
+                let mut count = 0;
+                let mut fresh = 0;
+                let mut latest = None;
+                let mut at_latest = HashSet::new();
+                while let Some(trade) = stream.next().await {
+                    let trade = trade?;
+                    count += 1;
+                    let ts = trade.trade.ts;
+                    // The exchange may return trades after the end of the range.
+                    if !(Bound::Unbounded, end).contains(&ts) {
+                        break 'outer;
+                    }
+                    if latest != Some(ts) {
+                        latest = Some(ts);
+                        at_latest.clear();
+                    }
+                    at_latest.insert(trade.id.clone());
+                    if boundary.contains(&ts, &trade.id) {
+                        continue;
+                    }
+                    fresh += 1;
+                    query.after_id = Some(trade.id.clone());
+                    yield trade;
+                }
+                let Some(latest) = latest else {
+                    break;
+                };
+                if fresh == 0 {
+                    if count >= limit {
+                        Err(ExchangeError::Other(anyhow::anyhow!(
+                            "too many trades at {latest}, try a larger limit"
+                        )))?;
+                    }
+                    break;
+                }
+                boundary.update(latest, at_latest);
+                query.query.start = Bound::Included(latest);
+            }
+        };
+        futures::future::ready(Ok(TradeHistoryStream::new_forward(stream))).boxed()
+    }
+}
+
+/// Fetch trades backward layer.
+#[derive(Debug, Clone, Copy)]
+pub struct FetchTradesBackwardLayer {
+    limit: NonZeroUsize,
+}
+
+impl FetchTradesBackwardLayer {
+    /// Create a new fetch trades backward layer.
+    /// # Panic
+    /// Panic if `limit` is zero.
+    pub fn new(limit: usize) -> Self {
+        Self {
+            limit: NonZeroUsize::new(limit).unwrap(),
+        }
+    }
+}
+
+impl<S> Layer<S> for FetchTradesBackwardLayer {
+    type Service = FetchTradesBackward<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        FetchTradesBackward {
+            svc: inner,
+            limit: self.limit,
+        }
+    }
+}
+
+/// Fetch trades backward.
+///
+/// Query the last trades of the range repeatedly, moving the end of the range
+/// to the oldest trade received (and asking for the trades before its id).
+/// The trades sharing the timestamp of the boundary are deduplicated by their ids,
+/// so without paging by id there must be fewer than `limit` trades sharing a timestamp.
+#[derive(Debug, Clone)]
+pub struct FetchTradesBackward<S> {
+    svc: S,
+    limit: NonZeroUsize,
+}
+
+impl<S> Service<QueryTrades> for FetchTradesBackward<S>
+where
+    S: ExcService<QueryLastTrades> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = TradeHistoryStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, query: QueryTrades) -> Self::Future {
+        let limit = self.limit.get();
+        let start = query.start;
+        let mut query = QueryLastTrades {
+            query,
+            last: limit,
+            before_id: None,
+        };
+        let mut svc = self.svc.clone();
+        let stream = try_stream! {
+            let mut boundary = Boundary::default();
+            'outer: loop {
+                tracing::trace!("query trades: {query}");
+                if query.query().is_empty() {
+                    break;
+                }
+                let mut stream = Service::call(svc.as_service().ready().await?, query.clone()).await?;
+                let mut count = 0;
+                let mut fresh = 0;
+                let mut oldest = None;
+                let mut at_oldest = HashSet::new();
+                while let Some(trade) = stream.next().await {
+                    let trade = trade?;
+                    count += 1;
+                    let ts = trade.trade.ts;
+                    // The exchange may return trades before the start of the range.
+                    if !(start, Bound::Unbounded).contains(&ts) {
+                        break 'outer;
+                    }
+                    if oldest != Some(ts) {
+                        oldest = Some(ts);
+                        at_oldest.clear();
+                    }
+                    at_oldest.insert(trade.id.clone());
+                    if boundary.contains(&ts, &trade.id) {
+                        continue;
+                    }
+                    fresh += 1;
+                    query.before_id = Some(trade.id.clone());
+                    yield trade;
+                }
+                let Some(oldest) = oldest else {
+                    break;
+                };
+                if fresh == 0 {
+                    if count >= limit {
+                        Err(ExchangeError::Other(anyhow::anyhow!(
+                            "too many trades at {oldest}, try a larger limit"
+                        )))?;
+                    }
+                    break;
+                }
+                boundary.update(oldest, at_oldest);
+                query.query.end = Bound::Included(oldest);
+            }
+        };
+        futures::future::ready(Ok(TradeHistoryStream::new_backward(stream))).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exc_types::{Trade, TradeRecord};
+    use futures::TryStreamExt;
+    use rust_decimal::Decimal;
+    use time::macros::datetime;
+
+    fn trade(id: usize, ts: OffsetDateTime) -> TradeRecord {
+        TradeRecord {
+            id: Str::new(id.to_string()),
+            trade: Trade {
+                ts,
+                price: Decimal::ONE,
+                size: Decimal::ONE,
+                buy: true,
+            },
+        }
+    }
+
+    fn trades() -> Vec<TradeRecord> {
+        let t0 = datetime!(2024-01-01 00:00 UTC);
+        let t1 = datetime!(2024-01-01 00:01 UTC);
+        let t2 = datetime!(2024-01-01 00:02 UTC);
+        vec![
+            trade(1, t0),
+            trade(2, t0),
+            trade(3, t1),
+            trade(4, t1),
+            trade(5, t1),
+            trade(6, t2),
+        ]
+    }
+
+    #[test]
+    fn test_fetch_trades_forward() {
+        let trades = trades();
+        // Ignore the ids and the end of the range, paging by time only.
+        let svc = tower::service_fn(move |req: QueryFirstTrades| {
+            let page = trades
+                .iter()
+                .filter(|t| (req.query.start, Bound::Unbounded).contains(&t.trade.ts))
+                .take(req.first)
+                .cloned()
+                .map(Ok)
+                .collect::<Vec<_>>();
+            async move {
+                Ok::<_, ExchangeError>(TradeHistoryStream::new_forward(futures::stream::iter(page)))
+            }
+        });
+        let mut fetch = FetchTradesForwardLayer::new(4).layer(svc);
+        let ids = futures::executor::block_on(async {
+            Service::call(
+                &mut fetch,
+                QueryTrades::new("BTC-USDT", ..datetime!(2024-01-01 00:02 UTC)),
+            )
+            .await?
+            .map_ok(|t| t.id)
+            .try_collect::<Vec<_>>()
+            .await
+        })
+        .unwrap();
+        assert_eq!(ids, ["1", "2", "3", "4", "5"]);
+    }
+
+    #[test]
+    fn test_fetch_trades_backward() {
+        let mut trades = trades();
+        trades.reverse();
+        // Page by id when possible.
+        let svc = tower::service_fn(move |req: QueryLastTrades| {
+            let before = req
+                .before_id
+                .as_ref()
+                .map(|id| id.as_str().parse::<usize>().unwrap());
+            let page = trades
+                .iter()
+                .filter(|t| req.query.contains(&t.trade.ts))
+                .filter(|t| {
+                    before
+                        .map(|b| t.id.as_str().parse::<usize>().unwrap() < b)
+                        .unwrap_or(true)
+                })
+                .take(req.last)
+                .cloned()
+                .map(Ok)
+                .collect::<Vec<_>>();
+            async move {
+                Ok::<_, ExchangeError>(TradeHistoryStream::new_backward(futures::stream::iter(
+                    page,
+                )))
+            }
+        });
+        let mut fetch = FetchTradesBackwardLayer::new(2).layer(svc);
+        let ids = futures::executor::block_on(async {
+            Service::call(
+                &mut fetch,
+                QueryTrades::new("BTC-USDT", datetime!(2024-01-01 00:01 UTC)..),
+            )
+            .await?
+            .map_ok(|t| t.id)
+            .try_collect::<Vec<_>>()
+            .await
+        })
+        .unwrap();
+        assert_eq!(ids, ["6", "5", "4", "3"]);
+    }
+}
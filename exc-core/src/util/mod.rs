@@ -4,6 +4,33 @@ pub mod period;
 /// Create a service to subscribe tickers from subscribe trades and bid/ask.
 pub mod trade_bid_ask;
 
+/// Create a service to subscribe candles from subscribe trades.
+pub mod trade_candles;
+
+/// Amend orders by cancel-replace.
+pub mod cancel_replace;
+
+/// Fan out or split batch requests.
+pub mod batch;
+
+/// Paginate paged requests.
+pub mod paginate;
+
+/// Fetch fills backward.
+pub mod fetch_fills;
+
+/// Fetch funding rates forward.
+pub mod fetch_funding_rates;
+
+/// Fetch trades forward or backward.
+pub mod fetch_trades;
+
+/// Track the offset of the server clock.
+pub mod clock_sync;
+
+/// Local order book.
+pub mod order_book;
+
 /// Create a service to subscribe instruments by first fetching.
 pub mod fetch_instruments_first;
 
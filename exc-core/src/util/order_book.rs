@@ -0,0 +1,607 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    task::{Context, Poll},
+};
+
+use async_stream::try_stream;
+use exc_service::{ExcService, ExchangeError};
+use exc_types::{
+    FetchOrderBook, OrderBook, OrderBookStream, OrderBookUpdate, OrderBookUpdateKind,
+    SubscribeOrderBook, SubscribeOrderBookUpdates,
+};
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use rust_decimal::Decimal;
+use thiserror::Error;
+use time::OffsetDateTime;
+use tower::{Layer, Service, ServiceExt};
+
+/// Number of levels of each side used to compute the checksum.
+const CHECKSUM_DEPTH: usize = 25;
+
+/// Maximum number of snapshots fetched in a row without catching up with the updates.
+const MAX_SNAPSHOT_ATTEMPTS: usize = 10;
+
+/// Errors of applying updates to a [`LocalBook`].
+#[derive(Debug, Error)]
+pub enum LocalBookError {
+    /// Received an update before any snapshot.
+    #[error("received an update before the snapshot")]
+    NotSynced,
+    /// A gap in the update sequence.
+    #[error("sequence gap: last={last}, first={first:?}, prev={prev:?}")]
+    Gap {
+        /// The last applied update id.
+        last: u64,
+        /// The first update id of the update.
+        first: Option<u64>,
+        /// The previous update id of the update.
+        prev: Option<u64>,
+    },
+    /// Checksum mismatched.
+    #[error("checksum mismatched: expected={expected}, local={local}")]
+    Checksum {
+        /// The checksum provided by the exchange.
+        expected: i32,
+        /// The checksum of the local book.
+        local: i32,
+    },
+}
+
+impl From<LocalBookError> for ExchangeError {
+    fn from(err: LocalBookError) -> Self {
+        Self::Other(err.into())
+    }
+}
+
+/// A local L2 order book built from snapshots and diffs.
+///
+/// Sequence ids are checked as follows (when provided):
+/// - updates with `last_id` not greater than the book's are stale and skipped;
+/// - the first update after a snapshot must satisfy `first_id <= last + 1`
+///   (or `prev_id == last` if `first_id` is not provided);
+/// - later updates must satisfy `prev_id == last` or else `first_id == last + 1`.
+///
+/// Checksums are computed as the CRC32 of the top 25 levels
+/// in the form of `bid_px:bid_sz:ask_px:ask_sz:...`.
+#[derive(Debug, Clone, Default)]
+pub struct LocalBook {
+    ts: Option<OffsetDateTime>,
+    synced: bool,
+    fresh: bool,
+    last_id: Option<u64>,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+}
+
+impl LocalBook {
+    /// Whether the book has been synced with a snapshot.
+    pub fn is_synced(&self) -> bool {
+        self.synced
+    }
+
+    /// The last applied update id.
+    pub fn last_id(&self) -> Option<u64> {
+        self.last_id
+    }
+
+    /// Clear the book. A new snapshot is required before applying updates.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Apply an update (or a snapshot) to the book.
+    ///
+    /// Return `Ok(false)` if the update is stale and has been skipped.
+    /// The book is cleared if an error is returned.
+    pub fn apply(&mut self, update: OrderBookUpdate) -> Result<bool, LocalBookError> {
+        let res = self.apply_inner(update);
+        if res.is_err() {
+            self.clear();
+        }
+        res
+    }
+
+    fn apply_inner(&mut self, update: OrderBookUpdate) -> Result<bool, LocalBookError> {
+        match update.kind {
+            OrderBookUpdateKind::Snapshot => {
+                self.bids.clear();
+                self.asks.clear();
+                self.synced = true;
+                self.fresh = true;
+            }
+            OrderBookUpdateKind::Update => {
+                if !self.synced {
+                    return Err(LocalBookError::NotSynced);
+                }
+                if !self.check_sequence(&update)? {
+                    return Ok(false);
+                }
+                self.fresh = false;
+            }
+        }
+        for (price, size) in update.bids {
+            apply_level(&mut self.bids, price, size);
+        }
+        for (price, size) in update.asks {
+            apply_level(&mut self.asks, price, size);
+        }
+        self.ts = Some(update.ts);
+        if update.last_id.is_some() {
+            self.last_id = update.last_id;
+        }
+        if let Some(expected) = update.checksum {
+            let local = self.checksum();
+            if local != expected {
+                return Err(LocalBookError::Checksum { expected, local });
+            }
+        }
+        Ok(true)
+    }
+
+    fn check_sequence(&self, update: &OrderBookUpdate) -> Result<bool, LocalBookError> {
+        let (Some(last), Some(update_last)) = (self.last_id, update.last_id) else {
+            return Ok(true);
+        };
+        if update_last <= last {
+            return Ok(false);
+        }
+        let gap = || LocalBookError::Gap {
+            last,
+            first: update.first_id,
+            prev: update.prev_id,
+        };
+        if self.fresh {
+            match (update.first_id, update.prev_id) {
+                (Some(first), _) if first > last + 1 => return Err(gap()),
+                (None, Some(prev)) if prev != last => return Err(gap()),
+                _ => {}
+            }
+        } else {
+            match (update.first_id, update.prev_id) {
+                (_, Some(prev)) if prev != last => return Err(gap()),
+                (Some(first), None) if first != last + 1 => return Err(gap()),
+                _ => {}
+            }
+        }
+        Ok(true)
+    }
+
+    /// Iterate over the bids, best first.
+    pub fn bids(&self) -> impl Iterator<Item = (Decimal, Decimal)> + '_ {
+        self.bids.iter().rev().map(|(p, s)| (*p, *s))
+    }
+
+    /// Iterate over the asks, best first.
+    pub fn asks(&self) -> impl Iterator<Item = (Decimal, Decimal)> + '_ {
+        self.asks.iter().map(|(p, s)| (*p, *s))
+    }
+
+    /// CRC32 checksum of the top 25 levels.
+    pub fn checksum(&self) -> i32 {
+        let mut bids = self.bids().take(CHECKSUM_DEPTH);
+        let mut asks = self.asks().take(CHECKSUM_DEPTH);
+        let mut parts = Vec::with_capacity(CHECKSUM_DEPTH * 4);
+        loop {
+            let (bid, ask) = (bids.next(), asks.next());
+            if bid.is_none() && ask.is_none() {
+                break;
+            }
+            for (price, size) in bid.into_iter().chain(ask) {
+                parts.push(price.to_string());
+                parts.push(size.to_string());
+            }
+        }
+        crc32fast::hash(parts.join(":").as_bytes()) as i32
+    }
+
+    /// Take an L2 snapshot with at most `depth` levels of each side.
+    /// Return `None` if the book is not synced.
+    pub fn to_order_book(&self, depth: usize) -> Option<OrderBook> {
+        if !self.synced {
+            return None;
+        }
+        Some(OrderBook {
+            ts: self.ts?,
+            bids: self
+                .bids()
+                .take(depth)
+                .map(|(p, s)| (p.normalize(), s.normalize()))
+                .collect(),
+            asks: self
+                .asks()
+                .take(depth)
+                .map(|(p, s)| (p.normalize(), s.normalize()))
+                .collect(),
+        })
+    }
+}
+
+fn apply_level(side: &mut BTreeMap<Decimal, Decimal>, price: Decimal, size: Decimal) {
+    if size.is_zero() {
+        side.remove(&price);
+    } else {
+        side.insert(price, size);
+    }
+}
+
+/// Local order book layer.
+#[derive(Debug, Clone, Copy)]
+pub struct LocalOrderBookLayer {
+    snapshot_depth: usize,
+}
+
+impl Default for LocalOrderBookLayer {
+    fn default() -> Self {
+        Self {
+            snapshot_depth: 1000,
+        }
+    }
+}
+
+impl LocalOrderBookLayer {
+    /// Set the depth of the snapshots to fetch. Default to `1000`.
+    pub fn snapshot_depth(&mut self, depth: usize) -> &mut Self {
+        self.snapshot_depth = depth;
+        self
+    }
+}
+
+impl<S> Layer<S> for LocalOrderBookLayer {
+    type Service = LocalOrderBook<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        LocalOrderBook {
+            svc: inner,
+            snapshot_depth: self.snapshot_depth,
+        }
+    }
+}
+
+/// Local order book service.
+///
+/// Maintain the book by applying the updates to a snapshot. The snapshot is taken
+/// from the update stream if it starts with one, or fetched otherwise.
+///
+/// When the book gets out of sync (a sequence gap or a checksum mismatch),
+/// the update stream is kept and its updates are buffered, while the snapshot is
+/// refetched until it catches up with the first buffered update. The update stream
+/// is only resubscribed if it provides the snapshots itself.
+#[derive(Debug, Clone, Copy)]
+pub struct LocalOrderBook<S> {
+    snapshot_depth: usize,
+    svc: S,
+}
+
+impl<S> Service<SubscribeOrderBook> for LocalOrderBook<S>
+where
+    S: Clone + Send + 'static,
+    S: ExcService<SubscribeOrderBookUpdates>,
+    S: ExcService<FetchOrderBook>,
+    <S as ExcService<SubscribeOrderBookUpdates>>::Future: Send,
+    <S as ExcService<FetchOrderBook>>::Future: Send,
+{
+    type Response = OrderBookStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Service::<SubscribeOrderBookUpdates>::poll_ready(&mut self.svc.as_service(), cx)
+    }
+
+    fn call(&mut self, req: SubscribeOrderBook) -> Self::Future {
+        let updates = Service::<SubscribeOrderBookUpdates>::call(
+            &mut self.svc.as_service(),
+            SubscribeOrderBookUpdates {
+                instrument: req.instrument.clone(),
+            },
+        );
+        let mut svc = self.svc.clone();
+        let fetch = FetchOrderBook {
+            instrument: req.instrument.clone(),
+            depth: self.snapshot_depth.max(req.depth),
+        };
+        let subscribe = SubscribeOrderBookUpdates {
+            instrument: req.instrument,
+        };
+        let depth = req.depth;
+        async move {
+            let mut updates = updates.await?;
+            let stream = try_stream! {
+                let mut book = LocalBook::default();
+                // The updates received while waiting for a snapshot to catch up.
+                let mut pending = VecDeque::new();
+                // Whether the snapshots are provided by the update stream.
+                let mut streamed = false;
+                let mut attempts = 0;
+                while let Some(update) = updates.next().await {
+                    let update = update?;
+                    let res = if update.kind == OrderBookUpdateKind::Snapshot {
+                        streamed = true;
+                        pending.clear();
+                        book.apply(update)
+                    } else if book.is_synced() {
+                        book.apply(update)
+                    } else {
+                        pending.push_back(update);
+                        let snapshot = Service::<FetchOrderBook>::call(
+                            svc.as_service().ready().await?,
+                            fetch.clone(),
+                        )
+                        .await?
+                        .await?;
+                        if let (Some(last), Some(first)) = (snapshot.last_id, pending.front()) {
+                            if is_behind(last, first) {
+                                attempts += 1;
+                                if attempts >= MAX_SNAPSHOT_ATTEMPTS {
+                                    Err(ExchangeError::Other(anyhow::anyhow!(
+                                        "the snapshots keep falling behind the updates"
+                                    )))?;
+                                }
+                                tracing::debug!(last, "the snapshot is behind the updates; refetching");
+                                continue;
+                            }
+                        }
+                        attempts = 0;
+                        let mut res = book.apply(snapshot);
+                        for update in pending.drain(..) {
+                            if res.is_err() {
+                                break;
+                            }
+                            res = book.apply(update).map(|_| true);
+                        }
+                        res
+                    };
+                    match res {
+                        Ok(true) => {
+                            if let Some(snapshot) = book.to_order_book(depth) {
+                                yield snapshot;
+                            }
+                        }
+                        Ok(false) => {}
+                        Err(err) if streamed => {
+                            tracing::warn!(%err, "local book is out of sync; resubscribing");
+                            updates = Service::<SubscribeOrderBookUpdates>::call(
+                                svc.as_service().ready().await?,
+                                subscribe.clone(),
+                            )
+                            .await?;
+                        }
+                        Err(err) => {
+                            tracing::warn!(%err, "local book is out of sync; refetching the snapshot");
+                        }
+                    }
+                }
+            };
+            Ok(stream.boxed())
+        }
+        .boxed()
+    }
+}
+
+/// Whether a snapshot with the last update id `last` is behind the `update`,
+/// that is, the updates between them are missing.
+fn is_behind(last: u64, update: &OrderBookUpdate) -> bool {
+    match (update.first_id, update.prev_id) {
+        (Some(first), _) => first > last + 1,
+        (None, Some(prev)) => prev > last,
+        (None, None) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::TryStreamExt;
+    use rust_decimal_macros::dec;
+
+    fn update(
+        kind: OrderBookUpdateKind,
+        first: Option<u64>,
+        last: Option<u64>,
+        prev: Option<u64>,
+        bids: Vec<(Decimal, Decimal)>,
+        asks: Vec<(Decimal, Decimal)>,
+    ) -> OrderBookUpdate {
+        OrderBookUpdate {
+            ts: OffsetDateTime::UNIX_EPOCH,
+            kind,
+            first_id: first,
+            last_id: last,
+            prev_id: prev,
+            checksum: None,
+            bids,
+            asks,
+        }
+    }
+
+    #[test]
+    fn test_diff_sequence() {
+        let mut book = LocalBook::default();
+        assert!(matches!(
+            book.apply(update(
+                OrderBookUpdateKind::Update,
+                Some(1),
+                Some(2),
+                None,
+                vec![],
+                vec![]
+            )),
+            Err(LocalBookError::NotSynced)
+        ));
+        book.apply(update(
+            OrderBookUpdateKind::Snapshot,
+            None,
+            Some(10),
+            None,
+            vec![(dec!(100), dec!(1)), (dec!(99), dec!(2))],
+            vec![(dec!(101), dec!(1)), (dec!(102), dec!(2))],
+        ))
+        .unwrap();
+        // Stale.
+        assert!(!book
+            .apply(update(
+                OrderBookUpdateKind::Update,
+                Some(5),
+                Some(10),
+                None,
+                vec![(dec!(100), dec!(0))],
+                vec![],
+            ))
+            .unwrap());
+        // Overlapping.
+        assert!(book
+            .apply(update(
+                OrderBookUpdateKind::Update,
+                Some(9),
+                Some(12),
+                None,
+                vec![(dec!(100), dec!(0))],
+                vec![(dec!(100.5), dec!(3))],
+            ))
+            .unwrap());
+        let snapshot = book.to_order_book(1).unwrap();
+        assert_eq!(snapshot.bids, vec![(dec!(99), dec!(2))]);
+        assert_eq!(snapshot.asks, vec![(dec!(100.5), dec!(3))]);
+        // Gap.
+        assert!(matches!(
+            book.apply(update(
+                OrderBookUpdateKind::Update,
+                Some(14),
+                Some(15),
+                None,
+                vec![],
+                vec![],
+            )),
+            Err(LocalBookError::Gap { last: 12, .. })
+        ));
+        assert!(!book.is_synced());
+    }
+
+    #[test]
+    fn test_checksum() {
+        let mut book = LocalBook::default();
+        let mut snapshot = update(
+            OrderBookUpdateKind::Snapshot,
+            None,
+            Some(1),
+            None,
+            vec![(dec!(3366.1), dec!(7)), (dec!(3366), dec!(6))],
+            vec![
+                (dec!(3366.8), dec!(9)),
+                (dec!(3368), dec!(8)),
+                (dec!(3372), dec!(8)),
+            ],
+        );
+        let expected = crc32fast::hash(b"3366.1:7:3366.8:9:3366:6:3368:8:3372:8") as i32;
+        snapshot.checksum = Some(expected);
+        assert!(book.apply(snapshot).unwrap());
+        let mut next = update(
+            OrderBookUpdateKind::Update,
+            None,
+            Some(2),
+            Some(1),
+            vec![],
+            vec![(dec!(3368), dec!(0))],
+        );
+        next.checksum = Some(expected);
+        assert!(matches!(
+            book.apply(next),
+            Err(LocalBookError::Checksum { .. })
+        ));
+    }
+
+    /// An exchange without snapshots in the update stream.
+    #[derive(Clone)]
+    struct Exchange {
+        updates: Vec<OrderBookUpdate>,
+        snapshots: std::sync::Arc<std::sync::Mutex<VecDeque<OrderBookUpdate>>>,
+        subscribed: std::sync::Arc<std::sync::atomic::AtomicUsize>,
+    }
+
+    impl Service<SubscribeOrderBookUpdates> for Exchange {
+        type Response = exc_types::OrderBookUpdateStream;
+        type Error = ExchangeError;
+        type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _req: SubscribeOrderBookUpdates) -> Self::Future {
+            self.subscribed
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let updates = futures::stream::iter(self.updates.clone().into_iter().map(Ok)).boxed();
+            futures::future::ready(Ok(updates)).boxed()
+        }
+    }
+
+    impl Service<FetchOrderBook> for Exchange {
+        type Response = BoxFuture<'static, Result<OrderBookUpdate, ExchangeError>>;
+        type Error = ExchangeError;
+        type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, _req: FetchOrderBook) -> Self::Future {
+            let snapshot = self.snapshots.lock().unwrap().pop_front().unwrap();
+            futures::future::ready(Ok(futures::future::ready(Ok(snapshot)).boxed())).boxed()
+        }
+    }
+
+    #[test]
+    fn test_snapshot_behind_updates() {
+        let snapshot = |last, bid| {
+            update(
+                OrderBookUpdateKind::Snapshot,
+                None,
+                Some(last),
+                None,
+                vec![(dec!(100), bid)],
+                vec![(dec!(101), dec!(1))],
+            )
+        };
+        let diff = |first, last, bid| {
+            update(
+                OrderBookUpdateKind::Update,
+                Some(first),
+                Some(last),
+                None,
+                vec![(dec!(100), bid)],
+                vec![],
+            )
+        };
+        let exchange = Exchange {
+            updates: vec![
+                diff(11, 12, dec!(2)),
+                diff(13, 14, dec!(3)),
+                diff(15, 16, dec!(4)),
+            ],
+            // The first snapshot is behind the first buffered update.
+            snapshots: std::sync::Arc::new(std::sync::Mutex::new(VecDeque::from([
+                snapshot(5, dec!(1)),
+                snapshot(13, dec!(2)),
+            ]))),
+            subscribed: Default::default(),
+        };
+        let svc = LocalOrderBookLayer::default().layer(exchange.clone());
+        let books = futures::executor::block_on(async move {
+            svc.oneshot(SubscribeOrderBook::new("BTC-USDT", 1))
+                .await?
+                .try_collect::<Vec<_>>()
+                .await
+        })
+        .unwrap();
+        let bids = books.iter().map(|book| book.bids[0].1).collect::<Vec<_>>();
+        // The stale update is skipped, and the rest are applied to the second snapshot.
+        assert_eq!(bids, [dec!(3), dec!(4)]);
+        assert!(exchange.snapshots.lock().unwrap().is_empty());
+        assert_eq!(
+            exchange
+                .subscribed
+                .load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use std::task::{Context, Poll};
+
+use async_stream::try_stream;
+use exc_service::{ExcService, ExchangeError, Request};
+use exc_types::{Paged, PagedRequest};
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use tower::{Layer, Service, ServiceExt};
+
+/// Paginate layer.
+#[derive(Debug, Default)]
+pub struct PaginateLayer;
+
+impl<S> Layer<S> for PaginateLayer {
+    type Service = Paginate<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Paginate(inner)
+    }
+}
+
+/// Paginate service.
+///
+/// Turn a [`PagedRequest`] into a stream of its items by fetching
+/// the pages one after another with the inner service.
+#[derive(Debug, Clone, Copy)]
+pub struct Paginate<S>(S);
+
+impl<S, Q> Service<Q> for Paginate<S>
+where
+    Q: PagedRequest,
+    S: ExcService<Paged<Q>> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = <Q as Request>::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, query: Q) -> Self::Future {
+        let mut svc = self.0.clone();
+        let stream = try_stream! {
+            let mut req = Paged::first(query);
+            loop {
+                let page = Service::call(svc.as_service().ready().await?, req.clone())
+                    .await?
+                    .await?;
+                for item in page.items {
+                    yield item;
+                }
+                match page.next {
+                    Some(cursor) if req.cursor.as_ref() == Some(&cursor) => {
+                        Err(ExchangeError::Other(anyhow::anyhow!(
+                            "the cursor does not advance: {cursor}"
+                        )))?;
+                    }
+                    Some(cursor) => req.cursor = Some(cursor),
+                    None => break,
+                }
+            }
+        };
+        futures::future::ready(Ok(stream.boxed())).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exc_types::{ListOpenOrders, OrderUpdate, Page};
+    use futures::TryStreamExt;
+
+    #[test]
+    fn test_paginate_follows_cursor() {
+        let svc = tower::service_fn(|req: Paged<ListOpenOrders>| async move {
+            let page: Result<Page<OrderUpdate>, ExchangeError> = match req.cursor.as_deref() {
+                None => Ok(Page {
+                    items: Vec::new(),
+                    next: Some("1".into()),
+                }),
+                Some("1") => Ok(Page {
+                    items: Vec::new(),
+                    next: Some("2".into()),
+                }),
+                _ => Ok(Page {
+                    items: Vec::new(),
+                    next: None,
+                }),
+            };
+            Ok::<_, ExchangeError>(futures::future::ready(page).boxed())
+        });
+        let mut paginate = PaginateLayer.layer(svc);
+        let items = futures::executor::block_on(async {
+            Service::call(&mut paginate, ListOpenOrders::all())
+                .await?
+                .try_collect::<Vec<_>>()
+                .await
+        })
+        .unwrap();
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn test_paginate_stuck_cursor() {
+        let svc = tower::service_fn(|_req: Paged<ListOpenOrders>| async move {
+            let page: Result<Page<OrderUpdate>, ExchangeError> = Ok(Page {
+                items: Vec::new(),
+                next: Some("1".into()),
+            });
+            Ok::<_, ExchangeError>(futures::future::ready(page).boxed())
+        });
+        let mut paginate = PaginateLayer.layer(svc);
+        let res = futures::executor::block_on(async {
+            Service::call(&mut paginate, ListOpenOrders::all())
+                .await?
+                .try_collect::<Vec<_>>()
+                .await
+        });
+        assert!(res.is_err());
+    }
+}
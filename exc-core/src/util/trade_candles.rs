@@ -0,0 +1,166 @@
+use std::task::{Context, Poll};
+
+use async_stream::try_stream;
+use exc_service::{ExcService, ExchangeError};
+use exc_types::{
+    Candle, CandleUpdate, CandleUpdateStream, Period, PeriodKind, SubscribeCandles, SubscribeTrades,
+};
+use futures::{future::BoxFuture, FutureExt, StreamExt};
+use time::OffsetDateTime;
+use tower::{Layer, Service};
+
+use super::{trunc, PeriodExt};
+
+/// Get the start of the bar containing `ts`.
+fn bar_start(period: &Period, ts: OffsetDateTime) -> Option<OffsetDateTime> {
+    let ts = ts.to_offset(period.utc_offset());
+    match period.kind() {
+        PeriodKind::Duration(dur) => trunc(ts, dur),
+        _ => period.iterate(ts..=ts).next(),
+    }
+}
+
+/// Trade-Candles layer.
+/// Build live candles from trades, for exchanges without a candle channel.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TradeCandlesLayer;
+
+impl<S> Layer<S> for TradeCandlesLayer {
+    type Service = TradeCandles<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        TradeCandles { svc: inner }
+    }
+}
+
+/// Trade-Candles service.
+///
+/// An update of the current bar is yielded for each trade,
+/// and the bar is yielded as closed once a trade of a later bar arrives.
+/// Bars without any trades are skipped.
+#[derive(Debug, Clone, Copy)]
+pub struct TradeCandles<S> {
+    svc: S,
+}
+
+impl<S> Service<SubscribeCandles> for TradeCandles<S>
+where
+    S: ExcService<SubscribeTrades> + Send,
+    S::Future: Send + 'static,
+{
+    type Response = CandleUpdateStream;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Service::<SubscribeTrades>::poll_ready(&mut self.svc.as_service(), cx)
+    }
+
+    fn call(&mut self, req: SubscribeCandles) -> Self::Future {
+        let trades = Service::<SubscribeTrades>::call(
+            &mut self.svc.as_service(),
+            SubscribeTrades::new(req.instrument.as_str()),
+        );
+        let period = req.period;
+        async move {
+            let trades = trades.await?;
+            let stream = try_stream! {
+                let mut current: Option<Candle> = None;
+                for await trade in trades {
+                    let trade = trade?;
+                    let start = bar_start(&period, trade.ts).ok_or_else(|| {
+                        ExchangeError::Other(anyhow::anyhow!(
+                            "failed to align {} with period {period}",
+                            trade.ts
+                        ))
+                    })?;
+                    match current.as_mut() {
+                        Some(bar) if bar.ts == start => {
+                            bar.high = bar.high.max(trade.price);
+                            bar.low = bar.low.min(trade.price);
+                            bar.close = trade.price;
+                            bar.volume += trade.size;
+                        }
+                        // Trades from the past bars are ignored.
+                        Some(bar) if bar.ts > start => continue,
+                        _ => {
+                            if let Some(candle) = current.take() {
+                                yield CandleUpdate { candle, closed: true };
+                            }
+                            current = Some(Candle {
+                                ts: start,
+                                open: trade.price,
+                                high: trade.price,
+                                low: trade.price,
+                                close: trade.price,
+                                volume: trade.size,
+                            });
+                        }
+                    }
+                    if let Some(candle) = current.as_ref() {
+                        yield CandleUpdate { candle: candle.clone(), closed: false };
+                    }
+                }
+            };
+            Ok(stream.boxed())
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use exc_types::Trade;
+    use futures::{executor::block_on, stream, TryStreamExt};
+    use rust_decimal_macros::dec;
+    use time::macros::{datetime, offset};
+    use tower::ServiceExt;
+
+    #[test]
+    fn test_trade_candles() {
+        let trades = [
+            (datetime!(2023-01-01 00:00:01 UTC), dec!(1), dec!(1)),
+            (datetime!(2023-01-01 00:00:30 UTC), dec!(3), dec!(2)),
+            (datetime!(2023-01-01 00:00:59 UTC), dec!(2), dec!(1)),
+            (datetime!(2023-01-01 00:02:00 UTC), dec!(4), dec!(1)),
+        ];
+        let svc = tower::service_fn(move |_req: SubscribeTrades| {
+            let trades = stream::iter(trades.into_iter().map(|(ts, price, size)| {
+                Ok(Trade {
+                    ts,
+                    price,
+                    size,
+                    buy: true,
+                })
+            }))
+            .boxed();
+            futures::future::ready(Ok::<_, ExchangeError>(trades))
+        });
+        let mut svc = TradeCandlesLayer.layer(svc);
+        let updates = block_on(async {
+            let stream = Service::<SubscribeCandles>::call(
+                svc.ready().await?,
+                SubscribeCandles::new("BTC-USDT", Period::minutes(offset!(UTC), 1)),
+            )
+            .await?;
+            stream.try_collect::<Vec<_>>().await
+        })
+        .unwrap();
+        assert_eq!(updates.len(), 5);
+        let closed = updates
+            .iter()
+            .filter(|update| update.closed)
+            .collect::<Vec<_>>();
+        assert_eq!(closed.len(), 1);
+        let bar = &closed[0].candle;
+        assert_eq!(bar.ts, datetime!(2023-01-01 00:00:00 UTC));
+        assert_eq!(
+            (bar.open, bar.high, bar.low, bar.close, bar.volume),
+            (dec!(1), dec!(3), dec!(1), dec!(2), dec!(4))
+        );
+        let last = updates.last().unwrap();
+        assert!(!last.closed);
+        assert_eq!(last.candle.ts, datetime!(2023-01-01 00:02:00 UTC));
+    }
+}
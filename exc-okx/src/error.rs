@@ -1,4 +1,4 @@
-use exc_core::ExchangeError;
+use exc_core::{ApiError, ApiErrorKind, ExchangeError};
 use thiserror::Error;
 
 use crate::{
@@ -15,9 +15,9 @@ pub enum OkxError {
     /// Stream dropped.
     #[error("stream is dropped")]
     StreamDropped,
-    /// Websocket error.
+    /// Websocket error (boxed to keep the results small).
     #[error("weboscket: {0}")]
-    Websocket(#[from] tokio_tungstenite::tungstenite::Error),
+    Websocket(Box<tokio_tungstenite::tungstenite::Error>),
     /// Remote closed.
     #[error("remote closed")]
     RemoteClosed,
@@ -57,6 +57,9 @@ pub enum OkxError {
     /// API Error.
     #[error("api error: {0}")]
     Api(StatusKind),
+    /// API error with the native code, converted into [`ExchangeError::ApiError`].
+    #[error("api error: {0}")]
+    ApiError(ApiError),
     /// Protocol Error.
     #[error("protocol: {0}")]
     Protocol(anyhow::Error),
@@ -91,14 +94,50 @@ pub enum OkxError {
 }
 
 impl OkxError {
+    /// API error with the code.
+    pub fn api_code(code: &str, msg: &str) -> Self {
+        Self::ApiError(api_error(code, msg))
+    }
+
     /// Parsing order error.
     pub fn parsing_order(msg: impl ToString) -> Self {
         Self::ParsingOrder(msg.to_string())
     }
 }
 
+impl From<tokio_tungstenite::tungstenite::Error> for OkxError {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        Self::Websocket(Box::new(err))
+    }
+}
+
 impl From<OkxError> for ExchangeError {
     fn from(err: OkxError) -> Self {
-        Self::Other(err.into())
+        match err {
+            OkxError::ApiError(err) => Self::ApiError(err),
+            err => Self::Other(err.into()),
+        }
     }
 }
+
+/// The name of the exchange in the [`ApiError`]s.
+const VENUE: &str = "okx";
+
+/// Map the error code (or `sCode`) of OKX into an [`ApiError`].
+///
+/// Note that OKX accepts the post-only orders that would take, and cancels them afterwards.
+pub fn api_error(code: &str, msg: &str) -> ApiError {
+    let kind = match code {
+        "51008" | "51119" | "51127" | "51131" => ApiErrorKind::InsufficientBalance,
+        "51006" => ApiErrorKind::InvalidPrice,
+        "51400" | "51603" => ApiErrorKind::OrderNotFound,
+        "51016" => ApiErrorKind::DuplicateClientId,
+        "50102" | "50112" => ApiErrorKind::TimestampDrift,
+        "50030" | "50110" | "50111" | "50113" | "50114" | "50119" | "50120" => {
+            ApiErrorKind::PermissionDenied
+        }
+        "50001" => ApiErrorKind::Maintenance,
+        _ => ApiErrorKind::Other,
+    };
+    ApiError::new(VENUE, code, msg, kind)
+}
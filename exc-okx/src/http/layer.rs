@@ -1,10 +1,15 @@
 use crate::key::OkxKey as Key;
 
 use super::types::{
-    request::HttpRequest,
+    request::{HttpRequest, PrivateGet},
     response::{FullHttpResponse, HttpResponse},
 };
-use exc_core::{retry::RetryPolicy, ExchangeError};
+use exc_core::{
+    retry::{Idempotent, RetryPolicy},
+    util::clock_sync::Clock,
+    weight::{RateLimiter, WeightedRateLimit, WeightedRateLimitLayer},
+    ExchangeError,
+};
 use futures::{
     future::{ready, BoxFuture},
     FutureExt, TryFutureExt,
@@ -18,6 +23,8 @@ pub struct OkxHttpApiLayer<F> {
     testing: bool,
     aws: bool,
     key: Option<Key>,
+    clock: Clock,
+    limiter: RateLimiter,
     retry_policy: RetryPolicy<HttpRequest, HttpResponse, F>,
 }
 
@@ -28,6 +35,19 @@ impl<F> OkxHttpApiLayer<F> {
         self
     }
 
+    /// Set the clock used to sign the private requests.
+    pub fn clock(&mut self, clock: Clock) -> &mut Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Set the rate limiter acquiring the weights of the requests before signing them.
+    /// See [`limit::rate_limiter`](super::limit::rate_limiter) for the default limits.
+    pub fn rate_limiter(&mut self, limiter: RateLimiter) -> &mut Self {
+        self.limiter = limiter;
+        self
+    }
+
     /// Set whether to use the testing environment.
     pub fn testing(&mut self, enable: bool) -> &mut Self {
         self.testing = enable;
@@ -40,7 +60,7 @@ impl<F> OkxHttpApiLayer<F> {
         self
     }
 
-    /// Set retry policy.
+    /// Set retry policy. The requests that are not idempotent are never retried.
     pub fn retry<F2>(
         self,
         policy: RetryPolicy<HttpRequest, HttpResponse, F2>,
@@ -52,6 +72,8 @@ impl<F> OkxHttpApiLayer<F> {
             aws: self.aws,
             retry_policy: policy,
             key: self.key,
+            clock: self.clock,
+            limiter: self.limiter,
             testing: self.testing,
         }
     }
@@ -93,6 +115,8 @@ impl OkxHttpApiLayer<fn(&ExchangeError) -> bool> {
             aws: false,
             retry_policy: RetryPolicy::never(),
             key: None,
+            clock: Clock::default(),
+            limiter: RateLimiter::default(),
             testing: false,
         }
     }
@@ -107,17 +131,22 @@ where
     F: Fn(&ExchangeError) -> bool,
     F: Send + 'static + Clone,
 {
-    type Service = Retry<RetryPolicy<HttpRequest, HttpResponse, F>, OkxHttpApi<S>>;
+    type Service = Retry<
+        Idempotent<RetryPolicy<HttpRequest, HttpResponse, F>>,
+        WeightedRateLimit<OkxHttpApi<S>>,
+    >;
 
     fn layer(&self, inner: S) -> Self::Service {
         let svc = OkxHttpApi {
             host: self.host().to_string(),
             http: inner,
             key: self.key.clone(),
+            clock: self.clock.clone(),
             testing: self.testing,
         };
         ServiceBuilder::default()
-            .retry(self.retry_policy.clone())
+            .retry(Idempotent(self.retry_policy.clone()))
+            .layer(WeightedRateLimitLayer::new(self.limiter.clone()))
             .service(svc)
     }
 }
@@ -127,6 +156,7 @@ where
 pub struct OkxHttpApi<S> {
     host: String,
     key: Option<Key>,
+    clock: Clock,
     http: S,
     testing: bool,
 }
@@ -152,6 +182,11 @@ where
     }
 
     fn call(&mut self, req: HttpRequest) -> Self::Future {
+        // Okx does not include the instrument in the trade fee response.
+        let trade_fee_inst = match &req {
+            HttpRequest::PrivateGet(PrivateGet::TradeFee(fee)) => Some(fee.instrument.clone()),
+            _ => None,
+        };
         let req = match req {
             HttpRequest::Get(get) => serde_qs::to_string(&get)
                 .map_err(|err| ExchangeError::Other(err.into()))
@@ -163,7 +198,7 @@ where
                 }),
             HttpRequest::PrivateGet(get) => {
                 if let Some(key) = self.key.as_ref() {
-                    get.to_request(&self.host, key)
+                    get.to_request(&self.host, key, &self.clock)
                 } else {
                     Err(ExchangeError::KeyError(anyhow::anyhow!(
                         "key has not been set"
@@ -172,7 +207,7 @@ where
             }
             HttpRequest::PrivatePost(post) => {
                 if let Some(key) = self.key.as_ref() {
-                    post.to_request(&self.host, key)
+                    post.to_request(&self.host, key, &self.clock)
                 } else {
                     Err(ExchangeError::KeyError(anyhow::anyhow!(
                         "key has not been set"
@@ -202,6 +237,10 @@ where
                         futures::future::ready(resp)
                     })
                     .and_then(|resp| ready(resp.try_into()))
+                    .map_ok(move |resp: HttpResponse| match trade_fee_inst {
+                        Some(inst) => resp.with_trade_fee_instrument(&inst),
+                        None => resp,
+                    })
                     .boxed()
             }
             Err(err) => ready(Err(err)).boxed(),
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use exc_core::weight::{RateLimiter, RequestWeight, Weight};
+
+use super::types::request::HttpRequest;
+
+/// The (per-endpoint) request limits of OKX, in requests per window.
+const LIMITS: [(&str, u64, u64); 24] = [
+    ("/api/v5/market/history-candles", 20, 2),
+    ("/api/v5/market/history-trades", 10, 2),
+    ("/api/v5/market/books", 40, 2),
+    ("/api/v5/public/instruments", 20, 2),
+    ("/api/v5/public/funding-rate", 20, 2),
+    ("/api/v5/public/funding-rate-history", 10, 2),
+    ("/api/v5/public/time", 10, 2),
+    ("/api/v5/finance/staking-defi/offers", 3, 1),
+    ("/api/v5/finance/staking-defi/orders-active", 3, 1),
+    ("/api/v5/finance/staking-defi/purchase", 2, 1),
+    ("/api/v5/finance/staking-defi/redeem", 2, 1),
+    ("/api/v5/trade/order", 60, 2),
+    ("/api/v5/trade/orders-pending", 60, 2),
+    ("/api/v5/trade/orders-history", 40, 2),
+    ("/api/v5/trade/fills-history", 10, 2),
+    ("/api/v5/trade/mass-cancel", 5, 2),
+    ("/api/v5/trade/cancel-all-after", 1, 1),
+    ("/api/v5/trade/order-algo", 20, 2),
+    ("/api/v5/account/balance", 10, 2),
+    ("/api/v5/account/positions", 10, 2),
+    ("/api/v5/account/trade-fee", 5, 2),
+    ("/api/v5/account/leverage-info", 20, 2),
+    ("/api/v5/account/set-leverage", 20, 2),
+    ("/api/v5/account/set-position-mode", 5, 2),
+];
+
+/// The (per-endpoint) request limits of the asset apis, in requests per second.
+const ASSET_LIMITS: [(&str, u64); 4] = [
+    ("/api/v5/asset/transfer", 1),
+    ("/api/v5/asset/transfer-state", 10),
+    ("/api/v5/asset/deposit-history", 6),
+    ("/api/v5/asset/withdrawal-history", 6),
+];
+
+/// Create a rate limiter with the default (per-endpoint) limits of OKX,
+/// in which every http request costs `1` of the bucket named after its path.
+///
+/// Share it between the services using the same IP and account to keep
+/// them from being rate limited together.
+pub fn rate_limiter() -> RateLimiter {
+    let limiter = LIMITS
+        .into_iter()
+        .fold(RateLimiter::new(), |limiter, (path, capacity, secs)| {
+            limiter.with_bucket(path, capacity, Duration::from_secs(secs))
+        });
+    ASSET_LIMITS
+        .into_iter()
+        .fold(limiter, |limiter, (path, capacity)| {
+            limiter.with_bucket(path, capacity, Duration::from_secs(1))
+        })
+}
+
+impl RequestWeight for HttpRequest {
+    fn weight(&self) -> Weight {
+        let path = match self {
+            Self::Get(get) => get.uri(),
+            Self::PrivateGet(get) => get.uri(),
+            Self::PrivatePost(post) => post.uri(),
+        };
+        Weight::bucket(path, 1)
+    }
+}
@@ -3,3 +3,6 @@ pub mod types;
 
 /// Layer.
 pub mod layer;
+
+/// Rate limits.
+pub mod limit;
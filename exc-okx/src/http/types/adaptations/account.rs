@@ -0,0 +1,249 @@
+use exc_core::{
+    types::{
+        Balances, FeeRates, FetchBalances, FetchFeeRates, FetchPositions, GetLeverage, Leverage,
+        MarginMode, Position, PositionMode, SetLeverage, SetPositionMode,
+    },
+    Adaptor, ExchangeError, Str,
+};
+use futures::FutureExt;
+
+use crate::http::types::{
+    request::{
+        account::{self, Balance, LeverageInfo, Positions, TradeFee},
+        HttpRequest, PrivateGet, PrivatePost,
+    },
+    response::ResponseData,
+};
+
+impl Adaptor<FetchBalances> for HttpRequest {
+    fn from_request(_req: FetchBalances) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::PrivateGet(PrivateGet::Balance(Balance::default())))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchBalances as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            match resp.data.into_iter().next() {
+                Some(ResponseData::Account(account)) => Ok(Balances::from(*account)),
+                Some(data) => Err(ExchangeError::unexpected_response_type(format!("{data:?}"))),
+                None => Err(ExchangeError::unexpected_response_type("empty response")),
+            }
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<FetchPositions> for HttpRequest {
+    fn from_request(req: FetchPositions) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::PrivateGet(PrivateGet::Positions(Positions {
+            inst_type: None,
+            inst_id: req.instrument,
+        })))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchPositions as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.data
+                .into_iter()
+                .map(|data| match data {
+                    ResponseData::Position(position) => {
+                        Position::try_from(*position).map_err(ExchangeError::from)
+                    }
+                    data => Err(ExchangeError::unexpected_response_type(format!("{data:?}"))),
+                })
+                .collect()
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<FetchFeeRates> for HttpRequest {
+    fn from_request(req: FetchFeeRates) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let inst_type = super::trading::inst_type(&req.instrument).ok_or_else(|| {
+            ExchangeError::Other(anyhow::anyhow!(
+                "unknown instrument type: {}",
+                req.instrument
+            ))
+        })?;
+        let req = if inst_type == "SPOT" {
+            TradeFee {
+                inst_type,
+                inst_id: Some(req.instrument.clone()),
+                inst_family: None,
+                instrument: req.instrument,
+            }
+        } else {
+            let family = req.instrument.splitn(3, '-').take(2).collect::<Vec<_>>();
+            TradeFee {
+                inst_type,
+                inst_id: None,
+                inst_family: Some(Str::new(family.join("-"))),
+                instrument: req.instrument,
+            }
+        };
+        Ok(Self::PrivateGet(PrivateGet::TradeFee(req)))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchFeeRates as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            let Some(data) = resp.data.into_iter().next() else {
+                return Err(ExchangeError::unexpected_response_type("empty response"));
+            };
+            let ResponseData::TradeFee(fee) = data else {
+                return Err(ExchangeError::unexpected_response_type(format!("{data:?}")));
+            };
+            let (maker, taker) = fee.rates().ok_or_else(|| {
+                ExchangeError::unexpected_response_type(format!("missing fee rates: {fee:?}"))
+            })?;
+            // Okx uses negative rates for fees.
+            Ok(FeeRates {
+                instrument: fee.instrument.unwrap_or_default(),
+                maker: -maker.normalize(),
+                taker: -taker.normalize(),
+            })
+        }
+        .boxed())
+    }
+}
+
+fn mgn_mode(mode: MarginMode) -> Result<Str, ExchangeError> {
+    match mode {
+        MarginMode::Cross => Ok(Str::new_inline("cross")),
+        MarginMode::Isolated => Ok(Str::new_inline("isolated")),
+        MarginMode::Cash => Err(ExchangeError::Other(anyhow::anyhow!(
+            "there is no leverage for cash trading"
+        ))),
+    }
+}
+
+fn leverage(data: Vec<ResponseData>) -> Result<Leverage, ExchangeError> {
+    let Some(data) = data.into_iter().next() else {
+        return Err(ExchangeError::unexpected_response_type("empty response"));
+    };
+    let ResponseData::Leverage(leverage) = data else {
+        return Err(ExchangeError::unexpected_response_type(format!("{data:?}")));
+    };
+    let margin_mode = match leverage.mgn_mode.as_str() {
+        "cross" => Some(MarginMode::Cross),
+        "isolated" => Some(MarginMode::Isolated),
+        _ => None,
+    };
+    Ok(Leverage {
+        instrument: leverage.inst_id,
+        leverage: leverage.lever.normalize(),
+        margin_mode,
+    })
+}
+
+impl Adaptor<SetLeverage> for HttpRequest {
+    fn from_request(req: SetLeverage) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::PrivatePost(PrivatePost::SetLeverage(
+            account::SetLeverage {
+                inst_id: req.instrument,
+                lever: Str::new(req.leverage.normalize().to_string()),
+                mgn_mode: mgn_mode(req.margin_mode)?,
+            },
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SetLeverage as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move { leverage(resp.data) }.boxed())
+    }
+}
+
+impl Adaptor<GetLeverage> for HttpRequest {
+    fn from_request(req: GetLeverage) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::PrivateGet(PrivateGet::LeverageInfo(LeverageInfo {
+            inst_id: req.instrument,
+            mgn_mode: mgn_mode(req.margin_mode)?,
+        })))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<GetLeverage as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move { leverage(resp.data) }.boxed())
+    }
+}
+
+impl Adaptor<SetPositionMode> for HttpRequest {
+    fn from_request(req: SetPositionMode) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let pos_mode = match req.mode {
+            PositionMode::OneWay => "net_mode",
+            PositionMode::Hedge => "long_short_mode",
+        };
+        Ok(Self::PrivatePost(PrivatePost::SetPositionMode(
+            account::SetPositionMode {
+                pos_mode: Str::new_inline(pos_mode),
+            },
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SetPositionMode as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            match resp.data.into_iter().next() {
+                Some(ResponseData::PositionMode(_)) => Ok(()),
+                Some(data) => Err(ExchangeError::unexpected_response_type(format!("{data:?}"))),
+                None => Err(ExchangeError::unexpected_response_type("empty response")),
+            }
+        }
+        .boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::types::response::HttpResponse;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_positions() -> anyhow::Result<()> {
+        let data = r#"[{"adl":"1","availPos":"1","avgPx":"2566.31","cTime":"1619507758793","ccy":"ETH","deltaBS":"","deltaPA":"","gammaBS":"","gammaPA":"","imr":"","instId":"ETH-USD-210430","instType":"FUTURES","interest":"0","idxPx":"2566.13","last":"2566.22","usdPx":"","bePx":"2353.949","lever":"10","liab":"","liabCcy":"","liqPx":"2352.8496681818233","markPx":"2353.849","margin":"0.0003896645377994","mgnMode":"isolated","mgnRatio":"11.731726509588816","mmr":"0.0000311811092368","notionalUsd":"2276.2546609009605","optVal":"","pTime":"1619507761462","pendingCloseOrdLiabVal":"0.1","pos":"1","baseBorrowed":"","baseInterest":"","quoteBorrowed":"","quoteInterest":"","posCcy":"","posId":"307173036051017730","posSide":"long","spotInUseAmt":"","spotInUseCcy":"","bizRefId":"","bizRefType":"","thetaBS":"","thetaPA":"","tradeId":"109844","uTime":"1619507761462","upl":"-0.0000009932766034","uplLastPx":"-0.0000009932766034","uplRatio":"-0.0025490556801078","uplRatioLastPx":"-0.0025490556801078","vegaBS":"","vegaPA":"","realizedPnl":"0.001","pnl":"0.0011","fee":"-0.0001","fundingFee":"0","liqPenalty":"0","closeOrderAlgo":[]},{"adl":"2","availPos":"","avgPx":"43000.5","cTime":"1705574510106","ccy":"USDT","instId":"BTC-USDT-SWAP","instType":"SWAP","lever":"5","liqPx":"","markPx":"42990.1","mgnMode":"cross","pos":"-2","posCcy":"","posId":"681096944655273984","posSide":"net","uTime":"1705574510106","upl":"0.208","closeOrderAlgo":[]}]"#;
+        let data = serde_json::from_str::<Vec<ResponseData>>(data)?;
+        let positions = futures::executor::block_on(
+            <HttpRequest as Adaptor<FetchPositions>>::into_response(HttpResponse { data })?,
+        )?;
+        assert_eq!(positions.len(), 2);
+        let eth = &positions[0];
+        assert_eq!(eth.ts.unix_timestamp(), 1619507761);
+        assert_eq!(eth.instrument, "ETH-USD-210430");
+        assert_eq!(eth.size, Decimal::ONE);
+        assert_eq!(eth.entry_price, Decimal::new(256631, 2));
+        assert_eq!(eth.mark_price, Some(Decimal::new(2353849, 3)));
+        assert_eq!(eth.leverage, Some(Decimal::new(10, 0)));
+        assert_eq!(eth.margin_mode, MarginMode::Isolated);
+        let btc = &positions[1];
+        assert_eq!(btc.size, Decimal::new(-2, 0));
+        assert_eq!(btc.unrealized_pnl, Decimal::new(208, 3));
+        assert_eq!(btc.margin_mode, MarginMode::Cross);
+        Ok(())
+    }
+}
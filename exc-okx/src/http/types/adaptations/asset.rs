@@ -0,0 +1,235 @@
+use std::ops::RangeBounds;
+
+use exc_core::{
+    types::{
+        FundsRecord, QueryDeposits, QueryTransfer, QueryWithdrawals, Transfer, TransferState,
+        TransferStatus, Wallet, WalletKind,
+    },
+    Adaptor, Asset, ExchangeError, Str,
+};
+use futures::FutureExt;
+
+use crate::{
+    http::types::{
+        request::{asset, HttpRequest, PrivateGet, PrivatePost},
+        response::ResponseData,
+    },
+    utils::timestamp::{end_bound_to_millis, start_bound_to_millis},
+};
+
+/// Max number of deposit or withdrawal records per request.
+const MAX_LIMIT: usize = 100;
+
+/// The account type of the wallet. The trading account of the unified account
+/// serves as the spot, margin and futures wallets.
+fn account(kind: WalletKind) -> Str {
+    match kind {
+        WalletKind::Funding => Str::new_inline("6"),
+        WalletKind::Spot | WalletKind::UsdMarginFutures | WalletKind::Margin => {
+            Str::new_inline("18")
+        }
+    }
+}
+
+/// The transfer type and the sub-account involved.
+fn transfer_type(from: &Wallet, to: &Wallet) -> Result<(Str, Option<Str>), ExchangeError> {
+    match (&from.sub_account, &to.sub_account) {
+        (None, None) => Ok((Str::new_inline("0"), None)),
+        (None, Some(sub)) => Ok((Str::new_inline("1"), Some(sub.clone()))),
+        (Some(sub), None) => Ok((Str::new_inline("2"), Some(sub.clone()))),
+        (Some(_), Some(_)) => Err(ExchangeError::Other(anyhow::anyhow!(
+            "transfers between sub-accounts are not supported by okx"
+        ))),
+    }
+}
+
+fn parse_asset(ccy: &str) -> Result<Asset, ExchangeError> {
+    ccy.parse::<Asset>()
+        .map_err(|err| ExchangeError::unexpected_response_type(format!("invalid asset: {err}")))
+}
+
+fn tx_id(tx_id: Str) -> Option<Str> {
+    (!tx_id.is_empty()).then_some(tx_id)
+}
+
+impl Adaptor<Transfer> for HttpRequest {
+    fn from_request(req: Transfer) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let (kind, sub_acct) = transfer_type(&req.from, &req.to)?;
+        Ok(Self::PrivatePost(PrivatePost::Transfer(asset::Transfer {
+            ccy: Str::new(req.asset.as_ref()),
+            amt: Str::new(req.amount.normalize().to_string()),
+            from: account(req.from.kind),
+            to: account(req.to.kind),
+            sub_acct,
+            kind,
+        })))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<Transfer as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            match resp.data.into_iter().next() {
+                Some(ResponseData::TransferResult(result)) => Ok(result.trans_id),
+                Some(data) => Err(ExchangeError::unexpected_response_type(format!("{data:?}"))),
+                None => Err(ExchangeError::unexpected_response_type("empty response")),
+            }
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<QueryTransfer> for HttpRequest {
+    fn from_request(req: QueryTransfer) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let (kind, _) = transfer_type(&req.from, &req.to)?;
+        Ok(Self::PrivateGet(PrivateGet::TransferState(
+            asset::TransferState {
+                trans_id: req.id,
+                kind,
+            },
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<QueryTransfer as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            match resp.data.into_iter().next() {
+                Some(ResponseData::TransferState(state)) => {
+                    let status = match state.state.as_str() {
+                        "success" => TransferStatus::Success,
+                        "pending" => TransferStatus::Pending,
+                        "failed" => TransferStatus::Failed,
+                        other => {
+                            return Err(ExchangeError::unexpected_response_type(format!(
+                                "unknown transfer state: {other}"
+                            )))
+                        }
+                    };
+                    Ok(TransferState {
+                        id: state.trans_id,
+                        asset: state.ccy.parse::<Asset>().map_err(|err| {
+                            ExchangeError::unexpected_response_type(format!("invalid asset: {err}"))
+                        })?,
+                        amount: state.amt.normalize(),
+                        status,
+                    })
+                }
+                Some(data) => Err(ExchangeError::unexpected_response_type(format!("{data:?}"))),
+                None => Err(ExchangeError::unexpected_response_type("empty response")),
+            }
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<QueryDeposits> for HttpRequest {
+    fn from_request(req: QueryDeposits) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::PrivateGet(PrivateGet::DepositHistory(
+            asset::DepositHistory {
+                ccy: req
+                    .query
+                    .asset
+                    .as_ref()
+                    .map(|asset| Str::new(asset.as_ref())),
+                after: end_bound_to_millis(req.query.end_bound()),
+                before: start_bound_to_millis(req.query.start_bound()),
+                limit: Some(MAX_LIMIT),
+            },
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<QueryDeposits as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.data
+                .into_iter()
+                .map(|data| match data {
+                    ResponseData::Deposit(record) => {
+                        let status = match record.state.as_str() {
+                            "1" | "2" => TransferStatus::Success,
+                            _ => TransferStatus::Pending,
+                        };
+                        Ok(FundsRecord {
+                            id: record.dep_id,
+                            tx_id: tx_id(record.tx_id),
+                            asset: parse_asset(&record.ccy)?,
+                            network: record.chain,
+                            amount: record.amt.normalize(),
+                            fee: None,
+                            address: record.to,
+                            status,
+                            ts: record.ts,
+                            completed_at: None,
+                        })
+                    }
+                    data => Err(ExchangeError::unexpected_response_type(format!("{data:?}"))),
+                })
+                .collect()
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<QueryWithdrawals> for HttpRequest {
+    fn from_request(req: QueryWithdrawals) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::PrivateGet(PrivateGet::WithdrawalHistory(
+            asset::WithdrawalHistory {
+                ccy: req
+                    .query
+                    .asset
+                    .as_ref()
+                    .map(|asset| Str::new(asset.as_ref())),
+                after: end_bound_to_millis(req.query.end_bound()),
+                before: start_bound_to_millis(req.query.start_bound()),
+                limit: Some(MAX_LIMIT),
+            },
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<QueryWithdrawals as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            resp.data
+                .into_iter()
+                .map(|data| match data {
+                    ResponseData::Withdrawal(record) => {
+                        let status = match record.state.as_str() {
+                            "2" => TransferStatus::Success,
+                            "-3" | "-2" | "-1" => TransferStatus::Failed,
+                            _ => TransferStatus::Pending,
+                        };
+                        Ok(FundsRecord {
+                            id: record.wd_id,
+                            tx_id: tx_id(record.tx_id),
+                            asset: parse_asset(&record.ccy)?,
+                            network: record.chain,
+                            amount: record.amt.normalize(),
+                            fee: Some(record.fee.normalize()),
+                            address: record.to,
+                            status,
+                            ts: record.ts,
+                            completed_at: None,
+                        })
+                    }
+                    data => Err(ExchangeError::unexpected_response_type(format!("{data:?}"))),
+                })
+                .collect()
+        }
+        .boxed())
+    }
+}
@@ -0,0 +1,47 @@
+use exc_core::{
+    types::{FetchOrderBook, OrderBookUpdate, OrderBookUpdateKind},
+    Adaptor, ExchangeError,
+};
+use futures::FutureExt;
+
+use crate::http::types::{
+    request::{book::Books, Get, HttpRequest},
+    response::ResponseData,
+};
+
+/// Max depth of the order book snapshot.
+const MAX_DEPTH: usize = 400;
+
+impl Adaptor<FetchOrderBook> for HttpRequest {
+    fn from_request(req: FetchOrderBook) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::Get(Get::Books(Books {
+            inst_id: req.instrument,
+            sz: Some(req.depth.min(MAX_DEPTH)),
+        })))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchOrderBook as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            match resp.data.into_iter().next() {
+                Some(ResponseData::Book(book)) => Ok(OrderBookUpdate {
+                    ts: book.ts,
+                    kind: OrderBookUpdateKind::Snapshot,
+                    first_id: None,
+                    last_id: None,
+                    prev_id: None,
+                    checksum: None,
+                    bids: book.bids.into_iter().map(|l| (l[0], l[1])).collect(),
+                    asks: book.asks.into_iter().map(|l| (l[0], l[1])).collect(),
+                }),
+                Some(data) => Err(ExchangeError::unexpected_response_type(format!("{data:?}"))),
+                None => Err(ExchangeError::unexpected_response_type("empty response")),
+            }
+        }
+        .boxed())
+    }
+}
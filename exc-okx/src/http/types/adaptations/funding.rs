@@ -0,0 +1,100 @@
+use std::ops::RangeBounds;
+
+use exc_core::{
+    types::{
+        CurrentFundingRate, FetchFundingRate, FundingRate as ExcFundingRate, QueryFirstFundingRates,
+    },
+    Adaptor, ExchangeError,
+};
+use futures::{FutureExt, StreamExt};
+use time::Duration;
+
+use crate::{
+    http::types::{
+        request::{
+            funding::{FundingRate, FundingRateHistory},
+            Get, HttpRequest,
+        },
+        response::ResponseData,
+    },
+    utils::timestamp::{end_bound_to_millis, start_bound_to_millis},
+};
+
+/// Max number of funding rates per request.
+const MAX_LIMIT: usize = 100;
+
+/// The shortest funding interval.
+const MIN_FUNDING_INTERVAL: Duration = Duration::HOUR;
+
+impl Adaptor<QueryFirstFundingRates> for HttpRequest {
+    fn from_request(req: QueryFirstFundingRates) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let limit = req.first().min(MAX_LIMIT);
+        let query = req.query();
+        // Okx returns the latest funding rates of the range, so we narrow the range
+        // to a window holding at most `limit` funding rates to get the first ones.
+        let start = start_bound_to_millis(query.start_bound());
+        let end = end_bound_to_millis(query.end_bound());
+        let window = start.map(|start| {
+            start.saturating_add(
+                (MIN_FUNDING_INTERVAL.whole_milliseconds() as u64) * (limit as u64) + 1,
+            )
+        });
+        let after = match (window, end) {
+            (Some(window), Some(end)) => Some(window.min(end)),
+            (window, end) => window.or(end),
+        };
+        Ok(Self::Get(Get::FundingRateHistory(FundingRateHistory {
+            inst_id: query.instrument.clone(),
+            after,
+            before: start,
+            limit: Some(limit),
+        })))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<QueryFirstFundingRates as exc_core::Request>::Response, ExchangeError> {
+        let mut rates = Vec::with_capacity(resp.data.len());
+        for data in resp.data {
+            match data {
+                ResponseData::FundingRateHistory(rate) => {
+                    rates.push(Ok::<_, ExchangeError>(ExcFundingRate {
+                        ts: rate.funding_time,
+                        rate: rate.realized_rate.normalize(),
+                    }))
+                }
+                data => return Err(ExchangeError::unexpected_response_type(format!("{data:?}"))),
+            }
+        }
+        // Okx returns the funding rates newest first.
+        rates.reverse();
+        Ok(futures::stream::iter(rates).boxed())
+    }
+}
+
+impl Adaptor<FetchFundingRate> for HttpRequest {
+    fn from_request(req: FetchFundingRate) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::Get(Get::FundingRate(FundingRate {
+            inst_id: req.instrument,
+        })))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchFundingRate as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            match resp.data.into_iter().next() {
+                Some(ResponseData::FundingRate(rate)) => Ok(CurrentFundingRate::from(*rate)),
+                Some(data) => Err(ExchangeError::unexpected_response_type(format!("{data:?}"))),
+                None => Err(ExchangeError::unexpected_response_type("empty response")),
+            }
+        }
+        .boxed())
+    }
+}
@@ -1,12 +1,30 @@
 use exc_core::Request;
 
-use super::request::HttpRequest;
+use super::request::{HttpRequest, PrivatePost};
 use super::response::HttpResponse;
 
 impl Request for HttpRequest {
     type Response = HttpResponse;
+
+    fn is_idempotent(&self) -> bool {
+        match self {
+            Self::PrivatePost(PrivatePost::OrderAlgo(order)) => order.algo_cl_ord_id.is_some(),
+            Self::PrivatePost(
+                PrivatePost::Transfer(_)
+                | PrivatePost::EarnPurchase(_)
+                | PrivatePost::EarnRedeem(_),
+            ) => false,
+            _ => true,
+        }
+    }
 }
 
+mod account;
+mod asset;
+mod book;
 mod candle;
+mod funding;
 mod instruments;
+mod trade;
 mod trading;
+mod utils;
@@ -0,0 +1,63 @@
+use std::ops::RangeBounds;
+
+use exc_core::{
+    types::{QueryLastTrades, Trade, TradeHistoryStream, TradeRecord},
+    Adaptor, ExchangeError,
+};
+
+use crate::{
+    http::types::{
+        request::{trade::HistoryTrades, Get, HttpRequest},
+        response::ResponseData,
+    },
+    utils::timestamp::end_bound_to_millis,
+};
+
+/// Max number of trades per request.
+const MAX_LIMIT: usize = 100;
+
+impl Adaptor<QueryLastTrades> for HttpRequest {
+    fn from_request(req: QueryLastTrades) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let limit = req.last().min(MAX_LIMIT);
+        let (pagination, after) = match req.before_id.as_ref() {
+            Some(id) => (1, Some(id.to_string())),
+            None => (
+                2,
+                end_bound_to_millis(req.query().end_bound()).map(|ts| ts.to_string()),
+            ),
+        };
+        Ok(Self::Get(Get::HistoryTrades(HistoryTrades {
+            inst_id: req.query.instrument.clone(),
+            pagination: Some(pagination),
+            after,
+            limit: Some(limit),
+        })))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<TradeHistoryStream, ExchangeError> {
+        let mut trades = Vec::with_capacity(resp.data.len());
+        for data in resp.data {
+            match data {
+                ResponseData::HistoryTrade(trade) => {
+                    trades.push(Ok::<_, ExchangeError>(TradeRecord {
+                        id: trade.trade_id.clone(),
+                        trade: Trade {
+                            ts: trade.ts,
+                            price: trade.px.normalize(),
+                            size: trade.sz.normalize(),
+                            buy: trade.is_buy(),
+                        },
+                    }))
+                }
+                data => return Err(ExchangeError::unexpected_response_type(format!("{data:?}"))),
+            }
+        }
+        // Okx returns the trades newest first.
+        Ok(TradeHistoryStream::new_backward(futures::stream::iter(
+            trades,
+        )))
+    }
+}
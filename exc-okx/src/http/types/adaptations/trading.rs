@@ -1,31 +1,237 @@
 use exc_core::{
     types::{
-        trading::{GetOrder, Order as ExcOrder, OrderId, OrderState, OrderStatus, Place},
-        OrderUpdate, TimeInForce,
+        trading::{
+            CancelOrder, Canceled, GetOrder, Order as ExcOrder, OrderId, OrderKind, OrderState,
+            OrderStatus, Place, PlaceOrder, Placed,
+        },
+        CancelAllAfter, CancelAllOrders, Fill, FillStream, ListOpenOrders, OrderUpdate, Page,
+        Paged, QueryLastFills, QueryOrderHistory, TimeInForce, Trigger, TriggerSource,
     },
-    Adaptor, ExchangeError,
+    Adaptor, ExchangeError, Str,
 };
-use futures::FutureExt;
+use futures::{FutureExt, StreamExt};
 use rust_decimal::{prelude::ToPrimitive, Decimal};
+use std::ops::RangeBounds;
 use time::OffsetDateTime;
 
 use crate::{
     http::types::{
-        request::{trading::Order, HttpRequest, PrivateGet},
-        response::ResponseData,
+        request::{
+            trading::{
+                self, CancelAlgo, FillsHistory, MassCancel, Order, OrderAlgo, OrderAlgoDetails,
+                OrdersHistory, OrdersPending,
+            },
+            HttpRequest, PrivateGet, PrivatePost,
+        },
+        response::{AlgoOrderDetail, FillDetail, OrderDetail, ResponseData},
+    },
+    utils::{
+        algo::{algo_id, algo_order_id},
+        place::PositionArgs,
+        timestamp::{end_bound_to_millis, millis_to_ts, start_bound_to_millis},
     },
-    utils::timestamp::millis_to_ts,
 };
 
 fn decimal_to_ts(ts: Decimal) -> Option<OffsetDateTime> {
     millis_to_ts(ts.to_u64()?)
 }
 
+fn order_update(order: OrderDetail) -> Result<OrderUpdate, ExchangeError> {
+    let (target, buy) = match order.side.as_str() {
+        "buy" => (Place::with_size(order.size), true),
+        "sell" => (Place::with_size(-order.size), false),
+        side => {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "unexpected order side: {side}"
+            )));
+        }
+    };
+    let target = match order.order_type.as_str() {
+        "market" => target,
+        "limit" => {
+            if let Some(price) = order.price {
+                target.limit(price)
+            } else {
+                return Err(ExchangeError::Other(anyhow::anyhow!("limit without price")));
+            }
+        }
+        "fok" => {
+            if let Some(price) = order.price {
+                target.limit_with_tif(price, TimeInForce::FillOrKill)
+            } else {
+                return Err(ExchangeError::Other(anyhow::anyhow!("fok without price")));
+            }
+        }
+        "ioc" => {
+            if let Some(price) = order.price {
+                target.limit_with_tif(price, TimeInForce::ImmediateOrCancel)
+            } else {
+                return Err(ExchangeError::Other(anyhow::anyhow!("ioc without price")));
+            }
+        }
+        "post_only" => {
+            if let Some(price) = order.price {
+                target.post_only(price)
+            } else {
+                return Err(ExchangeError::Other(anyhow::anyhow!(
+                    "post_only without price"
+                )));
+            }
+        }
+        t => {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "unsupported order type: {t}"
+            )));
+        }
+    };
+    let mut state = OrderState::default();
+    let status = match order.state.as_str() {
+        "live" | "partially_filled" => OrderStatus::Pending,
+        "canceled" | "filled" => OrderStatus::Finished,
+        s => {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "unknown order status: {s}"
+            )))
+        }
+    };
+    let mut filled = order.filled_size;
+    filled.set_sign_positive(buy);
+    let cost = order.avg_price.unwrap_or(Decimal::ONE);
+    if let Some((ccy, fee)) = order
+        .fee
+        .and_then(|fee| order.fee_currency.map(|ccy| (ccy, fee)))
+    {
+        let f = state.fees.entry(ccy).or_default();
+        *f += fee;
+    }
+    if let Some((ccy, fee)) = order
+        .rebate
+        .and_then(|fee| order.rebate_currency.map(|ccy| (ccy, fee)))
+    {
+        let f = state.fees.entry(ccy).or_default();
+        *f += fee;
+    }
+    state.status = status;
+    state.filled = filled;
+    state.cost = cost;
+    #[cfg(not(feature = "prefer-client-id"))]
+    let id = OrderId::from(order.order_id);
+    #[cfg(feature = "prefer-client-id")]
+    let id = if let Some(id) = order.client_id {
+        OrderId::from(id)
+    } else {
+        return Err(crate::error::OkxError::MissingClientId.into());
+    };
+    Ok(OrderUpdate {
+        ts: decimal_to_ts(order.updated_at).ok_or_else(|| {
+            ExchangeError::Other(anyhow::anyhow!("parse ts error, ts={}", order.updated_at))
+        })?,
+        order: ExcOrder {
+            id,
+            target,
+            state,
+            trade: None,
+        },
+    })
+}
+
+fn trigger_source(px_type: Option<&str>) -> Result<TriggerSource, ExchangeError> {
+    match px_type.unwrap_or("last") {
+        "last" => Ok(TriggerSource::Last),
+        "mark" => Ok(TriggerSource::Mark),
+        "index" => Ok(TriggerSource::Index),
+        t => Err(ExchangeError::Other(anyhow::anyhow!(
+            "unknown trigger price type: {t}"
+        ))),
+    }
+}
+
+/// The limit price of the order placed once triggered (`-1` for market).
+fn algo_limit_price(px: Option<Decimal>) -> Option<Decimal> {
+    px.filter(|px| *px != Decimal::NEGATIVE_ONE)
+}
+
+fn algo_order_update(order: AlgoOrderDetail) -> Result<OrderUpdate, ExchangeError> {
+    let target = match order.side.as_str() {
+        "buy" => Place::with_size(order.sz),
+        "sell" => Place::with_size(-order.sz),
+        side => {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "unexpected order side: {side}"
+            )));
+        }
+    };
+    let target = match (
+        order.ord_type.as_str(),
+        order.sl_trigger_px,
+        order.tp_trigger_px,
+    ) {
+        ("conditional", Some(px), None) => {
+            let trigger =
+                Trigger::new(px).with_source(trigger_source(order.sl_trigger_px_type.as_deref())?);
+            match algo_limit_price(order.sl_ord_px) {
+                Some(price) => target.stop_limit(trigger, price),
+                None => target.stop_market(trigger),
+            }
+        }
+        ("conditional", None, Some(px)) => {
+            let trigger =
+                Trigger::new(px).with_source(trigger_source(order.tp_trigger_px_type.as_deref())?);
+            match algo_limit_price(order.tp_ord_px) {
+                Some(price) => target.take_profit_limit(trigger, price),
+                None => target.take_profit_market(trigger),
+            }
+        }
+        ("move_order_stop", _, _) => {
+            let rate = order.callback_ratio.ok_or_else(|| {
+                ExchangeError::Other(anyhow::anyhow!("trailing stop without callback ratio"))
+            })?;
+            target.trailing_stop(rate, order.active_px)
+        }
+        (t, _, _) => {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "unsupported algo order type: {t}"
+            )));
+        }
+    };
+    let status = match order.state.as_str() {
+        "live" | "pause" | "partially_effective" => OrderStatus::Pending,
+        "effective" | "canceled" | "order_failed" | "partially_failed" => OrderStatus::Finished,
+        s => {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "unknown algo order state: {s}"
+            )))
+        }
+    };
+    let ts = order.u_time.unwrap_or(order.c_time);
+    Ok(OrderUpdate {
+        ts: decimal_to_ts(ts)
+            .ok_or_else(|| ExchangeError::Other(anyhow::anyhow!("parse ts error, ts={ts}")))?,
+        order: ExcOrder {
+            id: algo_order_id(&order.algo_id),
+            target,
+            state: OrderState {
+                status,
+                ..Default::default()
+            },
+            trade: None,
+        },
+    })
+}
+
+/// The algo orders are queried with their algo ids.
 impl Adaptor<GetOrder> for HttpRequest {
     fn from_request(req: GetOrder) -> Result<Self, exc_core::ExchangeError>
     where
         Self: Sized,
     {
+        if let Some(algo_id) = algo_id(&req.id) {
+            return Ok(HttpRequest::PrivateGet(PrivateGet::OrderAlgo(
+                OrderAlgoDetails {
+                    algo_id: Str::new(algo_id),
+                },
+            )));
+        }
         #[cfg(not(feature = "prefer-client-id"))]
         {
             Ok(HttpRequest::PrivateGet(PrivateGet::Order(Order {
@@ -48,123 +254,638 @@ impl Adaptor<GetOrder> for HttpRequest {
         mut resp: Self::Response,
     ) -> Result<<GetOrder as exc_core::Request>::Response, exc_core::ExchangeError> {
         Ok(async move {
-            if let Some(data) = resp.data.pop() {
-                if let ResponseData::Order(order) = data {
-                    let order = *order;
-                    let (target, buy) = match order.side.as_str() {
-                        "buy" => (Place::with_size(order.size), true),
-                        "sell" => (Place::with_size(-order.size), false),
-                        side => {
-                            return Err(ExchangeError::Other(anyhow::anyhow!(
-                                "unexpected order side: {side}"
-                            )));
-                        }
-                    };
-                    let target = match order.order_type.as_str() {
-                        "market" => target,
-                        "limit" => {
-                            if let Some(price) = order.price {
-                                target.limit(price)
-                            } else {
-                                return Err(ExchangeError::Other(anyhow::anyhow!(
-                                    "limit without price"
-                                )));
-                            }
-                        }
-                        "fok" => {
-                            if let Some(price) = order.price {
-                                target.limit_with_tif(price, TimeInForce::FillOrKill)
-                            } else {
-                                return Err(ExchangeError::Other(anyhow::anyhow!(
-                                    "fok without price"
-                                )));
-                            }
-                        }
-                        "ioc" => {
-                            if let Some(price) = order.price {
-                                target.limit_with_tif(price, TimeInForce::ImmediateOrCancel)
-                            } else {
-                                return Err(ExchangeError::Other(anyhow::anyhow!(
-                                    "ioc without price"
-                                )));
-                            }
-                        }
-                        "post_only" => {
-                            if let Some(price) = order.price {
-                                target.post_only(price)
-                            } else {
-                                return Err(ExchangeError::Other(anyhow::anyhow!(
-                                    "post_only without price"
-                                )));
-                            }
-                        }
-                        t => {
-                            return Err(ExchangeError::Other(anyhow::anyhow!(
-                                "unsupported order type: {t}"
-                            )));
-                        }
-                    };
-                    let mut state = OrderState::default();
-                    let status = match order.state.as_str() {
-                        "live" | "partially_filled" => OrderStatus::Pending,
-                        "canceled" | "filled" => OrderStatus::Finished,
-                        s => {
-                            return Err(ExchangeError::Other(anyhow::anyhow!(
-                                "unknown order status: {s}"
-                            )))
-                        }
-                    };
-                    let mut filled = order.filled_size;
-                    filled.set_sign_positive(buy);
-                    let cost = order.avg_price.unwrap_or(Decimal::ONE);
-                    if let Some((ccy, fee)) = order
-                        .fee
-                        .and_then(|fee| order.fee_currency.map(|ccy| (ccy, fee)))
-                    {
-                        let f = state.fees.entry(ccy).or_default();
-                        *f += fee;
-                    }
-                    if let Some((ccy, fee)) = order
-                        .rebate
-                        .and_then(|fee| order.rebate_currency.map(|ccy| (ccy, fee)))
-                    {
-                        let f = state.fees.entry(ccy).or_default();
-                        *f += fee;
-                    }
-                    state.status = status;
-                    state.filled = filled;
-                    state.cost = cost;
-                    #[cfg(not(feature = "prefer-client-id"))]
-                    let id = OrderId::from(order.order_id);
-                    #[cfg(feature = "prefer-client-id")]
-                    let id = if let Some(id) = order.client_id {
-                        OrderId::from(id)
-                    } else {
-                        return Err(crate::error::OkxError::MissingClientId.into());
-                    };
-                    Ok(OrderUpdate {
-                        ts: decimal_to_ts(order.updated_at).ok_or_else(|| {
-                            ExchangeError::Other(anyhow::anyhow!(
-                                "parse ts error, ts={}",
-                                order.updated_at
-                            ))
-                        })?,
-                        order: ExcOrder {
-                            id,
-                            target,
-                            state,
-                            trade: None,
-                        },
-                    })
-                } else {
-                    Err(ExchangeError::Api(anyhow::anyhow!(
-                        "unexpected response type"
-                    )))
-                }
+            match resp.data.pop() {
+                Some(ResponseData::Order(order)) => order_update(*order),
+                Some(ResponseData::AlgoOrderDetail(order)) => algo_order_update(*order),
+                Some(_) => Err(ExchangeError::unexpected_response_type(
+                    "unexpected response data",
+                )),
+                None => Err(ExchangeError::unexpected_response_type("empty response")),
+            }
+        }
+        .boxed())
+    }
+}
+
+/// Only the algo orders are canceled through HTTP.
+impl Adaptor<CancelOrder> for HttpRequest {
+    fn from_request(req: CancelOrder) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let algo_id = algo_id(&req.id).ok_or_else(|| {
+            ExchangeError::Other(anyhow::anyhow!(
+                "`{}` is not an algo order id",
+                req.id.as_str()
+            ))
+        })?;
+        Ok(HttpRequest::PrivatePost(PrivatePost::CancelAlgos(vec![
+            CancelAlgo {
+                algo_id: Str::new(algo_id),
+                inst_id: req.instrument,
+            },
+        ])))
+    }
+
+    fn into_response(
+        mut resp: Self::Response,
+    ) -> Result<<CancelOrder as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            match resp.data.pop() {
+                Some(ResponseData::AlgoOrder(res)) if res.s_code == "0" => Ok(Canceled {
+                    ts: OffsetDateTime::now_utc(),
+                    order: None,
+                }),
+                Some(ResponseData::AlgoOrder(res)) => Err(ExchangeError::ApiError(
+                    crate::error::api_error(&res.s_code, &res.s_msg),
+                )),
+                Some(_) => Err(ExchangeError::unexpected_response_type(
+                    "unexpected response data",
+                )),
+                None => Err(ExchangeError::unexpected_response_type("empty response")),
+            }
+        }
+        .boxed())
+    }
+}
+
+/// Get the option family of the instrument, e.g. `BTC-USD` for `BTC-USD-240628-50000-C`.
+fn option_family(inst: &str) -> Option<Str> {
+    let parts = inst.split('-').collect::<Vec<_>>();
+    match parts.as_slice() {
+        [base, quote] | [base, quote, _, _, "C" | "P"] => Some(Str::new(format!("{base}-{quote}"))),
+        _ => None,
+    }
+}
+
+impl Adaptor<CancelAllOrders> for HttpRequest {
+    fn from_request(req: CancelAllOrders) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let family = req
+            .instrument
+            .as_deref()
+            .and_then(option_family)
+            .ok_or_else(|| {
+                ExchangeError::Other(anyhow::anyhow!(
+                    "`CancelAllOrders` on okx requires an option instrument or family"
+                ))
+            })?;
+        Ok(HttpRequest::PrivatePost(PrivatePost::MassCancel(
+            MassCancel {
+                inst_type: Str::new_inline("OPTION"),
+                inst_family: family,
+            },
+        )))
+    }
+
+    fn into_response(
+        mut resp: Self::Response,
+    ) -> Result<<CancelAllOrders as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            match resp.data.pop() {
+                Some(ResponseData::MassCancel(res)) if res.result => Ok(()),
+                Some(ResponseData::MassCancel(_)) => Err(ExchangeError::ApiError(
+                    crate::error::api_error("", "mass cancel rejected"),
+                )),
+                Some(_) => Err(ExchangeError::unexpected_response_type(
+                    "unexpected response data",
+                )),
+                None => Err(ExchangeError::unexpected_response_type("empty response")),
+            }
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<CancelAllAfter> for HttpRequest {
+    fn from_request(req: CancelAllAfter) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        if req.instrument.is_some() {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "`CancelAllAfter` on okx applies to all instruments"
+            )));
+        }
+        let secs = req.timeout.as_secs();
+        if secs != 0 && !(10..=120).contains(&secs) {
+            return Err(ExchangeError::Other(anyhow::anyhow!(
+                "the timeout of `CancelAllAfter` on okx must be 0 or within [10, 120] seconds"
+            )));
+        }
+        Ok(HttpRequest::PrivatePost(PrivatePost::CancelAllAfter(
+            trading::CancelAllAfter {
+                time_out: secs.to_string(),
+            },
+        )))
+    }
+
+    fn into_response(
+        mut resp: Self::Response,
+    ) -> Result<<CancelAllAfter as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            match resp.data.pop() {
+                Some(ResponseData::CancelAllAfter(_)) => Ok(()),
+                Some(_) => Err(ExchangeError::unexpected_response_type(
+                    "unexpected response data",
+                )),
+                None => Err(ExchangeError::unexpected_response_type("empty response")),
+            }
+        }
+        .boxed())
+    }
+}
+
+/// Max number of orders per page of `orders-pending` and `orders-history`.
+const ORDERS_PAGE_LIMIT: usize = 100;
+
+/// Get the instrument type of the instrument, e.g. `SWAP` for `BTC-USDT-SWAP`.
+pub(super) fn inst_type(inst: &str) -> Option<Str> {
+    let parts = inst.split('-').collect::<Vec<_>>();
+    let ty = match parts.as_slice() {
+        [_, _, "SWAP"] => "SWAP",
+        [_, _] => "SPOT",
+        [_, _, _] => "FUTURES",
+        [_, _, _, _, "C" | "P"] => "OPTION",
+        _ => return None,
+    };
+    Some(Str::new_inline(ty))
+}
+
+/// Collect the orders of a page, following the last order id when the page is full.
+fn orders_page(data: Vec<ResponseData>) -> Result<Page<OrderUpdate>, ExchangeError> {
+    let mut items = Vec::with_capacity(data.len());
+    let mut last = None;
+    for data in data {
+        let ResponseData::Order(order) = data else {
+            return Err(ExchangeError::unexpected_response_type(
+                "unexpected response data",
+            ));
+        };
+        last = Some(order.order_id.clone());
+        items.push(order_update(*order)?);
+    }
+    let next = if items.len() < ORDERS_PAGE_LIMIT {
+        None
+    } else {
+        last
+    };
+    Ok(Page { items, next })
+}
+
+impl Adaptor<Paged<ListOpenOrders>> for HttpRequest {
+    fn from_request(req: Paged<ListOpenOrders>) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(HttpRequest::PrivateGet(PrivateGet::OrdersPending(
+            OrdersPending {
+                inst_type: None,
+                inst_id: req.query.instrument,
+                after: req.cursor,
+                limit: Some(ORDERS_PAGE_LIMIT),
+            },
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<Paged<ListOpenOrders> as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move { orders_page(resp.data) }.boxed())
+    }
+}
+
+impl Adaptor<Paged<QueryOrderHistory>> for HttpRequest {
+    fn from_request(req: Paged<QueryOrderHistory>) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let query = &req.query;
+        let inst_type = inst_type(&query.instrument).ok_or_else(|| {
+            ExchangeError::Other(anyhow::anyhow!(
+                "unknown instrument type of `{}`",
+                query.instrument
+            ))
+        })?;
+        Ok(HttpRequest::PrivateGet(PrivateGet::OrdersHistory(
+            OrdersHistory {
+                inst_type,
+                inst_id: Some(query.instrument.clone()),
+                after: req.cursor.clone(),
+                begin: start_bound_to_millis(query.start_bound()),
+                end: end_bound_to_millis(query.end_bound()),
+                limit: Some(ORDERS_PAGE_LIMIT),
+            },
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<Paged<QueryOrderHistory> as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move { orders_page(resp.data) }.boxed())
+    }
+}
+
+impl TryFrom<FillDetail> for Fill {
+    type Error = ExchangeError;
+
+    fn try_from(fill: FillDetail) -> Result<Self, Self::Error> {
+        let size = match fill.side.as_str() {
+            "buy" => fill.fill_sz,
+            "sell" => -fill.fill_sz,
+            side => {
+                return Err(ExchangeError::Other(anyhow::anyhow!(
+                    "unexpected fill side: {side}"
+                )));
+            }
+        };
+        Ok(Fill {
+            instrument: fill.inst_id,
+            trade_id: fill.trade_id,
+            order_id: fill.ord_id,
+            price: fill.fill_px,
+            size,
+            fee: fill.fee,
+            fee_asset: fill.fee_ccy,
+            maker: fill.exec_type == "M",
+            ts: decimal_to_ts(fill.ts)
+                .ok_or_else(|| ExchangeError::Other(anyhow::anyhow!("cannot parse ts")))?,
+        })
+    }
+}
+
+impl Adaptor<QueryLastFills> for HttpRequest {
+    fn from_request(req: QueryLastFills) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let query = req.query();
+        let inst_type = inst_type(&query.instrument).ok_or_else(|| {
+            ExchangeError::Other(anyhow::anyhow!(
+                "unknown instrument type of `{}`",
+                query.instrument
+            ))
+        })?;
+        Ok(HttpRequest::PrivateGet(PrivateGet::FillsHistory(
+            FillsHistory {
+                inst_type,
+                inst_id: Some(query.instrument.clone()),
+                begin: start_bound_to_millis(query.start_bound()),
+                end: end_bound_to_millis(query.end_bound()),
+                limit: Some(req.last().min(ORDERS_PAGE_LIMIT)),
+            },
+        )))
+    }
+
+    fn into_response(resp: Self::Response) -> Result<FillStream, ExchangeError> {
+        // The fills are returned backward.
+        let fills = resp.data.into_iter().map(|data| match data {
+            ResponseData::Fill(fill) => Fill::try_from(*fill),
+            _ => Err(ExchangeError::unexpected_response_type(
+                "unexpected response data",
+            )),
+        });
+        Ok(futures::stream::iter(fills).boxed())
+    }
+}
+
+fn trigger_px_type(source: TriggerSource) -> Str {
+    match source {
+        TriggerSource::Last => Str::new_inline("last"),
+        TriggerSource::Mark => Str::new_inline("mark"),
+        TriggerSource::Index => Str::new_inline("index"),
+    }
+}
+
+/// The ids of the placed algo orders are the tagged algo ids (even with `prefer-client-id`),
+/// since the algo orders can only be canceled with their algo ids.
+impl Adaptor<PlaceOrder> for HttpRequest {
+    fn from_request(req: PlaceOrder) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        let place = req.place;
+        let opts = &req.opts;
+        let inst = opts.instrument();
+        let position = PositionArgs::new(opts)?;
+        let mut algo = OrderAlgo {
+            inst_id: Str::new(inst),
+            td_mode: position.td_mode,
+            ccy: opts.margin().map(Str::new),
+            side: Str::new_inline(if place.size.is_sign_negative() {
+                "sell"
             } else {
-                Err(ExchangeError::Api(anyhow::anyhow!("empty response")))
+                "buy"
+            }),
+            pos_side: position.pos_side,
+            reduce_only: position.reduce_only.then_some(true),
+            ord_type: Str::new_inline("conditional"),
+            sz: Str::new(place.size.abs().to_string()),
+            tgt_ccy: None,
+            algo_cl_ord_id: opts.client_id().map(Str::new),
+            tp_trigger_px: None,
+            tp_trigger_px_type: None,
+            tp_ord_px: None,
+            sl_trigger_px: None,
+            sl_trigger_px_type: None,
+            sl_ord_px: None,
+            callback_ratio: None,
+            active_px: None,
+        };
+        #[cfg(feature = "prefer-client-id")]
+        if algo.algo_cl_ord_id.is_none() {
+            algo.algo_cl_ord_id = Some(Str::new(uuid::Uuid::new_v4().simple().to_string()));
+        }
+        // `-1` means placing a market order once triggered.
+        let market = Str::new_inline("-1");
+        match place.kind {
+            OrderKind::StopMarket(trigger) | OrderKind::StopLimit(trigger, _) => {
+                algo.sl_trigger_px = Some(Str::new(trigger.price.to_string()));
+                algo.sl_trigger_px_type = Some(trigger_px_type(trigger.source));
+                algo.sl_ord_px = Some(match place.kind {
+                    OrderKind::StopLimit(_, price) => Str::new(price.to_string()),
+                    _ => market,
+                });
+            }
+            OrderKind::TakeProfitMarket(trigger) | OrderKind::TakeProfitLimit(trigger, _) => {
+                algo.tp_trigger_px = Some(Str::new(trigger.price.to_string()));
+                algo.tp_trigger_px_type = Some(trigger_px_type(trigger.source));
+                algo.tp_ord_px = Some(match place.kind {
+                    OrderKind::TakeProfitLimit(_, price) => Str::new(price.to_string()),
+                    _ => market,
+                });
+            }
+            OrderKind::TrailingStop(trailing) => {
+                if trailing.source != TriggerSource::Last {
+                    return Err(ExchangeError::Other(anyhow::anyhow!(
+                        "trailing stops on okx only track the last price"
+                    )));
+                }
+                algo.ord_type = Str::new_inline("move_order_stop");
+                algo.callback_ratio = Some(Str::new(trailing.callback_rate.to_string()));
+                algo.active_px = trailing.activation.map(|price| Str::new(price.to_string()));
+            }
+            kind => {
+                return Err(ExchangeError::Other(anyhow::anyhow!(
+                    "`{kind:?}` orders are not algo orders"
+                )));
+            }
+        }
+        if inst.split('-').count() == 2 && algo.ord_type == "conditional" {
+            // The size of spot orders is in the base currency.
+            algo.tgt_ccy = Some(Str::new_inline("base_ccy"));
+        }
+        Ok(HttpRequest::PrivatePost(PrivatePost::OrderAlgo(Box::new(
+            algo,
+        ))))
+    }
+
+    fn into_response(
+        mut resp: Self::Response,
+    ) -> Result<<PlaceOrder as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            match resp.data.pop() {
+                Some(ResponseData::AlgoOrder(res)) if res.s_code == "0" => Ok(Placed {
+                    id: algo_order_id(&res.algo_id),
+                    order: None,
+                    ts: OffsetDateTime::now_utc(),
+                }),
+                Some(ResponseData::AlgoOrder(res)) => Err(ExchangeError::ApiError(
+                    crate::error::api_error(&res.s_code, &res.s_msg),
+                )),
+                Some(_) => Err(ExchangeError::unexpected_response_type(
+                    "unexpected response data",
+                )),
+                None => Err(ExchangeError::unexpected_response_type("empty response")),
             }
         }
         .boxed())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::types::response::HttpResponse;
+    use exc_core::types::{PlaceOrderOptions, TrailingStop};
+    use serde_json::{json, Value};
+
+    fn order_algo(place: Place, inst: &str) -> Result<Value, ExchangeError> {
+        let opts = PlaceOrderOptions::new(inst);
+        let HttpRequest::PrivatePost(PrivatePost::OrderAlgo(algo)) =
+            HttpRequest::from_request(place.into_request(&opts))?
+        else {
+            panic!("expecting an algo order");
+        };
+        Ok(serde_json::to_value(&*algo).unwrap())
+    }
+
+    #[test]
+    fn test_order_algo_body() {
+        let size = Decimal::ONE;
+        let trigger = Trigger::new(Decimal::new(90, 0)).with_source(TriggerSource::Mark);
+        let body =
+            order_algo(Place::with_size(size).stop_market(trigger), "BTC-USDT-SWAP").unwrap();
+        assert_eq!(body["ordType"], "conditional");
+        assert_eq!(body["side"], "buy");
+        assert_eq!(body["slTriggerPx"], "90");
+        assert_eq!(body["slTriggerPxType"], "mark");
+        assert_eq!(body["slOrdPx"], "-1");
+        assert!(body.get("tpTriggerPx").is_none());
+        assert!(body.get("tgtCcy").is_none());
+
+        let body = order_algo(
+            Place::with_size(-size).stop_limit(trigger, Decimal::new(89, 0)),
+            "BTC-USDT-SWAP",
+        )
+        .unwrap();
+        assert_eq!(body["side"], "sell");
+        assert_eq!(body["sz"], "1");
+        assert_eq!(body["slOrdPx"], "89");
+
+        let trigger = Trigger::new(Decimal::new(110, 0)).with_source(TriggerSource::Index);
+        let body = order_algo(
+            Place::with_size(-size).take_profit_market(trigger),
+            "BTC-USDT",
+        )
+        .unwrap();
+        assert_eq!(body["tpTriggerPx"], "110");
+        assert_eq!(body["tpTriggerPxType"], "index");
+        assert_eq!(body["tpOrdPx"], "-1");
+        assert!(body.get("slTriggerPx").is_none());
+        // The size of spot orders is in the base currency.
+        assert_eq!(body["tgtCcy"], "base_ccy");
+
+        let body = order_algo(
+            Place::with_size(-size).take_profit_limit(trigger, Decimal::new(111, 0)),
+            "BTC-USDT-SWAP",
+        )
+        .unwrap();
+        assert_eq!(body["tpOrdPx"], "111");
+
+        let body = order_algo(
+            Place::with_size(-size).trailing_stop(Decimal::new(1, 2), Some(Decimal::new(100, 0))),
+            "BTC-USDT",
+        )
+        .unwrap();
+        assert_eq!(body["ordType"], "move_order_stop");
+        assert_eq!(body["callbackRatio"], "0.01");
+        assert_eq!(body["activePx"], "100");
+        assert!(body.get("tgtCcy").is_none());
+
+        let mut place = Place::with_size(-size);
+        place.kind = OrderKind::TrailingStop(TrailingStop {
+            callback_rate: Decimal::new(1, 2),
+            activation: None,
+            source: TriggerSource::Mark,
+        });
+        assert!(order_algo(place, "BTC-USDT-SWAP").is_err());
+        assert!(order_algo(
+            Place::with_size(size).limit(Decimal::new(100, 0)),
+            "BTC-USDT"
+        )
+        .is_err());
+    }
+
+    fn algo_order(detail: Value) -> OrderUpdate {
+        let ResponseData::AlgoOrderDetail(order) = serde_json::from_value(detail).unwrap() else {
+            panic!("expecting an algo order detail");
+        };
+        algo_order_update(*order).unwrap()
+    }
+
+    #[test]
+    fn test_algo_order_detail() {
+        let mut detail = json!({
+            "activePx": "",
+            "actualPx": "",
+            "actualSide": "",
+            "actualSz": "0",
+            "algoClOrdId": "",
+            "algoId": "681096944655273984",
+            "cTime": "1708658165774",
+            "callbackRatio": "",
+            "ccy": "",
+            "clOrdId": "",
+            "instId": "BTC-USDT-SWAP",
+            "instType": "SWAP",
+            "last": "51014.6",
+            "lever": "",
+            "ordId": "",
+            "ordPx": "",
+            "ordType": "conditional",
+            "posSide": "net",
+            "reduceOnly": "false",
+            "side": "buy",
+            "slOrdPx": "-1",
+            "slTriggerPx": "50000",
+            "slTriggerPxType": "mark",
+            "state": "live",
+            "sz": "1",
+            "tag": "",
+            "tdMode": "cross",
+            "tgtCcy": "",
+            "tpOrdPx": "",
+            "tpTriggerPx": "",
+            "tpTriggerPxType": "",
+            "triggerPx": "",
+            "triggerTime": "",
+            "uTime": "1708658165774"
+        });
+        let update = algo_order(detail.clone());
+        assert_eq!(update.order.id.as_str(), "algo:681096944655273984");
+        assert_eq!(update.order.state.status, OrderStatus::Pending);
+        assert_eq!(update.order.target.size, Decimal::ONE);
+        let trigger = Trigger::new(Decimal::new(50000, 0)).with_source(TriggerSource::Mark);
+        assert_eq!(update.order.target.kind, OrderKind::StopMarket(trigger));
+
+        detail["slOrdPx"] = json!("49900");
+        detail["state"] = json!("effective");
+        let update = algo_order(detail.clone());
+        assert_eq!(
+            update.order.target.kind,
+            OrderKind::StopLimit(trigger, Decimal::new(49900, 0))
+        );
+        assert_eq!(update.order.state.status, OrderStatus::Finished);
+
+        detail["side"] = json!("sell");
+        detail["slOrdPx"] = json!("");
+        detail["slTriggerPx"] = json!("");
+        detail["slTriggerPxType"] = json!("");
+        detail["tpTriggerPx"] = json!("52000");
+        detail["tpTriggerPxType"] = json!("last");
+        detail["tpOrdPx"] = json!("-1");
+        let update = algo_order(detail.clone());
+        let trigger = Trigger::new(Decimal::new(52000, 0));
+        assert_eq!(update.order.target.size, -Decimal::ONE);
+        assert_eq!(
+            update.order.target.kind,
+            OrderKind::TakeProfitMarket(trigger)
+        );
+
+        detail["tpOrdPx"] = json!("52100");
+        let update = algo_order(detail.clone());
+        assert_eq!(
+            update.order.target.kind,
+            OrderKind::TakeProfitLimit(trigger, Decimal::new(52100, 0))
+        );
+
+        detail["ordType"] = json!("move_order_stop");
+        detail["tpOrdPx"] = json!("");
+        detail["tpTriggerPx"] = json!("");
+        detail["callbackRatio"] = json!("0.01");
+        detail["activePx"] = json!("53000");
+        let update = algo_order(detail);
+        assert_eq!(
+            update.order.target.kind,
+            OrderKind::TrailingStop(TrailingStop {
+                callback_rate: Decimal::new(1, 2),
+                activation: Some(Decimal::new(53000, 0)),
+                source: TriggerSource::Last,
+            })
+        );
+    }
+
+    #[test]
+    fn test_algo_order_routing() {
+        let id = algo_order_id("681096944655273984");
+        let HttpRequest::PrivateGet(PrivateGet::OrderAlgo(get)) =
+            HttpRequest::from_request(GetOrder::new("BTC-USDT-SWAP", id.clone())).unwrap()
+        else {
+            panic!("expecting an algo order query");
+        };
+        assert_eq!(get.algo_id, "681096944655273984");
+
+        let cancel = HttpRequest::from_request(CancelOrder::new("BTC-USDT-SWAP", id)).unwrap();
+        let HttpRequest::PrivatePost(post @ PrivatePost::CancelAlgos(_)) = cancel else {
+            panic!("expecting an algo order cancellation");
+        };
+        assert_eq!(post.uri(), "/api/v5/trade/cancel-algos");
+        assert_eq!(
+            serde_json::to_value(&post).unwrap(),
+            json!([{"algoId": "681096944655273984", "instId": "BTC-USDT-SWAP"}])
+        );
+        let normal = CancelOrder::new("BTC-USDT-SWAP", OrderId::from("1".to_string()));
+        assert!(HttpRequest::from_request(normal).is_err());
+    }
+
+    #[test]
+    fn test_cancel_algo_response() {
+        let resp = |data: Value| {
+            let resp = serde_json::from_value::<Vec<ResponseData>>(data).unwrap();
+            futures::executor::block_on(
+                <HttpRequest as Adaptor<CancelOrder>>::into_response(HttpResponse { data: resp })
+                    .unwrap(),
+            )
+        };
+        assert!(resp(json!([{"algoId": "681096944655273984", "sCode": "0", "sMsg": ""}])).is_ok());
+        let err = resp(json!([{"algoId": "681096944655273984", "sCode": "51603", "sMsg": "Order does not exist"}]))
+            .unwrap_err();
+        assert_eq!(
+            err.api_error_kind(),
+            Some(exc_core::ApiErrorKind::OrderNotFound)
+        );
+    }
+}
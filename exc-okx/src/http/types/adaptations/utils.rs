@@ -0,0 +1,29 @@
+use exc_core::{types::utils::FetchServerTime, Adaptor, ExchangeError};
+use futures::FutureExt;
+
+use crate::http::types::{
+    request::{utils::ServerTime, Get, HttpRequest},
+    response::ResponseData,
+};
+
+impl Adaptor<FetchServerTime> for HttpRequest {
+    fn from_request(_req: FetchServerTime) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::Get(Get::ServerTime(ServerTime {})))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchServerTime as exc_core::Request>::Response, ExchangeError> {
+        Ok(async move {
+            match resp.data.into_iter().next() {
+                Some(ResponseData::ServerTime(time)) => Ok(time.ts),
+                Some(data) => Err(ExchangeError::unexpected_response_type(format!("{data:?}"))),
+                None => Err(ExchangeError::unexpected_response_type("empty response")),
+            }
+        }
+        .boxed())
+    }
+}
@@ -0,0 +1,70 @@
+use exc_core::Str;
+use serde::Serialize;
+
+/// Balance.
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Balance {
+    /// Currencies (separated by commas).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<Str>,
+}
+
+/// Positions.
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Positions {
+    /// Instrument type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_type: Option<Str>,
+    /// Instrument id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<Str>,
+}
+
+/// Trade fee rates.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFee {
+    /// Instrument type.
+    pub inst_type: Str,
+    /// Instrument id (only for `SPOT` and `MARGIN`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<Str>,
+    /// Instrument family (only for `FUTURES`, `SWAP` and `OPTION`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_family: Option<Str>,
+    /// The instrument to attach to the response, which is not included by Okx.
+    #[serde(skip)]
+    pub instrument: Str,
+}
+
+/// Set leverage.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SetLeverage {
+    /// Instrument id.
+    pub inst_id: Str,
+    /// Leverage.
+    pub lever: Str,
+    /// Margin mode (`cross` or `isolated`).
+    pub mgn_mode: Str,
+}
+
+/// Leverage info.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LeverageInfo {
+    /// Instrument id.
+    pub inst_id: Str,
+    /// Margin mode (`cross` or `isolated`).
+    pub mgn_mode: Str,
+}
+
+/// Set position mode.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SetPositionMode {
+    /// Position mode (`long_short_mode` or `net_mode`).
+    pub pos_mode: Str,
+}
@@ -0,0 +1,79 @@
+use exc_core::Str;
+use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+/// Funds transfer.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Transfer {
+    /// Currency.
+    pub ccy: Str,
+    /// Amount.
+    pub amt: Str,
+    /// The remitting account (`6` for funding and `18` for trading).
+    pub from: Str,
+    /// The beneficiary account (`6` for funding and `18` for trading).
+    pub to: Str,
+    /// Name of the sub-account (required for the transfers involving sub-accounts).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_acct: Option<Str>,
+    /// Transfer type (`0` within the account, `1` from master to sub-account,
+    /// `2` from sub-account to master).
+    #[serde(rename = "type")]
+    pub kind: Str,
+}
+
+/// Transfer state.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferState {
+    /// Transfer id.
+    pub trans_id: Str,
+    /// Transfer type.
+    #[serde(rename = "type")]
+    pub kind: Str,
+}
+
+/// Deposit history.
+#[serde_as]
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositHistory {
+    /// Currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<Str>,
+    /// After (older than the millis).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<u64>,
+    /// Before (newer than the millis).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<u64>,
+    /// Limit (max 100).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// Withdrawal history.
+#[serde_as]
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalHistory {
+    /// Currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<Str>,
+    /// After (older than the millis).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<u64>,
+    /// Before (newer than the millis).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<u64>,
+    /// Limit (max 100).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
@@ -0,0 +1,13 @@
+use exc_core::Str;
+use serde::Serialize;
+
+/// Order book.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Books {
+    /// Instrument id.
+    pub inst_id: Str,
+    /// Depth of each side (max 400).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sz: Option<usize>,
+}
@@ -0,0 +1,32 @@
+use exc_core::Str;
+use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+/// Current funding rate.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    /// Instrument id.
+    pub inst_id: Str,
+}
+
+/// Funding rate history.
+#[serde_as]
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateHistory {
+    /// Instrument id.
+    pub inst_id: Str,
+    /// After (older) (millis).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<u64>,
+    /// Before (newer) (millis).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<u64>,
+    /// Limit (max 100).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
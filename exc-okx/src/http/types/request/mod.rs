@@ -1,14 +1,23 @@
-use exc_core::ExchangeError;
+use exc_core::{util::clock_sync::Clock, ExchangeError};
 use http::Request;
 use hyper::Body;
 use serde::Serialize;
 
 use crate::key::OkxKey as Key;
 
+use self::account::{Balance, LeverageInfo, Positions, SetLeverage, SetPositionMode, TradeFee};
+use self::asset::{DepositHistory, Transfer, TransferState, WithdrawalHistory};
+use self::book::Books;
 use self::earn::{EarnActiveOrders, EarnOffers, EarnPurchase, EarnRedeem};
+use self::funding::{FundingRate, FundingRateHistory};
 use self::history_candles::HistoryCandles;
 use self::instruments::Instruments;
-use self::trading::Order;
+use self::trade::HistoryTrades;
+use self::trading::{
+    CancelAlgo, CancelAllAfter, FillsHistory, MassCancel, Order, OrderAlgo, OrderAlgoDetails,
+    OrdersHistory, OrdersPending,
+};
+use self::utils::ServerTime;
 
 /// History candles.
 pub mod history_candles;
@@ -22,6 +31,24 @@ pub mod trading;
 /// Earn.
 pub mod earn;
 
+/// Account.
+pub mod account;
+
+/// Book.
+pub mod book;
+
+/// Funding.
+pub mod funding;
+
+/// Trade.
+pub mod trade;
+
+/// Utils.
+pub mod utils;
+
+/// Asset (funds transfer).
+pub mod asset;
+
 /// Okx HTTP API request types.
 #[derive(Debug, Clone)]
 pub enum HttpRequest {
@@ -42,7 +69,17 @@ pub enum Get {
     /// Get instruments.
     Instruments(Instruments),
     /// Earn offers.
-    EarnOffers(EarnOffers)
+    EarnOffers(EarnOffers),
+    /// Order book.
+    Books(Books),
+    /// Current funding rate.
+    FundingRate(FundingRate),
+    /// Funding rate history.
+    FundingRateHistory(FundingRateHistory),
+    /// History trades.
+    HistoryTrades(HistoryTrades),
+    /// Server time.
+    ServerTime(ServerTime),
 }
 
 impl Get {
@@ -51,6 +88,11 @@ impl Get {
             Self::HistoryCandles(_) => "/api/v5/market/history-candles",
             Self::Instruments(_) => "/api/v5/public/instruments",
             Self::EarnOffers(_) => "/api/v5/finance/staking-defi/offers",
+            Self::Books(_) => "/api/v5/market/books",
+            Self::FundingRate(_) => "/api/v5/public/funding-rate",
+            Self::FundingRateHistory(_) => "/api/v5/public/funding-rate-history",
+            Self::HistoryTrades(_) => "/api/v5/market/history-trades",
+            Self::ServerTime(_) => "/api/v5/public/time",
         }
     }
 }
@@ -62,7 +104,29 @@ pub enum PrivateGet {
     /// Order.
     Order(Order),
     /// Earn active orders.
-    EarnActiveOrders(EarnActiveOrders)
+    EarnActiveOrders(EarnActiveOrders),
+    /// Balance.
+    Balance(Balance),
+    /// Positions.
+    Positions(Positions),
+    /// Pending orders.
+    OrdersPending(OrdersPending),
+    /// Order history.
+    OrdersHistory(OrdersHistory),
+    /// Fills history.
+    FillsHistory(FillsHistory),
+    /// Trade fee rates.
+    TradeFee(TradeFee),
+    /// Leverage info.
+    LeverageInfo(LeverageInfo),
+    /// Transfer state.
+    TransferState(TransferState),
+    /// Deposit history.
+    DepositHistory(DepositHistory),
+    /// Withdrawal history.
+    WithdrawalHistory(WithdrawalHistory),
+    /// Algo order details.
+    OrderAlgo(OrderAlgoDetails),
 }
 
 impl PrivateGet {
@@ -70,16 +134,32 @@ impl PrivateGet {
         match self {
             Self::Order(_) => "/api/v5/trade/order",
             Self::EarnActiveOrders(_) => "/api/v5/finance/staking-defi/orders-active",
+            Self::Balance(_) => "/api/v5/account/balance",
+            Self::Positions(_) => "/api/v5/account/positions",
+            Self::OrdersPending(_) => "/api/v5/trade/orders-pending",
+            Self::OrdersHistory(_) => "/api/v5/trade/orders-history",
+            Self::FillsHistory(_) => "/api/v5/trade/fills-history",
+            Self::TradeFee(_) => "/api/v5/account/trade-fee",
+            Self::LeverageInfo(_) => "/api/v5/account/leverage-info",
+            Self::TransferState(_) => "/api/v5/asset/transfer-state",
+            Self::DepositHistory(_) => "/api/v5/asset/deposit-history",
+            Self::WithdrawalHistory(_) => "/api/v5/asset/withdrawal-history",
+            Self::OrderAlgo(_) => "/api/v5/trade/order-algo",
         }
     }
 
-    pub(crate) fn to_request(&self, host: &str, key: &Key) -> Result<Request<Body>, ExchangeError> {
+    pub(crate) fn to_request(
+        &self,
+        host: &str,
+        key: &Key,
+        clock: &Clock,
+    ) -> Result<Request<Body>, ExchangeError> {
         serde_qs::to_string(self)
             .map_err(|err| ExchangeError::Other(err.into()))
             .and_then(|q| {
                 let uri = format!("{}?{q}", self.uri());
                 let sign = key
-                    .sign_now("GET", &uri, false)
+                    .sign("GET", &uri, clock.now(), false)
                     .map_err(|e| ExchangeError::KeyError(anyhow::anyhow!("{e}")))?;
                 Request::get(format!("{host}{uri}"))
                     .header("OK-ACCESS-KEY", key.apikey.as_str())
@@ -100,6 +180,20 @@ pub enum PrivatePost {
     EarnPurchase(EarnPurchase),
     /// EarnRedeem.
     EarnRedeem(EarnRedeem),
+    /// Mass cancel.
+    MassCancel(MassCancel),
+    /// Cancel all after.
+    CancelAllAfter(CancelAllAfter),
+    /// Place an algo order.
+    OrderAlgo(Box<OrderAlgo>),
+    /// Set leverage.
+    SetLeverage(SetLeverage),
+    /// Set position mode.
+    SetPositionMode(SetPositionMode),
+    /// Funds transfer.
+    Transfer(Transfer),
+    /// Cancel algo orders (sent as a JSON array in the body).
+    CancelAlgos(Vec<CancelAlgo>),
 }
 
 impl PrivatePost {
@@ -107,16 +201,31 @@ impl PrivatePost {
         match self {
             Self::EarnPurchase(_) => "/api/v5/finance/staking-defi/purchase",
             Self::EarnRedeem(_) => "/api/v5/finance/staking-defi/redeem",
+            Self::MassCancel(_) => "/api/v5/trade/mass-cancel",
+            Self::CancelAllAfter(_) => "/api/v5/trade/cancel-all-after",
+            Self::OrderAlgo(_) => "/api/v5/trade/order-algo",
+            Self::SetLeverage(_) => "/api/v5/account/set-leverage",
+            Self::SetPositionMode(_) => "/api/v5/account/set-position-mode",
+            Self::Transfer(_) => "/api/v5/asset/transfer",
+            Self::CancelAlgos(_) => "/api/v5/trade/cancel-algos",
         }
     }
 
-    pub(crate) fn to_request(&self, host: &str, key: &Key) -> Result<Request<Body>, ExchangeError> {
+    pub(crate) fn to_request(
+        &self,
+        host: &str,
+        key: &Key,
+        clock: &Clock,
+    ) -> Result<Request<Body>, ExchangeError> {
+        if let Self::CancelAlgos(algos) = self {
+            return Self::to_json_request(self.uri(), algos, host, key, clock);
+        }
         serde_qs::to_string(self)
             .map_err(|err| ExchangeError::Other(err.into()))
             .and_then(|q| {
                 let uri = format!("{}?{q}", self.uri());
                 let sign = key
-                    .sign_now("post", &uri, false)
+                    .sign("post", &uri, clock.now(), false)
                     .map_err(|e| ExchangeError::KeyError(anyhow::anyhow!("{e}")))?;
                 Request::post(format!("{host}{uri}"))
                     .header("OK-ACCESS-KEY", key.apikey.as_str())
@@ -127,4 +236,26 @@ impl PrivatePost {
                     .map_err(|err| ExchangeError::Other(err.into()))
             })
     }
+
+    /// Build a request with a JSON body, which is signed along with the path.
+    fn to_json_request<T: Serialize>(
+        uri: &str,
+        body: &T,
+        host: &str,
+        key: &Key,
+        clock: &Clock,
+    ) -> Result<Request<Body>, ExchangeError> {
+        let body = serde_json::to_string(body).map_err(|err| ExchangeError::Other(err.into()))?;
+        let sign = key
+            .sign("POST", &format!("{uri}{body}"), clock.now(), false)
+            .map_err(|e| ExchangeError::KeyError(anyhow::anyhow!("{e}")))?;
+        Request::post(format!("{host}{uri}"))
+            .header("OK-ACCESS-KEY", key.apikey.as_str())
+            .header("OK-ACCESS-SIGN", sign.signature.as_str())
+            .header("OK-ACCESS-TIMESTAMP", sign.timestamp.as_str())
+            .header("OK-ACCESS-PASSPHRASE", key.passphrase.as_str())
+            .header("Content-Type", "application/json")
+            .body(Body::from(body))
+            .map_err(|err| ExchangeError::Other(err.into()))
+    }
 }
@@ -0,0 +1,23 @@
+use exc_core::Str;
+use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+/// History trades.
+#[serde_as]
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryTrades {
+    /// Instrument id.
+    pub inst_id: Str,
+    /// Pagination type: `1` for trade id, `2` for timestamp.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<u8>,
+    /// After (older) (trade id or millis, depending on the pagination type).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// Limit (max 100).
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
@@ -14,3 +14,154 @@ pub struct Order {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cl_ord_id: Option<Str>,
 }
+
+/// Mass cancel (the pending orders of an option family).
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MassCancel {
+    /// Instrument type (only `OPTION` is supported).
+    pub inst_type: Str,
+    /// Instrument family.
+    pub inst_family: Str,
+}
+
+/// Cancel all after.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllAfter {
+    /// Timeout in seconds (`0` to disarm).
+    pub time_out: String,
+}
+
+/// Pending orders.
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdersPending {
+    /// Instrument type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_type: Option<Str>,
+    /// Instrument Id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<Str>,
+    /// Pagination of data to return records earlier than the requested `ordId`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Str>,
+    /// Number of results per request (max 100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// Order history (the last 3 months).
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdersHistory {
+    /// Instrument type.
+    pub inst_type: Str,
+    /// Instrument Id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<Str>,
+    /// Pagination of data to return records earlier than the requested `ordId`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<Str>,
+    /// Filter with a begin timestamp (in millis).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin: Option<u64>,
+    /// Filter with an end timestamp (in millis).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<u64>,
+    /// Number of results per request (max 100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// Fills history (the last 3 months).
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FillsHistory {
+    /// Instrument type.
+    pub inst_type: Str,
+    /// Instrument Id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inst_id: Option<Str>,
+    /// Filter with a begin timestamp (in millis).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub begin: Option<u64>,
+    /// Filter with an end timestamp (in millis).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<u64>,
+    /// Number of results per request (max 100).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// Place an algo order.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAlgo {
+    /// Instrument Id.
+    pub inst_id: Str,
+    /// Trade mode.
+    pub td_mode: Str,
+    /// Margin currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ccy: Option<Str>,
+    /// Side.
+    pub side: Str,
+    /// Position side.
+    pub pos_side: Str,
+    /// Reduce only.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    /// Order type (`conditional` or `move_order_stop`).
+    pub ord_type: Str,
+    /// Size.
+    pub sz: Str,
+    /// The unit of the size (for spot market orders).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tgt_ccy: Option<Str>,
+    /// Client-supplied algo id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub algo_cl_ord_id: Option<Str>,
+    /// Take-profit trigger price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_trigger_px: Option<Str>,
+    /// Take-profit trigger price type (`last`, `index` or `mark`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_trigger_px_type: Option<Str>,
+    /// Take-profit order price (`-1` for market).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_ord_px: Option<Str>,
+    /// Stop-loss trigger price.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_trigger_px: Option<Str>,
+    /// Stop-loss trigger price type (`last`, `index` or `mark`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_trigger_px_type: Option<Str>,
+    /// Stop-loss order price (`-1` for market).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_ord_px: Option<Str>,
+    /// Callback ratio of trailing stops, e.g. `0.01` for `1%`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_ratio: Option<Str>,
+    /// Activation price of trailing stops.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_px: Option<Str>,
+}
+
+/// Algo order details.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAlgoDetails {
+    /// Algo id.
+    pub algo_id: Str,
+}
+
+/// Cancel an algo order.
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAlgo {
+    /// Algo id.
+    pub algo_id: Str,
+    /// Instrument Id.
+    pub inst_id: Str,
+}
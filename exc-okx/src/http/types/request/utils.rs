@@ -0,0 +1,5 @@
+use serde::Serialize;
+
+/// Server time.
+#[derive(Debug, Serialize, Clone, Copy, Default)]
+pub struct ServerTime {}
@@ -0,0 +1,46 @@
+use exc_core::Str;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// Leverage (of the `set-leverage` and `leverage-info` endpoints).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Leverage {
+    /// Instrument id.
+    pub inst_id: Str,
+    /// Margin mode.
+    pub mgn_mode: Str,
+    /// Position side.
+    #[serde(default)]
+    pub pos_side: Str,
+    /// Leverage.
+    pub lever: Decimal,
+}
+
+/// Position mode.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMode {
+    /// Position mode (`long_short_mode` or `net_mode`).
+    pub pos_mode: Str,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::types::response::ResponseData;
+
+    #[test]
+    fn test_leverage_data() -> anyhow::Result<()> {
+        let leverage = r#"{"instId":"BTC-USDT-SWAP","mgnMode":"cross","posSide":"","lever":"5"}"#;
+        let ResponseData::Leverage(leverage) = serde_json::from_str(leverage)? else {
+            anyhow::bail!("not a leverage");
+        };
+        assert_eq!(leverage.lever.to_string(), "5");
+        let mode = r#"{"posMode":"net_mode"}"#;
+        let ResponseData::PositionMode(mode) = serde_json::from_str(mode)? else {
+            anyhow::bail!("not a position mode");
+        };
+        assert_eq!(mode.pos_mode, "net_mode");
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use exc_core::Str;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+/// Transfer result.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferResult {
+    /// Transfer id.
+    pub trans_id: Str,
+    /// Currency.
+    pub ccy: Str,
+    /// Amount.
+    pub amt: Decimal,
+}
+
+/// Transfer state.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferState {
+    /// Transfer id.
+    pub trans_id: Str,
+    /// Currency.
+    pub ccy: Str,
+    /// Amount.
+    pub amt: Decimal,
+    /// State (`success`, `pending` or `failed`).
+    pub state: Str,
+}
+
+/// Deposit record.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    /// Deposit id.
+    pub dep_id: Str,
+    /// Currency.
+    pub ccy: Str,
+    /// Chain.
+    pub chain: Str,
+    /// Amount.
+    pub amt: Decimal,
+    /// Receiving address.
+    pub to: Str,
+    /// Transaction hash.
+    #[serde(default)]
+    pub tx_id: Str,
+    /// State (`0` waiting for confirmation, `1` credited, `2` successful,
+    /// others pending or intercepted).
+    pub state: Str,
+    /// Time of the deposit.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub ts: OffsetDateTime,
+}
+
+/// Withdrawal record.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalRecord {
+    /// Withdrawal id.
+    pub wd_id: Str,
+    /// Currency.
+    pub ccy: Str,
+    /// Chain.
+    pub chain: Str,
+    /// Amount.
+    pub amt: Decimal,
+    /// Fee.
+    pub fee: Decimal,
+    /// Receiving address.
+    pub to: Str,
+    /// Transaction hash.
+    #[serde(default)]
+    pub tx_id: Str,
+    /// State (`-3` canceling, `-2` canceled, `-1` failed, `2` successful,
+    /// others pending).
+    pub state: Str,
+    /// Time of the withdrawal request.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub ts: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::types::response::ResponseData;
+
+    #[test]
+    fn test_asset_data() -> anyhow::Result<()> {
+        let result =
+            r#"{"transId":"754147","ccy":"USDT","clientId":"","from":"6","amt":"0.1","to":"18"}"#;
+        let ResponseData::TransferResult(result) = serde_json::from_str(result)? else {
+            anyhow::bail!("not a transfer result");
+        };
+        assert_eq!(result.trans_id, "754147");
+        let state = r#"{"amt":"1.5","ccy":"USDT","clientId":"","from":"18","instId":"","state":"success","subAcct":"test","to":"6","toInstId":"","transId":"1","type":"1"}"#;
+        let ResponseData::TransferState(state) = serde_json::from_str(state)? else {
+            anyhow::bail!("not a transfer state");
+        };
+        assert_eq!(state.state, "success");
+        let deposit = r#"{"actualDepBlkConfirm":"2","amt":"1","areaCodeFrom":"","ccy":"USDT","chain":"USDT-TRC20","depId":"88165462","from":"","fromWdId":"","state":"2","to":"TN4hGjVXMzy4HYaGq7c6YcDJz1aDWAq3pw","ts":"1674038705000","txId":"fee235b3e812810b8ca20a20e0a52e07f78ce8a5bdb52c4fdc1da6d2dd47b3ea"}"#;
+        let ResponseData::Deposit(deposit) = serde_json::from_str(deposit)? else {
+            anyhow::bail!("not a deposit");
+        };
+        assert_eq!(deposit.dep_id, "88165462");
+        let withdrawal = r#"{"chain":"ETH-Ethereum","fee":"0.007","feeCcy":"ETH","ccy":"ETH","clientId":"","amt":"0.029809","txId":"0x35c6b7e5f6e7c7e6b5e3c7b4b7e8f6e7c7e6b5e3c7b4b7e8f6e7c7e6b5e3c7b4","from":"156****359","areaCodeFrom":"86","to":"0xa30d1fab0e9b7e5a2c3e36e2a6f3d8f2b0c1d2e3","areaCodeTo":"","state":"2","ts":"1655251200000","nonTradableAsset":false,"wdId":"15447421"}"#;
+        let ResponseData::Withdrawal(withdrawal) = serde_json::from_str(withdrawal)? else {
+            anyhow::bail!("not a withdrawal");
+        };
+        assert_eq!(withdrawal.fee.to_string(), "0.007");
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+/// Order book level: `[price, size, deprecated, number of orders]`.
+pub type Level = [Decimal; 4];
+
+/// Order book snapshot.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Book {
+    /// Asks.
+    pub asks: Vec<Level>,
+    /// Bids.
+    pub bids: Vec<Level>,
+    /// Timestamp.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub ts: OffsetDateTime,
+}
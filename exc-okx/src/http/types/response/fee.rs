@@ -0,0 +1,80 @@
+use exc_core::Str;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_with::{serde_as, NoneAsEmptyString};
+use time::OffsetDateTime;
+
+/// Trade fee rates (negative for fees and positive for rebates).
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeFee {
+    /// Instrument (attached from the request).
+    #[serde(skip)]
+    pub instrument: Option<Str>,
+    /// Instrument type.
+    pub inst_type: Str,
+    /// Fee level.
+    pub level: Str,
+    /// Maker fee rate (of spot trading pairs and coin-margined contracts).
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub maker: Option<Decimal>,
+    /// Taker fee rate (of spot trading pairs and coin-margined contracts).
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub taker: Option<Decimal>,
+    /// Maker fee rate of USDT-margined contracts.
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub maker_u: Option<Decimal>,
+    /// Taker fee rate of USDT-margined contracts.
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub taker_u: Option<Decimal>,
+    /// Maker fee rate of USDC-margined contracts (or USDC trading pairs).
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default, rename = "makerUSDC")]
+    pub maker_usdc: Option<Decimal>,
+    /// Taker fee rate of USDC-margined contracts (or USDC trading pairs).
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default, rename = "takerUSDC")]
+    pub taker_usdc: Option<Decimal>,
+    /// Timestamp.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub ts: OffsetDateTime,
+}
+
+impl TradeFee {
+    /// Get the (maker, taker) fee rates of the instrument.
+    pub fn rates(&self) -> Option<(Decimal, Decimal)> {
+        let inst = self.instrument.as_ref()?;
+        let mut parts = inst.split('-');
+        let quote = parts.nth(1)?;
+        let (maker, taker) = match (self.inst_type.as_str(), quote) {
+            ("FUTURES" | "SWAP", "USDT") => (self.maker_u, self.taker_u),
+            (_, "USDC") => (self.maker_usdc, self.taker_usdc),
+            _ => (self.maker, self.taker),
+        };
+        Some((maker?, taker?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::types::response::ResponseData;
+    use exc_core::Str;
+
+    #[test]
+    fn test_trade_fee_data() -> anyhow::Result<()> {
+        let fee = r#"{"category":"1","delivery":"","exercise":"","fiat":[],"instType":"SWAP","level":"Lv1","maker":"-0.0002","makerU":"-0.0002","makerUSDC":"-0.0002","taker":"-0.0005","takerU":"-0.0005","takerUSDC":"-0.0005","ts":"1703070685309"}"#;
+        let ResponseData::TradeFee(mut fee) = serde_json::from_str(fee)? else {
+            anyhow::bail!("not a trade fee");
+        };
+        fee.instrument = Some(Str::new("BTC-USDT-SWAP"));
+        let (maker, taker) = fee.rates().unwrap();
+        assert_eq!(maker.to_string(), "-0.0002");
+        assert_eq!(taker.to_string(), "-0.0005");
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use exc_core::{types::CurrentFundingRate, Str};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use serde_with::{serde_as, NoneAsEmptyString};
+use time::OffsetDateTime;
+
+/// Funding rate (of the `funding-rate` endpoint and channel).
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRate {
+    /// Instrument id.
+    pub inst_id: Str,
+    /// Current funding rate.
+    pub funding_rate: Decimal,
+    /// Settlement time of the current funding rate.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub funding_time: OffsetDateTime,
+    /// Forecasted funding rate of the next period.
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub next_funding_rate: Option<Decimal>,
+    /// Settlement time of the next funding rate.
+    #[serde(default, with = "crate::utils::timestamp_serde_option")]
+    pub next_funding_time: Option<OffsetDateTime>,
+    /// Timestamp.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub ts: OffsetDateTime,
+}
+
+impl From<FundingRate> for CurrentFundingRate {
+    fn from(rate: FundingRate) -> Self {
+        Self {
+            ts: rate.ts,
+            rate: rate.funding_rate.normalize(),
+            funding_time: rate.funding_time,
+            next_rate: rate.next_funding_rate.map(|r| r.normalize()),
+            next_funding_time: rate.next_funding_time,
+        }
+    }
+}
+
+/// Settled funding rate (of the `funding-rate-history` endpoint).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FundingRateHistory {
+    /// Instrument id.
+    pub inst_id: Str,
+    /// Predicted funding rate.
+    pub funding_rate: Decimal,
+    /// Actual funding rate.
+    pub realized_rate: Decimal,
+    /// Settlement time.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub funding_time: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::types::response::ResponseData;
+
+    #[test]
+    fn test_funding_rate_data() -> anyhow::Result<()> {
+        let current = r#"{"formulaType":"withRate","fundingRate":"0.0001","fundingTime":"1703088000000","impactValue":"","instId":"BTC-USDT-SWAP","instType":"SWAP","interestRate":"","maxFundingRate":"0.00375","method":"current_period","minFundingRate":"-0.00375","nextFundingRate":"","nextFundingTime":"1703116800000","premium":"0.0001","settFundingRate":"0.0001","settState":"settled","ts":"1703070685309"}"#;
+        let ResponseData::FundingRate(rate) = serde_json::from_str(current)? else {
+            anyhow::bail!("not a funding rate");
+        };
+        assert!(rate.next_funding_rate.is_none());
+        assert!(rate.next_funding_time.is_some());
+        let history = r#"{"fundingRate":"0.0000746604960499","fundingTime":"1703059200000","instId":"BTC-USDT-SWAP","instType":"SWAP","method":"next_period","realizedRate":"0.0000746572360545"}"#;
+        let ResponseData::FundingRateHistory(rate) = serde_json::from_str(history)? else {
+            anyhow::bail!("not a funding rate history");
+        };
+        assert_eq!(rate.funding_time.unix_timestamp(), 1703059200);
+        Ok(())
+    }
+}
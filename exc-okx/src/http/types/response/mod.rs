@@ -1,17 +1,50 @@
 use anyhow::anyhow;
-use exc_core::{ExchangeError, InstrumentError};
+use exc_core::{ExchangeError, InstrumentError, Str};
 use serde::Deserialize;
 
+use crate::error::api_error;
+
 /// Candle.
 pub mod candle;
 
 /// Trading.
 pub mod trading;
 
+/// Book.
+pub mod book;
+
+/// Funding.
+pub mod funding;
+
+/// Trade.
+pub mod trade;
+
+/// Utils.
+pub mod utils;
+
+/// Fee rates.
+pub mod fee;
+
+/// Account configuration.
+pub mod account;
+
+/// Asset (funds transfer).
+pub mod asset;
+
+pub use account::{Leverage, PositionMode};
+pub use asset::{DepositRecord, TransferResult, TransferState, WithdrawalRecord};
+pub use book::Book;
 pub use candle::Candle;
-pub use trading::OrderDetail;
+pub use fee::TradeFee;
+pub use funding::{FundingRate, FundingRateHistory};
+pub use trade::HistoryTrade;
+pub use trading::{
+    AlgoOrderDetail, AlgoOrderResult, CancelAllAfterResult, FillDetail, MassCancelResult,
+    OrderDetail,
+};
+pub use utils::ServerTime;
 
-pub use crate::websocket::types::messages::event::OkxInstrumentMeta;
+pub use crate::websocket::types::messages::event::{OkxAccount, OkxInstrumentMeta, OkxPosition};
 
 /// Okx HTTP API Response (with `code` and `msg`).
 #[derive(Debug, Deserialize)]
@@ -32,6 +65,18 @@ pub struct HttpResponse {
     pub data: Vec<ResponseData>,
 }
 
+impl HttpResponse {
+    /// Attach the instrument to the trade fees.
+    pub(crate) fn with_trade_fee_instrument(mut self, inst: &Str) -> Self {
+        for data in self.data.iter_mut() {
+            if let ResponseData::TradeFee(fee) = data {
+                fee.instrument = Some(inst.clone());
+            }
+        }
+        self
+    }
+}
+
 impl TryFrom<FullHttpResponse> for HttpResponse {
     type Error = ExchangeError;
 
@@ -44,7 +89,7 @@ impl TryFrom<FullHttpResponse> for HttpResponse {
             "50011" => Err(ExchangeError::RateLimited(anyhow!("{msg}"))),
             "50013" => Err(ExchangeError::Unavailable(anyhow!("{msg}"))),
             "51603" => Err(ExchangeError::OrderNotFound),
-            _ => Err(ExchangeError::Api(anyhow!("code={code} msg={msg}",))),
+            _ => Err(ExchangeError::ApiError(api_error(&code, &msg))),
         }
     }
 }
@@ -59,4 +104,42 @@ pub enum ResponseData {
     Instruments(OkxInstrumentMeta),
     /// Order.
     Order(Box<OrderDetail>),
+    /// Algo order (after the orders, which may carry the algo ids too).
+    AlgoOrderDetail(Box<AlgoOrderDetail>),
+    /// Account.
+    Account(Box<OkxAccount>),
+    /// Position.
+    Position(Box<OkxPosition>),
+    /// Order book.
+    Book(Box<Book>),
+    /// Mass cancel result.
+    MassCancel(MassCancelResult),
+    /// Cancel all after result.
+    CancelAllAfter(CancelAllAfterResult),
+    /// Fill.
+    Fill(Box<FillDetail>),
+    /// Algo order result.
+    AlgoOrder(AlgoOrderResult),
+    /// Current funding rate.
+    FundingRate(Box<FundingRate>),
+    /// Settled funding rate.
+    FundingRateHistory(FundingRateHistory),
+    /// History trade.
+    HistoryTrade(HistoryTrade),
+    /// Trade fee rates.
+    TradeFee(Box<TradeFee>),
+    /// Leverage.
+    Leverage(Leverage),
+    /// Position mode.
+    PositionMode(PositionMode),
+    /// Transfer state.
+    TransferState(TransferState),
+    /// Transfer result.
+    TransferResult(TransferResult),
+    /// Deposit.
+    Deposit(Box<DepositRecord>),
+    /// Withdrawal.
+    Withdrawal(Box<WithdrawalRecord>),
+    /// Server time (matching any data with `ts`, so it must be the last).
+    ServerTime(ServerTime),
 }
@@ -0,0 +1,30 @@
+use exc_core::Str;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+/// History trade (of the `history-trades` endpoint).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryTrade {
+    /// Instrument id.
+    pub inst_id: Str,
+    /// Trade id.
+    pub trade_id: Str,
+    /// Price.
+    pub px: Decimal,
+    /// Size.
+    pub sz: Decimal,
+    /// Taker side.
+    pub side: String,
+    /// Timestamp.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub ts: OffsetDateTime,
+}
+
+impl HistoryTrade {
+    /// Is the taker a buyer.
+    pub fn is_buy(&self) -> bool {
+        self.side == "buy"
+    }
+}
@@ -113,3 +113,129 @@ pub struct OrderDetail {
     #[serde(rename = "cTime")]
     pub created_at: Decimal,
 }
+
+/// Mass cancel result.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MassCancelResult {
+    /// Whether the request is accepted.
+    pub result: bool,
+}
+
+/// Cancel all after result.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelAllAfterResult {
+    /// The time the cancellation is triggered (`0` if disarmed).
+    pub trigger_time: Str,
+    /// Timestamp.
+    pub ts: Str,
+}
+
+/// Fill detail.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FillDetail {
+    /// Inst type.
+    pub inst_type: String,
+    /// Inst id.
+    pub inst_id: Str,
+    /// Trade id.
+    pub trade_id: Str,
+    /// Order id.
+    pub ord_id: Str,
+    /// Bill id.
+    pub bill_id: Str,
+    /// Filled price.
+    pub fill_px: Decimal,
+    /// Filled size.
+    pub fill_sz: Decimal,
+    /// Side.
+    pub side: String,
+    /// Liquidity taker or maker (`T` or `M`).
+    pub exec_type: String,
+    /// Fee currency.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub fee_ccy: Option<Asset>,
+    /// Fee (negative for charging).
+    pub fee: Decimal,
+    /// Timestamp.
+    pub ts: Decimal,
+}
+
+/// Algo order result.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrderResult {
+    /// Algo id.
+    pub algo_id: Str,
+    /// Client-supplied algo id.
+    #[serde(default)]
+    pub algo_cl_ord_id: Str,
+    /// Status code (`0` for success).
+    pub s_code: Str,
+    /// Status message.
+    pub s_msg: Str,
+}
+
+/// Algo order detail.
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrderDetail {
+    /// Inst id.
+    pub inst_id: Str,
+    /// Algo id.
+    pub algo_id: Str,
+    /// Client-supplied algo id.
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub algo_cl_ord_id: Option<Str>,
+    /// Size.
+    pub sz: Decimal,
+    /// Order type (`conditional` or `move_order_stop`).
+    pub ord_type: String,
+    /// Side.
+    pub side: String,
+    /// State (`live`, `pause`, `partially_effective`, `effective`, `canceled`,
+    /// `order_failed` or `partially_failed`).
+    pub state: String,
+    /// Take-profit trigger price.
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub tp_trigger_px: Option<Decimal>,
+    /// Take-profit trigger price type.
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub tp_trigger_px_type: Option<String>,
+    /// Take-profit order price (`-1` for market).
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub tp_ord_px: Option<Decimal>,
+    /// Stop-loss trigger price.
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub sl_trigger_px: Option<Decimal>,
+    /// Stop-loss trigger price type.
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub sl_trigger_px_type: Option<String>,
+    /// Stop-loss order price (`-1` for market).
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub sl_ord_px: Option<Decimal>,
+    /// Callback ratio of trailing stops.
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub callback_ratio: Option<Decimal>,
+    /// Activation price of trailing stops.
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub active_px: Option<Decimal>,
+    /// Created.
+    pub c_time: Decimal,
+    /// Updated.
+    #[serde(default)]
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub u_time: Option<Decimal>,
+}
@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use time::OffsetDateTime;
+
+/// Server time.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct ServerTime {
+    /// Timestamp.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub ts: OffsetDateTime,
+}
@@ -1,17 +1,25 @@
 use exc_core::{
     types::{
         instrument::{FetchInstruments, SubscribeInstruments},
-        utils::Reconnect,
-        CancelOrder, GetOrder, PlaceOrder, QueryLastCandles, SubscribeBidAsk, SubscribeOrders,
-        SubscribeTickers, SubscribeTrades,
+        utils::{FetchServerTime, Reconnect},
+        AmendOrder, CancelAllAfter, CancelAllOrders, CancelOrder, CancelOrders, FetchBalances,
+        FetchFeeRates, FetchFundingRate, FetchOrderBook, FetchPositions, GetLeverage, GetOrder,
+        ListOpenOrders, OrderId, Paged, PlaceOrder, PlaceOrders, QueryDeposits,
+        QueryFirstFundingRates, QueryLastCandles, QueryLastFills, QueryLastTrades,
+        QueryOrderHistory, QueryTransfer, QueryWithdrawals, SetLeverage, SetPositionMode,
+        SubscribeBalances, SubscribeBidAsk, SubscribeCandles, SubscribeFundingRate,
+        SubscribeLiquidations, SubscribeMarkPrice, SubscribeOpenInterest, SubscribeOptionGreeks,
+        SubscribeOrderBookUpdates, SubscribeOrders, SubscribePositions, SubscribeTickers,
+        SubscribeTrades, Transfer,
     },
     Adaptor, ExchangeError, Request,
 };
 
 use crate::http::types::request::HttpRequest;
+use crate::utils::algo::algo_id;
 use crate::websocket::Request as WsRequest;
 
-use super::OkxRequest;
+use super::{OkxRequest, OkxResponse};
 
 impl Adaptor<SubscribeInstruments> for OkxRequest {
     fn from_request(req: SubscribeInstruments) -> Result<Self, ExchangeError> {
@@ -69,6 +77,90 @@ impl Adaptor<SubscribeTrades> for OkxRequest {
     }
 }
 
+impl Adaptor<SubscribeCandles> for OkxRequest {
+    fn from_request(req: SubscribeCandles) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Business(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeCandles as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeCandles>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeMarkPrice> for OkxRequest {
+    fn from_request(req: SubscribeMarkPrice) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeMarkPrice as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeMarkPrice>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeFundingRate> for OkxRequest {
+    fn from_request(req: SubscribeFundingRate) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeFundingRate as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeFundingRate>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeOptionGreeks> for OkxRequest {
+    fn from_request(req: SubscribeOptionGreeks) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeOptionGreeks as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeOptionGreeks>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeOpenInterest> for OkxRequest {
+    fn from_request(req: SubscribeOpenInterest) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeOpenInterest as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeOpenInterest>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeLiquidations> for OkxRequest {
+    fn from_request(req: SubscribeLiquidations) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeLiquidations as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeLiquidations>>::into_response(res)
+    }
+}
+
 impl Adaptor<SubscribeBidAsk> for OkxRequest {
     fn from_request(req: SubscribeBidAsk) -> Result<Self, ExchangeError> {
         let req = WsRequest::from_request(req)?;
@@ -97,6 +189,34 @@ impl Adaptor<QueryLastCandles> for OkxRequest {
     }
 }
 
+impl Adaptor<QueryFirstFundingRates> for OkxRequest {
+    fn from_request(req: QueryFirstFundingRates) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<QueryFirstFundingRates as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<QueryFirstFundingRates>>::into_response(res)
+    }
+}
+
+impl Adaptor<FetchFundingRate> for OkxRequest {
+    fn from_request(req: FetchFundingRate) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchFundingRate as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchFundingRate>>::into_response(res)
+    }
+}
+
 impl Adaptor<GetOrder> for OkxRequest {
     fn from_request(req: GetOrder) -> Result<Self, ExchangeError> {
         let req = HttpRequest::from_request(req)?;
@@ -111,31 +231,173 @@ impl Adaptor<GetOrder> for OkxRequest {
     }
 }
 
+/// Conditional orders are placed with the algo order api through HTTP,
+/// so the ids of them are tagged algo ids.
 impl Adaptor<PlaceOrder> for OkxRequest {
     fn from_request(req: PlaceOrder) -> Result<Self, ExchangeError> {
-        let req = WsRequest::from_request(req)?;
-        Ok(Self::Ws(req))
+        if req.place.kind.is_conditional() {
+            let req = HttpRequest::from_request(req)?;
+            Ok(Self::Http(req))
+        } else {
+            let req = WsRequest::from_request(req)?;
+            Ok(Self::Ws(req))
+        }
     }
 
     fn into_response(
         resp: Self::Response,
     ) -> Result<<PlaceOrder as Request>::Response, ExchangeError> {
-        let res = resp.ws()?;
-        <WsRequest as Adaptor<PlaceOrder>>::into_response(res)
+        match resp {
+            OkxResponse::Http(res) => <HttpRequest as Adaptor<PlaceOrder>>::into_response(res),
+            OkxResponse::Ws(res) => <WsRequest as Adaptor<PlaceOrder>>::into_response(res),
+        }
     }
 }
 
+/// Algo orders are canceled with the algo order api through HTTP.
 impl Adaptor<CancelOrder> for OkxRequest {
     fn from_request(req: CancelOrder) -> Result<Self, ExchangeError> {
+        if algo_id(&req.id).is_some() {
+            let req = HttpRequest::from_request(req)?;
+            Ok(Self::Http(req))
+        } else {
+            let req = WsRequest::from_request(req)?;
+            Ok(Self::Ws(req))
+        }
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<CancelOrder as Request>::Response, ExchangeError> {
+        match resp {
+            OkxResponse::Http(res) => <HttpRequest as Adaptor<CancelOrder>>::into_response(res),
+            OkxResponse::Ws(res) => <WsRequest as Adaptor<CancelOrder>>::into_response(res),
+        }
+    }
+}
+
+/// Reject the algo order ids, which are not supported by the batch and amend requests.
+fn check_not_algo<'a>(mut ids: impl Iterator<Item = &'a OrderId>) -> Result<(), ExchangeError> {
+    if let Some(id) = ids.find(|id| algo_id(id).is_some()) {
+        return Err(ExchangeError::Other(anyhow::anyhow!(
+            "algo orders (`{}`) are not supported by this request on okx",
+            id.as_str()
+        )));
+    }
+    Ok(())
+}
+
+impl Adaptor<AmendOrder> for OkxRequest {
+    fn from_request(req: AmendOrder) -> Result<Self, ExchangeError> {
+        check_not_algo(std::iter::once(&req.id))?;
         let req = WsRequest::from_request(req)?;
         Ok(Self::Ws(req))
     }
 
     fn into_response(
         resp: Self::Response,
-    ) -> Result<<CancelOrder as Request>::Response, ExchangeError> {
+    ) -> Result<<AmendOrder as Request>::Response, ExchangeError> {
         let res = resp.ws()?;
-        <WsRequest as Adaptor<CancelOrder>>::into_response(res)
+        <WsRequest as Adaptor<AmendOrder>>::into_response(res)
+    }
+}
+
+impl Adaptor<PlaceOrders> for OkxRequest {
+    fn from_request(req: PlaceOrders) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<PlaceOrders as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<PlaceOrders>>::into_response(res)
+    }
+}
+
+impl Adaptor<CancelOrders> for OkxRequest {
+    fn from_request(req: CancelOrders) -> Result<Self, ExchangeError> {
+        check_not_algo(req.0.iter().map(|cancel| &cancel.id))?;
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<CancelOrders as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<CancelOrders>>::into_response(res)
+    }
+}
+
+impl Adaptor<CancelAllOrders> for OkxRequest {
+    fn from_request(req: CancelAllOrders) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<CancelAllOrders as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<CancelAllOrders>>::into_response(res)
+    }
+}
+
+impl Adaptor<CancelAllAfter> for OkxRequest {
+    fn from_request(req: CancelAllAfter) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<CancelAllAfter as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<CancelAllAfter>>::into_response(res)
+    }
+}
+
+impl Adaptor<Paged<ListOpenOrders>> for OkxRequest {
+    fn from_request(req: Paged<ListOpenOrders>) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<Paged<ListOpenOrders> as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<Paged<ListOpenOrders>>>::into_response(res)
+    }
+}
+
+impl Adaptor<Paged<QueryOrderHistory>> for OkxRequest {
+    fn from_request(req: Paged<QueryOrderHistory>) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<Paged<QueryOrderHistory> as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<Paged<QueryOrderHistory>>>::into_response(res)
+    }
+}
+
+impl Adaptor<QueryLastFills> for OkxRequest {
+    fn from_request(req: QueryLastFills) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<QueryLastFills as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<QueryLastFills>>::into_response(res)
     }
 }
 
@@ -167,3 +429,227 @@ impl Adaptor<SubscribeOrders> for OkxRequest {
         <WsRequest as Adaptor<SubscribeOrders>>::into_response(res)
     }
 }
+
+impl Adaptor<FetchBalances> for OkxRequest {
+    fn from_request(req: FetchBalances) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchBalances as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchBalances>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeBalances> for OkxRequest {
+    fn from_request(req: SubscribeBalances) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeBalances as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeBalances>>::into_response(res)
+    }
+}
+
+impl Adaptor<FetchPositions> for OkxRequest {
+    fn from_request(req: FetchPositions) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchPositions as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchPositions>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribePositions> for OkxRequest {
+    fn from_request(req: SubscribePositions) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribePositions as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribePositions>>::into_response(res)
+    }
+}
+
+impl Adaptor<FetchOrderBook> for OkxRequest {
+    fn from_request(req: FetchOrderBook) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchOrderBook as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchOrderBook>>::into_response(res)
+    }
+}
+
+impl Adaptor<SubscribeOrderBookUpdates> for OkxRequest {
+    fn from_request(req: SubscribeOrderBookUpdates) -> Result<Self, ExchangeError> {
+        let req = WsRequest::from_request(req)?;
+        Ok(Self::Ws(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeOrderBookUpdates as Request>::Response, ExchangeError> {
+        let res = resp.ws()?;
+        <WsRequest as Adaptor<SubscribeOrderBookUpdates>>::into_response(res)
+    }
+}
+
+impl Adaptor<QueryLastTrades> for OkxRequest {
+    fn from_request(req: QueryLastTrades) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<QueryLastTrades as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<QueryLastTrades>>::into_response(res)
+    }
+}
+
+impl Adaptor<FetchServerTime> for OkxRequest {
+    fn from_request(req: FetchServerTime) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchServerTime as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchServerTime>>::into_response(res)
+    }
+}
+
+impl Adaptor<FetchFeeRates> for OkxRequest {
+    fn from_request(req: FetchFeeRates) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<FetchFeeRates as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<FetchFeeRates>>::into_response(res)
+    }
+}
+
+impl Adaptor<SetLeverage> for OkxRequest {
+    fn from_request(req: SetLeverage) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SetLeverage as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<SetLeverage>>::into_response(res)
+    }
+}
+
+impl Adaptor<GetLeverage> for OkxRequest {
+    fn from_request(req: GetLeverage) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<GetLeverage as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<GetLeverage>>::into_response(res)
+    }
+}
+
+impl Adaptor<SetPositionMode> for OkxRequest {
+    fn from_request(req: SetPositionMode) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SetPositionMode as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<SetPositionMode>>::into_response(res)
+    }
+}
+
+impl Adaptor<Transfer> for OkxRequest {
+    fn from_request(req: Transfer) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<Transfer as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<Transfer>>::into_response(res)
+    }
+}
+
+impl Adaptor<QueryTransfer> for OkxRequest {
+    fn from_request(req: QueryTransfer) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<QueryTransfer as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<QueryTransfer>>::into_response(res)
+    }
+}
+
+impl Adaptor<QueryDeposits> for OkxRequest {
+    fn from_request(req: QueryDeposits) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<QueryDeposits as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<QueryDeposits>>::into_response(res)
+    }
+}
+
+impl Adaptor<QueryWithdrawals> for OkxRequest {
+    fn from_request(req: QueryWithdrawals) -> Result<Self, ExchangeError> {
+        let req = HttpRequest::from_request(req)?;
+        Ok(Self::Http(req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<QueryWithdrawals as Request>::Response, ExchangeError> {
+        let res = resp.http()?;
+        <HttpRequest as Adaptor<QueryWithdrawals>>::into_response(res)
+    }
+}
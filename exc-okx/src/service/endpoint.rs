@@ -1,9 +1,12 @@
 use std::time::Duration;
 
 use crate::{
-    http::layer::OkxHttpApiLayer, key::OkxKey, websocket::Endpoint as WsEndpoint, OkxRequest,
+    http::{layer::OkxHttpApiLayer, limit},
+    key::OkxKey,
+    websocket::Endpoint as WsEndpoint,
+    OkxRequest,
 };
-use exc_core::{transport::http, Exc, ExchangeError};
+use exc_core::{transport::http, util::clock_sync::Clock, weight::RateLimiter, Exc, ExchangeError};
 use tower::ServiceBuilder;
 
 use super::Okx;
@@ -19,9 +22,11 @@ pub struct Endpoint {
 
 impl Default for Endpoint {
     fn default() -> Self {
+        let mut http = OkxHttpApiLayer::default();
+        http.rate_limiter(limit::rate_limiter());
         Self {
             ws: WsEndpoint::default(),
-            http: OkxHttpApiLayer::default(),
+            http,
             buffer: CAP,
         }
     }
@@ -36,10 +41,14 @@ impl Endpoint {
     /// Connect.
     pub fn connect(&self) -> Okx {
         let ws = self.ws.connect();
+        // Only public channels of the business endpoint are used for now.
+        let mut business = self.ws.clone();
+        business.business(true).login = None;
+        let business = business.connect();
         let http = ServiceBuilder::default()
             .layer(&self.http)
             .service(http::endpoint::Endpoint::default().connect_https());
-        Okx::new(ws, http, self.buffer)
+        Okx::new(ws, business, http, self.buffer)
     }
 
     /// Set ping timeout for the websocket channel.
@@ -73,6 +82,22 @@ impl Endpoint {
         self
     }
 
+    /// Set the clock used to sign the private requests.
+    pub fn clock(&mut self, clock: Clock) -> &mut Self {
+        self.ws.clock(clock.clone());
+        self.http.clock(clock);
+        self
+    }
+
+    /// Set the rate limiter of the http requests (default to the limits of OKX,
+    /// see [`rate_limiter`](crate::http::limit::rate_limiter)).
+    ///
+    /// Share the same limiter between the endpoints using the same IP and account.
+    pub fn rate_limiter(&mut self, limiter: RateLimiter) -> &mut Self {
+        self.http.rate_limiter(limiter);
+        self
+    }
+
     /// Switch to AWS endpoint.
     pub fn aws(&mut self, enable: bool) -> &mut Self {
         self.ws.aws(enable);
@@ -1,5 +1,6 @@
-use exc_core::retry::RetryPolicy;
+use exc_core::retry::{Idempotent, RetryPolicy};
 use exc_core::transport::http::channel::HttpsChannel;
+use exc_core::weight::WeightedRateLimit;
 use exc_core::{ExchangeError, Request};
 use futures::future::{ready, BoxFuture};
 use futures::{FutureExt, TryFutureExt};
@@ -28,6 +29,8 @@ pub enum OkxRequest {
     Http(HttpRequest),
     /// Request of WS API.
     Ws(WsRequest),
+    /// Request of WS API on the business channel.
+    Business(WsRequest),
 }
 
 impl OkxRequest {
@@ -40,6 +43,11 @@ impl OkxRequest {
     pub fn subscribe(args: Args) -> Self {
         Self::Ws(WsRequest::subscribe(args))
     }
+
+    /// Subscribe with the given args on the business channel.
+    pub fn subscribe_business(args: Args) -> Self {
+        Self::Business(WsRequest::subscribe(args))
+    }
 }
 
 /// Okx response.
@@ -76,10 +84,20 @@ impl OkxResponse {
 
 impl Request for OkxRequest {
     type Response = OkxResponse;
+
+    fn is_idempotent(&self) -> bool {
+        match self {
+            Self::Http(req) => req.is_idempotent(),
+            Self::Ws(req) | Self::Business(req) => req.is_idempotent(),
+        }
+    }
 }
 
 type HttpInner = OkxHttpApi<HttpsChannel>;
-type Http = Retry<RetryPolicy<HttpRequest, HttpResponse, fn(&ExchangeError) -> bool>, HttpInner>;
+type Http = Retry<
+    Idempotent<RetryPolicy<HttpRequest, HttpResponse, fn(&ExchangeError) -> bool>>,
+    WeightedRateLimit<HttpInner>,
+>;
 type Ws = WsChannel;
 
 impl Service<OkxRequest> for Http {
@@ -123,7 +141,7 @@ impl Service<OkxRequest> for Ws {
     }
 
     fn call(&mut self, req: OkxRequest) -> Self::Future {
-        if let OkxRequest::Ws(req) = req {
+        if let OkxRequest::Ws(req) | OkxRequest::Business(req) = req {
             Service::call(self, req).map_ok(OkxResponse::Ws).boxed()
         } else {
             ready(Err(ExchangeError::Other(anyhow::anyhow!(
@@ -140,11 +158,13 @@ struct Inner {
 
 const HTTP_KEY: &str = "http";
 const WS_KEY: &str = "ws";
+const BUSINESS_KEY: &str = "business";
 
 impl Inner {
-    fn new(ws: Ws, http: Http) -> Self {
+    fn new(ws: Ws, business: Ws, http: Http) -> Self {
         let mut svcs = ReadyCache::default();
         svcs.push(WS_KEY, Either::B(ws));
+        svcs.push(BUSINESS_KEY, Either::B(business));
         svcs.push(HTTP_KEY, Either::A(http));
         Inner { svcs }
     }
@@ -168,6 +188,7 @@ impl Service<OkxRequest> for Inner {
         let key = match &req {
             OkxRequest::Http(_) => HTTP_KEY,
             OkxRequest::Ws(_) => WS_KEY,
+            OkxRequest::Business(_) => BUSINESS_KEY,
         };
         self.svcs
             .call_ready(&key, req)
@@ -183,9 +204,9 @@ pub struct Okx {
 }
 
 impl Okx {
-    fn new(ws: Ws, http: Http, cap: usize) -> Self {
+    fn new(ws: Ws, business: Ws, http: Http, cap: usize) -> Self {
         Self {
-            inner: Buffer::new(Inner::new(ws, http), cap),
+            inner: Buffer::new(Inner::new(ws, business, http), cap),
         }
     }
 
@@ -213,3 +234,61 @@ impl Service<OkxRequest> for Okx {
         self.inner.call(req).map_err(ExchangeError::layer).boxed()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exc_core::{
+        types::{
+            trading::{AmendOrder, CancelOrder, OrderId, Place, PlaceOrderOptions},
+            Trigger,
+        },
+        Adaptor,
+    };
+    use rust_decimal::Decimal;
+    use tower::retry::Policy;
+
+    fn place(place: Place, client_id: Option<&str>) -> OkxRequest {
+        let mut opts = PlaceOrderOptions::new("BTC-USDT-SWAP");
+        opts.with_client_id(client_id);
+        OkxRequest::from_request(place.into_request(&opts)).unwrap()
+    }
+
+    #[test]
+    fn test_place_order_idempotency() {
+        let limit = Place::with_size(Decimal::ONE).limit(Decimal::new(100, 0));
+        assert!(!place(limit, None).is_idempotent());
+        assert!(place(limit, Some("my-order")).is_idempotent());
+        let stop = Place::with_size(Decimal::ONE).stop_market(Trigger::new(Decimal::new(90, 0)));
+        assert!(place(stop, Some("my-stop")).is_idempotent());
+
+        // A place-order without client id is never retried
+        // (unless a client id is generated for it).
+        let OkxRequest::Http(req) = place(stop, None) else {
+            panic!("expecting an http request");
+        };
+        let policy = Idempotent(
+            RetryPolicy::<HttpRequest, HttpResponse, ()>::default()
+                .retry_on::<ExchangeError, _>(|_: &ExchangeError| true),
+        );
+        let retried = Policy::<_, _, ExchangeError>::clone_request(&policy, &req).is_some();
+        assert_eq!(retried, cfg!(feature = "prefer-client-id"));
+    }
+
+    #[test]
+    fn test_algo_order_routing() {
+        let algo = crate::utils::algo::algo_order_id("681096944655273984");
+        let cancel = CancelOrder::new("BTC-USDT-SWAP", algo.clone());
+        assert!(matches!(
+            OkxRequest::from_request(cancel).unwrap(),
+            OkxRequest::Http(_)
+        ));
+        let cancel = CancelOrder::new("BTC-USDT-SWAP", OrderId::from("1".to_string()));
+        assert!(matches!(
+            OkxRequest::from_request(cancel).unwrap(),
+            OkxRequest::Ws(_)
+        ));
+        let amend = AmendOrder::new("BTC-USDT-SWAP", algo, Decimal::ONE, Decimal::ONE);
+        assert!(OkxRequest::from_request(amend).is_err());
+    }
+}
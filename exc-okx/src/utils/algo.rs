@@ -0,0 +1,31 @@
+use exc_core::{types::OrderId, Str};
+
+/// The prefix tagging the ids of algo orders, which cannot appear in the ids
+/// of the normal orders (OKX only accepts alphanumeric client ids).
+const ALGO_ID_PREFIX: &str = "algo:";
+
+/// Tag the algo id so that it can be routed to the algo order api.
+pub(crate) fn algo_order_id(algo_id: &str) -> OrderId {
+    OrderId::from(Str::new(format!("{ALGO_ID_PREFIX}{algo_id}")))
+}
+
+/// Get the algo id if the order id is a tagged one.
+pub(crate) fn algo_id(id: &OrderId) -> Option<&str> {
+    id.as_str().strip_prefix(ALGO_ID_PREFIX)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_algo_id() {
+        let id = algo_order_id("681096944655273984");
+        assert_eq!(id.as_str(), "algo:681096944655273984");
+        assert_eq!(algo_id(&id), Some("681096944655273984"));
+        assert_eq!(
+            algo_id(&OrderId::from("681096944655273984".to_string())),
+            None
+        );
+    }
+}
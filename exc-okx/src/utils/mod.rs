@@ -12,3 +12,9 @@ pub mod period;
 
 /// Parse Instrument Tag.
 pub mod inst_tag;
+
+/// Order placement arguments.
+pub(crate) mod place;
+
+/// Algo order ids.
+pub(crate) mod algo;
@@ -0,0 +1,85 @@
+use exc_core::{
+    types::{MarginMode, PlaceOrderOptions, PositionSide},
+    ExchangeError, Str,
+};
+
+/// Position-related arguments of an order.
+#[derive(Debug, Clone)]
+pub(crate) struct PositionArgs {
+    /// Trade mode (`tdMode`).
+    pub(crate) td_mode: Str,
+    /// Position side (`posSide`).
+    pub(crate) pos_side: Str,
+    /// Reduce only.
+    pub(crate) reduce_only: bool,
+}
+
+impl PositionArgs {
+    /// Resolve the position-related arguments from the options.
+    /// The typed margin mode takes precedence over the custom `tdMode` option,
+    /// and they must not conflict with each other.
+    pub(crate) fn new(opts: &PlaceOrderOptions) -> Result<Self, ExchangeError> {
+        let typed = opts.margin_mode().map(|mode| match mode {
+            MarginMode::Cross => "cross",
+            MarginMode::Isolated => "isolated",
+            MarginMode::Cash => "cash",
+        });
+        let custom = opts.custom().get("tdMode").map(|s| s.as_str());
+        let td_mode = match (typed, custom) {
+            (Some(typed), Some(custom)) if typed != custom => {
+                return Err(ExchangeError::Other(anyhow::anyhow!(
+                    "the margin mode `{typed}` conflicts with the custom `tdMode={custom}`"
+                )));
+            }
+            (Some(mode), _) | (None, Some(mode)) => mode,
+            (None, None) => "cross",
+        };
+        let pos_side = match opts.position_side() {
+            PositionSide::Net => "net",
+            PositionSide::Long => "long",
+            PositionSide::Short => "short",
+        };
+        let reduce_only = opts.reduce_only();
+        if td_mode == "cash" && (reduce_only || pos_side != "net") {
+            return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                "cash orders cannot be reduce-only or on a position side"
+            )));
+        }
+        if reduce_only && pos_side != "net" {
+            return Err(ExchangeError::Unsupported(anyhow::anyhow!(
+                "reduce-only orders are only available in net mode on okx"
+            )));
+        }
+        Ok(Self {
+            td_mode: Str::new(td_mode),
+            pos_side: Str::new_inline(pos_side),
+            reduce_only,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_position_args() {
+        let mut opts = PlaceOrderOptions::new("BTC-USDT-SWAP");
+        let args = PositionArgs::new(&opts).unwrap();
+        assert_eq!(args.td_mode, "cross");
+        assert_eq!(args.pos_side, "net");
+        opts.with_margin_mode(MarginMode::Isolated)
+            .with_reduce_only(true);
+        let args = PositionArgs::new(&opts).unwrap();
+        assert_eq!(args.td_mode, "isolated");
+        assert!(args.reduce_only);
+        opts.with_position_side(PositionSide::Long);
+        assert!(matches!(
+            PositionArgs::new(&opts),
+            Err(ExchangeError::Unsupported(_))
+        ));
+        opts.with_position_side(PositionSide::Net)
+            .insert("tdMode", "cross");
+        assert!(PositionArgs::new(&opts).is_err());
+    }
+}
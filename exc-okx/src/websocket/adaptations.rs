@@ -1,21 +1,32 @@
 use exc_core::{
     types::{
         instrument::{InstrumentMeta, SubscribeInstruments},
-        trading::{CancelOrder, OrderId, PlaceOrder},
-        BidAsk, Canceled, OrderUpdate, Placed, SubscribeBidAsk, SubscribeOrders, SubscribeTrades,
-        Trade,
+        trading::{
+            AmendOrder, Amended, CancelOrder, CancelOrders, OrderId, PlaceOrder, PlaceOrders,
+        },
+        Balances, BidAsk, Canceled, CandleUpdate, CurrentFundingRate, Liquidation, MarkPrice,
+        OpenInterest, OptionGreeks, OrderBookUpdate, OrderUpdate, Placed, Position,
+        SubscribeBalances, SubscribeBidAsk, SubscribeCandles, SubscribeFundingRate,
+        SubscribeLiquidations, SubscribeMarkPrice, SubscribeOpenInterest, SubscribeOptionGreeks,
+        SubscribeOrderBookUpdates, SubscribeOrders, SubscribePositions, SubscribeTrades, Trade,
     },
     Adaptor, ExchangeError,
 };
 use futures::{future::ready, stream::iter, FutureExt, StreamExt, TryStreamExt};
 use time::OffsetDateTime;
 
-use crate::{error::OkxError, utils::inst_tag::parse_inst_tag};
+use crate::{
+    error::OkxError,
+    utils::{inst_tag::parse_inst_tag, period::period_to_bar},
+};
 
 use super::{
     types::{
         messages::{
-            event::{order::OkxOrder, Event, OkxInstrumentMeta, TradeResponse},
+            event::{
+                order::OkxOrder, Event, OkxAccount, OkxInstrumentMeta, OkxPosition, OrderData,
+                TradeResponse,
+            },
             Args,
         },
         response::StatusKind,
@@ -126,12 +137,112 @@ impl Adaptor<SubscribeOrders> for Request {
     }
 }
 
+impl Adaptor<SubscribeBalances> for Request {
+    fn from_request(_req: SubscribeBalances) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::subscribe(Args::subscribe_account()))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeBalances as exc_core::Request>::Response, ExchangeError> {
+        match resp {
+            Response::Error(err) => Err(ExchangeError::Other(anyhow::anyhow!("status: {err}"))),
+            Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+                "invalid response kind"
+            ))),
+            Response::Streaming(stream) => {
+                let stream =
+                    stream
+                        .skip(1)
+                        .filter_map(|frame| {
+                            ready(match frame {
+                                Ok(frame) => frame.into_change().map(Ok),
+                                Err(err) => Some(Err(err)),
+                            })
+                        })
+                        .flat_map(|change| match change {
+                            Ok(change) => iter(change.deserialize_data::<OkxAccount>())
+                                .filter_map(|m| match m {
+                                    Ok(m) => ready(Some(Ok(Balances::from(m)))),
+                                    Err(err) => {
+                                        error!(%err, "deserialize account error, skipped.");
+                                        ready(None)
+                                    }
+                                })
+                                .left_stream(),
+                            Err(err) => futures::stream::once(async move {
+                                Err(ExchangeError::Other(err.into()))
+                            })
+                            .right_stream(),
+                        })
+                        .boxed();
+                Ok(stream)
+            }
+        }
+    }
+}
+
+impl Adaptor<SubscribePositions> for Request {
+    fn from_request(req: SubscribePositions) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::subscribe(Args::subscribe_positions(
+            req.instrument.as_deref(),
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribePositions as exc_core::Request>::Response, ExchangeError> {
+        match resp {
+            Response::Error(err) => Err(ExchangeError::Other(anyhow::anyhow!("status: {err}"))),
+            Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+                "invalid response kind"
+            ))),
+            Response::Streaming(stream) => {
+                let stream =
+                    stream
+                        .skip(1)
+                        .filter_map(|frame| {
+                            ready(match frame {
+                                Ok(frame) => frame.into_change().map(Ok),
+                                Err(err) => Some(Err(err)),
+                            })
+                        })
+                        .flat_map(|change| match change {
+                            Ok(change) => iter(change.deserialize_data::<OkxPosition>())
+                                .filter_map(|m| {
+                                    match m.map_err(OkxError::from).and_then(Position::try_from) {
+                                        Ok(m) => ready(Some(Ok(m))),
+                                        Err(err) => {
+                                            error!(%err, "deserialize position error, skipped.");
+                                            ready(None)
+                                        }
+                                    }
+                                })
+                                .left_stream(),
+                            Err(err) => futures::stream::once(async move {
+                                Err(ExchangeError::Other(err.into()))
+                            })
+                            .right_stream(),
+                        })
+                        .boxed();
+                Ok(stream)
+            }
+        }
+    }
+}
+
 impl Adaptor<PlaceOrder> for Request {
     fn from_request(req: PlaceOrder) -> Result<Self, ExchangeError>
     where
         Self: Sized,
     {
-        Ok(Self::order(&req))
+        Self::order(&req)
     }
 
     fn into_response(
@@ -169,15 +280,9 @@ impl Adaptor<PlaceOrder> for Request {
                         Err(OkxError::Api(StatusKind::EmptyResponse))
                     }
                 } else if let Some(data) = data.pop() {
-                    Err(OkxError::Api(StatusKind::Other(anyhow::anyhow!(
-                        "code={} msg={}",
-                        data.s_code,
-                        data.s_msg
-                    ))))
+                    Err(OkxError::api_code(&data.s_code, &data.s_msg))
                 } else {
-                    Err(OkxError::Api(StatusKind::Other(anyhow::anyhow!(
-                        "code={code} msg={msg}"
-                    ))))
+                    Err(OkxError::api_code(&code, &msg))
                 }
             } else {
                 Err(OkxError::UnexpectedDataType(anyhow::anyhow!("{event:?}")))
@@ -224,15 +329,9 @@ impl Adaptor<CancelOrder> for Request {
                         Err(OkxError::Api(StatusKind::EmptyResponse))
                     }
                 } else if let Some(data) = data.pop() {
-                    Err(OkxError::Api(StatusKind::Other(anyhow::anyhow!(
-                        "code={} msg={}",
-                        data.s_code,
-                        data.s_msg
-                    ))))
+                    Err(OkxError::api_code(&data.s_code, &data.s_msg))
                 } else {
-                    Err(OkxError::Api(StatusKind::Other(anyhow::anyhow!(
-                        "code={code} msg={msg}"
-                    ))))
+                    Err(OkxError::api_code(&code, &msg))
                 }
             } else {
                 Err(OkxError::UnexpectedDataType(anyhow::anyhow!("{event:?}")))
@@ -246,6 +345,189 @@ impl Adaptor<CancelOrder> for Request {
     }
 }
 
+impl Adaptor<AmendOrder> for Request {
+    fn from_request(req: AmendOrder) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        Ok(Self::amend_order(&req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<AmendOrder as exc_core::Request>::Response, ExchangeError> {
+        let resp = resp.into_unary().map_err(OkxError::Api)?;
+
+        Ok(async move {
+            let event = resp.await?.inner;
+            let id = if let Event::TradeResponse(TradeResponse::AmendOrder {
+                code,
+                msg,
+                mut data,
+                ..
+            }) = event
+            {
+                if code == "0" {
+                    if let Some(data) = data.pop() {
+                        #[cfg(not(feature = "prefer-client-id"))]
+                        {
+                            Ok(OrderId::from(data.ord_id))
+                        }
+                        #[cfg(feature = "prefer-client-id")]
+                        if data.cl_ord_id.is_empty() {
+                            Err(OkxError::MissingClientId)
+                        } else {
+                            Ok(OrderId::from(data.cl_ord_id))
+                        }
+                    } else {
+                        Err(OkxError::Api(StatusKind::EmptyResponse))
+                    }
+                } else if let Some(data) = data.pop() {
+                    Err(OkxError::api_code(&data.s_code, &data.s_msg))
+                } else {
+                    Err(OkxError::api_code(&code, &msg))
+                }
+            } else {
+                Err(OkxError::UnexpectedDataType(anyhow::anyhow!("{event:?}")))
+            }?;
+            Ok(Amended {
+                id,
+                order: None,
+                ts: OffsetDateTime::now_utc(),
+            })
+        }
+        .boxed())
+    }
+}
+
+/// The maximum number of orders in a batch request,
+/// larger batches are rejected with [`ExchangeError::Unsupported`].
+const MAX_BATCH_SIZE: usize = 20;
+
+fn check_batch_size(len: usize) -> Result<(), ExchangeError> {
+    if len == 0 {
+        Err(ExchangeError::Other(anyhow::anyhow!("empty batch")))
+    } else if len > MAX_BATCH_SIZE {
+        Err(ExchangeError::Unsupported(anyhow::anyhow!(
+            "too many orders in a batch: {len} > {MAX_BATCH_SIZE}, split the batch or send the orders one by one"
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Split the results of a batch operation by `sCode`.
+fn batch_results(
+    code: String,
+    msg: String,
+    data: Vec<OrderData>,
+) -> Result<Vec<Result<OrderData, ExchangeError>>, OkxError> {
+    if data.is_empty() {
+        if code == "0" {
+            return Err(OkxError::Api(StatusKind::EmptyResponse));
+        } else {
+            return Err(OkxError::api_code(&code, &msg));
+        }
+    }
+    Ok(data
+        .into_iter()
+        .map(|data| {
+            if data.s_code == "0" {
+                Ok(data)
+            } else {
+                Err(OkxError::api_code(&data.s_code, &data.s_msg).into())
+            }
+        })
+        .collect())
+}
+
+impl Adaptor<PlaceOrders> for Request {
+    fn from_request(req: PlaceOrders) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        check_batch_size(req.0.len())?;
+        Self::batch_orders(&req)
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<PlaceOrders as exc_core::Request>::Response, ExchangeError> {
+        let resp = resp.into_unary().map_err(OkxError::Api)?;
+
+        Ok(async move {
+            let event = resp.await?.inner;
+            let results =
+                if let Event::TradeResponse(TradeResponse::BatchOrders {
+                    code, msg, data, ..
+                }) = event
+                {
+                    batch_results(code, msg, data)
+                } else {
+                    Err(OkxError::UnexpectedDataType(anyhow::anyhow!("{event:?}")))
+                }?;
+            let ts = OffsetDateTime::now_utc();
+            Ok(results
+                .into_iter()
+                .map(|res| {
+                    let data = res?;
+                    #[cfg(not(feature = "prefer-client-id"))]
+                    let id = OrderId::from(data.ord_id);
+                    #[cfg(feature = "prefer-client-id")]
+                    let id = if data.cl_ord_id.is_empty() {
+                        return Err(OkxError::MissingClientId.into());
+                    } else {
+                        OrderId::from(data.cl_ord_id)
+                    };
+                    Ok(Placed {
+                        id,
+                        order: None,
+                        ts,
+                    })
+                })
+                .collect())
+        }
+        .boxed())
+    }
+}
+
+impl Adaptor<CancelOrders> for Request {
+    fn from_request(req: CancelOrders) -> Result<Self, ExchangeError>
+    where
+        Self: Sized,
+    {
+        check_batch_size(req.0.len())?;
+        Ok(Self::batch_cancel_orders(&req))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<CancelOrders as exc_core::Request>::Response, ExchangeError> {
+        let resp = resp.into_unary().map_err(OkxError::Api)?;
+
+        Ok(async move {
+            let event = resp.await?.inner;
+            let results = if let Event::TradeResponse(TradeResponse::BatchCancelOrders {
+                code,
+                msg,
+                data,
+                ..
+            }) = event
+            {
+                batch_results(code, msg, data)
+            } else {
+                Err(OkxError::UnexpectedDataType(anyhow::anyhow!("{event:?}")))
+            }?;
+            let ts = OffsetDateTime::now_utc();
+            Ok(results
+                .into_iter()
+                .map(|res| res.map(|_| Canceled { ts, order: None }))
+                .collect())
+        }
+        .boxed())
+    }
+}
+
 impl Adaptor<SubscribeTrades> for Request {
     fn from_request(req: SubscribeTrades) -> Result<Self, ExchangeError> {
         Ok(Self::subscribe_trades(&req.instrument))
@@ -280,6 +562,237 @@ impl Adaptor<SubscribeTrades> for Request {
     }
 }
 
+impl Adaptor<SubscribeMarkPrice> for Request {
+    fn from_request(req: SubscribeMarkPrice) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Args::subscribe_mark_price(&req.instrument)))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeMarkPrice as exc_core::Request>::Response, ExchangeError> {
+        match resp {
+            Response::Streaming(stream) => {
+                let stream = stream
+                    .skip(1)
+                    .flat_map(|frame| {
+                        let res: Result<Vec<Result<MarkPrice, OkxError>>, OkxError> =
+                            frame.and_then(|f| f.inner.try_into());
+                        match res {
+                            Ok(prices) => futures::stream::iter(prices).left_stream(),
+                            Err(err) => {
+                                futures::stream::once(async move { Err(err) }).right_stream()
+                            }
+                        }
+                    })
+                    .map_err(ExchangeError::from)
+                    .boxed();
+                Ok(stream)
+            }
+            Response::Error(status) => Err(OkxError::Api(status).into()),
+            Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+                "invalid response kind"
+            ))),
+        }
+    }
+}
+
+impl Adaptor<SubscribeFundingRate> for Request {
+    fn from_request(req: SubscribeFundingRate) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Args::subscribe_funding_rate(
+            &req.instrument,
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeFundingRate as exc_core::Request>::Response, ExchangeError> {
+        match resp {
+            Response::Streaming(stream) => {
+                let stream = stream
+                    .skip(1)
+                    .flat_map(|frame| {
+                        let res: Result<Vec<Result<CurrentFundingRate, OkxError>>, OkxError> =
+                            frame.and_then(|f| f.inner.try_into());
+                        match res {
+                            Ok(rates) => futures::stream::iter(rates).left_stream(),
+                            Err(err) => {
+                                futures::stream::once(async move { Err(err) }).right_stream()
+                            }
+                        }
+                    })
+                    .map_err(ExchangeError::from)
+                    .boxed();
+                Ok(stream)
+            }
+            Response::Error(status) => Err(OkxError::Api(status).into()),
+            Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+                "invalid response kind"
+            ))),
+        }
+    }
+}
+
+impl Adaptor<SubscribeOpenInterest> for Request {
+    fn from_request(req: SubscribeOpenInterest) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Args::subscribe_open_interest(
+            &req.instrument,
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeOpenInterest as exc_core::Request>::Response, ExchangeError> {
+        match resp {
+            Response::Streaming(stream) => {
+                let stream = stream
+                    .skip(1)
+                    .flat_map(|frame| {
+                        let res: Result<Vec<Result<OpenInterest, OkxError>>, OkxError> =
+                            frame.and_then(|f| f.inner.try_into());
+                        match res {
+                            Ok(oi) => futures::stream::iter(oi).left_stream(),
+                            Err(err) => {
+                                futures::stream::once(async move { Err(err) }).right_stream()
+                            }
+                        }
+                    })
+                    .map_err(ExchangeError::from)
+                    .boxed();
+                Ok(stream)
+            }
+            Response::Error(status) => Err(OkxError::Api(status).into()),
+            Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+                "invalid response kind"
+            ))),
+        }
+    }
+}
+
+impl Adaptor<SubscribeOptionGreeks> for Request {
+    fn from_request(req: SubscribeOptionGreeks) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Args::subscribe_option_summary(&req.family)))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeOptionGreeks as exc_core::Request>::Response, ExchangeError> {
+        match resp {
+            Response::Streaming(stream) => {
+                let stream = stream
+                    .skip(1)
+                    .flat_map(|frame| {
+                        let res: Result<Vec<Result<OptionGreeks, OkxError>>, OkxError> =
+                            frame.and_then(|f| f.inner.try_into());
+                        match res {
+                            Ok(greeks) => futures::stream::iter(greeks).left_stream(),
+                            Err(err) => {
+                                futures::stream::once(async move { Err(err) }).right_stream()
+                            }
+                        }
+                    })
+                    .map_err(ExchangeError::from)
+                    .boxed();
+                Ok(stream)
+            }
+            Response::Error(status) => Err(OkxError::Api(status).into()),
+            Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+                "invalid response kind"
+            ))),
+        }
+    }
+}
+
+/// Get the instrument type from the name of the instrument.
+fn inst_type(inst: &str) -> &'static str {
+    if inst.ends_with("-SWAP") {
+        return "SWAP";
+    }
+    match inst.split('-').count() {
+        2 => "MARGIN",
+        3 => "FUTURES",
+        _ => "OPTION",
+    }
+}
+
+/// The `liquidation-orders` channel is subscribed by instrument type,
+/// so the stream yields the liquidations of all the instruments of the same type
+/// as the requested one, and only one of them can be subscribed on a connection.
+impl Adaptor<SubscribeLiquidations> for Request {
+    fn from_request(req: SubscribeLiquidations) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Args::subscribe_liquidations(inst_type(
+            &req.instrument,
+        ))))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeLiquidations as exc_core::Request>::Response, ExchangeError> {
+        match resp {
+            Response::Streaming(stream) => {
+                let stream = stream
+                    .skip(1)
+                    .flat_map(|frame| {
+                        let res: Result<Vec<Result<Liquidation, OkxError>>, OkxError> =
+                            frame.and_then(|f| f.inner.try_into());
+                        match res {
+                            Ok(liquidations) => futures::stream::iter(liquidations).left_stream(),
+                            Err(err) => {
+                                futures::stream::once(async move { Err(err) }).right_stream()
+                            }
+                        }
+                    })
+                    .map_err(ExchangeError::from)
+                    .boxed();
+                Ok(stream)
+            }
+            Response::Error(status) => Err(OkxError::Api(status).into()),
+            Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+                "invalid response kind"
+            ))),
+        }
+    }
+}
+
+impl Adaptor<SubscribeCandles> for Request {
+    fn from_request(req: SubscribeCandles) -> Result<Self, ExchangeError> {
+        let bar = period_to_bar(&req.period).ok_or_else(|| {
+            ExchangeError::Other(anyhow::anyhow!("unsupported period: {}", req.period))
+        })?;
+        Ok(Self::subscribe(Args::subscribe_candles(
+            &req.instrument,
+            bar,
+        )))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeCandles as exc_core::Request>::Response, ExchangeError> {
+        match resp {
+            Response::Streaming(stream) => {
+                let stream = stream
+                    .skip(1)
+                    .flat_map(|frame| {
+                        let res: Result<Vec<Result<CandleUpdate, OkxError>>, OkxError> =
+                            frame.and_then(|f| f.inner.try_into());
+                        match res {
+                            Ok(candles) => futures::stream::iter(candles).left_stream(),
+                            Err(err) => {
+                                futures::stream::once(async move { Err(err) }).right_stream()
+                            }
+                        }
+                    })
+                    .map_err(ExchangeError::from)
+                    .boxed();
+                Ok(stream)
+            }
+            Response::Error(status) => Err(OkxError::Api(status).into()),
+            Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+                "invalid response kind"
+            ))),
+        }
+    }
+}
+
 impl Adaptor<SubscribeBidAsk> for Request {
     fn from_request(req: SubscribeBidAsk) -> Result<Self, ExchangeError> {
         Ok(Self::subscribe_bid_ask(&req.instrument))
@@ -313,3 +826,37 @@ impl Adaptor<SubscribeBidAsk> for Request {
         }
     }
 }
+
+impl Adaptor<SubscribeOrderBookUpdates> for Request {
+    fn from_request(req: SubscribeOrderBookUpdates) -> Result<Self, ExchangeError> {
+        Ok(Self::subscribe(Args::subscribe_order_book(&req.instrument)))
+    }
+
+    fn into_response(
+        resp: Self::Response,
+    ) -> Result<<SubscribeOrderBookUpdates as exc_core::Request>::Response, ExchangeError> {
+        match resp {
+            Response::Streaming(stream) => {
+                let stream = stream
+                    .skip(1)
+                    .flat_map(|frame| {
+                        let res: Result<Vec<Result<OrderBookUpdate, OkxError>>, OkxError> =
+                            frame.and_then(|f| f.inner.try_into());
+                        match res {
+                            Ok(updates) => futures::stream::iter(updates).left_stream(),
+                            Err(err) => {
+                                futures::stream::once(async move { Err(err) }).right_stream()
+                            }
+                        }
+                    })
+                    .map_err(ExchangeError::from)
+                    .boxed();
+                Ok(stream)
+            }
+            Response::Error(status) => Err(OkxError::Api(status).into()),
+            Response::Reconnected => Err(ExchangeError::Other(anyhow::anyhow!(
+                "invalid response kind"
+            ))),
+        }
+    }
+}
@@ -14,4 +14,8 @@ pub use types::{request::Request, response::Response};
 
 impl ExcRequest for Request {
     type Response = Response;
+
+    fn is_idempotent(&self) -> bool {
+        self.idempotent
+    }
 }
@@ -8,6 +8,7 @@ use crate::websocket::types::messages::event::ResponseKind;
 use crate::websocket::types::response::StatusKind;
 use crate::websocket::types::{request::Request, response::Response};
 use exc_core::transport::websocket::connector::WsConnector;
+use exc_core::util::clock_sync::Clock;
 use futures::future::BoxFuture;
 use futures::FutureExt;
 use http::Uri;
@@ -20,14 +21,16 @@ pub(crate) struct Connect {
     inner: WsConnector,
     ping_timeout: Duration,
     key: Option<Key>,
+    clock: Clock,
 }
 
 impl Connect {
-    fn new(inner: WsConnector, ping_timeout: Duration, key: Option<&Key>) -> Self {
+    fn new(inner: WsConnector, ping_timeout: Duration, key: Option<&Key>, clock: &Clock) -> Self {
         Self {
             inner,
             ping_timeout,
             key: key.cloned(),
+            clock: clock.clone(),
         }
     }
 }
@@ -48,6 +51,7 @@ impl tower::Service<Uri> for Connect {
         let conn = self.inner.call(req);
         let ping_timeout = self.ping_timeout;
         let key = self.key.clone();
+        let clock = self.clock.clone();
         async move {
             let conn = conn.await?;
             let mut svc = Protocol::init(conn, ping_timeout)
@@ -59,7 +63,7 @@ impl tower::Service<Uri> for Connect {
             if let Some(key) = key {
                 svc.ready().await?;
                 let resp = svc
-                    .call(Request::login(key)?)
+                    .call(Request::login(key, &clock)?)
                     .await?
                     .into_unary()
                     .map_err(OkxError::Api)?
@@ -102,6 +106,7 @@ impl Connection {
                 WsConnector::default(),
                 endpoint.ping_timeout,
                 endpoint.login.as_ref(),
+                &endpoint.clock,
             ))
             .boxed();
         let conn =
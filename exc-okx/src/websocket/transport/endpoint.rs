@@ -1,5 +1,6 @@
 use super::{channel::Channel, connection::Connection};
 use crate::{error::OkxError, key::OkxKey as Key};
+use exc_core::util::clock_sync::Clock;
 use http::Uri;
 use std::time::Duration;
 use tower::{buffer::Buffer, timeout::Timeout, ServiceExt};
@@ -9,14 +10,17 @@ const DEFAULT_PING_TIMEOUT: Duration = Duration::from_secs(15);
 
 /// Okx websocket endpoint.
 /// Builder for channels.
+#[derive(Clone)]
 pub struct Endpoint {
     pub(crate) testing: bool,
     pub(crate) aws: bool,
+    pub(crate) business: bool,
     pub(crate) request_timeout: Option<Duration>,
     pub(crate) connection_timeout: Option<Duration>,
     pub(crate) ping_timeout: Duration,
     pub(crate) buffer_size: Option<usize>,
     pub(crate) login: Option<Key>,
+    pub(crate) clock: Clock,
 }
 
 impl Endpoint {
@@ -56,12 +60,24 @@ impl Endpoint {
         self
     }
 
+    /// Switch to the business channel (e.g. for candles).
+    pub fn business(&mut self, enable: bool) -> &mut Self {
+        self.business = enable;
+        self
+    }
+
     /// Switch to private channel.
     pub fn private(&mut self, key: Key) -> &mut Self {
         self.login = Some(key);
         self
     }
 
+    /// Set the clock used to sign the login request.
+    pub fn clock(&mut self, clock: Clock) -> &mut Self {
+        self.clock = clock;
+        self
+    }
+
     /// Create a okx websocket channel.
     pub fn connect(&self) -> Channel {
         let svc = match self.request_timeout {
@@ -87,6 +103,15 @@ impl Endpoint {
 
     /// Get current uri.
     pub fn uri(&self) -> Uri {
+        if self.business {
+            return match (self.testing, self.aws) {
+                (true, _) => {
+                    Uri::from_static("wss://wspap.okx.com:8443/ws/v5/business?brokerId=9999")
+                }
+                (false, true) => Uri::from_static("wss://wsaws.okx.com:8443/ws/v5/business"),
+                (false, false) => Uri::from_static("wss://ws.okx.com:8443/ws/v5/business"),
+            };
+        }
         match (self.login.is_some(), self.testing, self.aws) {
             (true, true, _) => {
                 Uri::from_static("wss://wspap.okx.com:8443/ws/v5/private?brokerId=9999")
@@ -107,11 +132,13 @@ impl Default for Endpoint {
         Self {
             aws: false,
             testing: false,
+            business: false,
             request_timeout: None,
             connection_timeout: None,
             buffer_size: None,
             ping_timeout: DEFAULT_PING_TIMEOUT,
             login: None,
+            clock: Clock::default(),
         }
     }
 }
@@ -26,7 +26,10 @@ fn client_message_to_tag(msg: &WsRequest) -> String {
     match msg {
         WsRequest::Subscribe(args) | WsRequest::Unsubscribe(args) => args.to_tag(),
         WsRequest::Login(_) => LOGIN_TAG.to_string(),
-        WsRequest::Order(id, _) | WsRequest::CancelOrder(id, _) => id.clone(),
+        WsRequest::Order(id, _) | WsRequest::CancelOrder(id, _) | WsRequest::AmendOrder(id, _) => {
+            id.clone()
+        }
+        WsRequest::BatchOrders(id, _) | WsRequest::BatchCancelOrders(id, _) => id.clone(),
     }
 }
 
@@ -48,9 +51,11 @@ fn server_message_to_tag(msg: &Event) -> Option<String> {
             },
         },
         Event::TradeResponse(resp) => match resp {
-            TradeResponse::Order { id, .. } | TradeResponse::CancelOrder { id, .. } => {
-                Some(id.clone())
-            }
+            TradeResponse::Order { id, .. }
+            | TradeResponse::CancelOrder { id, .. }
+            | TradeResponse::AmendOrder { id, .. }
+            | TradeResponse::BatchOrders { id, .. }
+            | TradeResponse::BatchCancelOrders { id, .. } => Some(id.clone()),
         },
     }
 }
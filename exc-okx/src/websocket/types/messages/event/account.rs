@@ -0,0 +1,86 @@
+use exc_core::{
+    types::{Balance, Balances},
+    Asset,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+use time::OffsetDateTime;
+
+/// Okx balance details of a currency.
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxBalanceDetail {
+    /// Currency.
+    pub ccy: Asset,
+    /// Equity.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub eq: Option<Decimal>,
+    /// Cash balance.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub cash_bal: Option<Decimal>,
+    /// Available balance.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub avail_bal: Option<Decimal>,
+    /// Available equity.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub avail_eq: Option<Decimal>,
+    /// Frozen balance.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub frozen_bal: Option<Decimal>,
+    /// Update time.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    #[serde(rename = "uTime")]
+    pub update_ts: OffsetDateTime,
+}
+
+impl From<&OkxBalanceDetail> for Balance {
+    fn from(detail: &OkxBalanceDetail) -> Self {
+        let locked = detail.frozen_bal.unwrap_or_default().normalize();
+        let total = detail
+            .eq
+            .or(detail.cash_bal)
+            .unwrap_or_default()
+            .normalize();
+        let free = detail
+            .avail_bal
+            .or(detail.avail_eq)
+            .unwrap_or(total - locked)
+            .normalize();
+        Self {
+            free,
+            locked,
+            total,
+        }
+    }
+}
+
+/// Okx account balance (from both the `account` channel and the REST API).
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxAccount {
+    /// Update time.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    #[serde(rename = "uTime")]
+    pub update_ts: OffsetDateTime,
+    /// Total equity in USD.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub total_eq: Option<Decimal>,
+    /// Details.
+    pub details: Vec<OkxBalanceDetail>,
+}
+
+impl From<OkxAccount> for Balances {
+    fn from(account: OkxAccount) -> Self {
+        Self {
+            ts: account.update_ts,
+            balances: account
+                .details
+                .iter()
+                .map(|detail| (detail.ccy.clone(), Balance::from(detail)))
+                .collect(),
+        }
+    }
+}
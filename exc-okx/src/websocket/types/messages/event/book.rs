@@ -1,8 +1,10 @@
-use exc_core::types::BidAsk;
+use exc_core::types::{BidAsk, OrderBookUpdate, OrderBookUpdateKind};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 
+use super::Action;
+
 type Depth = [Decimal; 4];
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -17,13 +19,6 @@ pub(super) struct OkxBook {
     pub(super) seq_id: i64,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
-#[serde(rename_all = "camelCase")]
-pub(super) enum Side {
-    Buy,
-    Sell,
-}
-
 impl From<OkxBook> for BidAsk {
     fn from(value: OkxBook) -> Self {
         let bid = value.bids.first().map(|depth| (depth[0], depth[1]));
@@ -35,3 +30,23 @@ impl From<OkxBook> for BidAsk {
         }
     }
 }
+
+impl OkxBook {
+    pub(super) fn into_update(self, action: Action) -> OrderBookUpdate {
+        let kind = match action {
+            Action::Snapshot => OrderBookUpdateKind::Snapshot,
+            Action::Update => OrderBookUpdateKind::Update,
+        };
+        OrderBookUpdate {
+            ts: self.ts,
+            kind,
+            first_id: None,
+            last_id: u64::try_from(self.seq_id).ok(),
+            // `prevSeqId` is `-1` for snapshots.
+            prev_id: self.prev_seq_id.and_then(|id| u64::try_from(id).ok()),
+            checksum: self.checksum.map(|c| c as i32),
+            bids: self.bids.into_iter().map(|d| (d[0], d[1])).collect(),
+            asks: self.asks.into_iter().map(|d| (d[0], d[1])).collect(),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use exc_core::{types::Liquidation, Str};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use super::trade::Side;
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OkxLiquidations {
+    pub(super) inst_type: String,
+    pub(super) inst_id: Str,
+    pub(super) details: Vec<OkxLiquidationDetail>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OkxLiquidationDetail {
+    pub(super) side: Side,
+    pub(super) sz: Decimal,
+    pub(super) bk_px: Decimal,
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub(super) ts: OffsetDateTime,
+}
+
+impl OkxLiquidations {
+    pub(super) fn into_liquidations(self) -> impl Iterator<Item = Liquidation> {
+        let inst = self.inst_id;
+        self.details.into_iter().map(move |detail| {
+            let size = detail.sz.normalize();
+            Liquidation {
+                ts: detail.ts,
+                instrument: inst.clone(),
+                price: detail.bk_px.normalize(),
+                size: match detail.side {
+                    Side::Buy => size,
+                    Side::Sell => -size,
+                },
+            }
+        })
+    }
+}
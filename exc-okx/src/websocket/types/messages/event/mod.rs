@@ -1,23 +1,48 @@
-use crate::error::OkxError;
+use crate::{
+    error::OkxError,
+    http::types::response::{Candle as OkxCandle, FundingRate as OkxFundingRate},
+    utils::timestamp::millis_to_ts,
+};
 
-use self::{book::OkxBook, ticker::OkxTicker, trade::OkxTrade};
+use self::{
+    book::OkxBook,
+    liquidation::OkxLiquidations,
+    open_interest::OkxOpenInterest,
+    ticker::{OkxMarkPrice, OkxTicker},
+    trade::OkxTrade,
+};
 
 use super::Args;
-use exc_core::types::{ticker::Ticker, BidAsk, Trade};
+use exc_core::types::{
+    ticker::{MarkPrice, Ticker},
+    BidAsk, Candle, CandleUpdate, CurrentFundingRate, Liquidation, OpenInterest, OptionGreeks,
+    OrderBookUpdate, Trade,
+};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fmt;
 
-pub use self::{instrument::OkxInstrumentMeta, options::OkxOptionSummary};
+pub use self::{
+    account::OkxAccount, instrument::OkxInstrumentMeta, options::OkxOptionSummary,
+    position::OkxPosition,
+};
 
 mod book;
 mod instrument;
+mod liquidation;
+mod open_interest;
 mod ticker;
 mod trade;
 
 /// Order.
 pub mod order;
 
+/// Account.
+pub mod account;
+
+/// Position.
+pub mod position;
+
 /// Options message.
 pub mod options;
 
@@ -98,6 +123,39 @@ pub enum TradeResponse {
         /// Data.
         data: Vec<OrderData>,
     },
+    /// Amend order.
+    AmendOrder {
+        /// Id.
+        id: String,
+        /// Code.
+        code: String,
+        /// Msg.
+        msg: String,
+        /// Data.
+        data: Vec<OrderData>,
+    },
+    /// Batch orders.
+    BatchOrders {
+        /// Id.
+        id: String,
+        /// Code.
+        code: String,
+        /// Msg.
+        msg: String,
+        /// Data.
+        data: Vec<OrderData>,
+    },
+    /// Batch cancel orders.
+    BatchCancelOrders {
+        /// Id.
+        id: String,
+        /// Code.
+        code: String,
+        /// Msg.
+        msg: String,
+        /// Data.
+        data: Vec<OrderData>,
+    },
 }
 
 /// Action kind.
@@ -171,6 +229,127 @@ impl TryFrom<Event> for Vec<Result<Ticker, OkxError>> {
     }
 }
 
+impl TryFrom<Event> for Vec<Result<MarkPrice, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::Change(change) => Ok(change
+                .data
+                .into_iter()
+                .map(|v| {
+                    serde_json::from_value::<OkxMarkPrice>(v)
+                        .map(MarkPrice::from)
+                        .map_err(OkxError::from)
+                })
+                .collect()),
+            Event::Response(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+            Event::TradeResponse(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+        }
+    }
+}
+
+impl TryFrom<Event> for Vec<Result<CurrentFundingRate, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::Change(change) => Ok(change
+                .data
+                .into_iter()
+                .map(|v| {
+                    serde_json::from_value::<OkxFundingRate>(v)
+                        .map(CurrentFundingRate::from)
+                        .map_err(OkxError::from)
+                })
+                .collect()),
+            Event::Response(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+            Event::TradeResponse(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+        }
+    }
+}
+
+impl TryFrom<Event> for Vec<Result<OpenInterest, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::Change(change) => Ok(change
+                .data
+                .into_iter()
+                .map(|v| {
+                    serde_json::from_value::<OkxOpenInterest>(v)
+                        .map(OpenInterest::from)
+                        .map_err(OkxError::from)
+                })
+                .collect()),
+            Event::Response(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+            Event::TradeResponse(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+        }
+    }
+}
+
+impl TryFrom<Event> for Vec<Result<OptionGreeks, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::Change(change) => Ok(change
+                .data
+                .into_iter()
+                .map(|v| {
+                    serde_json::from_value::<OkxOptionSummary>(v)
+                        .map_err(OkxError::from)
+                        .and_then(OptionGreeks::try_from)
+                })
+                .collect()),
+            Event::Response(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+            Event::TradeResponse(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+        }
+    }
+}
+
+impl TryFrom<Event> for Vec<Result<Liquidation, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::Change(change) => {
+                let mut liquidations = Vec::new();
+                for v in change.data {
+                    match serde_json::from_value::<OkxLiquidations>(v) {
+                        Ok(data) => liquidations.extend(data.into_liquidations().map(Ok)),
+                        Err(err) => liquidations.push(Err(OkxError::from(err))),
+                    }
+                }
+                Ok(liquidations)
+            }
+            Event::Response(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+            Event::TradeResponse(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+        }
+    }
+}
+
 impl TryFrom<Event> for Vec<Result<Trade, OkxError>> {
     type Error = OkxError;
 
@@ -195,6 +374,43 @@ impl TryFrom<Event> for Vec<Result<Trade, OkxError>> {
     }
 }
 
+impl TryFrom<Event> for Vec<Result<CandleUpdate, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::Change(change) => Ok(change
+                .data
+                .into_iter()
+                .map(|v| {
+                    let c = serde_json::from_value::<OkxCandle>(v)?;
+                    let ts = millis_to_ts(c.0).ok_or_else(|| {
+                        OkxError::UnexpectedDataType(anyhow::anyhow!("cannot parse ts: {}", c.0))
+                    })?;
+                    Ok(CandleUpdate {
+                        candle: Candle {
+                            ts,
+                            open: c.1,
+                            high: c.2,
+                            low: c.3,
+                            close: c.4,
+                            volume: c.5,
+                        },
+                        // The `confirm` field.
+                        closed: !c.8.is_zero(),
+                    })
+                })
+                .collect()),
+            Event::Response(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+            Event::TradeResponse(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+        }
+    }
+}
+
 impl TryFrom<Event> for Vec<Result<BidAsk, OkxError>> {
     type Error = OkxError;
 
@@ -218,3 +434,30 @@ impl TryFrom<Event> for Vec<Result<BidAsk, OkxError>> {
         }
     }
 }
+
+impl TryFrom<Event> for Vec<Result<OrderBookUpdate, OkxError>> {
+    type Error = OkxError;
+
+    fn try_from(event: Event) -> Result<Self, Self::Error> {
+        match event {
+            Event::Change(change) => {
+                let action = change.action;
+                Ok(change
+                    .data
+                    .into_iter()
+                    .map(|v| {
+                        serde_json::from_value::<OkxBook>(v)
+                            .map(|book| book.into_update(action))
+                            .map_err(OkxError::from)
+                    })
+                    .collect())
+            }
+            Event::Response(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+            Event::TradeResponse(resp) => Err(OkxError::UnexpectedDataType(anyhow::anyhow!(
+                "response: {resp:?}"
+            ))),
+        }
+    }
+}
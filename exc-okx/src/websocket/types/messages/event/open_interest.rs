@@ -0,0 +1,29 @@
+use exc_core::types::OpenInterest;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+use time::OffsetDateTime;
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OkxOpenInterest {
+    pub(super) inst_type: String,
+    pub(super) inst_id: String,
+    pub(super) oi: Decimal,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub(super) oi_usd: Option<Decimal>,
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub(super) ts: OffsetDateTime,
+}
+
+impl From<OkxOpenInterest> for OpenInterest {
+    fn from(oi: OkxOpenInterest) -> Self {
+        Self {
+            ts: oi.ts,
+            size: oi.oi.normalize(),
+            value: oi.oi_usd.map(|v| v.normalize()),
+        }
+    }
+}
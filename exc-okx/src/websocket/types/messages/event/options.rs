@@ -1,8 +1,11 @@
+use exc_core::{symbol::ExcSymbol, types::OptionGreeks, Asset, Str};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, NoneAsEmptyString};
 use time::OffsetDateTime;
 
+use crate::error::OkxError;
+
 /// Option Summary.
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug)]
@@ -63,3 +66,68 @@ pub struct OkxOptionSummary {
     #[serde_as(as = "NoneAsEmptyString")]
     pub fwd_px: Option<Decimal>,
 }
+
+impl OkxOptionSummary {
+    /// Convert the instrument id to an [`ExcSymbol`].
+    pub fn to_exc_symbol(&self) -> Result<ExcSymbol, OkxError> {
+        let mut parts = self.inst_id.split('-');
+        let base = parts.next().and_then(|base| base.parse::<Asset>().ok());
+        let quote = parts.next().and_then(|quote| quote.parse::<Asset>().ok());
+        let (Some(base), Some(quote)) = (base, quote) else {
+            return Err(OkxError::FailedToBuildExcSymbol);
+        };
+        // The options of the `USD` families are settled in the base currency.
+        let quote = if quote.as_ref() == "USD" {
+            base.clone()
+        } else {
+            quote
+        };
+        let date = parts.next();
+        let price = parts.next();
+        parts
+            .next()
+            .and_then(|ty| match ty {
+                "c" | "C" => ExcSymbol::call_with_str(&base, &quote, date?, price?),
+                "p" | "P" => ExcSymbol::put_with_str(&base, &quote, date?, price?),
+                _ => None,
+            })
+            .ok_or(OkxError::FailedToBuildExcSymbol)
+    }
+}
+
+impl TryFrom<OkxOptionSummary> for OptionGreeks {
+    type Error = OkxError;
+
+    fn try_from(summary: OkxOptionSummary) -> Result<Self, Self::Error> {
+        // Use the greeks in BS mode (in units of the quote currency).
+        Ok(Self {
+            ts: summary.ts,
+            symbol: summary.to_exc_symbol()?,
+            instrument: Str::new(&summary.inst_id),
+            delta: summary.delta_b_s.map(|d| d.normalize()),
+            gamma: summary.gamma_b_s.map(|d| d.normalize()),
+            vega: summary.vega_b_s.map(|d| d.normalize()),
+            theta: summary.theta_b_s.map(|d| d.normalize()),
+            mark_iv: summary.mark_vol.map(|d| d.normalize()),
+            bid_iv: summary.bid_vol.map(|d| d.normalize()),
+            ask_iv: summary.ask_vol.map(|d| d.normalize()),
+            underlying_price: summary.fwd_px.map(|d| d.normalize()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_option_summary_to_greeks() -> anyhow::Result<()> {
+        let data = r#"{"askVol":"0.5625","bidVol":"0.4921","delta":"0.1047","deltaBS":"0.1176","fwdPx":"43600.83","gamma":"1.2493","gammaBS":"0.0001","instId":"BTC-USD-240126-52000-C","instType":"OPTION","lever":"312.52","markVol":"0.5277","realVol":"","theta":"-0.0015","thetaBS":"-67.02","ts":"1705574510106","uly":"BTC-USD","vega":"0.0002","vegaBS":"10.24","volLv":"0.4727"}"#;
+        let summary = serde_json::from_str::<OkxOptionSummary>(data)?;
+        let greeks = OptionGreeks::try_from(summary)?;
+        assert_eq!(greeks.symbol.to_string(), "O240126C52000:BTC-BTC");
+        assert_eq!(greeks.delta, Some(Decimal::new(1176, 4)));
+        assert!(greeks.underlying_price.is_some());
+        Ok(())
+    }
+}
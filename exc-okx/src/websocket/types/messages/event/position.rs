@@ -0,0 +1,106 @@
+use exc_core::{
+    types::{MarginMode, Position},
+    Str,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+use time::OffsetDateTime;
+
+use crate::error::OkxError;
+
+/// Okx Margin Mode.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum OkxMarginMode {
+    /// Cross.
+    Cross,
+    /// Isolated.
+    Isolated,
+}
+
+impl From<OkxMarginMode> for MarginMode {
+    fn from(mode: OkxMarginMode) -> Self {
+        match mode {
+            OkxMarginMode::Cross => Self::Cross,
+            OkxMarginMode::Isolated => Self::Isolated,
+        }
+    }
+}
+
+/// Okx Position Side.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum OkxPositionSide {
+    /// Net.
+    Net,
+    /// Long.
+    Long,
+    /// Short.
+    Short,
+}
+
+/// Okx Position (from both the `positions` channel and the REST API).
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxPosition {
+    /// Instrument type.
+    pub inst_type: String,
+    /// Instrument name.
+    pub inst_id: Str,
+    /// Margin mode.
+    pub mgn_mode: OkxMarginMode,
+    /// Position id.
+    pub pos_id: String,
+    /// Position side.
+    pub pos_side: OkxPositionSide,
+    /// Position size.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub pos: Option<Decimal>,
+    /// Average open price.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub avg_px: Option<Decimal>,
+    /// Unrealized PnL.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub upl: Option<Decimal>,
+    /// Leverage.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub lever: Option<Decimal>,
+    /// Mark price.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub mark_px: Option<Decimal>,
+    /// Liquidation price.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub liq_px: Option<Decimal>,
+    /// Margin currency.
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub ccy: Option<String>,
+    /// Update time.
+    #[serde(with = "crate::utils::timestamp_serde")]
+    #[serde(rename = "uTime")]
+    pub update_ts: OffsetDateTime,
+}
+
+impl TryFrom<OkxPosition> for Position {
+    type Error = OkxError;
+
+    fn try_from(position: OkxPosition) -> Result<Self, Self::Error> {
+        let size = position.pos.unwrap_or_default().normalize();
+        let size = match position.pos_side {
+            OkxPositionSide::Net => size,
+            OkxPositionSide::Long => size.abs(),
+            OkxPositionSide::Short => -size.abs(),
+        };
+        Ok(Self {
+            ts: position.update_ts,
+            instrument: position.inst_id,
+            size,
+            entry_price: position.avg_px.unwrap_or_default().normalize(),
+            mark_price: position.mark_px.map(|p| p.normalize()),
+            unrealized_pnl: position.upl.unwrap_or_default().normalize(),
+            leverage: position.lever.map(|l| l.normalize()),
+            margin_mode: position.mgn_mode.into(),
+        })
+    }
+}
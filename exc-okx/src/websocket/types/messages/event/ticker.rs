@@ -1,4 +1,4 @@
-use exc_core::types::ticker::Ticker;
+use exc_core::types::ticker::{MarkPrice, Ticker};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, NoneAsEmptyString};
@@ -58,3 +58,23 @@ impl From<OkxTicker> for Ticker {
         }
     }
 }
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct OkxMarkPrice {
+    pub(super) inst_type: String,
+    pub(super) inst_id: String,
+    pub(super) mark_px: Decimal,
+    #[serde(with = "crate::utils::timestamp_serde")]
+    pub(super) ts: OffsetDateTime,
+}
+
+impl From<OkxMarkPrice> for MarkPrice {
+    fn from(mark: OkxMarkPrice) -> Self {
+        Self {
+            ts: mark.ts,
+            price: mark.mark_px.normalize(),
+            index: None,
+        }
+    }
+}
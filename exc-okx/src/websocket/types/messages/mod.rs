@@ -46,6 +46,23 @@ impl Args {
         ]))
     }
 
+    /// Args for account subscription.
+    pub fn subscribe_account() -> Self {
+        Args(BTreeMap::from([(CHANNEL, Str::new_inline("account"))]))
+    }
+
+    /// Args for positions subscription.
+    pub fn subscribe_positions(inst: Option<&str>) -> Self {
+        let mut map = BTreeMap::from([
+            (CHANNEL, Str::new_inline("positions")),
+            (Str::new_inline("instType"), Str::new_inline("ANY")),
+        ]);
+        if let Some(inst) = inst {
+            map.insert(INST_ID, Str::new(inst));
+        }
+        Args(map)
+    }
+
     /// Args for trades subscription.
     pub fn subscribe_trades(inst: &str) -> Self {
         Args(BTreeMap::from([
@@ -54,6 +71,46 @@ impl Args {
         ]))
     }
 
+    /// Args for candles subscription (on the business channel).
+    pub fn subscribe_candles(inst: &str, bar: &str) -> Self {
+        Args(BTreeMap::from([
+            (CHANNEL, Str::new(format!("candle{bar}"))),
+            (INST_ID, Str::new(inst)),
+        ]))
+    }
+
+    /// Args for mark price subscription.
+    pub fn subscribe_mark_price(inst: &str) -> Self {
+        Args(BTreeMap::from([
+            (CHANNEL, Str::new_inline("mark-price")),
+            (INST_ID, Str::new(inst)),
+        ]))
+    }
+
+    /// Args for funding rate subscription.
+    pub fn subscribe_funding_rate(inst: &str) -> Self {
+        Args(BTreeMap::from([
+            (CHANNEL, Str::new_inline("funding-rate")),
+            (INST_ID, Str::new(inst)),
+        ]))
+    }
+
+    /// Args for open interest subscription.
+    pub fn subscribe_open_interest(inst: &str) -> Self {
+        Args(BTreeMap::from([
+            (CHANNEL, Str::new_inline("open-interest")),
+            (INST_ID, Str::new(inst)),
+        ]))
+    }
+
+    /// Args for liquidation orders subscription.
+    pub fn subscribe_liquidations(inst_type: &str) -> Self {
+        Args(BTreeMap::from([
+            (CHANNEL, Str::new_inline("liquidation-orders")),
+            (Str::new_inline("instType"), Str::new(inst_type)),
+        ]))
+    }
+
     /// Args for bid/ask subscription.
     pub fn subscribe_bid_ask(inst: &str) -> Self {
         Args(BTreeMap::from([
@@ -62,6 +119,14 @@ impl Args {
         ]))
     }
 
+    /// Args for order book subscription.
+    pub fn subscribe_order_book(inst: &str) -> Self {
+        Args(BTreeMap::from([
+            (CHANNEL, Str::new_inline("books")),
+            (INST_ID, Str::new(inst)),
+        ]))
+    }
+
     /// Args for option summary subscription.
     pub fn subscribe_option_summary(inst_family: &str) -> Self {
         Args(BTreeMap::from([
@@ -1,9 +1,11 @@
 use super::Args;
 use crate::error::OkxError;
 use crate::key::{OkxKey as Key, Signature};
+use crate::utils::place::PositionArgs;
 use exc_core::types::trading::{OrderKind, Place, PlaceOrderOptions};
 use exc_core::types::TimeInForce;
-use exc_core::Str;
+use exc_core::{ExchangeError, Str};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fmt;
@@ -23,6 +25,12 @@ pub enum Op {
     Order,
     /// Cancel order.
     CancelOrder,
+    /// Amend order.
+    AmendOrder,
+    /// Batch orders.
+    BatchOrders,
+    /// Batch cancel orders.
+    BatchCancelOrders,
 }
 
 /// Okx websocket request messagee.
@@ -59,6 +67,12 @@ pub enum WsRequest {
     Order(String, Args),
     /// Cancel order.
     CancelOrder(String, Args),
+    /// Amend order.
+    AmendOrder(String, Args),
+    /// Batch orders.
+    BatchOrders(String, Vec<Args>),
+    /// Batch cancel orders.
+    BatchCancelOrders(String, Vec<Args>),
 }
 
 impl fmt::Display for WsRequest {
@@ -79,6 +93,15 @@ impl fmt::Display for WsRequest {
             Self::CancelOrder(id, args) => {
                 write!(f, "cancel-order:{id}:{args}")
             }
+            Self::AmendOrder(id, args) => {
+                write!(f, "amend-order:{id}:{args}")
+            }
+            Self::BatchOrders(id, args) => {
+                write!(f, "batch-orders:{id}:{}", args.len())
+            }
+            Self::BatchCancelOrders(id, args) => {
+                write!(f, "batch-cancel-orders:{id}:{}", args.len())
+            }
         }
     }
 }
@@ -95,9 +118,29 @@ impl WsRequest {
     }
 
     /// Order request.
-    pub(crate) fn order(place: &Place, opts: &PlaceOrderOptions) -> Self {
+    pub(crate) fn order(place: &Place, opts: &PlaceOrderOptions) -> Result<Self, ExchangeError> {
+        Ok(Self::Order(
+            format!("{:x}", uuid::Uuid::new_v4().as_u128()),
+            Self::order_args(place, opts)?,
+        ))
+    }
+
+    /// Batch orders request.
+    pub(crate) fn batch_orders<'a>(
+        orders: impl IntoIterator<Item = (&'a Place, &'a PlaceOrderOptions)>,
+    ) -> Result<Self, ExchangeError> {
+        Ok(Self::BatchOrders(
+            format!("{:x}", uuid::Uuid::new_v4().as_u128()),
+            orders
+                .into_iter()
+                .map(|(place, opts)| Self::order_args(place, opts))
+                .collect::<Result<_, _>>()?,
+        ))
+    }
+
+    fn order_args(place: &Place, opts: &PlaceOrderOptions) -> Result<Args, ExchangeError> {
         let inst = opts.instrument();
-        let custom = opts.custom();
+        let position = PositionArgs::new(opts)?;
         let size = place.size.abs();
         let side = if place.size.is_sign_negative() {
             "sell"
@@ -106,14 +149,14 @@ impl WsRequest {
         };
         let mut map = BTreeMap::from([
             (Str::new_inline("instId"), Str::new(inst)),
-            (
-                Str::new_inline("tdMode"),
-                Str::new(custom.get("tdMode").map(|s| s.as_str()).unwrap_or("cross")),
-            ),
+            (Str::new_inline("tdMode"), position.td_mode),
             (Str::new_inline("side"), Str::new_inline(side)),
-            (Str::new_inline("posSide"), Str::new_inline("net")),
+            (Str::new_inline("posSide"), position.pos_side),
             (Str::new_inline("sz"), Str::new(size.to_string())),
         ]);
+        if position.reduce_only {
+            map.insert(Str::new_inline("reduceOnly"), Str::new_inline("true"));
+        }
         if let Some(margin) = opts.margin() {
             map.insert(Str::new_inline("ccy"), Str::new(margin));
         }
@@ -159,13 +202,49 @@ impl WsRequest {
                 map.insert(Str::new_inline("px"), Str::new(price.to_string()));
                 map.insert(Str::new_inline("ordType"), Str::new_inline("post_only"));
             }
+            kind => {
+                return Err(ExchangeError::Other(anyhow::anyhow!(
+                    "`{kind:?}` orders can only be placed with the algo order api"
+                )));
+            }
         }
-        Self::Order(format!("{:x}", uuid::Uuid::new_v4().as_u128()), Args(map))
+        Ok(Args(map))
     }
 
     /// Cancel order request.
     pub(crate) fn cancel_order(inst: &str, id: &str) -> Self {
         Self::CancelOrder(
+            format!("{:x}", uuid::Uuid::new_v4().as_u128()),
+            Self::cancel_args(inst, id),
+        )
+    }
+
+    /// Batch cancel orders request.
+    pub(crate) fn batch_cancel_orders<'a>(
+        orders: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> Self {
+        Self::BatchCancelOrders(
+            format!("{:x}", uuid::Uuid::new_v4().as_u128()),
+            orders
+                .into_iter()
+                .map(|(inst, id)| Self::cancel_args(inst, id))
+                .collect(),
+        )
+    }
+
+    fn cancel_args(inst: &str, id: &str) -> Args {
+        Args(BTreeMap::from([
+            (Str::new_inline("instId"), Str::new(inst)),
+            #[cfg(not(feature = "prefer-client-id"))]
+            (Str::new_inline("ordId"), Str::new(id)),
+            #[cfg(feature = "prefer-client-id")]
+            (Str::new_inline("clOrdId"), Str::new(id)),
+        ]))
+    }
+
+    /// Amend order request.
+    pub(crate) fn amend_order(inst: &str, id: &str, price: &Decimal, size: &Decimal) -> Self {
+        Self::AmendOrder(
             format!("{:x}", uuid::Uuid::new_v4().as_u128()),
             Args(BTreeMap::from([
                 (Str::new_inline("instId"), Str::new(inst)),
@@ -173,6 +252,8 @@ impl WsRequest {
                 (Str::new_inline("ordId"), Str::new(id)),
                 #[cfg(feature = "prefer-client-id")]
                 (Str::new_inline("clOrdId"), Str::new(id)),
+                (Str::new_inline("newPx"), Str::new(price.to_string())),
+                (Str::new_inline("newSz"), Str::new(size.abs().to_string())),
             ])),
         )
     }
@@ -206,6 +287,21 @@ impl From<WsRequest> for WsRequestMessage {
                 op: Op::CancelOrder,
                 args: vec![args],
             },
+            WsRequest::AmendOrder(id, args) => Self {
+                id: Some(id),
+                op: Op::AmendOrder,
+                args: vec![args],
+            },
+            WsRequest::BatchOrders(id, args) => Self {
+                id: Some(id),
+                op: Op::BatchOrders,
+                args,
+            },
+            WsRequest::BatchCancelOrders(id, args) => Self {
+                id: Some(id),
+                op: Op::BatchCancelOrders,
+                args,
+            },
         }
     }
 }
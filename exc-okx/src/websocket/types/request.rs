@@ -5,7 +5,8 @@ use crate::{
 };
 use async_stream::stream;
 use exc_core::{
-    types::{ticker::SubscribeTickers, PlaceOrder},
+    types::{ticker::SubscribeTickers, AmendOrder, CancelOrders, PlaceOrder, PlaceOrders},
+    util::clock_sync::Clock,
     ExchangeError,
 };
 use futures::stream::{empty, BoxStream, StreamExt};
@@ -15,6 +16,8 @@ pub struct Request {
     cb: Callback,
     inner: BoxStream<'static, ClientFrame>,
     pub(crate) reconnect: bool,
+    /// Whether the request can be resent safely.
+    pub(crate) idempotent: bool,
 }
 
 impl Request {
@@ -59,13 +62,14 @@ impl Request {
             cb,
             inner: stream.boxed(),
             reconnect: false,
+            idempotent: true,
         }
     }
 
     /// Login request.
-    pub(crate) fn login(key: Key) -> Result<Self, SignError> {
+    pub(crate) fn login(key: Key, clock: &Clock) -> Result<Self, SignError> {
         let (cb, _rx) = Callback::new();
-        let signature = key.sign_now("GET", "/users/self/verify", true)?;
+        let signature = key.sign("GET", "/users/self/verify", clock.now(), true)?;
         let stream = stream! {
             yield ClientFrame { stream_id: 0, inner: WsRequest::login(key, signature) };
             // let _ = rx.await;
@@ -75,24 +79,25 @@ impl Request {
             cb,
             inner: stream.boxed(),
             reconnect: false,
+            idempotent: true,
         })
     }
 
     /// Order request.
-    pub fn order(req: &PlaceOrder) -> Self {
+    pub fn order(req: &PlaceOrder) -> Result<Self, ExchangeError> {
         let (cb, _rx) = Callback::new();
-        let opts = req.opts.clone();
-        let place = req.place;
+        let frame = WsRequest::order(&req.place, &req.opts)?;
         let stream = stream! {
-            yield ClientFrame { stream_id: 0, inner: WsRequest::order(&place, &opts) };
+            yield ClientFrame { stream_id: 0, inner: frame };
             // let _ = rx.await;
         };
 
-        Self {
+        Ok(Self {
             cb,
             inner: stream.boxed(),
             reconnect: false,
-        }
+            idempotent: exc_core::Request::is_idempotent(req),
+        })
     }
 
     /// Cancel order request.
@@ -109,6 +114,68 @@ impl Request {
             cb,
             inner: stream.boxed(),
             reconnect: false,
+            idempotent: true,
+        }
+    }
+
+    /// Amend order request.
+    pub fn amend_order(req: &AmendOrder) -> Self {
+        let (cb, _rx) = Callback::new();
+        let frame = WsRequest::amend_order(
+            &req.instrument,
+            req.id.as_str(),
+            &req.new_price,
+            &req.new_size,
+        );
+        let stream = stream! {
+            yield ClientFrame { stream_id: 0, inner: frame };
+        };
+
+        Self {
+            cb,
+            inner: stream.boxed(),
+            reconnect: false,
+            idempotent: true,
+        }
+    }
+
+    /// Batch orders request.
+    pub fn batch_orders(req: &PlaceOrders) -> Result<Self, ExchangeError> {
+        let (cb, _rx) = Callback::new();
+        let frame = WsRequest::batch_orders(
+            req.0
+                .iter()
+                .map(|order| (&order.place, order.opts.as_ref())),
+        )?;
+        let stream = stream! {
+            yield ClientFrame { stream_id: 0, inner: frame };
+        };
+
+        Ok(Self {
+            cb,
+            inner: stream.boxed(),
+            reconnect: false,
+            idempotent: exc_core::Request::is_idempotent(req),
+        })
+    }
+
+    /// Batch cancel orders request.
+    pub fn batch_cancel_orders(req: &CancelOrders) -> Self {
+        let (cb, _rx) = Callback::new();
+        let frame = WsRequest::batch_cancel_orders(
+            req.0
+                .iter()
+                .map(|order| (order.instrument.as_str(), order.id.as_str())),
+        );
+        let stream = stream! {
+            yield ClientFrame { stream_id: 0, inner: frame };
+        };
+
+        Self {
+            cb,
+            inner: stream.boxed(),
+            reconnect: false,
+            idempotent: true,
         }
     }
 
@@ -119,6 +186,7 @@ impl Request {
             cb,
             inner: empty().boxed(),
             reconnect: true,
+            idempotent: true,
         }
     }
 }
@@ -0,0 +1,509 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::{future::BoxFuture, FutureExt};
+use tower::{timeout::error::Elapsed, Layer, Service};
+
+use crate::{ApiErrorKind, ExcService, ExchangeError, Request};
+
+/// State of a [`Breaker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent.
+    Closed,
+    /// Requests are rejected with [`ExchangeError::CircuitOpen`].
+    Open,
+    /// Probing requests are sent to find out whether the exchange has recovered.
+    HalfOpen,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed,
+    Open { until: Instant },
+    HalfOpen { probing: usize },
+}
+
+#[derive(Debug)]
+struct Counts {
+    consecutive: usize,
+    requests: usize,
+    failures: usize,
+    window_start: Instant,
+}
+
+impl Counts {
+    fn new(now: Instant) -> Self {
+        Self {
+            consecutive: 0,
+            requests: 0,
+            failures: 0,
+            window_start: now,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    state: State,
+    counts: Counts,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Config {
+    consecutive_failures: usize,
+    failure_rate: f64,
+    min_requests: usize,
+    window: Duration,
+    open_for: Duration,
+    probes: usize,
+}
+
+/// The source of the current time.
+#[derive(Clone)]
+struct Now(Arc<dyn Fn() -> Instant + Send + Sync>);
+
+impl fmt::Debug for Now {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Now")
+    }
+}
+
+impl Default for Now {
+    fn default() -> Self {
+        Self(Arc::new(Instant::now))
+    }
+}
+
+/// Admission of a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Admission {
+    Normal,
+    Probe,
+}
+
+/// A circuit breaker shared by the services calling the same exchange api.
+///
+/// Trips (opens) after `consecutive_failures` consecutive failures, or when the failure rate
+/// in the current window exceeds `failure_rate` (with at least `min_requests` requests).
+/// Only the errors showing that the api is degraded count as failures, that is
+/// [`ExchangeError::Unavailable`], [`ExchangeError::Http`] and the api errors of maintenance.
+/// After `open_for`, up to `probes` requests are let through to probe the api,
+/// closing the circuit on success and opening it again on failure
+/// (or if the probe is dropped before its response).
+#[derive(Debug, Clone)]
+pub struct Breaker {
+    config: Config,
+    now: Now,
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl Default for Breaker {
+    fn default() -> Self {
+        Self {
+            config: Config {
+                consecutive_failures: 5,
+                failure_rate: 0.5,
+                min_requests: 20,
+                window: Duration::from_secs(60),
+                open_for: Duration::from_secs(30),
+                probes: 1,
+            },
+            now: Now::default(),
+            inner: Arc::new(Mutex::new(Inner {
+                state: State::Closed,
+                counts: Counts::new(Instant::now()),
+            })),
+        }
+    }
+}
+
+impl Breaker {
+    /// Create a circuit breaker with the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of consecutive failures to trip (default to `5`).
+    pub fn consecutive_failures(mut self, failures: usize) -> Self {
+        self.config.consecutive_failures = failures.max(1);
+        self
+    }
+
+    /// Set the failure rate to trip, counted in every `window` with at least
+    /// `min_requests` requests (default to `0.5` of at least `20` requests in a minute).
+    pub fn failure_rate(mut self, rate: f64, min_requests: usize, window: Duration) -> Self {
+        self.config.failure_rate = rate;
+        self.config.min_requests = min_requests.max(1);
+        self.config.window = window;
+        self
+    }
+
+    /// Set the duration to reject the requests for after tripping (default to 30s).
+    pub fn open_for(mut self, duration: Duration) -> Self {
+        self.config.open_for = duration;
+        self
+    }
+
+    /// Set the number of probing requests in flight allowed in the half-open state
+    /// (default to `1`).
+    pub fn probes(mut self, probes: usize) -> Self {
+        self.config.probes = probes.max(1);
+        self
+    }
+
+    /// Use the given source of the current time.
+    #[cfg(test)]
+    fn with_now(mut self, now: impl Fn() -> Instant + Send + Sync + 'static) -> Self {
+        self.now = Now(Arc::new(now));
+        self.inner.lock().unwrap().counts = Counts::new((self.now.0)());
+        self
+    }
+
+    fn now(&self) -> Instant {
+        (self.now.0)()
+    }
+
+    /// Get the current state, e.g. for health checks.
+    pub fn state(&self) -> CircuitState {
+        let inner = self.inner.lock().unwrap();
+        match inner.state {
+            State::Closed => CircuitState::Closed,
+            State::Open { until } if self.now() < until => CircuitState::Open,
+            State::Open { .. } | State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Whether the requests are being rejected.
+    fn is_rejecting(&self) -> bool {
+        self.state() == CircuitState::Open
+    }
+
+    fn admit(&self) -> Option<Admission> {
+        let mut inner = self.inner.lock().unwrap();
+        match inner.state {
+            State::Closed => Some(Admission::Normal),
+            State::Open { until } => {
+                if self.now() < until {
+                    None
+                } else {
+                    inner.state = State::HalfOpen { probing: 1 };
+                    Some(Admission::Probe)
+                }
+            }
+            State::HalfOpen { ref mut probing } => {
+                if *probing < self.config.probes {
+                    *probing += 1;
+                    Some(Admission::Probe)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn record(&self, admission: Admission, failed: bool) {
+        let now = self.now();
+        let mut inner = self.inner.lock().unwrap();
+        if admission == Admission::Probe {
+            if let State::HalfOpen { ref mut probing } = inner.state {
+                *probing = probing.saturating_sub(1);
+                if failed {
+                    inner.state = State::Open {
+                        until: now + self.config.open_for,
+                    };
+                } else {
+                    inner.state = State::Closed;
+                    inner.counts = Counts::new(now);
+                }
+            }
+            return;
+        }
+        if !matches!(inner.state, State::Closed) {
+            return;
+        }
+        let counts = &mut inner.counts;
+        if now >= counts.window_start + self.config.window {
+            *counts = Counts::new(now);
+        }
+        counts.requests += 1;
+        if failed {
+            counts.consecutive += 1;
+            counts.failures += 1;
+        } else {
+            counts.consecutive = 0;
+        }
+        let rate = counts.failures as f64 / counts.requests as f64;
+        if counts.consecutive >= self.config.consecutive_failures
+            || (counts.requests >= self.config.min_requests && rate >= self.config.failure_rate)
+        {
+            inner.state = State::Open {
+                until: now + self.config.open_for,
+            };
+        }
+    }
+
+    /// Release a request dropped before its response, e.g. by a timeout.
+    ///
+    /// A dropped probe tells nothing about the recovery, so the circuit is opened again
+    /// instead of waiting for a result that will never be recorded.
+    fn abandon(&self, admission: Admission) {
+        if admission != Admission::Probe {
+            return;
+        }
+        let now = self.now();
+        let mut inner = self.inner.lock().unwrap();
+        if let State::HalfOpen { .. } = inner.state {
+            inner.state = State::Open {
+                until: now + self.config.open_for,
+            };
+        }
+    }
+}
+
+/// Records the outcome of an admitted request, or abandons it if dropped before the response.
+struct Pending {
+    breaker: Breaker,
+    admission: Option<Admission>,
+}
+
+impl Pending {
+    fn record(mut self, failed: bool) {
+        if let Some(admission) = self.admission.take() {
+            self.breaker.record(admission, failed);
+        }
+    }
+}
+
+impl Drop for Pending {
+    fn drop(&mut self) {
+        if let Some(admission) = self.admission.take() {
+            self.breaker.abandon(admission);
+        }
+    }
+}
+
+/// Whether the error shows that the api is degraded.
+///
+/// Timeouts (the [`Elapsed`] of a timeout layer) are failures too.
+fn is_failure(err: &ExchangeError) -> bool {
+    match err {
+        ExchangeError::Unavailable(_) => true,
+        ExchangeError::ApiError(err) => err.kind == ApiErrorKind::Maintenance,
+        #[cfg(feature = "http")]
+        ExchangeError::Http(_) => true,
+        ExchangeError::Layer(err) => {
+            err.is::<Elapsed>() || err.downcast_ref::<ExchangeError>().is_some_and(is_failure)
+        }
+        ExchangeError::Other(err) => err.is::<Elapsed>(),
+        _ => false,
+    }
+}
+
+/// Circuit breaker layer.
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerLayer {
+    breaker: Breaker,
+}
+
+impl CircuitBreakerLayer {
+    /// Create a new circuit breaker layer sharing the state of `breaker`.
+    pub fn new(breaker: Breaker) -> Self {
+        Self { breaker }
+    }
+}
+
+impl<S> Layer<S> for CircuitBreakerLayer {
+    type Service = CircuitBreaker<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        CircuitBreaker {
+            svc: inner,
+            breaker: self.breaker.clone(),
+            rejecting: false,
+        }
+    }
+}
+
+/// Circuit breaker.
+///
+/// Fail fast with [`ExchangeError::CircuitOpen`] while the [`Breaker`] is open,
+/// without waiting for the inner service to be ready.
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker<S> {
+    svc: S,
+    breaker: Breaker,
+    rejecting: bool,
+}
+
+impl<S> CircuitBreaker<S> {
+    /// Get the breaker.
+    pub fn breaker(&self) -> &Breaker {
+        &self.breaker
+    }
+}
+
+impl<S, R> Service<R> for CircuitBreaker<S>
+where
+    R: Request,
+    R::Response: Send + 'static,
+    S: ExcService<R>,
+    S::Future: Send + 'static,
+{
+    type Response = R::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.rejecting = self.breaker.is_rejecting();
+        if self.rejecting {
+            Poll::Ready(Ok(()))
+        } else {
+            self.svc.poll_ready(cx)
+        }
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        let admission = if std::mem::take(&mut self.rejecting) {
+            None
+        } else {
+            self.breaker.admit()
+        };
+        let Some(admission) = admission else {
+            return futures::future::ready(Err(ExchangeError::CircuitOpen)).boxed();
+        };
+        let pending = Pending {
+            breaker: self.breaker.clone(),
+            admission: Some(admission),
+        };
+        let fut = self.svc.call(req);
+        async move {
+            let res = fut.await;
+            pending.record(res.as_ref().err().is_some_and(is_failure));
+            res
+        }
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU8, Ordering};
+    use tower::ServiceExt;
+
+    #[derive(Debug)]
+    struct Ping;
+
+    impl Request for Ping {
+        type Response = ();
+    }
+
+    const DOWN: u8 = 0;
+    const UP: u8 = 1;
+    const HANGING: u8 = 2;
+
+    /// A clock advanced by hand.
+    #[derive(Clone)]
+    struct ManualClock {
+        start: Instant,
+        elapsed: Arc<Mutex<Duration>>,
+    }
+
+    impl ManualClock {
+        fn new() -> Self {
+            Self {
+                start: Instant::now(),
+                elapsed: Arc::default(),
+            }
+        }
+
+        fn now(&self) -> Instant {
+            self.start + *self.elapsed.lock().unwrap()
+        }
+
+        fn advance(&self, duration: Duration) {
+            *self.elapsed.lock().unwrap() += duration;
+        }
+    }
+
+    #[test]
+    fn test_circuit_breaker() {
+        let mode = Arc::new(AtomicU8::new(DOWN));
+        let svc = tower::service_fn({
+            let mode = mode.clone();
+            move |_: Ping| {
+                let mode = mode.load(Ordering::Relaxed);
+                async move {
+                    match mode {
+                        DOWN => Err(ExchangeError::Unavailable(anyhow::anyhow!("down"))),
+                        HANGING => futures::future::pending().await,
+                        _ => Ok(()),
+                    }
+                }
+            }
+        });
+        let clock = ManualClock::new();
+        let breaker = Breaker::new()
+            .consecutive_failures(3)
+            .open_for(Duration::from_secs(30))
+            .with_now({
+                let clock = clock.clone();
+                move || clock.now()
+            });
+        let mut svc = CircuitBreakerLayer::new(breaker.clone()).layer(svc);
+        futures::executor::block_on(async {
+            for _ in 0..3 {
+                let err = (&mut svc).oneshot(Ping).await.unwrap_err();
+                assert!(matches!(err, ExchangeError::Unavailable(_)));
+            }
+            assert_eq!(breaker.state(), CircuitState::Open);
+            let err = (&mut svc).oneshot(Ping).await.unwrap_err();
+            assert!(matches!(err, ExchangeError::CircuitOpen));
+
+            // The probe fails.
+            clock.advance(Duration::from_secs(31));
+            assert_eq!(breaker.state(), CircuitState::HalfOpen);
+            let err = (&mut svc).oneshot(Ping).await.unwrap_err();
+            assert!(matches!(err, ExchangeError::Unavailable(_)));
+            assert_eq!(breaker.state(), CircuitState::Open);
+
+            // The probe is dropped before its response (e.g. timed out),
+            // which opens the circuit again instead of holding the probe slot forever.
+            mode.store(HANGING, Ordering::Relaxed);
+            clock.advance(Duration::from_secs(31));
+            let mut probe = Box::pin((&mut svc).oneshot(Ping));
+            assert!(futures::poll!(probe.as_mut()).is_pending());
+            drop(probe);
+            assert_eq!(breaker.state(), CircuitState::Open);
+
+            // The probe succeeds.
+            mode.store(UP, Ordering::Relaxed);
+            clock.advance(Duration::from_secs(31));
+            (&mut svc).oneshot(Ping).await.unwrap();
+            assert_eq!(breaker.state(), CircuitState::Closed);
+        });
+    }
+
+    #[test]
+    fn test_timeout_failures() {
+        let svc = tower::service_fn(|_: Ping| async {
+            Err::<(), _>(ExchangeError::Layer(Box::new(Elapsed::new())))
+        });
+        let breaker = Breaker::new().consecutive_failures(2);
+        let mut svc = CircuitBreakerLayer::new(breaker.clone()).layer(svc);
+        futures::executor::block_on(async {
+            for _ in 0..2 {
+                let err = (&mut svc).oneshot(Ping).await.unwrap_err();
+                assert!(matches!(err, ExchangeError::Layer(_)));
+            }
+        });
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(is_failure(&ExchangeError::Other(Elapsed::new().into())));
+        assert!(!is_failure(&ExchangeError::Other(anyhow::anyhow!("other"))));
+    }
+}
@@ -1,4 +1,4 @@
-use std::convert::Infallible;
+use std::{convert::Infallible, time::Duration};
 
 use thiserror::Error;
 
@@ -10,6 +10,73 @@ pub enum InstrumentError {
     NotFound,
 }
 
+/// Normalized category of the api errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ApiErrorKind {
+    /// Insufficient balance or margin.
+    InsufficientBalance,
+    /// Invalid price, e.g. not a multiple of the tick size or out of the price limits.
+    InvalidPrice,
+    /// A post-only order would take liquidity.
+    PostOnlyWouldTake,
+    /// Order not found.
+    OrderNotFound,
+    /// Duplicate client id.
+    DuplicateClientId,
+    /// The timestamp of the request is out of the recv window,
+    /// the local clock has drifted from the server clock.
+    TimestampDrift,
+    /// Permission denied, e.g. invalid api key or signature, or not allowed IP.
+    PermissionDenied,
+    /// Maintenance, the api is temporarily unavailable.
+    Maintenance,
+    /// Other api errors.
+    Other,
+}
+
+/// Api error with the native code of the exchange.
+#[derive(Debug, Clone, Error)]
+#[error("{venue}: code={code} msg={msg} ({kind:?})")]
+pub struct ApiError {
+    /// The name of the exchange.
+    pub venue: &'static str,
+    /// The native error code.
+    pub code: String,
+    /// The error message.
+    pub msg: String,
+    /// The normalized category.
+    pub kind: ApiErrorKind,
+}
+
+impl ApiError {
+    /// Create a new [`ApiError`].
+    pub fn new(
+        venue: &'static str,
+        code: impl ToString,
+        msg: impl ToString,
+        kind: ApiErrorKind,
+    ) -> Self {
+        Self {
+            venue,
+            code: code.to_string(),
+            msg: msg.to_string(),
+            kind,
+        }
+    }
+}
+
+/// Rate limited with the time to wait before retrying told by the exchange
+/// (e.g. the `Retry-After` header), carried in [`ExchangeError::RateLimited`].
+#[derive(Debug, Error)]
+#[error("{msg} (retry after {}s)", .after.as_secs())]
+pub struct RetryAfter {
+    /// The time to wait before retrying.
+    pub after: Duration,
+    /// The message.
+    pub msg: anyhow::Error,
+}
+
 /// Exchange Errors.
 #[derive(Debug, Error)]
 pub enum ExchangeError {
@@ -23,7 +90,11 @@ pub enum ExchangeError {
     /// All other errors.
     #[error(transparent)]
     Other(#[from] anyhow::Error),
-    /// All other api errors.
+    /// All other api errors, without the native code.
+    ///
+    /// Adapters should emit [`ExchangeError::ApiError`] instead, with [`ApiErrorKind::Other`]
+    /// when the error has no normalized category.
+    #[deprecated(note = "emit `ExchangeError::ApiError` instead")]
     #[error("api: {0}")]
     Api(anyhow::Error),
     /// Unavailable.
@@ -44,14 +115,27 @@ pub enum ExchangeError {
     /// Forbidden.
     #[error("forbidden: {0}")]
     Forbidden(anyhow::Error),
+    /// The request, or the combination of its options, is not supported by the exchange.
+    #[error("unsupported: {0}")]
+    Unsupported(anyhow::Error),
     /// Unexpected response type.
     #[error("unexpected response type: {0}")]
     UnexpectedResponseType(String),
+    /// Api error with the native code and the normalized category,
+    /// the variant adapters should emit for the errors reported by the exchange.
+    #[error("api error: {0}")]
+    ApiError(ApiError),
+    /// Rejected by an open circuit breaker, the api is considered degraded.
+    #[error("circuit breaker is open")]
+    CircuitOpen,
 }
 
 impl ExchangeError {
     /// Is temporary.
     pub fn is_temporary(&self) -> bool {
+        if self.api_error_kind() == Some(ApiErrorKind::Maintenance) {
+            return true;
+        }
         #[cfg(feature = "http")]
         {
             matches!(
@@ -65,6 +149,38 @@ impl ExchangeError {
         }
     }
 
+    /// Get the api error with the native code, if it is one.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Self::ApiError(err) => Some(err),
+            Self::Layer(err) => err.downcast_ref::<Self>().and_then(Self::api_error),
+            _ => None,
+        }
+    }
+
+    /// Get the normalized category of the api error, if it is one.
+    pub fn api_error_kind(&self) -> Option<ApiErrorKind> {
+        match self {
+            Self::OrderNotFound => Some(ApiErrorKind::OrderNotFound),
+            Self::Layer(err) => err.downcast_ref::<Self>().and_then(Self::api_error_kind),
+            err => err.api_error().map(|err| err.kind),
+        }
+    }
+
+    /// The time to wait before retrying told by the exchange, if rate limited.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::RateLimited(err) => err.downcast_ref::<RetryAfter>().map(|err| err.after),
+            Self::Layer(err) => err.downcast_ref::<Self>().and_then(Self::retry_after),
+            _ => None,
+        }
+    }
+
+    /// Rate limited, and told to retry after `after`.
+    pub fn rate_limited_with_retry_after(msg: anyhow::Error, after: Duration) -> Self {
+        Self::RateLimited(anyhow::Error::new(RetryAfter { after, msg }))
+    }
+
     /// Flatten.
     pub fn flatten(self) -> Self {
         match self {
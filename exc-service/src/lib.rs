@@ -23,6 +23,17 @@ pub mod adapt;
 /// Retry utils.
 pub mod retry;
 
+/// Circuit breaker.
+pub mod breaker;
+
+#[cfg(feature = "limit")]
+/// Weighted rate limit.
+pub mod weight;
+
+#[cfg(feature = "metrics")]
+/// Metrics and tracing.
+pub mod metrics;
+
 pub use layer::ExcLayer;
 pub use {
     adapt::Adaptor,
@@ -30,11 +41,18 @@ pub use {
 };
 
 use self::adapt::{Adapt, AdaptLayer, AdaptService};
-pub use self::error::ExchangeError;
+pub use self::breaker::{Breaker, CircuitState};
+pub use self::error::{ApiError, ApiErrorKind, ExchangeError};
 
 #[cfg(feature = "send")]
 pub use self::traits::send::SendExcService;
 
+#[cfg(feature = "limit")]
+pub use self::weight::{RateLimiter, RequestWeight, Weight};
+
+#[cfg(feature = "metrics")]
+pub use self::metrics::MetricsLayer;
+
 /// The core service wrapper of this crate, which implements
 /// [`ExcService<T>`] *if* the request type of the underlying
 /// service implements [`Adaptor<T>`].
@@ -102,6 +120,22 @@ where
         self.into_layered(&RateLimitLayer::new(num, per))
     }
 
+    /// Apply weighted rate-limit layer to the channel.
+    #[cfg(feature = "limit")]
+    pub fn into_weighted_rate_limited(
+        self,
+        limiter: RateLimiter,
+    ) -> Exc<crate::weight::WeightedRateLimit<IntoService<C, Req>>, Req>
+    where
+        Req: RequestWeight + Send + 'static,
+        Req::Response: Send + 'static,
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+    {
+        use crate::weight::WeightedRateLimitLayer;
+        self.into_layered(&WeightedRateLimitLayer::new(limiter))
+    }
+
     #[cfg(feature = "retry")]
     /// Apply retry layer to the channel.
     pub fn into_retry(
@@ -118,6 +152,23 @@ where
         self.into_layered(&RetryLayer::new(Always::with_max_duration(max_duration)))
     }
 
+    #[cfg(feature = "retry")]
+    /// Apply retry layer with the given policy (e.g. [`Backoff`](crate::retry::Backoff))
+    /// to the channel.
+    pub fn into_retry_with<P>(
+        self,
+        policy: P,
+    ) -> Exc<tower::retry::Retry<P, IntoService<C, Req>>, Req>
+    where
+        Req: Clone,
+        C: Clone,
+        P: tower::retry::Policy<Req, Req::Response, ExchangeError> + Clone,
+    {
+        use tower::retry::RetryLayer;
+
+        self.into_layered(&RetryLayer::new(policy))
+    }
+
     /// Adapt the request type of the underlying channel to the target type `R`.
     pub fn into_adapted<R>(self) -> Exc<Adapt<IntoService<C, Req>, Req, R>, R>
     where
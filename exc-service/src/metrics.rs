@@ -0,0 +1,285 @@
+use std::{
+    borrow::Cow,
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use futures::{future::BoxFuture, FutureExt};
+use tower::{Layer, Service};
+use tracing::Instrument;
+
+use crate::{ApiErrorKind, ExcService, ExchangeError, Request};
+
+/// Histogram of the latencies (in seconds), labeled with `exchange`, `request` and `outcome`
+/// (`ok` or `error`).
+pub const REQUEST_DURATION: &str = "exc_request_duration_seconds";
+
+/// Gauge of the requests in flight, labeled with `exchange` and `request`.
+pub const REQUESTS_IN_FLIGHT: &str = "exc_requests_in_flight";
+
+/// Counter of the failed requests, labeled with `exchange`, `request` and `category`
+/// (e.g. `insufficient_balance`, `post_only_would_take`, `unavailable` or `http`).
+pub const REQUEST_ERRORS: &str = "exc_request_errors_total";
+
+/// Counter of the requests rejected for rate limits, labeled with `exchange` and `request`.
+pub const RATE_LIMITED: &str = "exc_rate_limited_total";
+
+/// The name of the request type without the module paths,
+/// e.g. `PlaceOrder` or `Paged<QueryDeposits>`.
+fn request_name<R>() -> Cow<'static, str> {
+    let name = std::any::type_name::<R>();
+    if !name.contains('<') {
+        return Cow::Borrowed(name.rsplit("::").next().unwrap_or(name));
+    }
+    let mut short = String::with_capacity(name.len());
+    for part in name.split_inclusive(['<', '>', ',', ' ', '(', ')', '[', ']', '&', ';']) {
+        short.push_str(part.rsplit("::").next().unwrap_or(part));
+    }
+    Cow::Owned(short)
+}
+
+/// The category of the error used as the `category` label.
+fn category(err: &ExchangeError) -> &'static str {
+    if let Some(kind) = err.api_error_kind() {
+        return match kind {
+            ApiErrorKind::InsufficientBalance => "insufficient_balance",
+            ApiErrorKind::InvalidPrice => "invalid_price",
+            ApiErrorKind::PostOnlyWouldTake => "post_only_would_take",
+            ApiErrorKind::OrderNotFound => "order_not_found",
+            ApiErrorKind::DuplicateClientId => "duplicate_client_id",
+            ApiErrorKind::TimestampDrift => "timestamp_drift",
+            ApiErrorKind::PermissionDenied => "permission_denied",
+            ApiErrorKind::Maintenance => "maintenance",
+            _ => "api",
+        };
+    }
+    match err {
+        ExchangeError::Layer(err) => err
+            .downcast_ref::<ExchangeError>()
+            .map_or("layer", category),
+        #[cfg(feature = "http")]
+        ExchangeError::Http(_) => "http",
+        ExchangeError::Other(_) => "other",
+        #[allow(deprecated)]
+        ExchangeError::Api(_) => "api",
+        ExchangeError::ApiError(_) => "api",
+        ExchangeError::Unavailable(_) => "unavailable",
+        ExchangeError::Instrument(_) => "instrument",
+        ExchangeError::RateLimited(_) => "rate_limited",
+        ExchangeError::KeyError(_) => "key",
+        ExchangeError::OrderNotFound => "order_not_found",
+        ExchangeError::Forbidden(_) => "forbidden",
+        ExchangeError::Unsupported(_) => "unsupported",
+        ExchangeError::UnexpectedResponseType(_) => "unexpected_response_type",
+        ExchangeError::CircuitOpen => "circuit_open",
+    }
+}
+
+/// Decrease the in-flight gauge when the request is done or dropped.
+struct InFlight(::metrics::Gauge);
+
+impl InFlight {
+    fn new(gauge: ::metrics::Gauge) -> Self {
+        gauge.increment(1.0);
+        Self(gauge)
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.decrement(1.0);
+    }
+}
+
+/// Metrics layer.
+#[derive(Debug, Clone, Copy)]
+pub struct MetricsLayer {
+    exchange: &'static str,
+}
+
+impl MetricsLayer {
+    /// Create a new metrics layer labeling the metrics and spans with `exchange`.
+    pub fn new(exchange: &'static str) -> Self {
+        Self { exchange }
+    }
+}
+
+impl<S> Layer<S> for MetricsLayer {
+    type Service = Metrics<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        Metrics {
+            svc: inner,
+            exchange: self.exchange,
+        }
+    }
+}
+
+/// Metrics.
+///
+/// Record the metrics of the calls to the inner service through the [`metrics`](::metrics)
+/// facade, labeled with the exchange and the request type (see [`REQUEST_DURATION`],
+/// [`REQUESTS_IN_FLIGHT`], [`REQUEST_ERRORS`] and [`RATE_LIMITED`]), and run each call
+/// in an `exc` span with the `exchange`, `request`, `instrument` and `client_id` fields.
+///
+/// The latency is measured from the call to the response of the inner service,
+/// so put the layer right above the transport to measure the exchange alone,
+/// or on top of the stack to include the time spent in the other layers.
+#[derive(Debug, Clone)]
+pub struct Metrics<S> {
+    svc: S,
+    exchange: &'static str,
+}
+
+impl<S> Metrics<S> {
+    /// Get the exchange label.
+    pub fn exchange(&self) -> &'static str {
+        self.exchange
+    }
+}
+
+impl<S, R> Service<R> for Metrics<S>
+where
+    R: Request,
+    R::Response: Send + 'static,
+    S: ExcService<R>,
+    S::Future: Send + 'static,
+{
+    type Response = R::Response;
+    type Error = ExchangeError;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.svc.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        let exchange = self.exchange;
+        let request = request_name::<R>();
+        let span = tracing::info_span!(
+            "exc",
+            exchange,
+            request = %request,
+            instrument = Request::instrument(&req),
+            client_id = Request::client_id(&req),
+        );
+        let in_flight = InFlight::new(::metrics::gauge!(
+            REQUESTS_IN_FLIGHT,
+            "exchange" => exchange,
+            "request" => request.clone(),
+        ));
+        let start = Instant::now();
+        let fut = span.in_scope(|| self.svc.call(req));
+        async move {
+            let res = fut.await;
+            let elapsed = start.elapsed();
+            drop(in_flight);
+            let outcome = match &res {
+                Ok(_) => {
+                    tracing::trace!(?elapsed, "ok");
+                    "ok"
+                }
+                Err(err) => {
+                    let category = category(err);
+                    tracing::debug!(?elapsed, category, "error: {err}");
+                    if category == "rate_limited" {
+                        ::metrics::counter!(
+                            RATE_LIMITED,
+                            "exchange" => exchange,
+                            "request" => request.clone(),
+                        )
+                        .increment(1);
+                    }
+                    ::metrics::counter!(
+                        REQUEST_ERRORS,
+                        "exchange" => exchange,
+                        "request" => request.clone(),
+                        "category" => category,
+                    )
+                    .increment(1);
+                    "error"
+                }
+            };
+            ::metrics::histogram!(
+                REQUEST_DURATION,
+                "exchange" => exchange,
+                "request" => request,
+                "outcome" => outcome,
+            )
+            .record(elapsed.as_secs_f64());
+            res
+        }
+        .instrument(span)
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+    use tower::ServiceExt;
+
+    #[derive(Debug)]
+    struct Ping;
+
+    impl Request for Ping {
+        type Response = ();
+    }
+
+    #[test]
+    fn test_request_name() {
+        assert_eq!(request_name::<Ping>(), "Ping");
+        assert_eq!(request_name::<Option<Vec<Ping>>>(), "Option<Vec<Ping>>");
+    }
+
+    #[test]
+    fn test_metrics() {
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+        let mut calls = 0;
+        let svc = tower::service_fn(move |_: Ping| {
+            calls += 1;
+            let res = if calls == 1 {
+                Err(ExchangeError::RateLimited(anyhow::anyhow!(
+                    "too many requests"
+                )))
+            } else {
+                Ok(())
+            };
+            futures::future::ready(res)
+        });
+        let mut svc = MetricsLayer::new("test").layer(svc);
+        ::metrics::with_local_recorder(&recorder, || {
+            futures::executor::block_on(async {
+                assert!((&mut svc).oneshot(Ping).await.is_err());
+                (&mut svc).oneshot(Ping).await.unwrap();
+            })
+        });
+        let mut counters = Vec::new();
+        let mut observations = 0;
+        for (key, _, _, value) in snapshotter.snapshot().into_vec() {
+            let key = key.key();
+            assert!(key
+                .labels()
+                .any(|l| l.key() == "exchange" && l.value() == "test"));
+            assert!(key
+                .labels()
+                .any(|l| l.key() == "request" && l.value() == "Ping"));
+            match value {
+                DebugValue::Counter(n) => counters.push((key.name().to_string(), n)),
+                DebugValue::Gauge(n) => assert_eq!(n.into_inner(), 0.0),
+                DebugValue::Histogram(values) => observations += values.len(),
+            }
+        }
+        counters.sort();
+        assert_eq!(
+            counters,
+            [
+                (RATE_LIMITED.to_string(), 1),
+                (REQUEST_ERRORS.to_string(), 1)
+            ]
+        );
+        assert_eq!(observations, 2);
+    }
+}
@@ -1,4 +1,7 @@
 use std::{
+    collections::hash_map::RandomState,
+    fmt,
+    hash::{BuildHasher, Hasher},
     marker::PhantomData,
     sync::{
         atomic::{AtomicU64, Ordering},
@@ -9,11 +12,17 @@ use std::{
 
 use futures::{future::BoxFuture, FutureExt};
 use humantime::format_duration;
+use tokio::time::Instant;
 use tower::retry::{Policy, RetryLayer};
 
+use crate::{ExchangeError, Request};
+
 const DEFAULT_MAX_SECS_TO_WAIT: u64 = 128;
 
 /// Retry Policy.
+///
+/// Every request is retried, wrap it in [`Idempotent`] to skip the requests
+/// that are not idempotent.
 #[derive(Debug)]
 pub enum RetryPolicy<T, U, F = ()> {
     /// On.
@@ -127,7 +136,10 @@ where
     }
 }
 
-/// Always.
+/// Always retry on errors.
+///
+/// Every request is retried, wrap it in [`Idempotent`] to skip the requests
+/// that are not idempotent.
 #[derive(Debug, Clone)]
 pub struct Always {
     times: Arc<AtomicU64>,
@@ -193,3 +205,265 @@ where
 pub fn retry_always(max_duration: Duration) -> RetryLayer<Always> {
     RetryLayer::new(Always::with_max_duration(max_duration))
 }
+
+/// Retry only the idempotent requests (see [`Request::is_idempotent`])
+/// with the inner policy.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Idempotent<P>(pub P);
+
+impl<T, U, E, P> Policy<T, U, E> for Idempotent<P>
+where
+    T: Request,
+    P: Policy<T, U, E>,
+{
+    type Future = futures::future::Map<P::Future, fn(P) -> Self>;
+
+    fn retry(&self, req: &T, result: Result<&U, &E>) -> Option<Self::Future> {
+        let fut = self.0.retry(req, result)?;
+        Some(fut.map(Idempotent as fn(P) -> Self))
+    }
+
+    fn clone_request(&self, req: &T) -> Option<T> {
+        // Retrying is disabled for the requests that are not idempotent.
+        if req.is_idempotent() {
+            self.0.clone_request(req)
+        } else {
+            None
+        }
+    }
+}
+
+/// Errors that tell whether the request is worth retrying.
+pub trait RetryableError: fmt::Display {
+    /// Whether the error is temporary, i.e. the request may succeed if retried.
+    fn is_temporary(&self) -> bool;
+
+    /// The time to wait before retrying told by the exchange.
+    fn retry_after(&self) -> Option<Duration> {
+        None
+    }
+}
+
+impl RetryableError for ExchangeError {
+    fn is_temporary(&self) -> bool {
+        ExchangeError::is_temporary(self)
+    }
+
+    fn retry_after(&self) -> Option<Duration> {
+        ExchangeError::retry_after(self)
+    }
+}
+
+/// A random duration in `[low, high]`.
+fn random_between(low: Duration, high: Duration) -> Duration {
+    let span = high.saturating_sub(low).as_nanos() as u64;
+    if span == 0 {
+        return low;
+    }
+    let random = RandomState::new().build_hasher().finish();
+    low + Duration::from_nanos(random % span)
+}
+
+/// Retry policy with decorrelated jitter.
+///
+/// Only the temporary errors (see [`RetryableError`]) of the idempotent requests
+/// (see [`Request::is_idempotent`]) are retried, waiting for a random duration
+/// between `base` and three times the previous wait (capped at `max_delay`),
+/// or for the `Retry-After` told by the exchange if longer.
+/// Gives up after `max_attempts` retries, or if the retry would happen later than
+/// `max_elapsed` after the first failure.
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    base: Duration,
+    max_delay: Duration,
+    max_attempts: usize,
+    max_elapsed: Duration,
+    attempts: usize,
+    delay: Duration,
+    failed_at: Option<Instant>,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            base: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_attempts: 5,
+            max_elapsed: Duration::from_secs(DEFAULT_MAX_SECS_TO_WAIT),
+            attempts: 0,
+            delay: Duration::ZERO,
+            failed_at: None,
+        }
+    }
+}
+
+impl Backoff {
+    /// Set the base (minimum) duration to wait before retrying (default to 500ms).
+    pub fn base(mut self, base: Duration) -> Self {
+        self.base = base;
+        self
+    }
+
+    /// Set the maximum duration to wait before retrying, unless the exchange
+    /// asks for a longer one (default to 30s).
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Set the maximum number of retries (default to 5).
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Set the maximum time to keep retrying after the first failure (default to 128s).
+    pub fn max_elapsed(mut self, max_elapsed: Duration) -> Self {
+        self.max_elapsed = max_elapsed;
+        self
+    }
+
+    /// Get the time to wait and the state of the next retry, or `None` to give up.
+    fn next<E: RetryableError>(&self, err: &E, now: Instant) -> Option<(Duration, Self)> {
+        if !err.is_temporary() {
+            tracing::trace!("not retrying on error: {err}");
+            return None;
+        }
+        if self.attempts >= self.max_attempts {
+            tracing::warn!("retry given up after {} attempts: {err}", self.attempts);
+            return None;
+        }
+        let failed_at = self.failed_at.unwrap_or(now);
+        let max_delay = self.max_delay.max(self.base);
+        let jittered = random_between(self.base, (self.delay * 3).clamp(self.base, max_delay));
+        let delay = match err.retry_after() {
+            Some(after) => after.max(jittered),
+            None => jittered,
+        };
+        if now + delay > failed_at + self.max_elapsed {
+            tracing::warn!(
+                "retry given up, would wait for {} beyond the deadline: {err}",
+                format_duration(delay)
+            );
+            return None;
+        }
+        let next = Self {
+            attempts: self.attempts + 1,
+            delay: jittered,
+            failed_at: Some(failed_at),
+            ..self.clone()
+        };
+        Some((delay, next))
+    }
+}
+
+impl<T, U, E> Policy<T, U, E> for Backoff
+where
+    T: Request + Clone,
+    E: RetryableError,
+{
+    type Future = BoxFuture<'static, Self>;
+
+    fn retry(&self, _req: &T, result: Result<&U, &E>) -> Option<Self::Future> {
+        let err = result.err()?;
+        let (delay, next) = self.next(err, Instant::now())?;
+        tracing::debug!(
+            "request error: {err}, retry in {} (attempt {})",
+            format_duration(delay),
+            next.attempts
+        );
+        let fut = async move {
+            tokio::time::sleep(delay).await;
+            next
+        };
+        Some(fut.boxed())
+    }
+
+    fn clone_request(&self, req: &T) -> Option<T> {
+        // Retrying is disabled for the requests that are not idempotent.
+        req.is_idempotent().then(|| req.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Place {
+        client_id: Option<&'static str>,
+    }
+
+    impl Request for Place {
+        type Response = ();
+
+        fn is_idempotent(&self) -> bool {
+            self.client_id.is_some()
+        }
+    }
+
+    #[test]
+    fn test_backoff() {
+        let backoff = Backoff::default()
+            .base(Duration::from_secs(1))
+            .max_delay(Duration::from_secs(10))
+            .max_attempts(3)
+            .max_elapsed(Duration::from_secs(60));
+        let now = Instant::now();
+        let temporary = ExchangeError::Unavailable(anyhow::anyhow!("unavailable"));
+
+        // Never retry on the errors that are not temporary.
+        assert!(backoff.next(&ExchangeError::OrderNotFound, now).is_none());
+
+        // The delays are jittered within the bounds, and the attempts are capped.
+        let mut state = backoff.clone();
+        for _ in 0..3 {
+            let (delay, next) = state.next(&temporary, now).unwrap();
+            assert!(delay >= Duration::from_secs(1) && delay <= Duration::from_secs(10));
+            state = next;
+        }
+        assert!(state.next(&temporary, now).is_none());
+
+        // Honor the retry after told by the exchange.
+        let limited = ExchangeError::rate_limited_with_retry_after(
+            anyhow::anyhow!("too many requests"),
+            Duration::from_secs(30),
+        );
+        let (delay, _) = backoff.next(&limited, now).unwrap();
+        assert_eq!(delay, Duration::from_secs(30));
+
+        // Give up if the retry would happen after the deadline.
+        let banned = ExchangeError::rate_limited_with_retry_after(
+            anyhow::anyhow!("banned"),
+            Duration::from_secs(3600),
+        );
+        assert!(backoff.next(&banned, now).is_none());
+
+        // Never retry the requests that are not idempotent.
+        let place = Place { client_id: None };
+        assert!(Policy::<_, (), ExchangeError>::clone_request(&backoff, &place).is_none());
+        let place = Place {
+            client_id: Some("my-order"),
+        };
+        assert!(Policy::<_, (), ExchangeError>::clone_request(&backoff, &place).is_some());
+    }
+
+    #[test]
+    fn test_never_retry_non_idempotent() {
+        let place = Place { client_id: None };
+        let policy = RetryPolicy::<Place, (), ()>::default()
+            .retry_on::<ExchangeError, _>(|_: &ExchangeError| true);
+        let always = Always::default();
+        // The policies retry every request unless wrapped in `Idempotent`.
+        assert!(Policy::<_, (), ExchangeError>::clone_request(&always, &place).is_some());
+        let policy = Idempotent(policy);
+        let always = Idempotent(always);
+        assert!(Policy::<_, (), ExchangeError>::clone_request(&policy, &place).is_none());
+        assert!(Policy::<_, (), ExchangeError>::clone_request(&always, &place).is_none());
+        let place = Place {
+            client_id: Some("my-order"),
+        };
+        assert!(Policy::<_, (), ExchangeError>::clone_request(&policy, &place).is_some());
+        assert!(Policy::<_, (), ExchangeError>::clone_request(&always, &place).is_some());
+    }
+}
@@ -21,6 +21,22 @@ pub mod send;
 pub trait Request: Sized {
     /// Response type.
     type Response;
+
+    /// Whether sending the request more than once has the same effect as sending it once,
+    /// so that it can be retried safely (default to `true`).
+    fn is_idempotent(&self) -> bool {
+        true
+    }
+
+    /// The instrument the request is about, if any (used for diagnostics).
+    fn instrument(&self) -> Option<&str> {
+        None
+    }
+
+    /// The client id of the order the request is about, if any (used for diagnostics).
+    fn client_id(&self) -> Option<&str> {
+        None
+    }
 }
 
 /// An alias of [`Service`] that requires the input type to be a [`Request`],
@@ -207,6 +223,23 @@ where
         self.into_service().apply(&RateLimitLayer::new(num, per))
     }
 
+    /// Apply a weighted rate-limit layer to the service.
+    #[cfg(feature = "limit")]
+    fn weighted_rate_limited(
+        self,
+        limiter: crate::RateLimiter,
+    ) -> crate::weight::WeightedRateLimit<IntoService<Self, R>>
+    where
+        R: crate::RequestWeight + Send + 'static,
+        R::Response: Send + 'static,
+        Self: Sized + Clone + Send + 'static,
+        Self::Future: Send + 'static,
+    {
+        use crate::weight::WeightedRateLimitLayer;
+        self.into_service()
+            .apply(&WeightedRateLimitLayer::new(limiter))
+    }
+
     /// Apply a retry layer to the service.
     #[cfg(feature = "retry")]
     fn retry(
@@ -224,6 +257,20 @@ where
             .apply(&RetryLayer::new(Always::with_max_duration(max_duration)))
     }
 
+    /// Apply a retry layer with the given policy (e.g. [`Backoff`](crate::retry::Backoff))
+    /// to the service.
+    #[cfg(feature = "retry")]
+    fn retry_with<P>(self, policy: P) -> tower::retry::Retry<P, IntoService<Self, R>>
+    where
+        R: Clone,
+        Self: Sized + Clone,
+        P: tower::retry::Policy<R, R::Response, ExchangeError> + Clone,
+    {
+        use tower::retry::RetryLayer;
+
+        self.into_service().apply(&RetryLayer::new(policy))
+    }
+
     /// Create a boxed [`ExcService`].
     fn boxed(self) -> BoxExcService<R>
     where
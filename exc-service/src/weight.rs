@@ -0,0 +1,342 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use futures::{future::BoxFuture, FutureExt, TryFutureExt};
+use tokio::time::Instant;
+use tower::{Layer, Service, ServiceExt};
+
+use crate::ExchangeError;
+
+/// The bucket of the requests, in which every request of `exc-types` costs `1`.
+pub const REQUESTS: &str = "requests";
+
+/// The bucket of the orders, in which every order placed (or amended)
+/// by the requests of `exc-types` costs `1`.
+pub const ORDERS: &str = "orders";
+
+/// The cost of a request in the buckets of a [`RateLimiter`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Weight {
+    costs: Vec<(&'static str, u64)>,
+}
+
+impl Weight {
+    /// Create a weight that costs nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a weight that costs `cost` of the bucket.
+    pub fn bucket(name: &'static str, cost: u64) -> Self {
+        Self::new().with(name, cost)
+    }
+
+    /// Add `cost` of the bucket to the weight.
+    pub fn with(mut self, name: &'static str, cost: u64) -> Self {
+        match self.costs.iter_mut().find(|(bucket, _)| *bucket == name) {
+            Some((_, total)) => *total += cost,
+            None => self.costs.push((name, cost)),
+        }
+        self
+    }
+
+    /// Iterate over the costs of the buckets.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, u64)> + '_ {
+        self.costs.iter().copied()
+    }
+
+    /// Whether the weight costs nothing.
+    pub fn is_empty(&self) -> bool {
+        self.costs.iter().all(|(_, cost)| *cost == 0)
+    }
+}
+
+/// Requests that declare their cost in the buckets of a [`RateLimiter`].
+pub trait RequestWeight {
+    /// The weight of the request.
+    fn weight(&self) -> Weight;
+}
+
+#[derive(Debug)]
+struct Bucket {
+    capacity: u64,
+    window: Duration,
+    /// The end of the current window.
+    end: Instant,
+    used: u64,
+}
+
+impl Bucket {
+    /// Create a bucket of which the windows are aligned to the wall clock
+    /// (e.g. the minute windows start at the beginning of the minutes),
+    /// as the exchanges reset their counters.
+    fn new(capacity: u64, window: Duration, now: Instant) -> Self {
+        let since_epoch = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let offset = since_epoch.as_nanos() % window.as_nanos().max(1);
+        let offset = Duration::from_nanos(offset as u64);
+        Self {
+            capacity,
+            window,
+            end: now + (window - offset),
+            used: 0,
+        }
+    }
+
+    fn refresh(&mut self, now: Instant) {
+        if now >= self.end {
+            let elapsed = now.duration_since(self.end).as_nanos();
+            let window = self.window.as_nanos().max(1);
+            let skipped = elapsed - elapsed % window + window;
+            self.end += Duration::from_nanos(skipped as u64);
+            self.used = 0;
+        }
+    }
+}
+
+/// A set of buckets with a capacity per window (e.g. the IP weight per minute
+/// and the order count per 10 seconds), shared by the services using the same limits.
+/// The windows are aligned to the wall clock.
+///
+/// The usage is counted locally by [`RateLimiter::try_acquire`], and can be corrected
+/// with the usage reported by the exchange with [`RateLimiter::record_usage`].
+/// The costs of the buckets that are not registered are ignored.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<HashMap<&'static str, Bucket>>>,
+}
+
+impl RateLimiter {
+    /// Create a rate limiter without buckets.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a bucket allowing `capacity` in every `window`.
+    pub fn with_bucket(self, name: &'static str, capacity: u64, window: Duration) -> Self {
+        self.buckets
+            .lock()
+            .unwrap()
+            .insert(name, Bucket::new(capacity, window, Instant::now()));
+        self
+    }
+
+    /// Try to acquire the weight from all the buckets at once.
+    ///
+    /// Returns `Ok(None)` if acquired, or `Ok(Some(wait))` with the time to wait
+    /// before trying again. Returns an error if the weight can never be acquired.
+    pub fn try_acquire(&self, weight: &Weight) -> Result<Option<Duration>, ExchangeError> {
+        if weight.is_empty() {
+            return Ok(None);
+        }
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        let mut wait = None;
+        for (name, cost) in weight.iter() {
+            let Some(bucket) = buckets.get_mut(name) else {
+                continue;
+            };
+            if cost > bucket.capacity {
+                return Err(ExchangeError::Other(anyhow::anyhow!(
+                    "the cost {cost} exceeds the capacity {} of bucket `{name}`",
+                    bucket.capacity
+                )));
+            }
+            bucket.refresh(now);
+            if bucket.used + cost > bucket.capacity {
+                let until = bucket.end.saturating_duration_since(now);
+                wait = wait.max(Some(until));
+            }
+        }
+        if wait.is_some() {
+            return Ok(wait);
+        }
+        for (name, cost) in weight.iter() {
+            if let Some(bucket) = buckets.get_mut(name) {
+                bucket.used += cost;
+            }
+        }
+        Ok(None)
+    }
+
+    /// Record the usage of the bucket reported by the exchange.
+    ///
+    /// The larger of the local and the reported usage is kept, since neither
+    /// the requests in flight nor the other clients sharing the limit
+    /// are seen by both.
+    pub fn record_usage(&self, name: &str, used: u64) {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        if let Some(bucket) = buckets.get_mut(name) {
+            bucket.refresh(now);
+            bucket.used = bucket.used.max(used);
+        }
+    }
+
+    /// Get the usage of the bucket in the current window.
+    pub fn usage(&self, name: &str) -> Option<u64> {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets.get_mut(name).map(|bucket| {
+            bucket.refresh(now);
+            bucket.used
+        })
+    }
+
+    /// Wait until the weight is acquired.
+    pub async fn acquire(&self, weight: &Weight) -> Result<(), ExchangeError> {
+        while let Some(wait) = self.try_acquire(weight)? {
+            tracing::debug!("rate limited; wait for {wait:?}");
+            tokio::time::sleep(wait).await;
+        }
+        Ok(())
+    }
+}
+
+/// Weighted rate limit layer.
+#[derive(Debug, Clone)]
+pub struct WeightedRateLimitLayer {
+    limiter: RateLimiter,
+}
+
+impl WeightedRateLimitLayer {
+    /// Create a new weighted rate limit layer acquiring the weights from `limiter`.
+    pub fn new(limiter: RateLimiter) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<S> Layer<S> for WeightedRateLimitLayer {
+    type Service = WeightedRateLimit<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        WeightedRateLimit {
+            svc: inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+/// Weighted rate limit.
+///
+/// Acquire the [`Weight`] of the request from the [`RateLimiter`] before sending it,
+/// waiting for the windows of the exhausted buckets to end.
+#[derive(Debug, Clone)]
+pub struct WeightedRateLimit<S> {
+    svc: S,
+    limiter: RateLimiter,
+}
+
+impl<S> WeightedRateLimit<S> {
+    /// Get the rate limiter.
+    pub fn limiter(&self) -> &RateLimiter {
+        &self.limiter
+    }
+}
+
+impl<S, R> Service<R> for WeightedRateLimit<S>
+where
+    R: RequestWeight + Send + 'static,
+    S: Service<R> + Clone + Send + 'static,
+    S::Response: Send + 'static,
+    S::Error: From<ExchangeError> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.svc.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        let weight = req.weight();
+        match self.limiter.try_acquire(&weight) {
+            Ok(None) => self.svc.call(req).boxed(),
+            Ok(Some(_)) => {
+                let clone = self.svc.clone();
+                let svc = std::mem::replace(&mut self.svc, clone);
+                let limiter = self.limiter.clone();
+                async move {
+                    limiter.acquire(&weight).await?;
+                    svc.oneshot(req).await
+                }
+                .boxed()
+            }
+            Err(err) => futures::future::ready(Err(err)).err_into().boxed(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limiter() {
+        let limiter = RateLimiter::new()
+            .with_bucket("weight", 10, Duration::from_secs(60))
+            .with_bucket("orders", 2, Duration::from_secs(10));
+        let order = Weight::bucket("weight", 1).with("orders", 1);
+        assert_eq!(limiter.try_acquire(&order).unwrap(), None);
+        assert_eq!(limiter.try_acquire(&order).unwrap(), None);
+        assert!(limiter.try_acquire(&order).unwrap().is_some());
+        // Nothing is acquired if one of the buckets is exhausted.
+        assert_eq!(limiter.usage("weight"), Some(2));
+        assert_eq!(
+            limiter.try_acquire(&Weight::bucket("weight", 5)).unwrap(),
+            None
+        );
+        limiter.record_usage("weight", 9);
+        assert!(limiter
+            .try_acquire(&Weight::bucket("weight", 2))
+            .unwrap()
+            .is_some());
+        limiter.record_usage("weight", 1);
+        assert_eq!(limiter.usage("weight"), Some(9));
+        assert!(limiter.try_acquire(&Weight::bucket("weight", 11)).is_err());
+        assert_eq!(
+            limiter.try_acquire(&Weight::bucket("other", 11)).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_aligned_windows() {
+        let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        let limiter = RateLimiter::new().with_bucket("weight", 1, Duration::from_secs(60));
+        assert_eq!(
+            limiter.try_acquire(&Weight::bucket("weight", 1)).unwrap(),
+            None
+        );
+        let wait = limiter
+            .try_acquire(&Weight::bucket("weight", 1))
+            .unwrap()
+            .unwrap();
+        // The window ends at the beginning of the next minute
+        // (modulo a minute in case a minute has just begun).
+        let until = 60.0 - since_epoch.as_secs_f64() % 60.0;
+        assert!((until - wait.as_secs_f64()).rem_euclid(60.0) < 1.0);
+    }
+
+    #[test]
+    fn test_refresh_windows() {
+        let now = Instant::now();
+        let mut bucket = Bucket::new(1, Duration::from_secs(10), now);
+        let end = bucket.end;
+        assert!(end > now && end <= now + Duration::from_secs(10));
+        bucket.used = 1;
+        bucket.refresh(end - Duration::from_secs(1));
+        assert_eq!(bucket.used, 1);
+        // The windows stay aligned after skipping the idle windows.
+        bucket.refresh(end + Duration::from_secs(25));
+        assert_eq!(bucket.used, 0);
+        assert_eq!(bucket.end, end + Duration::from_secs(30));
+    }
+}
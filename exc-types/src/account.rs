@@ -0,0 +1,92 @@
+use std::{collections::HashMap, fmt};
+
+use exc_service::{ExchangeError, Request};
+use futures::{future::BoxFuture, stream::BoxStream};
+use indicator::{Tick, TickValue, Tickable};
+use positions::Asset;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+/// Balance of an asset.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Balance {
+    /// Available to use.
+    pub free: Decimal,
+    /// Locked (e.g. by open orders).
+    pub locked: Decimal,
+    /// Total.
+    pub total: Decimal,
+}
+
+impl Balance {
+    /// Create a balance from `free` and `locked`.
+    pub fn new(free: Decimal, locked: Decimal) -> Self {
+        Self {
+            free,
+            locked,
+            total: free + locked,
+        }
+    }
+}
+
+/// Balances keyed by asset.
+#[derive(Clone)]
+pub struct Balances {
+    /// Timestamp.
+    pub ts: OffsetDateTime,
+    /// Balances.
+    pub balances: HashMap<Asset, Balance>,
+}
+
+impl fmt::Debug for Balances {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Balances")
+            .field("ts", &self.ts.to_string())
+            .field("balances", &self.balances)
+            .finish()
+    }
+}
+
+impl Balances {
+    /// Get the balance of the given asset.
+    pub fn get(&self, asset: &Asset) -> Option<&Balance> {
+        self.balances.get(asset)
+    }
+}
+
+impl Tickable for Balances {
+    type Value = HashMap<Asset, Balance>;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.balances
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.ts, self.balances)
+    }
+}
+
+/// Fetch balances.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FetchBalances;
+
+impl Request for FetchBalances {
+    type Response = BoxFuture<'static, Result<Balances, ExchangeError>>;
+}
+
+/// Balance Stream.
+pub type BalanceStream = BoxStream<'static, Result<Balances, ExchangeError>>;
+
+/// Subscribe to balance updates.
+/// # Note
+/// An update may only contain the assets that have changed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SubscribeBalances;
+
+impl Request for SubscribeBalances {
+    type Response = BalanceStream;
+}
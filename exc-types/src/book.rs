@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::Str;
-use futures::stream::BoxStream;
+use futures::{future::BoxFuture, stream::BoxStream};
 use indicator::{Tick, TickValue, Tickable};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -78,3 +78,184 @@ impl SubscribeBidAsk {
 impl Request for SubscribeBidAsk {
     type Response = BidAskStream;
 }
+
+/// Order book (L2) Stream.
+pub type OrderBookStream = BoxStream<'static, Result<OrderBook, ExchangeError>>;
+
+/// A consistent L2 snapshot of the order book.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct OrderBook {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Bids (price, size), best first.
+    pub bids: Vec<(Decimal, Decimal)>,
+    /// Asks (price, size), best first.
+    pub asks: Vec<(Decimal, Decimal)>,
+}
+
+impl fmt::Debug for OrderBook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrderBook")
+            .field("ts", &self.ts.to_string())
+            .field("bids", &self.bids)
+            .field("asks", &self.asks)
+            .finish()
+    }
+}
+
+impl OrderBook {
+    /// Best bid (price, size).
+    pub fn best_bid(&self) -> Option<(Decimal, Decimal)> {
+        self.bids.first().copied()
+    }
+
+    /// Best ask (price, size).
+    pub fn best_ask(&self) -> Option<(Decimal, Decimal)> {
+        self.asks.first().copied()
+    }
+
+    /// Convert to [`BidAsk`].
+    pub fn to_bid_ask(&self) -> BidAsk {
+        BidAsk {
+            ts: self.ts,
+            bid: self.best_bid(),
+            ask: self.best_ask(),
+        }
+    }
+}
+
+impl Tickable for OrderBook {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.ts, self)
+    }
+}
+
+/// Subscribe L2 snapshots of the order book.
+#[derive(Debug, Clone)]
+pub struct SubscribeOrderBook {
+    /// Instrument.
+    pub instrument: Str,
+    /// Number of levels of each side in the snapshots.
+    pub depth: usize,
+}
+
+impl SubscribeOrderBook {
+    /// Create a new [`SubscribeOrderBook`] request.
+    pub fn new(inst: impl AsRef<str>, depth: usize) -> Self {
+        Self {
+            instrument: Str::new(inst),
+            depth,
+        }
+    }
+}
+
+impl Request for SubscribeOrderBook {
+    type Response = OrderBookStream;
+}
+
+/// Kind of an order book update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderBookUpdateKind {
+    /// A full snapshot, replacing the whole book.
+    Snapshot,
+    /// A diff, a level with zero size is to be removed.
+    Update,
+}
+
+/// Order book update (snapshot or diff) with sequence infos.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct OrderBookUpdate {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Kind.
+    pub kind: OrderBookUpdateKind,
+    /// First update id contained in this update.
+    pub first_id: Option<u64>,
+    /// Last update id contained in this update.
+    pub last_id: Option<u64>,
+    /// The last update id of the previous update.
+    pub prev_id: Option<u64>,
+    /// CRC32 checksum of the book after applying this update.
+    pub checksum: Option<i32>,
+    /// Bids (price, size).
+    pub bids: Vec<(Decimal, Decimal)>,
+    /// Asks (price, size).
+    pub asks: Vec<(Decimal, Decimal)>,
+}
+
+impl fmt::Debug for OrderBookUpdate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OrderBookUpdate")
+            .field("ts", &self.ts.to_string())
+            .field("kind", &self.kind)
+            .field("first_id", &self.first_id)
+            .field("last_id", &self.last_id)
+            .field("prev_id", &self.prev_id)
+            .field("checksum", &self.checksum)
+            .field("bids", &self.bids)
+            .field("asks", &self.asks)
+            .finish()
+    }
+}
+
+/// Order book update Stream.
+pub type OrderBookUpdateStream = BoxStream<'static, Result<OrderBookUpdate, ExchangeError>>;
+
+/// Subscribe raw order book updates (diffs, and snapshots if provided by the exchange).
+#[derive(Debug, Clone)]
+pub struct SubscribeOrderBookUpdates {
+    /// Instrument.
+    pub instrument: Str,
+}
+
+impl SubscribeOrderBookUpdates {
+    /// Create a new [`SubscribeOrderBookUpdates`] request.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+        }
+    }
+}
+
+impl Request for SubscribeOrderBookUpdates {
+    type Response = OrderBookUpdateStream;
+}
+
+/// Fetch an order book snapshot.
+#[derive(Debug, Clone)]
+pub struct FetchOrderBook {
+    /// Instrument.
+    pub instrument: Str,
+    /// Number of levels of each side.
+    pub depth: usize,
+}
+
+impl FetchOrderBook {
+    /// Create a new [`FetchOrderBook`] request.
+    pub fn new(inst: impl AsRef<str>, depth: usize) -> Self {
+        Self {
+            instrument: Str::new(inst),
+            depth,
+        }
+    }
+}
+
+impl Request for FetchOrderBook {
+    type Response = BoxFuture<'static, Result<OrderBookUpdate, ExchangeError>>;
+
+    fn instrument(&self) -> Option<&str> {
+        Some(&self.instrument)
+    }
+}
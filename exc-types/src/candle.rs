@@ -74,7 +74,7 @@ pub struct QueryCandles {
     pub end: Bound<OffsetDateTime>,
 }
 
-fn fmt_ts_start_bound(bound: &Bound<OffsetDateTime>) -> String {
+pub(crate) fn fmt_ts_start_bound(bound: &Bound<OffsetDateTime>) -> String {
     match bound {
         Bound::Unbounded => "(".to_string(),
         Bound::Excluded(ts) => format!("({ts}"),
@@ -82,7 +82,7 @@ fn fmt_ts_start_bound(bound: &Bound<OffsetDateTime>) -> String {
     }
 }
 
-fn fmt_ts_end_bound(bound: &Bound<OffsetDateTime>) -> String {
+pub(crate) fn fmt_ts_end_bound(bound: &Bound<OffsetDateTime>) -> String {
     match bound {
         Bound::Unbounded => ")".to_string(),
         Bound::Excluded(ts) => format!("{ts})"),
@@ -171,6 +171,10 @@ impl RangeBounds<OffsetDateTime> for QueryCandles {
 
 impl Request for QueryCandles {
     type Response = CandleStream;
+
+    fn instrument(&self) -> Option<&str> {
+        Some(&self.inst)
+    }
 }
 
 /// Query last `n` candles in range.
@@ -212,6 +216,10 @@ impl QueryLastCandles {
 
 impl Request for QueryLastCandles {
     type Response = CandleStream;
+
+    fn instrument(&self) -> Option<&str> {
+        Some(&self.query.inst)
+    }
 }
 
 /// Query first `n` candles in range.
@@ -253,6 +261,69 @@ impl QueryFirstCandles {
 
 impl Request for QueryFirstCandles {
     type Response = CandleStream;
+
+    fn instrument(&self) -> Option<&str> {
+        Some(&self.query.inst)
+    }
+}
+
+/// Candle update stream.
+pub type CandleUpdateStream = BoxStream<'static, Result<CandleUpdate, ExchangeError>>;
+
+/// Subscribe live candles.
+/// Return a stream of the updates of the current bar.
+#[derive(Debug, Clone)]
+pub struct SubscribeCandles {
+    /// Instrument.
+    pub instrument: Str,
+    /// Period.
+    pub period: Period,
+}
+
+impl SubscribeCandles {
+    /// Create a new [`SubscribeCandles`] request.
+    pub fn new(inst: impl AsRef<str>, period: Period) -> Self {
+        Self {
+            instrument: Str::new(inst),
+            period,
+        }
+    }
+}
+
+impl fmt::Display for SubscribeCandles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.instrument, self.period)
+    }
+}
+
+impl Request for SubscribeCandles {
+    type Response = CandleUpdateStream;
+}
+
+/// Candle update.
+#[derive(Debug, Clone, Serialize, Deserialize, Display)]
+#[display(fmt = "{candle} closed={closed}")]
+pub struct CandleUpdate {
+    /// The bar.
+    pub candle: Candle,
+    /// Whether the bar is closed (`false` for the in-progress bar).
+    pub closed: bool,
+}
+
+impl Tickable for CandleUpdate {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.candle.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.candle.ts, self)
+    }
 }
 
 /// Candle (OHLCV).
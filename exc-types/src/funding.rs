@@ -0,0 +1,199 @@
+use std::{
+    fmt,
+    ops::{Bound, RangeBounds},
+};
+
+use derive_more::Display;
+use exc_service::{ExchangeError, Request};
+use futures::{future::BoxFuture, stream::BoxStream};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::Str;
+
+/// Funding rate (a settled funding).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Display)]
+#[display(fmt = "ts={ts} rate={rate}")]
+pub struct FundingRate {
+    /// Funding time.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Funding rate.
+    pub rate: Decimal,
+}
+
+/// Funding rate stream.
+pub type FundingRateStream = BoxStream<'static, Result<FundingRate, ExchangeError>>;
+
+/// Query the funding rates in the range.
+/// Return a funding rate stream that produce the funding rates forward.
+#[derive(Debug, Clone)]
+pub struct QueryFundingRates {
+    /// Instrument.
+    pub instrument: Str,
+    /// Start.
+    pub start: Bound<OffsetDateTime>,
+    /// End.
+    pub end: Bound<OffsetDateTime>,
+}
+
+impl QueryFundingRates {
+    /// Create a new [`QueryFundingRates`] request.
+    pub fn new<R>(inst: impl AsRef<str>, range: R) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            instrument: Str::new(inst),
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+
+    /// Is empty.
+    pub fn is_empty(&self) -> bool {
+        match (self.start_bound(), self.end_bound()) {
+            (Bound::Unbounded, _) => false,
+            (_, Bound::Unbounded) => false,
+            (Bound::Included(start), Bound::Included(end)) => *start > *end,
+            (Bound::Included(start), Bound::Excluded(end)) => *start >= *end,
+            (Bound::Excluded(start), Bound::Included(end)) => *start >= *end,
+            (Bound::Excluded(start), Bound::Excluded(end)) => *start >= *end,
+        }
+    }
+}
+
+impl fmt::Display for QueryFundingRates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}, {}",
+            self.instrument,
+            crate::candle::fmt_ts_start_bound(&self.start),
+            crate::candle::fmt_ts_end_bound(&self.end)
+        )
+    }
+}
+
+impl RangeBounds<OffsetDateTime> for QueryFundingRates {
+    fn start_bound(&self) -> Bound<&OffsetDateTime> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&OffsetDateTime> {
+        self.end.as_ref()
+    }
+}
+
+impl Request for QueryFundingRates {
+    type Response = FundingRateStream;
+}
+
+/// Query first `n` funding rates in range.
+/// Return a funding rate stream that produce the first `first` funding rates forward.
+#[derive(Debug, Clone)]
+pub struct QueryFirstFundingRates {
+    /// Query.
+    pub query: QueryFundingRates,
+    /// First.
+    pub first: usize,
+}
+
+impl fmt::Display for QueryFirstFundingRates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-({})", self.query, self.first)
+    }
+}
+
+impl QueryFirstFundingRates {
+    /// Create a new query.
+    pub fn new<R>(inst: impl AsRef<str>, range: R, first: usize) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            query: QueryFundingRates::new(inst, range),
+            first,
+        }
+    }
+
+    /// Get first.
+    pub fn first(&self) -> usize {
+        self.first
+    }
+
+    /// Get query.
+    pub fn query(&self) -> &QueryFundingRates {
+        &self.query
+    }
+}
+
+impl Request for QueryFirstFundingRates {
+    type Response = FundingRateStream;
+}
+
+/// Current funding rate.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Display)]
+#[display(
+    fmt = "ts={ts} rate={rate} funding_time={funding_time} next=({next_rate:?}, {next_funding_time:?})"
+)]
+pub struct CurrentFundingRate {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// The funding rate to be settled at `funding_time`.
+    pub rate: Decimal,
+    /// The upcoming funding time.
+    #[serde(with = "time::serde::rfc3339")]
+    pub funding_time: OffsetDateTime,
+    /// The (forecasted) funding rate of the funding after the upcoming one.
+    #[serde(default)]
+    pub next_rate: Option<Decimal>,
+    /// The funding time after the upcoming one.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub next_funding_time: Option<OffsetDateTime>,
+}
+
+/// Current funding rate stream.
+pub type CurrentFundingRateStream = BoxStream<'static, Result<CurrentFundingRate, ExchangeError>>;
+
+/// Fetch the current funding rate.
+#[derive(Debug, Clone)]
+pub struct FetchFundingRate {
+    /// Instrument.
+    pub instrument: Str,
+}
+
+impl FetchFundingRate {
+    /// Create a new [`FetchFundingRate`] request.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+        }
+    }
+}
+
+impl Request for FetchFundingRate {
+    type Response = BoxFuture<'static, Result<CurrentFundingRate, ExchangeError>>;
+}
+
+/// Subscribe the updates of the current funding rate.
+#[derive(Debug, Clone)]
+pub struct SubscribeFundingRate {
+    /// Instrument.
+    pub instrument: Str,
+}
+
+impl SubscribeFundingRate {
+    /// Create a new [`SubscribeFundingRate`] request.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+        }
+    }
+}
+
+impl Request for SubscribeFundingRate {
+    type Response = CurrentFundingRateStream;
+}
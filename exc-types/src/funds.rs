@@ -0,0 +1,160 @@
+use std::{
+    fmt,
+    ops::{Bound, RangeBounds},
+};
+
+use exc_service::{ExchangeError, Request};
+use futures::future::BoxFuture;
+use positions::Asset;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::{transfer::TransferStatus, Str};
+
+/// A deposit or a withdrawal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FundsRecord {
+    /// Id of the record at the exchange.
+    pub id: Str,
+    /// Transaction id (hash) on the chain.
+    pub tx_id: Option<Str>,
+    /// Asset.
+    pub asset: Asset,
+    /// Network (chain).
+    pub network: Str,
+    /// Amount.
+    pub amount: Decimal,
+    /// Fee (withdrawals only).
+    pub fee: Option<Decimal>,
+    /// Address.
+    pub address: Str,
+    /// Status (a canceled or rejected record is considered failed).
+    pub status: TransferStatus,
+    /// The time of the record created.
+    pub ts: OffsetDateTime,
+    /// The time of the record completed (if available).
+    pub completed_at: Option<OffsetDateTime>,
+}
+
+/// Query the deposit (or withdrawal) history.
+#[derive(Debug, Clone)]
+pub struct QueryFunds {
+    /// Asset (all assets if not set).
+    pub asset: Option<Asset>,
+    /// Start.
+    pub start: Bound<OffsetDateTime>,
+    /// End.
+    pub end: Bound<OffsetDateTime>,
+}
+
+impl QueryFunds {
+    fn new<R>(range: R) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            asset: None,
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+}
+
+impl fmt::Display for QueryFunds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.asset {
+            Some(asset) => write!(f, "{asset}-")?,
+            None => write!(f, "*-")?,
+        }
+        write!(
+            f,
+            "{}, {}",
+            crate::candle::fmt_ts_start_bound(&self.start),
+            crate::candle::fmt_ts_end_bound(&self.end)
+        )
+    }
+}
+
+impl RangeBounds<OffsetDateTime> for QueryFunds {
+    fn start_bound(&self) -> Bound<&OffsetDateTime> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&OffsetDateTime> {
+        self.end.as_ref()
+    }
+}
+
+/// Query the deposit history.
+///
+/// The records are returned newest first, up to the limit of a single query of the exchange.
+#[derive(Debug, Clone)]
+pub struct QueryDeposits {
+    /// Query.
+    pub query: QueryFunds,
+}
+
+impl QueryDeposits {
+    /// Create a new [`QueryDeposits`] request for all assets.
+    pub fn new<R>(range: R) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            query: QueryFunds::new(range),
+        }
+    }
+
+    /// Only query the deposits of the given asset.
+    pub fn asset(mut self, asset: Asset) -> Self {
+        self.query.asset = Some(asset);
+        self
+    }
+}
+
+impl fmt::Display for QueryDeposits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "deposits {}", self.query)
+    }
+}
+
+impl Request for QueryDeposits {
+    type Response = BoxFuture<'static, Result<Vec<FundsRecord>, ExchangeError>>;
+}
+
+/// Query the withdrawal history.
+///
+/// The records are returned newest first, up to the limit of a single query of the exchange.
+#[derive(Debug, Clone)]
+pub struct QueryWithdrawals {
+    /// Query.
+    pub query: QueryFunds,
+}
+
+impl QueryWithdrawals {
+    /// Create a new [`QueryWithdrawals`] request for all assets.
+    pub fn new<R>(range: R) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            query: QueryFunds::new(range),
+        }
+    }
+
+    /// Only query the withdrawals of the given asset.
+    pub fn asset(mut self, asset: Asset) -> Self {
+        self.query.asset = Some(asset);
+        self
+    }
+}
+
+impl fmt::Display for QueryWithdrawals {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "withdrawals {}", self.query)
+    }
+}
+
+impl Request for QueryWithdrawals {
+    type Response = BoxFuture<'static, Result<Vec<FundsRecord>, ExchangeError>>;
+}
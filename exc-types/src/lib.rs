@@ -17,12 +17,40 @@ pub mod candle;
 /// Instrument.
 pub mod instrument;
 
+/// Funding.
+pub mod funding;
+
+/// Open interest.
+pub mod open_interest;
+
+/// Liquidation.
+pub mod liquidation;
+
+/// Option market data.
+pub mod option;
+
 /// Trading.
 pub mod trading;
 
+/// Account.
+pub mod account;
+
+/// Transfer.
+pub mod transfer;
+
+/// Deposits and withdrawals.
+pub mod funds;
+
+/// Pagination.
+pub mod page;
+
 /// Utils.
 pub mod utils;
 
+/// The weights of the requests.
+#[cfg(feature = "limit")]
+pub mod weight;
+
 /// Exc Symbol.
 pub mod symbol {
     pub use exc_symbol::*;
@@ -31,15 +59,42 @@ pub mod symbol {
 pub use self::instrument::{
     FetchInstruments, InstrumentMeta, InstrumentStream, SubscribeInstruments,
 };
-pub use book::{BidAsk, BidAskStream, SubscribeBidAsk};
+pub use account::{Balance, BalanceStream, Balances, FetchBalances, SubscribeBalances};
+pub use book::{
+    BidAsk, BidAskStream, FetchOrderBook, OrderBook, OrderBookStream, OrderBookUpdate,
+    OrderBookUpdateKind, OrderBookUpdateStream, SubscribeBidAsk, SubscribeOrderBook,
+    SubscribeOrderBookUpdates,
+};
 pub use candle::{
-    Candle, CandleStream, Period, PeriodKind, QueryCandles, QueryFirstCandles, QueryLastCandles,
+    Candle, CandleStream, CandleUpdate, CandleUpdateStream, Period, PeriodKind, QueryCandles,
+    QueryFirstCandles, QueryLastCandles, SubscribeCandles,
+};
+pub use funding::{
+    CurrentFundingRate, CurrentFundingRateStream, FetchFundingRate, FundingRate, FundingRateStream,
+    QueryFirstFundingRates, QueryFundingRates, SubscribeFundingRate,
 };
+pub use funds::{FundsRecord, QueryDeposits, QueryFunds, QueryWithdrawals};
+pub use liquidation::{Liquidation, LiquidationStream, SubscribeLiquidations};
+pub use open_interest::{
+    OpenInterest, OpenInterestStream, QueryOpenInterest, SubscribeOpenInterest,
+};
+pub use option::{OptionGreeks, OptionGreeksStream, SubscribeOptionGreeks};
+pub use page::{Page, Paged, PagedRequest};
 pub use positions::prelude::Str;
-pub use ticker::{SubscribeTickers, Ticker, TickerStream};
-pub use trade::{SubscribeTrades, Trade, TradeStream};
+pub use ticker::{
+    MarkPrice, MarkPriceStream, SubscribeMarkPrice, SubscribeTickers, Ticker, TickerStream,
+};
+pub use trade::{
+    QueryFirstTrades, QueryLastTrades, QueryTrades, SubscribeTrades, Trade, TradeHistoryStream,
+    TradeRecord, TradeStream,
+};
 pub use trading::{
-    CancelOrder, Canceled, GetOrder, Order, OrderId, OrderKind, OrderState, OrderStatus,
-    OrderStream, OrderTrade, OrderUpdate, Place, PlaceOrder, PlaceOrderOptions, Placed,
-    SubscribeOrders, TimeInForce,
+    AmendOrder, Amended, CancelAllAfter, CancelAllOrders, CancelOrder, CancelOrders, Canceled,
+    CanceledBatch, FeeRates, FetchFeeRates, FetchPositions, Fill, FillStream, GetLeverage,
+    GetOrder, Leverage, ListOpenOrders, MarginMode, Order, OrderId, OrderKind, OrderState,
+    OrderStatus, OrderStream, OrderTrade, OrderUpdate, Place, PlaceOrder, PlaceOrderOptions,
+    PlaceOrders, Placed, PlacedBatch, Position, PositionMode, PositionSide, PositionStream,
+    QueryFills, QueryLastFills, QueryOrderHistory, SetLeverage, SetMarginMode, SetPositionMode,
+    SubscribeOrders, SubscribePositions, TimeInForce, TrailingStop, Trigger, TriggerSource,
 };
+pub use transfer::{QueryTransfer, Transfer, TransferState, TransferStatus, Wallet, WalletKind};
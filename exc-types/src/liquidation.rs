@@ -0,0 +1,64 @@
+use derive_more::Display;
+use exc_service::{ExchangeError, Request};
+use futures::stream::BoxStream;
+use indicator::{Tick, TickValue, Tickable};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::Str;
+
+/// Liquidation (a liquidation order of the exchange).
+#[derive(Debug, Clone, Serialize, Deserialize, Display)]
+#[display(fmt = "ts={ts} inst={instrument} price={price} size={size}")]
+pub struct Liquidation {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Instrument.
+    pub instrument: Str,
+    /// Price.
+    pub price: Decimal,
+    /// Size of the liquidation order
+    /// (negative for selling, i.e. a long position is liquidated).
+    pub size: Decimal,
+}
+
+impl Tickable for Liquidation {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.ts, self)
+    }
+}
+
+/// Liquidation stream.
+pub type LiquidationStream = BoxStream<'static, Result<Liquidation, ExchangeError>>;
+
+/// Subscribe liquidations.
+#[derive(Debug, Clone)]
+pub struct SubscribeLiquidations {
+    /// Instrument.
+    pub instrument: Str,
+}
+
+impl SubscribeLiquidations {
+    /// Create a new [`SubscribeLiquidations`] request.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+        }
+    }
+}
+
+impl Request for SubscribeLiquidations {
+    type Response = LiquidationStream;
+}
@@ -0,0 +1,126 @@
+use std::{
+    fmt,
+    ops::{Bound, RangeBounds},
+};
+
+use derive_more::Display;
+use exc_service::{ExchangeError, Request};
+use futures::stream::BoxStream;
+use indicator::{Tick, TickValue, Tickable};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::{Period, Str};
+
+/// Open interest.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Display)]
+#[display(fmt = "ts={ts} size={size} value={value:?}")]
+pub struct OpenInterest {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Open interest (in the unit of the contract size).
+    pub size: Decimal,
+    /// The notional value of the open interest (in USD).
+    #[serde(default)]
+    pub value: Option<Decimal>,
+}
+
+impl Tickable for OpenInterest {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.ts, self)
+    }
+}
+
+/// Open interest stream.
+pub type OpenInterestStream = BoxStream<'static, Result<OpenInterest, ExchangeError>>;
+
+/// Subscribe open interest.
+#[derive(Debug, Clone)]
+pub struct SubscribeOpenInterest {
+    /// Instrument.
+    pub instrument: Str,
+}
+
+impl SubscribeOpenInterest {
+    /// Create a new [`SubscribeOpenInterest`] request.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+        }
+    }
+}
+
+impl Request for SubscribeOpenInterest {
+    type Response = OpenInterestStream;
+}
+
+/// Query the open interest history sampled by `period`.
+/// Return an open interest stream that produce the samples forward.
+///
+/// Exchanges only keep a limited history of open interest,
+/// and may return only a page of the samples from the start of the range.
+#[derive(Debug, Clone)]
+pub struct QueryOpenInterest {
+    /// Instrument.
+    pub instrument: Str,
+    /// Period.
+    pub period: Period,
+    /// Start.
+    pub start: Bound<OffsetDateTime>,
+    /// End.
+    pub end: Bound<OffsetDateTime>,
+}
+
+impl QueryOpenInterest {
+    /// Create a new [`QueryOpenInterest`] request.
+    pub fn new<R>(inst: impl AsRef<str>, period: Period, range: R) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            instrument: Str::new(inst),
+            period,
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+}
+
+impl fmt::Display for QueryOpenInterest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}, {}",
+            self.instrument,
+            self.period,
+            crate::candle::fmt_ts_start_bound(&self.start),
+            crate::candle::fmt_ts_end_bound(&self.end)
+        )
+    }
+}
+
+impl RangeBounds<OffsetDateTime> for QueryOpenInterest {
+    fn start_bound(&self) -> Bound<&OffsetDateTime> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&OffsetDateTime> {
+        self.end.as_ref()
+    }
+}
+
+impl Request for QueryOpenInterest {
+    type Response = OpenInterestStream;
+}
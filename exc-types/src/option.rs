@@ -0,0 +1,78 @@
+use derive_more::Display;
+use exc_service::{ExchangeError, Request};
+use futures::stream::BoxStream;
+use indicator::{Tick, TickValue, Tickable};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::{symbol::ExcSymbol, Str};
+
+/// Greeks and implied volatilities of an option.
+#[derive(Debug, Clone, Display)]
+#[display(
+    fmt = "ts={ts} {symbol} delta={delta:?} gamma={gamma:?} vega={vega:?} theta={theta:?} iv=({bid_iv:?}, {mark_iv:?}, {ask_iv:?}) underlying={underlying_price:?}"
+)]
+pub struct OptionGreeks {
+    /// Timestamp.
+    pub ts: OffsetDateTime,
+    /// Symbol of the option.
+    pub symbol: ExcSymbol,
+    /// Name of the option on the exchange.
+    pub instrument: Str,
+    /// Delta.
+    pub delta: Option<Decimal>,
+    /// Gamma.
+    pub gamma: Option<Decimal>,
+    /// Vega.
+    pub vega: Option<Decimal>,
+    /// Theta.
+    pub theta: Option<Decimal>,
+    /// Implied volatility of the mark price.
+    pub mark_iv: Option<Decimal>,
+    /// Implied volatility of the best bid.
+    pub bid_iv: Option<Decimal>,
+    /// Implied volatility of the best ask.
+    pub ask_iv: Option<Decimal>,
+    /// Price of the underlying (the forward price on OKX and the index price on Binance).
+    pub underlying_price: Option<Decimal>,
+}
+
+impl Tickable for OptionGreeks {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.ts, self)
+    }
+}
+
+/// Option greeks stream.
+pub type OptionGreeksStream = BoxStream<'static, Result<OptionGreeks, ExchangeError>>;
+
+/// Subscribe the greeks of all the options of an instrument family,
+/// e.g. `BTC-USD` (OKX) or `BTC` (Binance, the underlying asset).
+#[derive(Debug, Clone)]
+pub struct SubscribeOptionGreeks {
+    /// Instrument family.
+    pub family: Str,
+}
+
+impl SubscribeOptionGreeks {
+    /// Create a new [`SubscribeOptionGreeks`] request.
+    pub fn new(family: impl AsRef<str>) -> Self {
+        Self {
+            family: Str::new(family),
+        }
+    }
+}
+
+impl Request for SubscribeOptionGreeks {
+    type Response = OptionGreeksStream;
+}
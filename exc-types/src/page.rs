@@ -0,0 +1,43 @@
+use exc_service::{ExchangeError, Request};
+use futures::{future::BoxFuture, stream::BoxStream};
+use positions::prelude::Str;
+
+/// Requests whose results are fetched page by page.
+pub trait PagedRequest:
+    Request<Response = BoxStream<'static, Result<Self::Item, ExchangeError>>> + Clone + Send + 'static
+{
+    /// The item type.
+    type Item: Send + 'static;
+}
+
+/// Fetch a page of the results of the request.
+#[derive(Debug, Clone)]
+pub struct Paged<Q> {
+    /// Query.
+    pub query: Q,
+    /// The cursor returned by the previous page (`None` for the first page).
+    pub cursor: Option<Str>,
+}
+
+impl<Q> Paged<Q> {
+    /// Create a request for the first page.
+    pub fn first(query: Q) -> Self {
+        Self {
+            query,
+            cursor: None,
+        }
+    }
+}
+
+/// A page of results.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    /// Items.
+    pub items: Vec<T>,
+    /// The cursor of the next page (`None` if it is the last page).
+    pub next: Option<Str>,
+}
+
+impl<Q: PagedRequest> Request for Paged<Q> {
+    type Response = BoxFuture<'static, Result<Page<Q::Item>, ExchangeError>>;
+}
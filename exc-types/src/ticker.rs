@@ -76,3 +76,56 @@ impl Tickable for Ticker {
         TickValue::new(self.ts, self)
     }
 }
+
+/// Mark price stream.
+pub type MarkPriceStream = BoxStream<'static, Result<MarkPrice, ExchangeError>>;
+
+/// Subscribe mark prices.
+#[derive(Debug, Clone)]
+pub struct SubscribeMarkPrice {
+    /// Instrument.
+    pub instrument: Str,
+}
+
+impl SubscribeMarkPrice {
+    /// Create a new [`SubscribeMarkPrice`] request.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+        }
+    }
+}
+
+impl Request for SubscribeMarkPrice {
+    type Response = MarkPriceStream;
+}
+
+/// Mark price.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Display)]
+#[display(fmt = "ts={ts}, mark={price}, index={index:?}")]
+pub struct MarkPrice {
+    /// Timestamp.
+    #[serde(with = "time::serde::rfc3339")]
+    pub ts: OffsetDateTime,
+    /// Mark price.
+    pub price: Decimal,
+    /// Index price (if provided along with the mark price).
+    #[serde(default)]
+    pub index: Option<Decimal>,
+}
+
+impl Tickable for MarkPrice {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.ts, self)
+    }
+}
@@ -1,6 +1,11 @@
+use std::{
+    fmt,
+    ops::{Bound, RangeBounds},
+};
+
 use derive_more::Display;
 use exc_service::{ExchangeError, Request};
-use futures::stream::BoxStream;
+use futures::{stream::BoxStream, Stream, StreamExt};
 use indicator::{Tick, TickValue, Tickable};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -61,3 +66,254 @@ impl Tickable for Trade {
         TickValue::new(self.ts, self)
     }
 }
+
+/// A trade with its id (from the trade history).
+#[derive(Debug, Clone, Serialize, Deserialize, Display)]
+#[display(fmt = "id={id} {trade}")]
+pub struct TradeRecord {
+    /// Trade id.
+    pub id: Str,
+    /// Trade.
+    pub trade: Trade,
+}
+
+impl Tickable for TradeRecord {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.trade.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.trade.ts, self)
+    }
+}
+
+/// Trade history stream.
+pub struct TradeHistoryStream {
+    forward: bool,
+    stream: BoxStream<'static, Result<TradeRecord, ExchangeError>>,
+}
+
+impl TradeHistoryStream {
+    /// Create a new trade history stream that produce trades forward.
+    pub fn new_forward(
+        stream: impl Stream<Item = Result<TradeRecord, ExchangeError>> + Send + 'static,
+    ) -> Self {
+        Self {
+            forward: true,
+            stream: stream.boxed(),
+        }
+    }
+
+    /// Create a new trade history stream that produce trades backward.
+    pub fn new_backward(
+        stream: impl Stream<Item = Result<TradeRecord, ExchangeError>> + Send + 'static,
+    ) -> Self {
+        Self {
+            forward: false,
+            stream: stream.boxed(),
+        }
+    }
+
+    /// Is forward.
+    pub fn is_forward(&self) -> bool {
+        self.forward
+    }
+}
+
+impl Stream for TradeHistoryStream {
+    type Item = Result<TradeRecord, ExchangeError>;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.stream.poll_next_unpin(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+/// Query the trades in the range.
+#[derive(Debug, Clone)]
+pub struct QueryTrades {
+    /// Instrument.
+    pub instrument: Str,
+    /// Start.
+    pub start: Bound<OffsetDateTime>,
+    /// End.
+    pub end: Bound<OffsetDateTime>,
+}
+
+impl QueryTrades {
+    /// Create a new [`QueryTrades`] request.
+    pub fn new<R>(inst: impl AsRef<str>, range: R) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            instrument: Str::new(inst),
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+
+    /// Is empty.
+    pub fn is_empty(&self) -> bool {
+        match (self.start_bound(), self.end_bound()) {
+            (Bound::Unbounded, _) => false,
+            (_, Bound::Unbounded) => false,
+            (Bound::Included(start), Bound::Included(end)) => *start > *end,
+            (Bound::Included(start), Bound::Excluded(end)) => *start >= *end,
+            (Bound::Excluded(start), Bound::Included(end)) => *start >= *end,
+            (Bound::Excluded(start), Bound::Excluded(end)) => *start >= *end,
+        }
+    }
+}
+
+impl fmt::Display for QueryTrades {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}, {}",
+            self.instrument,
+            crate::candle::fmt_ts_start_bound(&self.start),
+            crate::candle::fmt_ts_end_bound(&self.end)
+        )
+    }
+}
+
+impl RangeBounds<OffsetDateTime> for QueryTrades {
+    fn start_bound(&self) -> Bound<&OffsetDateTime> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&OffsetDateTime> {
+        self.end.as_ref()
+    }
+}
+
+impl Request for QueryTrades {
+    type Response = TradeHistoryStream;
+
+    fn instrument(&self) -> Option<&str> {
+        Some(&self.instrument)
+    }
+}
+
+/// Query first `n` trades in range.
+/// Return a trade history stream that produce the first `first` trades forward.
+#[derive(Debug, Clone)]
+pub struct QueryFirstTrades {
+    /// Query.
+    pub query: QueryTrades,
+    /// First.
+    pub first: usize,
+    /// Only the trades after the trade with this id are queried (if supported),
+    /// which takes precedence over the start of the range.
+    pub after_id: Option<Str>,
+}
+
+impl fmt::Display for QueryFirstTrades {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-({})", self.query, self.first)?;
+        if let Some(id) = self.after_id.as_ref() {
+            write!(f, "-after({id})")?;
+        }
+        Ok(())
+    }
+}
+
+impl QueryFirstTrades {
+    /// Create a new query.
+    pub fn new<R>(inst: impl AsRef<str>, range: R, first: usize) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            query: QueryTrades::new(inst, range),
+            first,
+            after_id: None,
+        }
+    }
+
+    /// Get first.
+    pub fn first(&self) -> usize {
+        self.first
+    }
+
+    /// Get query.
+    pub fn query(&self) -> &QueryTrades {
+        &self.query
+    }
+}
+
+impl Request for QueryFirstTrades {
+    type Response = TradeHistoryStream;
+
+    fn instrument(&self) -> Option<&str> {
+        Some(&self.query.instrument)
+    }
+}
+
+/// Query last `n` trades in range.
+/// Return a trade history stream that produce the last `last` trades backward.
+#[derive(Debug, Clone)]
+pub struct QueryLastTrades {
+    /// Query.
+    pub query: QueryTrades,
+    /// Last.
+    pub last: usize,
+    /// Only the trades before the trade with this id are queried (if supported),
+    /// which takes precedence over the end of the range.
+    pub before_id: Option<Str>,
+}
+
+impl fmt::Display for QueryLastTrades {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-(-{})", self.query, self.last)?;
+        if let Some(id) = self.before_id.as_ref() {
+            write!(f, "-before({id})")?;
+        }
+        Ok(())
+    }
+}
+
+impl QueryLastTrades {
+    /// Create a new query.
+    pub fn new<R>(inst: impl AsRef<str>, range: R, last: usize) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            query: QueryTrades::new(inst, range),
+            last,
+            before_id: None,
+        }
+    }
+
+    /// Get last.
+    pub fn last(&self) -> usize {
+        self.last
+    }
+
+    /// Get query.
+    pub fn query(&self) -> &QueryTrades {
+        &self.query
+    }
+}
+
+impl Request for QueryLastTrades {
+    type Response = TradeHistoryStream;
+
+    fn instrument(&self) -> Option<&str> {
+        Some(&self.query.instrument)
+    }
+}
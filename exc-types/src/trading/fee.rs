@@ -0,0 +1,54 @@
+use exc_service::{ExchangeError, Request};
+use futures::future::BoxFuture;
+use rust_decimal::Decimal;
+
+use crate::Str;
+
+/// Trading fee rates of an instrument (for the account).
+///
+/// A positive rate is a fee paid, while a negative rate is a rebate received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeRates {
+    /// Instrument.
+    pub instrument: Str,
+    /// Maker fee rate.
+    pub maker: Decimal,
+    /// Taker fee rate.
+    pub taker: Decimal,
+}
+
+impl FeeRates {
+    /// Get the fee rate for a maker (`true`) or a taker (`false`) order.
+    pub fn rate(&self, maker: bool) -> Decimal {
+        if maker {
+            self.maker
+        } else {
+            self.taker
+        }
+    }
+
+    /// The expected fee for trading `notional` as a maker (`true`) or a taker (`false`).
+    pub fn expected_fee(&self, notional: Decimal, maker: bool) -> Decimal {
+        notional.abs() * self.rate(maker)
+    }
+}
+
+/// Fetch the trading fee rates of an instrument.
+#[derive(Debug, Clone)]
+pub struct FetchFeeRates {
+    /// Instrument.
+    pub instrument: Str,
+}
+
+impl FetchFeeRates {
+    /// Create a new [`FetchFeeRates`] request.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Str::new(inst),
+        }
+    }
+}
+
+impl Request for FetchFeeRates {
+    type Response = BoxFuture<'static, Result<FeeRates, ExchangeError>>;
+}
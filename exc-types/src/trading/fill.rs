@@ -0,0 +1,145 @@
+use std::{
+    fmt,
+    ops::{Bound, RangeBounds},
+};
+
+use exc_service::{ExchangeError, Request};
+use futures::stream::BoxStream;
+use positions::Asset;
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::Str;
+
+/// Fill (an execution of an order).
+#[derive(Debug, Clone)]
+pub struct Fill {
+    /// Instrument.
+    pub instrument: Str,
+    /// Trade id.
+    pub trade_id: Str,
+    /// The id of the order assigned by the exchange.
+    pub order_id: Str,
+    /// Price.
+    pub price: Decimal,
+    /// Size (negative for selling).
+    pub size: Decimal,
+    /// Fee (negative for paying).
+    pub fee: Decimal,
+    /// Fee asset.
+    pub fee_asset: Option<Asset>,
+    /// Whether the fill is on the maker side.
+    pub maker: bool,
+    /// Timestamp.
+    pub ts: OffsetDateTime,
+}
+
+/// Fill stream.
+pub type FillStream = BoxStream<'static, Result<Fill, ExchangeError>>;
+
+/// Query the fills in the range.
+/// Return a fill stream that produce the fills backward.
+#[derive(Debug, Clone)]
+pub struct QueryFills {
+    /// Instrument.
+    pub instrument: Str,
+    /// Start.
+    pub start: Bound<OffsetDateTime>,
+    /// End.
+    pub end: Bound<OffsetDateTime>,
+}
+
+impl QueryFills {
+    /// Create a new [`QueryFills`] request.
+    pub fn new<R>(inst: impl AsRef<str>, range: R) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            instrument: Str::new(inst),
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+
+    /// Is empty.
+    pub fn is_empty(&self) -> bool {
+        match (self.start_bound(), self.end_bound()) {
+            (Bound::Unbounded, _) => false,
+            (_, Bound::Unbounded) => false,
+            (Bound::Included(start), Bound::Included(end)) => *start > *end,
+            (Bound::Included(start), Bound::Excluded(end)) => *start >= *end,
+            (Bound::Excluded(start), Bound::Included(end)) => *start >= *end,
+            (Bound::Excluded(start), Bound::Excluded(end)) => *start >= *end,
+        }
+    }
+}
+
+impl fmt::Display for QueryFills {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}, {}",
+            self.instrument,
+            crate::candle::fmt_ts_start_bound(&self.start),
+            crate::candle::fmt_ts_end_bound(&self.end)
+        )
+    }
+}
+
+impl RangeBounds<OffsetDateTime> for QueryFills {
+    fn start_bound(&self) -> Bound<&OffsetDateTime> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&OffsetDateTime> {
+        self.end.as_ref()
+    }
+}
+
+impl Request for QueryFills {
+    type Response = FillStream;
+}
+
+/// Query last `n` fills in range.
+/// Return a fill stream that produce the last `last` fills backward.
+#[derive(Debug, Clone)]
+pub struct QueryLastFills {
+    /// Query.
+    pub query: QueryFills,
+    /// Last.
+    pub last: usize,
+}
+
+impl fmt::Display for QueryLastFills {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-(-{})", self.query, self.last)
+    }
+}
+
+impl QueryLastFills {
+    /// Create a new query.
+    pub fn new<R>(inst: impl AsRef<str>, range: R, last: usize) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            query: QueryFills::new(inst, range),
+            last,
+        }
+    }
+
+    /// Get last.
+    pub fn last(&self) -> usize {
+        self.last
+    }
+
+    /// Get query.
+    pub fn query(&self) -> &QueryFills {
+        &self.query
+    }
+}
+
+impl Request for QueryLastFills {
+    type Response = FillStream;
+}
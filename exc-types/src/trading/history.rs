@@ -0,0 +1,95 @@
+use std::{
+    fmt,
+    ops::{Bound, RangeBounds},
+};
+
+use exc_service::Request;
+use time::OffsetDateTime;
+
+use crate::{page::PagedRequest, Str};
+
+use super::{OrderStream, OrderUpdate};
+
+/// List open orders.
+#[derive(Debug, Clone, Default)]
+pub struct ListOpenOrders {
+    /// Instrument (`None` for all instruments).
+    pub instrument: Option<Str>,
+}
+
+impl ListOpenOrders {
+    /// Create a new [`ListOpenOrders`] request for the given instrument.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Some(Str::new(inst)),
+        }
+    }
+
+    /// Create a new [`ListOpenOrders`] request for all instruments.
+    pub fn all() -> Self {
+        Self::default()
+    }
+}
+
+impl Request for ListOpenOrders {
+    type Response = OrderStream;
+}
+
+impl PagedRequest for ListOpenOrders {
+    type Item = OrderUpdate;
+}
+
+/// Query the orders created in the range.
+#[derive(Debug, Clone)]
+pub struct QueryOrderHistory {
+    /// Instrument.
+    pub instrument: Str,
+    /// Start.
+    pub start: Bound<OffsetDateTime>,
+    /// End.
+    pub end: Bound<OffsetDateTime>,
+}
+
+impl QueryOrderHistory {
+    /// Create a new [`QueryOrderHistory`] request.
+    pub fn new<R>(inst: impl AsRef<str>, range: R) -> Self
+    where
+        R: RangeBounds<OffsetDateTime>,
+    {
+        Self {
+            instrument: Str::new(inst),
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        }
+    }
+}
+
+impl fmt::Display for QueryOrderHistory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}, {}",
+            self.instrument,
+            crate::candle::fmt_ts_start_bound(&self.start),
+            crate::candle::fmt_ts_end_bound(&self.end)
+        )
+    }
+}
+
+impl RangeBounds<OffsetDateTime> for QueryOrderHistory {
+    fn start_bound(&self) -> Bound<&OffsetDateTime> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&OffsetDateTime> {
+        self.end.as_ref()
+    }
+}
+
+impl Request for QueryOrderHistory {
+    type Response = OrderStream;
+}
+
+impl PagedRequest for QueryOrderHistory {
+    type Item = OrderUpdate;
+}
@@ -0,0 +1,122 @@
+use exc_service::{ExchangeError, Request};
+use futures::future::BoxFuture;
+use rust_decimal::Decimal;
+
+use crate::Str;
+
+use super::MarginMode;
+
+/// Position mode of the account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PositionMode {
+    /// One-way mode (net positions).
+    #[default]
+    OneWay,
+    /// Hedge mode (long and short positions).
+    Hedge,
+}
+
+/// Leverage of an instrument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leverage {
+    /// Instrument.
+    pub instrument: Str,
+    /// Leverage.
+    pub leverage: Decimal,
+    /// Margin mode (`None` if not reported by the exchange).
+    pub margin_mode: Option<MarginMode>,
+}
+
+/// Set the leverage of an instrument.
+///
+/// Exchanges keeping a leverage per margin mode (e.g. OKX) set the one of `margin_mode`,
+/// while others (e.g. Binance) keep a single leverage per instrument and switch
+/// the margin mode with [`SetMarginMode`].
+#[derive(Debug, Clone)]
+pub struct SetLeverage {
+    /// Instrument.
+    pub instrument: Str,
+    /// Leverage.
+    pub leverage: Decimal,
+    /// Margin mode.
+    pub margin_mode: MarginMode,
+}
+
+impl SetLeverage {
+    /// Create a new [`SetLeverage`] request.
+    pub fn new(inst: impl AsRef<str>, leverage: Decimal, margin_mode: MarginMode) -> Self {
+        Self {
+            instrument: Str::new(inst),
+            leverage,
+            margin_mode,
+        }
+    }
+}
+
+impl Request for SetLeverage {
+    type Response = BoxFuture<'static, Result<Leverage, ExchangeError>>;
+}
+
+/// Get the leverage of an instrument.
+#[derive(Debug, Clone)]
+pub struct GetLeverage {
+    /// Instrument.
+    pub instrument: Str,
+    /// Margin mode (ignored by exchanges keeping a single leverage per instrument).
+    pub margin_mode: MarginMode,
+}
+
+impl GetLeverage {
+    /// Create a new [`GetLeverage`] request.
+    pub fn new(inst: impl AsRef<str>, margin_mode: MarginMode) -> Self {
+        Self {
+            instrument: Str::new(inst),
+            margin_mode,
+        }
+    }
+}
+
+impl Request for GetLeverage {
+    type Response = BoxFuture<'static, Result<Leverage, ExchangeError>>;
+}
+
+/// Set the margin mode of an instrument.
+#[derive(Debug, Clone)]
+pub struct SetMarginMode {
+    /// Instrument.
+    pub instrument: Str,
+    /// Margin mode.
+    pub margin_mode: MarginMode,
+}
+
+impl SetMarginMode {
+    /// Create a new [`SetMarginMode`] request.
+    pub fn new(inst: impl AsRef<str>, margin_mode: MarginMode) -> Self {
+        Self {
+            instrument: Str::new(inst),
+            margin_mode,
+        }
+    }
+}
+
+impl Request for SetMarginMode {
+    type Response = BoxFuture<'static, Result<(), ExchangeError>>;
+}
+
+/// Set the position mode of the account.
+#[derive(Debug, Clone, Copy)]
+pub struct SetPositionMode {
+    /// Position mode.
+    pub mode: PositionMode,
+}
+
+impl SetPositionMode {
+    /// Create a new [`SetPositionMode`] request.
+    pub fn new(mode: PositionMode) -> Self {
+        Self { mode }
+    }
+}
+
+impl Request for SetPositionMode {
+    type Response = BoxFuture<'static, Result<(), ExchangeError>>;
+}
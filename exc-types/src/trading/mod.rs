@@ -4,14 +4,42 @@ pub mod place;
 /// Order.
 pub mod order;
 
-use std::{collections::BTreeMap, fmt, sync::Arc};
+/// Position.
+pub mod position;
+
+/// Order history.
+pub mod history;
+
+/// Fills.
+pub mod fill;
+
+/// Fee rates.
+pub mod fee;
+
+/// Leverage and margin configuration.
+pub mod leverage;
+
+use std::{collections::BTreeMap, fmt, sync::Arc, time::Duration};
 
 use exc_service::{ExchangeError, Request};
+pub use fee::{FeeRates, FetchFeeRates};
+pub use fill::{Fill, FillStream, QueryFills, QueryLastFills};
 use futures::{future::BoxFuture, stream::BoxStream};
+pub use history::{ListOpenOrders, QueryOrderHistory};
 use indicator::{Tick, TickValue, Tickable};
-pub use order::{Order, OrderId, OrderKind, OrderState, OrderStatus, OrderTrade, TimeInForce};
+pub use leverage::{
+    GetLeverage, Leverage, PositionMode, SetLeverage, SetMarginMode, SetPositionMode,
+};
+pub use order::{
+    Order, OrderId, OrderKind, OrderState, OrderStatus, OrderTrade, TimeInForce, TrailingStop,
+    Trigger, TriggerSource,
+};
 pub use place::Place;
+pub use position::{
+    FetchPositions, MarginMode, Position, PositionSide, PositionStream, SubscribePositions,
+};
 use positions::Asset;
+use rust_decimal::Decimal;
 use time::OffsetDateTime;
 
 use crate::Str;
@@ -25,6 +53,12 @@ pub struct PlaceOrderOptions {
     client_id: Option<Str>,
     /// Margin currency perferred to use.
     margin: Option<Asset>,
+    /// Reduce only.
+    reduce_only: bool,
+    /// Position side.
+    position_side: PositionSide,
+    /// Margin mode.
+    margin_mode: Option<MarginMode>,
     /// Exchange-defined options.
     custom: BTreeMap<Str, Str>,
 }
@@ -36,6 +70,9 @@ impl PlaceOrderOptions {
             instrument: Str::new(inst),
             client_id: None,
             margin: None,
+            reduce_only: false,
+            position_side: PositionSide::default(),
+            margin_mode: None,
             custom: BTreeMap::default(),
         }
     }
@@ -55,6 +92,25 @@ impl PlaceOrderOptions {
         self
     }
 
+    /// Set whether the order can only reduce the position.
+    pub fn with_reduce_only(&mut self, reduce_only: bool) -> &mut Self {
+        self.reduce_only = reduce_only;
+        self
+    }
+
+    /// Set the position side to trade (defaults to [`PositionSide::Net`]).
+    pub fn with_position_side(&mut self, side: PositionSide) -> &mut Self {
+        self.position_side = side;
+        self
+    }
+
+    /// Set the margin mode to trade with.
+    /// The exchange default is used if not set.
+    pub fn with_margin_mode(&mut self, mode: MarginMode) -> &mut Self {
+        self.margin_mode = Some(mode);
+        self
+    }
+
     /// Insert an exchange-defined custom option.
     pub fn insert<K, V>(&mut self, key: K, value: V) -> &mut Self
     where
@@ -81,6 +137,21 @@ impl PlaceOrderOptions {
         self.margin.as_deref()
     }
 
+    /// Whether the order can only reduce the position.
+    pub fn reduce_only(&self) -> bool {
+        self.reduce_only
+    }
+
+    /// Get the position side to trade.
+    pub fn position_side(&self) -> PositionSide {
+        self.position_side
+    }
+
+    /// Get the margin mode to trade with.
+    pub fn margin_mode(&self) -> Option<MarginMode> {
+        self.margin_mode
+    }
+
     /// Get the exchange-defined custom options.
     pub fn custom(&self) -> &BTreeMap<Str, Str> {
         &self.custom
@@ -129,6 +200,20 @@ impl fmt::Debug for Placed {
 
 impl Request for PlaceOrder {
     type Response = BoxFuture<'static, Result<Placed, ExchangeError>>;
+
+    /// Placing an order is idempotent only if the client id is set,
+    /// which the exchange uses to reject the duplicates.
+    fn is_idempotent(&self) -> bool {
+        self.opts.client_id().is_some()
+    }
+
+    fn instrument(&self) -> Option<&str> {
+        Some(self.opts.instrument())
+    }
+
+    fn client_id(&self) -> Option<&str> {
+        self.opts.client_id()
+    }
 }
 
 /// Cancel order.
@@ -170,6 +255,181 @@ impl fmt::Debug for Canceled {
 
 impl Request for CancelOrder {
     type Response = BoxFuture<'static, Result<Canceled, ExchangeError>>;
+
+    fn instrument(&self) -> Option<&str> {
+        Some(&self.instrument)
+    }
+}
+
+/// Amend (modify) an open order in place.
+#[derive(Debug, Clone)]
+pub struct AmendOrder {
+    /// Instrument.
+    pub instrument: Str,
+    /// Id.
+    pub id: OrderId,
+    /// New price.
+    pub new_price: Decimal,
+    /// New size (including the filled part).
+    /// The sign represents the side of the order, which must not be changed.
+    pub new_size: Decimal,
+    /// The options the order was placed with, required to place the new order
+    /// by the exchanges amending orders with cancel-replace.
+    pub opts: Option<Arc<PlaceOrderOptions>>,
+}
+
+impl AmendOrder {
+    /// Create a new [`AmendOrder`] request.
+    pub fn new(inst: impl AsRef<str>, id: OrderId, new_price: Decimal, new_size: Decimal) -> Self {
+        Self {
+            instrument: Str::new(inst),
+            id,
+            new_price,
+            new_size,
+            opts: None,
+        }
+    }
+
+    /// Set the options the order was placed with.
+    ///
+    /// The client id of the options is used by the new order of a cancel-replace,
+    /// so change it if the exchange rejects reusing the client ids of canceled orders.
+    pub fn with_options(mut self, opts: &PlaceOrderOptions) -> Self {
+        self.opts = Some(Arc::new(opts.clone()));
+        self
+    }
+}
+
+/// Amend order response.
+#[derive(Clone)]
+pub struct Amended {
+    /// Order id.
+    /// It can be different from the requested one if the order was cancel-replaced.
+    pub id: OrderId,
+    /// The amended order.
+    pub order: Option<Order>,
+    /// Timestamp.
+    pub ts: OffsetDateTime,
+}
+
+impl fmt::Debug for Amended {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Amended")
+            .field("ts", &self.ts.to_string())
+            .field("id", &self.id.as_str())
+            .field("order", &self.order)
+            .finish()
+    }
+}
+
+impl Request for AmendOrder {
+    type Response = BoxFuture<'static, Result<Amended, ExchangeError>>;
+
+    fn instrument(&self) -> Option<&str> {
+        Some(&self.instrument)
+    }
+}
+
+/// Place a batch of orders.
+#[derive(Debug, Clone)]
+pub struct PlaceOrders(pub Vec<PlaceOrder>);
+
+impl PlaceOrders {
+    /// Create a new [`PlaceOrders`] request.
+    pub fn new(orders: impl IntoIterator<Item = PlaceOrder>) -> Self {
+        Self(orders.into_iter().collect())
+    }
+}
+
+/// Results of a batch placement, in the same order as the requests.
+pub type PlacedBatch = Vec<Result<Placed, ExchangeError>>;
+
+impl Request for PlaceOrders {
+    type Response = BoxFuture<'static, Result<PlacedBatch, ExchangeError>>;
+
+    fn is_idempotent(&self) -> bool {
+        self.0.iter().all(Request::is_idempotent)
+    }
+}
+
+/// Cancel a batch of orders.
+#[derive(Debug, Clone)]
+pub struct CancelOrders(pub Vec<CancelOrder>);
+
+impl CancelOrders {
+    /// Create a new [`CancelOrders`] request.
+    pub fn new(orders: impl IntoIterator<Item = CancelOrder>) -> Self {
+        Self(orders.into_iter().collect())
+    }
+}
+
+/// Results of a batch cancellation, in the same order as the requests.
+pub type CanceledBatch = Vec<Result<Canceled, ExchangeError>>;
+
+impl Request for CancelOrders {
+    type Response = BoxFuture<'static, Result<CanceledBatch, ExchangeError>>;
+}
+
+/// Cancel all open orders.
+#[derive(Debug, Clone, Default)]
+pub struct CancelAllOrders {
+    /// Instrument (`None` for all instruments).
+    pub instrument: Option<Str>,
+}
+
+impl CancelAllOrders {
+    /// Create a new [`CancelAllOrders`] request for the given instrument.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Some(Str::new(inst)),
+        }
+    }
+
+    /// Create a new [`CancelAllOrders`] request for all instruments.
+    pub fn all() -> Self {
+        Self::default()
+    }
+}
+
+impl Request for CancelAllOrders {
+    type Response = BoxFuture<'static, Result<(), ExchangeError>>;
+
+    fn instrument(&self) -> Option<&str> {
+        self.instrument.as_deref()
+    }
+}
+
+/// Cancel all open orders after the timeout (a dead man's switch).
+/// Sending it again before the timeout re-arms the countdown,
+/// and a zero timeout disarms it.
+#[derive(Debug, Clone)]
+pub struct CancelAllAfter {
+    /// Instrument (`None` for all instruments).
+    pub instrument: Option<Str>,
+    /// Timeout.
+    pub timeout: Duration,
+}
+
+impl CancelAllAfter {
+    /// Create a new [`CancelAllAfter`] request for the given instrument.
+    pub fn new(inst: impl AsRef<str>, timeout: Duration) -> Self {
+        Self {
+            instrument: Some(Str::new(inst)),
+            timeout,
+        }
+    }
+
+    /// Create a new [`CancelAllAfter`] request for all instruments.
+    pub fn all(timeout: Duration) -> Self {
+        Self {
+            instrument: None,
+            timeout,
+        }
+    }
+}
+
+impl Request for CancelAllAfter {
+    type Response = BoxFuture<'static, Result<(), ExchangeError>>;
 }
 
 /// Get order.
@@ -227,6 +487,10 @@ impl Tickable for OrderUpdate {
 
 impl Request for GetOrder {
     type Response = BoxFuture<'static, Result<OrderUpdate, ExchangeError>>;
+
+    fn instrument(&self) -> Option<&str> {
+        Some(&self.instrument)
+    }
 }
 
 /// Orders Stream.
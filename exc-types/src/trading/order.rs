@@ -6,9 +6,10 @@ use rust_decimal::Decimal;
 use super::place::Place;
 
 /// Time in force.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TimeInForce {
     /// Good-Til-Cancelled.
+    #[default]
     GoodTilCancelled,
     /// Fill-Or-Kill.
     FillOrKill,
@@ -16,12 +17,54 @@ pub enum TimeInForce {
     ImmediateOrCancel,
 }
 
-impl Default for TimeInForce {
-    fn default() -> Self {
-        Self::GoodTilCancelled
+/// The price source of triggers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TriggerSource {
+    /// Last price.
+    #[default]
+    Last,
+    /// Mark price.
+    Mark,
+    /// Index price.
+    Index,
+}
+
+/// Trigger of conditional orders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trigger {
+    /// Trigger price.
+    pub price: Decimal,
+    /// The price source to compare with the trigger price.
+    pub source: TriggerSource,
+}
+
+impl Trigger {
+    /// Create a new trigger on the last price.
+    pub fn new(price: Decimal) -> Self {
+        Self {
+            price,
+            source: TriggerSource::default(),
+        }
+    }
+
+    /// Change the price source.
+    pub fn with_source(mut self, source: TriggerSource) -> Self {
+        self.source = source;
+        self
     }
 }
 
+/// Trailing stop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrailingStop {
+    /// Callback rate, e.g. `0.01` for `1%`.
+    pub callback_rate: Decimal,
+    /// The trailing starts when the price reaches it (`None` to start immediately).
+    pub activation: Option<Decimal>,
+    /// The price source to track.
+    pub source: TriggerSource,
+}
+
 /// Order types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OrderKind {
@@ -31,6 +74,26 @@ pub enum OrderKind {
     Limit(Decimal, TimeInForce),
     /// Post-Only.
     PostOnly(Decimal),
+    /// Stop-Market: place a market order when the price moves adversely to the trigger.
+    StopMarket(Trigger),
+    /// Stop-Limit: place a limit order with the given price when the price moves adversely
+    /// to the trigger.
+    StopLimit(Trigger, Decimal),
+    /// Take-Profit-Market: place a market order when the price moves favorably to the trigger.
+    TakeProfitMarket(Trigger),
+    /// Take-Profit-Limit: place a limit order with the given price when the price moves
+    /// favorably to the trigger.
+    TakeProfitLimit(Trigger, Decimal),
+    /// Trailing-Stop: place a market order when the price retraces by the callback rate
+    /// from its extreme.
+    TrailingStop(TrailingStop),
+}
+
+impl OrderKind {
+    /// Whether the order is conditional, i.e. it is placed until it is triggered.
+    pub fn is_conditional(&self) -> bool {
+        !matches!(self, Self::Market | Self::Limit(..) | Self::PostOnly(_))
+    }
 }
 
 /// Order Status.
@@ -0,0 +1,137 @@
+use std::fmt;
+
+use exc_service::{ExchangeError, Request};
+use futures::{future::BoxFuture, stream::BoxStream};
+use indicator::{Tick, TickValue, Tickable};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
+
+use crate::Str;
+
+/// Margin mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarginMode {
+    /// Cross margin.
+    Cross,
+    /// Isolated margin.
+    Isolated,
+    /// Cash (non-margin) trading.
+    Cash,
+}
+
+/// Position side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PositionSide {
+    /// Net position (one-way mode).
+    #[default]
+    Net,
+    /// Long side (hedge mode).
+    Long,
+    /// Short side (hedge mode).
+    Short,
+}
+
+/// Position.
+#[derive(Clone)]
+pub struct Position {
+    /// Timestamp.
+    pub ts: OffsetDateTime,
+    /// Instrument.
+    pub instrument: Str,
+    /// Size (positive for long and negative for short).
+    pub size: Decimal,
+    /// Entry price.
+    pub entry_price: Decimal,
+    /// Mark price.
+    pub mark_price: Option<Decimal>,
+    /// Unrealized PnL.
+    pub unrealized_pnl: Decimal,
+    /// Leverage.
+    pub leverage: Option<Decimal>,
+    /// Margin mode.
+    pub margin_mode: MarginMode,
+}
+
+impl fmt::Debug for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Position")
+            .field("ts", &self.ts.to_string())
+            .field("instrument", &self.instrument)
+            .field("size", &self.size)
+            .field("entry_price", &self.entry_price)
+            .field("mark_price", &self.mark_price)
+            .field("unrealized_pnl", &self.unrealized_pnl)
+            .field("leverage", &self.leverage)
+            .field("margin_mode", &self.margin_mode)
+            .finish()
+    }
+}
+
+impl Tickable for Position {
+    type Value = Self;
+
+    fn tick(&self) -> Tick {
+        Tick::new(self.ts)
+    }
+
+    fn value(&self) -> &Self::Value {
+        self
+    }
+
+    fn into_tick_value(self) -> TickValue<Self::Value> {
+        TickValue::new(self.ts, self)
+    }
+}
+
+/// Fetch positions.
+#[derive(Debug, Clone, Default)]
+pub struct FetchPositions {
+    /// Instrument (`None` for all instruments).
+    pub instrument: Option<Str>,
+}
+
+impl FetchPositions {
+    /// Create a new [`FetchPositions`] request for the given instrument.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Some(Str::new(inst)),
+        }
+    }
+
+    /// Create a new [`FetchPositions`] request for all instruments.
+    pub fn all() -> Self {
+        Self::default()
+    }
+}
+
+impl Request for FetchPositions {
+    type Response = BoxFuture<'static, Result<Vec<Position>, ExchangeError>>;
+}
+
+/// Position Stream.
+pub type PositionStream = BoxStream<'static, Result<Position, ExchangeError>>;
+
+/// Subscribe to position updates.
+#[derive(Debug, Clone, Default)]
+pub struct SubscribePositions {
+    /// Instrument (`None` for all instruments).
+    pub instrument: Option<Str>,
+}
+
+impl SubscribePositions {
+    /// Create a new [`SubscribePositions`] request for the given instrument.
+    pub fn new(inst: impl AsRef<str>) -> Self {
+        Self {
+            instrument: Some(Str::new(inst)),
+        }
+    }
+
+    /// Create a new [`SubscribePositions`] request for all instruments.
+    pub fn all() -> Self {
+        Self::default()
+    }
+}
+
+impl Request for SubscribePositions {
+    type Response = PositionStream;
+}
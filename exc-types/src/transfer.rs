@@ -0,0 +1,171 @@
+use std::fmt;
+
+use exc_service::{ExchangeError, Request};
+use futures::future::BoxFuture;
+use positions::Asset;
+use rust_decimal::Decimal;
+
+use crate::Str;
+
+/// Kind of a wallet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WalletKind {
+    /// Spot (or the unified trading account).
+    Spot,
+    /// Funding.
+    Funding,
+    /// USD-margin futures.
+    UsdMarginFutures,
+    /// Margin.
+    Margin,
+}
+
+/// A wallet of the account or of one of its sub-accounts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Wallet {
+    /// Sub-account id (`None` for the account itself).
+    pub sub_account: Option<Str>,
+    /// Kind.
+    pub kind: WalletKind,
+}
+
+impl Wallet {
+    /// Create a wallet of the given kind.
+    pub fn new(kind: WalletKind) -> Self {
+        Self {
+            sub_account: None,
+            kind,
+        }
+    }
+
+    /// Spot wallet.
+    pub fn spot() -> Self {
+        Self::new(WalletKind::Spot)
+    }
+
+    /// Funding wallet.
+    pub fn funding() -> Self {
+        Self::new(WalletKind::Funding)
+    }
+
+    /// USD-margin futures wallet.
+    pub fn usd_margin_futures() -> Self {
+        Self::new(WalletKind::UsdMarginFutures)
+    }
+
+    /// Margin wallet.
+    pub fn margin() -> Self {
+        Self::new(WalletKind::Margin)
+    }
+
+    /// The wallet of the same kind of the given sub-account
+    /// (the email on Binance and the name on OKX).
+    pub fn of_sub_account(mut self, id: impl AsRef<str>) -> Self {
+        self.sub_account = Some(Str::new(id));
+        self
+    }
+}
+
+impl fmt::Display for Wallet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.sub_account {
+            Some(id) => write!(f, "{:?}@{id}", self.kind),
+            None => write!(f, "{:?}", self.kind),
+        }
+    }
+}
+
+/// Transfer an asset between wallets.
+#[derive(Debug, Clone)]
+pub struct Transfer {
+    /// Asset.
+    pub asset: Asset,
+    /// Amount.
+    pub amount: Decimal,
+    /// From.
+    pub from: Wallet,
+    /// To.
+    pub to: Wallet,
+}
+
+impl Transfer {
+    /// Create a new [`Transfer`] request.
+    pub fn new(asset: Asset, amount: Decimal, from: Wallet, to: Wallet) -> Self {
+        Self {
+            asset,
+            amount,
+            from,
+            to,
+        }
+    }
+}
+
+impl fmt::Display for Transfer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "transfer {} {} from {} to {}",
+            self.amount, self.asset, self.from, self.to
+        )
+    }
+}
+
+/// The response of [`Transfer`] is the transfer id.
+impl Request for Transfer {
+    type Response = BoxFuture<'static, Result<Str, ExchangeError>>;
+
+    /// Transfers cannot be deduplicated by the exchanges.
+    fn is_idempotent(&self) -> bool {
+        false
+    }
+}
+
+/// Status of a transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferStatus {
+    /// Pending.
+    Pending,
+    /// Succeeded.
+    Success,
+    /// Failed.
+    Failed,
+}
+
+/// State of a transfer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferState {
+    /// Transfer id.
+    pub id: Str,
+    /// Asset.
+    pub asset: Asset,
+    /// Amount.
+    pub amount: Decimal,
+    /// Status.
+    pub status: TransferStatus,
+}
+
+/// Query the state of a transfer.
+#[derive(Debug, Clone)]
+pub struct QueryTransfer {
+    /// Transfer id.
+    pub id: Str,
+    /// From (some exchanges look up the transfer by the wallets).
+    pub from: Wallet,
+    /// To (some exchanges look up the transfer by the wallets).
+    pub to: Wallet,
+}
+
+impl QueryTransfer {
+    /// Create a new [`QueryTransfer`] request for the transfer of the given id.
+    pub fn new(id: impl AsRef<str>, transfer: &Transfer) -> Self {
+        Self {
+            id: Str::new(id),
+            from: transfer.from.clone(),
+            to: transfer.to.clone(),
+        }
+    }
+}
+
+impl Request for QueryTransfer {
+    type Response = BoxFuture<'static, Result<TransferState, ExchangeError>>;
+}
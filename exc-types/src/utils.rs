@@ -1,4 +1,6 @@
-use exc_service::Request;
+use exc_service::{ExchangeError, Request};
+use futures::future::BoxFuture;
+use time::OffsetDateTime;
 
 /// Force reconnect.
 #[derive(Debug, Clone, Copy, Default)]
@@ -7,3 +9,11 @@ pub struct Reconnect;
 impl Request for Reconnect {
     type Response = ();
 }
+
+/// Fetch the current time of the exchange server.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FetchServerTime;
+
+impl Request for FetchServerTime {
+    type Response = BoxFuture<'static, Result<OffsetDateTime, ExchangeError>>;
+}
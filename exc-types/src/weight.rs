@@ -0,0 +1,105 @@
+use exc_service::weight::{RequestWeight, Weight, ORDERS, REQUESTS};
+
+use crate::{
+    AmendOrder, CancelAllAfter, CancelAllOrders, CancelOrder, CancelOrders, FetchBalances,
+    FetchFeeRates, FetchFundingRate, FetchInstruments, FetchOrderBook, FetchPositions, GetLeverage,
+    GetOrder, ListOpenOrders, Paged, PagedRequest, PlaceOrder, PlaceOrders, QueryCandles,
+    QueryDeposits, QueryFills, QueryFirstCandles, QueryFirstFundingRates, QueryFirstTrades,
+    QueryFundingRates, QueryLastCandles, QueryLastFills, QueryLastTrades, QueryOpenInterest,
+    QueryOrderHistory, QueryTrades, QueryTransfer, QueryWithdrawals, SetLeverage, SetMarginMode,
+    SetPositionMode, SubscribeBalances, SubscribeBidAsk, SubscribeCandles, SubscribeFundingRate,
+    SubscribeInstruments, SubscribeLiquidations, SubscribeMarkPrice, SubscribeOpenInterest,
+    SubscribeOptionGreeks, SubscribeOrderBook, SubscribeOrderBookUpdates, SubscribeOrders,
+    SubscribePositions, SubscribeTickers, SubscribeTrades, Transfer,
+};
+
+use crate::utils::{FetchServerTime, Reconnect};
+
+/// Every request costs `1` of the [`REQUESTS`] bucket.
+macro_rules! request_weight {
+    ($($req:ty),* $(,)?) => {
+        $(
+            impl RequestWeight for $req {
+                fn weight(&self) -> Weight {
+                    Weight::bucket(REQUESTS, 1)
+                }
+            }
+        )*
+    };
+}
+
+request_weight!(
+    CancelAllAfter,
+    CancelAllOrders,
+    CancelOrder,
+    CancelOrders,
+    FetchBalances,
+    FetchFeeRates,
+    FetchFundingRate,
+    FetchInstruments,
+    FetchOrderBook,
+    FetchPositions,
+    FetchServerTime,
+    GetLeverage,
+    GetOrder,
+    ListOpenOrders,
+    QueryCandles,
+    QueryDeposits,
+    QueryFills,
+    QueryFirstCandles,
+    QueryFirstFundingRates,
+    QueryFirstTrades,
+    QueryFundingRates,
+    QueryLastCandles,
+    QueryLastFills,
+    QueryLastTrades,
+    QueryOpenInterest,
+    QueryOrderHistory,
+    QueryTrades,
+    QueryTransfer,
+    QueryWithdrawals,
+    Reconnect,
+    SetLeverage,
+    SetMarginMode,
+    SetPositionMode,
+    SubscribeBalances,
+    SubscribeBidAsk,
+    SubscribeCandles,
+    SubscribeFundingRate,
+    SubscribeInstruments,
+    SubscribeLiquidations,
+    SubscribeMarkPrice,
+    SubscribeOpenInterest,
+    SubscribeOptionGreeks,
+    SubscribeOrderBook,
+    SubscribeOrderBookUpdates,
+    SubscribeOrders,
+    SubscribePositions,
+    SubscribeTickers,
+    SubscribeTrades,
+    Transfer,
+);
+
+impl RequestWeight for PlaceOrder {
+    fn weight(&self) -> Weight {
+        Weight::bucket(REQUESTS, 1).with(ORDERS, 1)
+    }
+}
+
+impl RequestWeight for AmendOrder {
+    fn weight(&self) -> Weight {
+        Weight::bucket(REQUESTS, 1).with(ORDERS, 1)
+    }
+}
+
+impl RequestWeight for PlaceOrders {
+    fn weight(&self) -> Weight {
+        Weight::bucket(REQUESTS, 1).with(ORDERS, self.0.len() as u64)
+    }
+}
+
+impl<Q: PagedRequest> RequestWeight for Paged<Q> {
+    fn weight(&self) -> Weight {
+        Weight::bucket(REQUESTS, 1)
+    }
+}
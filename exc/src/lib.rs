@@ -12,16 +12,35 @@ pub mod types;
 pub mod util;
 
 pub use self::core::{
-    service::adapt::AdaptLayer, Adaptor, Exc, ExcLayer, ExcService, ExchangeError, IntoExc, Request,
+    service::adapt::AdaptLayer, Adaptor, ApiError, ApiErrorKind, Exc, ExcLayer, ExcService,
+    ExchangeError, IntoExc, Request,
 };
 pub use exc_core as core;
 pub use util::{
-    book::SubscribeBidAskService,
+    account::{
+        FetchBalancesService, FetchPositionsService, SubscribeBalancesService,
+        SubscribePositionsService,
+    },
+    book::{SubscribeBidAskService, SubscribeOrderBookService},
     fetch_candles::FetchCandlesService,
+    funding::{FetchFundingRatesService, FundingRateService, SubscribeFundingRateService},
+    funds::FundsHistoryService,
     instrument::{FetchInstrumentsService, SubscribeInstrumentsService},
+    leverage::{LeverageService, MarginModeService, PositionModeService},
+    liquidation::SubscribeLiquidationsService,
+    mark_price::SubscribeMarkPriceService,
+    open_interest::{QueryOpenInterestService, SubscribeOpenInterestService},
+    option::SubscribeOptionGreeksService,
+    server_time::ServerTimeService,
+    subscribe_candles::SubscribeCandlesService,
     subscribe_tickers::SubscribeTickersService,
-    trade::SubscribeTradesService,
-    trading::{CheckOrderService, SubscribeOrdersService, TradingService},
+    trade::{FetchTradesService, SubscribeTradesService},
+    trading::{
+        AmendOrderService, BatchTradingService, CancelAllAfterService, CancelAllOrdersService,
+        CheckOrderService, FeeRatesService, FetchFillsService, OrderHistoryService,
+        SubscribeOrdersService, TradingService,
+    },
+    transfer::TransferService,
     ExcExt,
 };
 
@@ -32,13 +51,31 @@ pub mod prelude {
         Adaptor, Exc, ExcService, ExcServiceExt, ExchangeError, Request,
     };
     pub use crate::util::{
-        book::SubscribeBidAskService,
+        account::{
+            FetchBalancesService, FetchPositionsService, SubscribeBalancesService,
+            SubscribePositionsService,
+        },
+        book::{SubscribeBidAskService, SubscribeOrderBookService},
         fetch_candles::{FetchCandlesService, FetchCandlesServiceExt},
+        funding::{FetchFundingRatesService, FundingRateService, SubscribeFundingRateService},
+        funds::FundsHistoryService,
         instrument::{FetchInstrumentsService, SubscribeInstrumentsService},
+        leverage::{LeverageService, MarginModeService, PositionModeService},
+        liquidation::SubscribeLiquidationsService,
+        mark_price::SubscribeMarkPriceService,
+        open_interest::{QueryOpenInterestService, SubscribeOpenInterestService},
+        option::SubscribeOptionGreeksService,
         reconnect::ReconnectService,
+        server_time::ServerTimeService,
+        subscribe_candles::SubscribeCandlesService,
         subscribe_tickers::SubscribeTickersService,
-        trade::SubscribeTradesService,
-        trading::{CheckOrderService, SubscribeOrdersService, TradingService},
+        trade::{FetchTradesService, SubscribeTradesService},
+        trading::{
+            AmendOrderService, BatchTradingService, CancelAllAfterService, CancelAllOrdersService,
+            CheckOrderService, FeeRatesService, FetchFillsService, OrderHistoryService,
+            SubscribeOrdersService, TradingService,
+        },
+        transfer::TransferService,
         ExcExt,
     };
 
@@ -55,6 +92,9 @@ pub type Result<T> = std::result::Result<T, ExchangeError>;
 #[cfg(feature = "retry")]
 pub use crate::core::retry;
 
+#[cfg(feature = "metrics")]
+pub use crate::core::metrics;
+
 #[cfg(feature = "okx")]
 /// Okx exchange service.
 pub mod okx {
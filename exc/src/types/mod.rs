@@ -3,10 +3,13 @@ pub use crate::core::types::{Period, PeriodKind};
 // These types are going to be replaced by theirs
 // higer-level versions in the future.
 pub use crate::core::types::{
-    BidAsk, BidAskStream, CancelOrder, Canceled, Candle, CandleStream, GetOrder, Order, OrderId,
-    OrderKind, OrderState, OrderStatus, OrderStream, OrderTrade, OrderUpdate, Place, PlaceOrder,
-    PlaceOrderOptions, Placed, QueryCandles, SubscribeBidAsk, SubscribeOrders, SubscribeTickers,
-    Ticker, TickerStream, TimeInForce,
+    BidAsk, BidAskStream, CancelOrder, Canceled, Candle, CandleStream, CandleUpdate,
+    CandleUpdateStream, CurrentFundingRate, FeeRates, FundingRate, FundsRecord, GetOrder,
+    Liquidation, MarkPrice, OpenInterest, OptionGreeks, Order, OrderId, OrderKind, OrderState,
+    OrderStatus, OrderStream, OrderTrade, OrderUpdate, Place, PlaceOrder, PlaceOrderOptions,
+    Placed, QueryCandles, SubscribeBidAsk, SubscribeCandles, SubscribeOrders, SubscribeTickers,
+    Ticker, TickerStream, TimeInForce, TradeHistoryStream, TradeRecord, TrailingStop, Transfer,
+    TransferState, TransferStatus, Trigger, TriggerSource, Wallet, WalletKind,
 };
 
 /// Instrument.
@@ -0,0 +1,152 @@
+use exc_core::types::{
+    BalanceStream, Balances, FetchBalances, FetchPositions, Position, PositionStream,
+    SubscribeBalances, SubscribePositions,
+};
+use futures::future::BoxFuture;
+use futures::{FutureExt, TryFutureExt};
+use tower::ServiceExt;
+
+use crate::ExcService;
+
+/// Fetch balances service.
+pub trait FetchBalancesService {
+    /// Fetch balances.
+    fn fetch_balances(&mut self) -> BoxFuture<'_, crate::Result<Balances>>;
+}
+
+impl<S> FetchBalancesService for S
+where
+    S: ExcService<FetchBalances> + Send,
+    S::Future: Send,
+{
+    /// Fetch balances.
+    fn fetch_balances(&mut self) -> BoxFuture<'_, crate::Result<Balances>> {
+        ServiceExt::oneshot(self.as_service(), FetchBalances)
+            .and_then(|fut| fut)
+            .boxed()
+    }
+}
+
+/// Subscribe balances service.
+pub trait SubscribeBalancesService {
+    /// Subscribe balance updates.
+    fn subscribe_balances(&mut self) -> BoxFuture<'_, crate::Result<BalanceStream>>;
+}
+
+impl<S> SubscribeBalancesService for S
+where
+    S: ExcService<SubscribeBalances> + Send,
+    S::Future: Send,
+{
+    /// Subscribe balance updates.
+    fn subscribe_balances(&mut self) -> BoxFuture<'_, crate::Result<BalanceStream>> {
+        ServiceExt::oneshot(self.as_service(), SubscribeBalances).boxed()
+    }
+}
+
+/// Fetch positions service.
+pub trait FetchPositionsService {
+    /// Fetch positions of the given instrument.
+    fn fetch_position(&mut self, inst: &str) -> BoxFuture<'_, crate::Result<Vec<Position>>>;
+
+    /// Fetch positions of all instruments.
+    fn fetch_positions(&mut self) -> BoxFuture<'_, crate::Result<Vec<Position>>>;
+}
+
+impl<S> FetchPositionsService for S
+where
+    S: ExcService<FetchPositions> + Send,
+    S::Future: Send,
+{
+    /// Fetch positions of the given instrument.
+    fn fetch_position(&mut self, inst: &str) -> BoxFuture<'_, crate::Result<Vec<Position>>> {
+        ServiceExt::oneshot(self.as_service(), FetchPositions::new(inst))
+            .and_then(|fut| fut)
+            .boxed()
+    }
+
+    /// Fetch positions of all instruments.
+    fn fetch_positions(&mut self) -> BoxFuture<'_, crate::Result<Vec<Position>>> {
+        ServiceExt::oneshot(self.as_service(), FetchPositions::all())
+            .and_then(|fut| fut)
+            .boxed()
+    }
+}
+
+/// Subscribe positions service.
+pub trait SubscribePositionsService {
+    /// Subscribe position updates of the given instrument.
+    fn subscribe_position(&mut self, inst: &str) -> BoxFuture<'_, crate::Result<PositionStream>>;
+
+    /// Subscribe position updates of all instruments.
+    fn subscribe_positions(&mut self) -> BoxFuture<'_, crate::Result<PositionStream>>;
+}
+
+impl<S> SubscribePositionsService for S
+where
+    S: ExcService<SubscribePositions> + Send,
+    S::Future: Send,
+{
+    /// Subscribe position updates of the given instrument.
+    fn subscribe_position(&mut self, inst: &str) -> BoxFuture<'_, crate::Result<PositionStream>> {
+        ServiceExt::oneshot(self.as_service(), SubscribePositions::new(inst)).boxed()
+    }
+
+    /// Subscribe position updates of all instruments.
+    fn subscribe_positions(&mut self) -> BoxFuture<'_, crate::Result<PositionStream>> {
+        ServiceExt::oneshot(self.as_service(), SubscribePositions::all()).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    fn boxed_fetch_balances<'a, S>(svc: S) -> Box<dyn FetchBalancesService + 'a>
+    where
+        S: FetchBalancesService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[allow(dead_code)]
+    fn boxed_subscribe_balances<'a, S>(svc: S) -> Box<dyn SubscribeBalancesService + 'a>
+    where
+        S: SubscribeBalancesService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[allow(dead_code)]
+    fn boxed_fetch_positions<'a, S>(svc: S) -> Box<dyn FetchPositionsService + 'a>
+    where
+        S: FetchPositionsService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[allow(dead_code)]
+    fn boxed_subscribe_positions<'a, S>(svc: S) -> Box<dyn SubscribePositionsService + 'a>
+    where
+        S: SubscribePositionsService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_balances() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_fetch_balances(okx.clone()));
+        std::hint::black_box(boxed_subscribe_balances(okx));
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_positions() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_fetch_positions(okx.clone()));
+        std::hint::black_box(boxed_subscribe_positions(okx));
+    }
+}
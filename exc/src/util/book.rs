@@ -1,4 +1,4 @@
-use exc_core::types::BidAskStream;
+use exc_core::types::{BidAskStream, OrderBookStream, SubscribeOrderBook};
 use futures::future::BoxFuture;
 use futures::FutureExt;
 use tower::ServiceExt;
@@ -24,6 +24,31 @@ where
     }
 }
 
+/// Subscribe order book service.
+pub trait SubscribeOrderBookService {
+    /// Subscribe L2 snapshots of the order book with at most `depth` levels of each side.
+    fn subscribe_order_book(
+        &mut self,
+        inst: &str,
+        depth: usize,
+    ) -> BoxFuture<'_, crate::Result<OrderBookStream>>;
+}
+
+impl<S> SubscribeOrderBookService for S
+where
+    S: ExcService<SubscribeOrderBook> + Send,
+    S::Future: Send,
+{
+    /// Subscribe L2 snapshots of the order book with at most `depth` levels of each side.
+    fn subscribe_order_book(
+        &mut self,
+        inst: &str,
+        depth: usize,
+    ) -> BoxFuture<'_, crate::Result<OrderBookStream>> {
+        ServiceExt::oneshot(self.as_service(), SubscribeOrderBook::new(inst, depth)).boxed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,10 +61,27 @@ mod tests {
         Box::new(svc)
     }
 
+    #[allow(dead_code)]
+    fn boxed_order_book<'a, S>(svc: S) -> Box<dyn SubscribeOrderBookService + 'a>
+    where
+        S: SubscribeOrderBookService + 'a,
+    {
+        Box::new(svc)
+    }
+
     #[cfg(feature = "binance")]
     #[tokio::test]
     async fn test_box_bid_ask() {
         let binance = crate::Binance::usd_margin_futures().connect_exc();
         std::hint::black_box(boxed_bid_ask(binance));
     }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_order_book() {
+        use crate::ExcExt;
+
+        let binance = crate::Binance::usd_margin_futures().connect_exc();
+        std::hint::black_box(boxed_order_book(binance.into_subscribe_order_book()));
+    }
 }
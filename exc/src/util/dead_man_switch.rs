@@ -0,0 +1,161 @@
+use std::{
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use exc_core::{types::CancelAllAfter, ExcService, ExchangeError, Request};
+use futures::TryFutureExt;
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use tower::{Layer, Service, ServiceExt};
+
+/// Dead man's switch layer.
+#[derive(Debug, Clone)]
+pub struct DeadManSwitchLayer {
+    req: CancelAllAfter,
+    interval: Duration,
+}
+
+impl DeadManSwitchLayer {
+    /// Create a layer re-arming the countdown of the given [`CancelAllAfter`] request
+    /// every third of its timeout.
+    pub fn new(req: CancelAllAfter) -> Self {
+        Self {
+            interval: req.timeout / 3,
+            req,
+        }
+    }
+
+    /// Set the interval of re-arming the countdown.
+    /// It should be well below the timeout.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+impl<S> Layer<S> for DeadManSwitchLayer {
+    type Service = DeadManSwitch<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        DeadManSwitch {
+            inner,
+            worker: Arc::new(Worker {
+                req: self.req.clone(),
+                interval: self.interval,
+                handle: Mutex::default(),
+            }),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Worker {
+    req: CancelAllAfter,
+    interval: Duration,
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Worker {
+    fn spawn_once<S>(&self, svc: &S)
+    where
+        S: ExcService<CancelAllAfter> + Clone + Send + 'static,
+        S::Future: Send,
+    {
+        let mut handle = self.handle.lock().unwrap();
+        if handle.is_some() {
+            return;
+        }
+        let mut svc = svc.clone();
+        let req = self.req.clone();
+        let mut interval = tokio::time::interval(self.interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        *handle = Some(tokio::spawn(async move {
+            loop {
+                interval.tick().await;
+                let res = ServiceExt::oneshot(svc.as_service(), req.clone())
+                    .try_flatten()
+                    .await;
+                match res {
+                    Ok(()) => tracing::trace!("dead man's switch re-armed"),
+                    Err(err) => tracing::warn!(%err, "failed to re-arm dead man's switch"),
+                }
+            }
+        }));
+    }
+}
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        if let Some(handle) = self.handle.get_mut().ok().and_then(|h| h.take()) {
+            handle.abort();
+        }
+    }
+}
+
+/// Dead man's switch service.
+///
+/// Keep re-arming the countdown of a [`CancelAllAfter`] request in the background
+/// while the service (or any of its clones) is alive, so that all open orders get
+/// canceled by the exchange once the process crashes or hangs.
+/// The countdown is not disarmed when the service is dropped.
+///
+/// The worker is spawned on the first readiness check, which must be
+/// within a tokio runtime. Other requests are passed through to the inner service.
+#[derive(Debug, Clone)]
+pub struct DeadManSwitch<S> {
+    inner: S,
+    worker: Arc<Worker>,
+}
+
+impl<S, R> Service<R> for DeadManSwitch<S>
+where
+    R: Request,
+    S: ExcService<R> + ExcService<CancelAllAfter> + Clone + Send + 'static,
+    <S as ExcService<CancelAllAfter>>::Future: Send,
+{
+    type Response = R::Response;
+    type Error = ExchangeError;
+    type Future = <S as ExcService<R>>::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.worker.spawn_once(&self.inner);
+        ExcService::<R>::poll_ready(&mut self.inner, cx)
+    }
+
+    fn call(&mut self, req: R) -> Self::Future {
+        ExcService::<R>::call(&mut self.inner, req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use futures::{future::ready, FutureExt};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_dead_man_switch() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let inner = {
+            let count = count.clone();
+            tower::service_fn(move |_req: CancelAllAfter| {
+                count.fetch_add(1, Ordering::SeqCst);
+                ready(Ok::<_, ExchangeError>(ready(Ok(())).boxed()))
+            })
+        };
+        let mut svc = DeadManSwitchLayer::new(CancelAllAfter::all(Duration::from_secs(1)))
+            .interval(Duration::from_millis(10))
+            .layer(inner);
+        ServiceExt::<CancelAllAfter>::ready(&mut svc).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(55)).await;
+        assert!(count.load(Ordering::SeqCst) >= 3);
+        drop(svc);
+        tokio::task::yield_now().await;
+        let armed = count.load(Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(count.load(Ordering::SeqCst), armed);
+    }
+}
@@ -0,0 +1,147 @@
+use std::ops::Bound;
+
+use futures::{future::BoxFuture, FutureExt, TryFutureExt};
+use time::OffsetDateTime;
+use tower::ServiceExt;
+
+use crate::{
+    core::types::{
+        CurrentFundingRate, CurrentFundingRateStream, FetchFundingRate, FundingRateStream,
+        QueryFundingRates, SubscribeFundingRate,
+    },
+    ExcService,
+};
+
+/// Funding rate service.
+pub trait FundingRateService {
+    /// Fetch the current funding rate of the instrument.
+    fn fetch_funding_rate(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<CurrentFundingRate>>;
+}
+
+impl<S> FundingRateService for S
+where
+    S: ExcService<FetchFundingRate> + Send,
+    S::Future: Send,
+{
+    fn fetch_funding_rate(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<CurrentFundingRate>> {
+        ServiceExt::<FetchFundingRate>::oneshot(self.as_service(), FetchFundingRate::new(inst))
+            .and_then(|fut| fut)
+            .boxed()
+    }
+}
+
+/// Fetch funding rates service.
+pub trait FetchFundingRatesService {
+    /// Fetch the funding rates of the instrument in the range forward.
+    fn fetch_funding_rates(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<FundingRateStream>>;
+}
+
+impl<S> FetchFundingRatesService for S
+where
+    S: ExcService<QueryFundingRates> + Send,
+    S::Future: Send,
+{
+    fn fetch_funding_rates(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<FundingRateStream>> {
+        ServiceExt::<QueryFundingRates>::oneshot(
+            self.as_service(),
+            QueryFundingRates::new(inst, (start, end)),
+        )
+        .boxed()
+    }
+}
+
+/// Subscribe funding rate service.
+pub trait SubscribeFundingRateService {
+    /// Subscribe the current funding rate of the instrument.
+    fn subscribe_funding_rate(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<CurrentFundingRateStream>>;
+}
+
+impl<S> SubscribeFundingRateService for S
+where
+    S: ExcService<SubscribeFundingRate> + Send,
+    S::Future: Send,
+{
+    fn subscribe_funding_rate(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<CurrentFundingRateStream>> {
+        ServiceExt::<SubscribeFundingRate>::oneshot(
+            self.as_service(),
+            SubscribeFundingRate::new(inst),
+        )
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    fn boxed_funding_rate<'a, S>(svc: S) -> Box<dyn FundingRateService + 'a>
+    where
+        S: FundingRateService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[allow(dead_code)]
+    fn boxed_fetch_funding_rates<'a, S>(svc: S) -> Box<dyn FetchFundingRatesService + 'a>
+    where
+        S: FetchFundingRatesService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[allow(dead_code)]
+    fn boxed_subscribe_funding_rate<'a, S>(svc: S) -> Box<dyn SubscribeFundingRateService + 'a>
+    where
+        S: SubscribeFundingRateService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_funding_rate() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_funding_rate(okx));
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_fetch_funding_rates() {
+        use crate::ExcExt;
+
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_fetch_funding_rates(
+            okx.into_fetch_funding_rates_forward(100),
+        ));
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_subscribe_funding_rate() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_subscribe_funding_rate(okx));
+    }
+}
@@ -0,0 +1,99 @@
+use std::ops::Bound;
+
+use futures::{future::BoxFuture, FutureExt, TryFutureExt};
+use time::OffsetDateTime;
+use tower::ServiceExt;
+
+use crate::{
+    core::{
+        types::{FundsRecord, QueryDeposits, QueryWithdrawals},
+        Asset,
+    },
+    ExcService,
+};
+
+/// Funds history service.
+pub trait FundsHistoryService {
+    /// Query the deposits (of the asset, or of all assets) in the range.
+    fn query_deposits(
+        &mut self,
+        asset: Option<&Asset>,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<Vec<FundsRecord>>>;
+
+    /// Query the withdrawals (of the asset, or of all assets) in the range.
+    fn query_withdrawals(
+        &mut self,
+        asset: Option<&Asset>,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<Vec<FundsRecord>>>;
+}
+
+impl<S> FundsHistoryService for S
+where
+    S: ExcService<QueryDeposits> + ExcService<QueryWithdrawals> + Send,
+    <S as ExcService<QueryDeposits>>::Future: Send,
+    <S as ExcService<QueryWithdrawals>>::Future: Send,
+{
+    fn query_deposits(
+        &mut self,
+        asset: Option<&Asset>,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<Vec<FundsRecord>>> {
+        let mut req = QueryDeposits::new((start, end));
+        if let Some(asset) = asset {
+            req = req.asset(asset.clone());
+        }
+        ServiceExt::<QueryDeposits>::oneshot(ExcService::<QueryDeposits>::as_service(self), req)
+            .try_flatten()
+            .boxed()
+    }
+
+    fn query_withdrawals(
+        &mut self,
+        asset: Option<&Asset>,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<Vec<FundsRecord>>> {
+        let mut req = QueryWithdrawals::new((start, end));
+        if let Some(asset) = asset {
+            req = req.asset(asset.clone());
+        }
+        ServiceExt::<QueryWithdrawals>::oneshot(
+            ExcService::<QueryWithdrawals>::as_service(self),
+            req,
+        )
+        .try_flatten()
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    fn boxed_funds_history<'a, S>(svc: S) -> Box<dyn FundsHistoryService + 'a>
+    where
+        S: FundsHistoryService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_funds_history_okx() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_funds_history(okx));
+    }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_funds_history_binance() {
+        let binance = crate::Binance::spot().connect_exc();
+        std::hint::black_box(boxed_funds_history(binance));
+    }
+}
@@ -0,0 +1,153 @@
+use futures::{future::BoxFuture, FutureExt, TryFutureExt};
+use rust_decimal::Decimal;
+use tower::ServiceExt;
+
+use crate::{
+    core::types::{
+        GetLeverage, Leverage, MarginMode, PositionMode, SetLeverage, SetMarginMode,
+        SetPositionMode,
+    },
+    ExcService,
+};
+
+/// Leverage service.
+pub trait LeverageService {
+    /// Set the leverage of the instrument (for the margin mode).
+    fn set_leverage(
+        &mut self,
+        inst: &str,
+        leverage: Decimal,
+        margin_mode: MarginMode,
+    ) -> BoxFuture<'_, crate::Result<Leverage>>;
+
+    /// Get the leverage of the instrument (for the margin mode).
+    fn get_leverage(
+        &mut self,
+        inst: &str,
+        margin_mode: MarginMode,
+    ) -> BoxFuture<'_, crate::Result<Leverage>>;
+}
+
+impl<S> LeverageService for S
+where
+    S: ExcService<SetLeverage> + ExcService<GetLeverage> + Send,
+    <S as ExcService<SetLeverage>>::Future: Send,
+    <S as ExcService<GetLeverage>>::Future: Send,
+{
+    fn set_leverage(
+        &mut self,
+        inst: &str,
+        leverage: Decimal,
+        margin_mode: MarginMode,
+    ) -> BoxFuture<'_, crate::Result<Leverage>> {
+        ServiceExt::<SetLeverage>::oneshot(
+            ExcService::<SetLeverage>::as_service(self),
+            SetLeverage::new(inst, leverage, margin_mode),
+        )
+        .try_flatten()
+        .boxed()
+    }
+
+    fn get_leverage(
+        &mut self,
+        inst: &str,
+        margin_mode: MarginMode,
+    ) -> BoxFuture<'_, crate::Result<Leverage>> {
+        ServiceExt::<GetLeverage>::oneshot(
+            ExcService::<GetLeverage>::as_service(self),
+            GetLeverage::new(inst, margin_mode),
+        )
+        .try_flatten()
+        .boxed()
+    }
+}
+
+/// Margin mode service.
+pub trait MarginModeService {
+    /// Set the margin mode of the instrument.
+    fn set_margin_mode(
+        &mut self,
+        inst: &str,
+        margin_mode: MarginMode,
+    ) -> BoxFuture<'_, crate::Result<()>>;
+}
+
+impl<S> MarginModeService for S
+where
+    S: ExcService<SetMarginMode> + Send,
+    S::Future: Send,
+{
+    fn set_margin_mode(
+        &mut self,
+        inst: &str,
+        margin_mode: MarginMode,
+    ) -> BoxFuture<'_, crate::Result<()>> {
+        ServiceExt::oneshot(self.as_service(), SetMarginMode::new(inst, margin_mode))
+            .try_flatten()
+            .boxed()
+    }
+}
+
+/// Position mode service.
+pub trait PositionModeService {
+    /// Set the position mode of the account.
+    fn set_position_mode(&mut self, mode: PositionMode) -> BoxFuture<'_, crate::Result<()>>;
+}
+
+impl<S> PositionModeService for S
+where
+    S: ExcService<SetPositionMode> + Send,
+    S::Future: Send,
+{
+    fn set_position_mode(&mut self, mode: PositionMode) -> BoxFuture<'_, crate::Result<()>> {
+        ServiceExt::oneshot(self.as_service(), SetPositionMode::new(mode))
+            .try_flatten()
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    fn boxed_leverage<'a, S>(svc: S) -> Box<dyn LeverageService + 'a>
+    where
+        S: LeverageService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[allow(dead_code)]
+    fn boxed_margin_mode<'a, S>(svc: S) -> Box<dyn MarginModeService + 'a>
+    where
+        S: MarginModeService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[allow(dead_code)]
+    fn boxed_position_mode<'a, S>(svc: S) -> Box<dyn PositionModeService + 'a>
+    where
+        S: PositionModeService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_leverage_okx() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_leverage(okx.clone()));
+        std::hint::black_box(boxed_position_mode(okx));
+    }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_leverage_binance() {
+        let binance = crate::Binance::usd_margin_futures().connect_exc();
+        std::hint::black_box(boxed_leverage(binance.clone()));
+        std::hint::black_box(boxed_margin_mode(binance.clone()));
+        std::hint::black_box(boxed_position_mode(binance));
+    }
+}
@@ -0,0 +1,53 @@
+use futures::{future::BoxFuture, FutureExt};
+use tower::ServiceExt;
+
+use crate::{
+    core::types::{LiquidationStream, SubscribeLiquidations},
+    ExcService,
+};
+
+/// Subscribe liquidations service.
+pub trait SubscribeLiquidationsService {
+    /// Subscribe the liquidations of the instrument.
+    fn subscribe_liquidations(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<LiquidationStream>>;
+}
+
+impl<S> SubscribeLiquidationsService for S
+where
+    S: ExcService<SubscribeLiquidations> + Send,
+    S::Future: Send,
+{
+    fn subscribe_liquidations(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<LiquidationStream>> {
+        ServiceExt::<SubscribeLiquidations>::oneshot(
+            self.as_service(),
+            SubscribeLiquidations::new(inst),
+        )
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    fn boxed_subscribe_liquidations<'a, S>(svc: S) -> Box<dyn SubscribeLiquidationsService + 'a>
+    where
+        S: SubscribeLiquidationsService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_subscribe_liquidations() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_subscribe_liquidations(okx));
+    }
+}
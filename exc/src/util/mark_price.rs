@@ -0,0 +1,48 @@
+use futures::{future::BoxFuture, FutureExt};
+use tower::ServiceExt;
+
+use crate::{
+    core::types::{MarkPriceStream, SubscribeMarkPrice},
+    ExcService,
+};
+
+/// Subscribe mark price service.
+pub trait SubscribeMarkPriceService {
+    /// Subscribe the mark price of the instrument.
+    fn subscribe_mark_price(&mut self, inst: &str)
+        -> BoxFuture<'_, crate::Result<MarkPriceStream>>;
+}
+
+impl<S> SubscribeMarkPriceService for S
+where
+    S: ExcService<SubscribeMarkPrice> + Send,
+    S::Future: Send,
+{
+    fn subscribe_mark_price(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<MarkPriceStream>> {
+        ServiceExt::<SubscribeMarkPrice>::oneshot(self.as_service(), SubscribeMarkPrice::new(inst))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    fn boxed_subscribe_mark_price<'a, S>(svc: S) -> Box<dyn SubscribeMarkPriceService + 'a>
+    where
+        S: SubscribeMarkPriceService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_subscribe_mark_price() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_subscribe_mark_price(okx));
+    }
+}
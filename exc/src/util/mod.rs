@@ -4,6 +4,24 @@ pub mod subscribe_tickers;
 /// Trade.
 pub mod trade;
 
+/// Subscribe candles.
+pub mod subscribe_candles;
+
+/// Mark price.
+pub mod mark_price;
+
+/// Funding.
+pub mod funding;
+
+/// Open interest.
+pub mod open_interest;
+
+/// Liquidation.
+pub mod liquidation;
+
+/// Option market data.
+pub mod option;
+
 /// Book.
 pub mod book;
 
@@ -19,9 +37,47 @@ pub mod trading;
 /// Reconnect.
 pub mod reconnect;
 
+/// Account.
+pub mod account;
+
+/// Dead man's switch.
+pub mod dead_man_switch;
+
+/// Server time.
+pub mod server_time;
+
+/// Leverage and margin configuration.
+pub mod leverage;
+
+/// Transfer.
+pub mod transfer;
+
+/// Deposits and withdrawals.
+pub mod funds;
+
 use exc_core::{
-    types::{SubscribeBidAsk, SubscribeTickers, SubscribeTrades},
-    util::trade_bid_ask::{TradeBidAsk, TradeBidAskLayer},
+    types::{
+        utils::FetchServerTime, AmendOrder, CancelOrder, CancelOrders, FetchOrderBook, GetOrder,
+        PlaceOrder, PlaceOrders, QueryFills, QueryFirstFundingRates, QueryFirstTrades,
+        QueryFundingRates, QueryLastFills, QueryLastTrades, QueryTrades, SubscribeBidAsk,
+        SubscribeCandles, SubscribeOrderBook, SubscribeOrderBookUpdates, SubscribeTickers,
+        SubscribeTrades,
+    },
+    util::{
+        batch::{ChunkedBatch, ChunkedBatchLayer, FanOutBatch, FanOutBatchLayer},
+        cancel_replace::{CancelReplace, CancelReplaceLayer},
+        clock_sync::{Clock, ClockSync, ClockSyncLayer},
+        fetch_fills::{FetchFillsBackward, FetchFillsBackwardLayer},
+        fetch_funding_rates::{FetchFundingRatesForward, FetchFundingRatesForwardLayer},
+        fetch_trades::{
+            FetchTradesBackward, FetchTradesBackwardLayer, FetchTradesForward,
+            FetchTradesForwardLayer,
+        },
+        order_book::{LocalOrderBook, LocalOrderBookLayer},
+        paginate::{Paginate, PaginateLayer},
+        trade_bid_ask::{TradeBidAsk, TradeBidAskLayer},
+        trade_candles::{TradeCandles, TradeCandlesLayer},
+    },
     Adaptor, Exc, ExcService, Request,
 };
 use tower::Layer;
@@ -71,6 +127,148 @@ where
         )
     }
 
+    /// Convert into a [`SubscribeCandlesService`](crate::SubscribeCandlesService)
+    /// building candles from trades.
+    fn into_subscribe_candles(self) -> Exc<TradeCandles<Exc<C, Req>>, SubscribeCandles>
+    where
+        C: Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<SubscribeTrades> + 'static,
+    {
+        Exc::new(TradeCandlesLayer.layer(self.into_exc()))
+    }
+
+    /// Convert into a [`SubscribeOrderBookService`](crate::SubscribeOrderBookService)
+    /// maintaining a local book.
+    fn into_subscribe_order_book(self) -> Exc<LocalOrderBook<Exc<C, Req>>, SubscribeOrderBook>
+    where
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<SubscribeOrderBookUpdates> + Adaptor<FetchOrderBook> + 'static,
+    {
+        Exc::new(LocalOrderBookLayer::default().layer(self.into_exc()))
+    }
+
+    /// Convert into an [`AmendOrderService`](crate::AmendOrderService)
+    /// amending orders by cancel-replace.
+    fn into_cancel_replace(self) -> Exc<CancelReplace<Exc<C, Req>>, AmendOrder>
+    where
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<GetOrder> + Adaptor<CancelOrder> + Adaptor<PlaceOrder> + 'static,
+    {
+        Exc::new(CancelReplaceLayer.layer(self.into_exc()))
+    }
+
+    /// Convert into a [`BatchTradingService`](crate::BatchTradingService)
+    /// sending the orders of a batch one by one.
+    fn into_fan_out_batch(self) -> FanOutBatch<Exc<C, Req>>
+    where
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<PlaceOrder> + Adaptor<CancelOrder> + 'static,
+    {
+        FanOutBatchLayer.layer(self.into_exc())
+    }
+
+    /// Convert into a [`BatchTradingService`](crate::BatchTradingService)
+    /// splitting the batches into chunks of at most `place` orders to place
+    /// and `cancel` orders to cancel.
+    fn into_chunked_batch(self, place: usize, cancel: usize) -> ChunkedBatch<Exc<C, Req>>
+    where
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<PlaceOrders> + Adaptor<CancelOrders> + 'static,
+    {
+        ChunkedBatchLayer::new(place, cancel).layer(self.into_exc())
+    }
+
+    /// Keep the `clock` synchronized with the exchange server,
+    /// measuring the offset at most once per `interval`.
+    fn into_clock_sync(self, clock: Clock, interval: std::time::Duration) -> ClockSync<Exc<C, Req>>
+    where
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<FetchServerTime> + 'static,
+    {
+        ClockSyncLayer::new(clock, interval).layer(self.into_exc())
+    }
+
+    /// Convert into an [`OrderHistoryService`](crate::OrderHistoryService)
+    /// fetching the results page by page.
+    fn into_paginate(self) -> Paginate<Exc<C, Req>>
+    where
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+        Req: 'static,
+    {
+        PaginateLayer.layer(self.into_exc())
+    }
+
+    /// Convert into a [`FetchFillsService`](crate::FetchFillsService)
+    /// fetching at most `limit` fills per request.
+    /// # Panic
+    /// Panic if `limit` is zero.
+    fn into_fetch_fills_backward(
+        self,
+        limit: usize,
+    ) -> Exc<FetchFillsBackward<Exc<C, Req>>, QueryFills>
+    where
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<QueryLastFills> + 'static,
+    {
+        Exc::new(FetchFillsBackwardLayer::new(limit).layer(self.into_exc()))
+    }
+
+    /// Convert into a [`FetchFundingRatesService`](crate::FetchFundingRatesService)
+    /// fetching at most `limit` funding rates per request.
+    /// # Panic
+    /// Panic if `limit` is zero.
+    fn into_fetch_funding_rates_forward(
+        self,
+        limit: usize,
+    ) -> Exc<FetchFundingRatesForward<Exc<C, Req>>, QueryFundingRates>
+    where
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<QueryFirstFundingRates> + 'static,
+    {
+        Exc::new(FetchFundingRatesForwardLayer::new(limit).layer(self.into_exc()))
+    }
+
+    /// Convert into a [`FetchTradesService`](crate::FetchTradesService)
+    /// fetching at most `limit` trades per request forward.
+    /// # Panic
+    /// Panic if `limit` is zero.
+    fn into_fetch_trades_forward(
+        self,
+        limit: usize,
+    ) -> Exc<FetchTradesForward<Exc<C, Req>>, QueryTrades>
+    where
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<QueryFirstTrades> + 'static,
+    {
+        Exc::new(FetchTradesForwardLayer::new(limit).layer(self.into_exc()))
+    }
+
+    /// Convert into a [`FetchTradesService`](crate::FetchTradesService)
+    /// fetching at most `limit` trades per request backward.
+    /// # Panic
+    /// Panic if `limit` is zero.
+    fn into_fetch_trades_backward(
+        self,
+        limit: usize,
+    ) -> Exc<FetchTradesBackward<Exc<C, Req>>, QueryTrades>
+    where
+        C: Clone + Send + 'static,
+        C::Future: Send + 'static,
+        Req: Adaptor<QueryLastTrades> + 'static,
+    {
+        Exc::new(FetchTradesBackwardLayer::new(limit).layer(self.into_exc()))
+    }
+
     /// Convert into a [`FetchCandlesService`](crate::FetchCandlesService)
     /// # Panic
     /// Panic if `limit` is zero.
@@ -0,0 +1,103 @@
+use std::ops::Bound;
+
+use futures::{future::BoxFuture, FutureExt};
+use time::OffsetDateTime;
+use tower::ServiceExt;
+
+use crate::{
+    core::types::{OpenInterestStream, Period, QueryOpenInterest, SubscribeOpenInterest},
+    ExcService,
+};
+
+/// Subscribe open interest service.
+pub trait SubscribeOpenInterestService {
+    /// Subscribe the open interest of the instrument.
+    fn subscribe_open_interest(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<OpenInterestStream>>;
+}
+
+impl<S> SubscribeOpenInterestService for S
+where
+    S: ExcService<SubscribeOpenInterest> + Send,
+    S::Future: Send,
+{
+    fn subscribe_open_interest(
+        &mut self,
+        inst: &str,
+    ) -> BoxFuture<'_, crate::Result<OpenInterestStream>> {
+        ServiceExt::<SubscribeOpenInterest>::oneshot(
+            self.as_service(),
+            SubscribeOpenInterest::new(inst),
+        )
+        .boxed()
+    }
+}
+
+/// Query open interest service.
+pub trait QueryOpenInterestService {
+    /// Query the open interest history of the instrument sampled by `period`.
+    fn query_open_interest(
+        &mut self,
+        inst: &str,
+        period: Period,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<OpenInterestStream>>;
+}
+
+impl<S> QueryOpenInterestService for S
+where
+    S: ExcService<QueryOpenInterest> + Send,
+    S::Future: Send,
+{
+    fn query_open_interest(
+        &mut self,
+        inst: &str,
+        period: Period,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<OpenInterestStream>> {
+        ServiceExt::<QueryOpenInterest>::oneshot(
+            self.as_service(),
+            QueryOpenInterest::new(inst, period, (start, end)),
+        )
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    fn boxed_subscribe_open_interest<'a, S>(svc: S) -> Box<dyn SubscribeOpenInterestService + 'a>
+    where
+        S: SubscribeOpenInterestService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[allow(dead_code)]
+    fn boxed_query_open_interest<'a, S>(svc: S) -> Box<dyn QueryOpenInterestService + 'a>
+    where
+        S: QueryOpenInterestService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_subscribe_open_interest() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_subscribe_open_interest(okx));
+    }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_query_open_interest() {
+        let binance = crate::Binance::usd_margin_futures().connect_exc();
+        std::hint::black_box(boxed_query_open_interest(binance));
+    }
+}
@@ -0,0 +1,60 @@
+use futures::{future::BoxFuture, FutureExt};
+use tower::ServiceExt;
+
+use crate::{
+    core::types::{OptionGreeksStream, SubscribeOptionGreeks},
+    ExcService,
+};
+
+/// Subscribe option greeks service.
+pub trait SubscribeOptionGreeksService {
+    /// Subscribe the greeks of the options of the instrument family.
+    fn subscribe_option_greeks(
+        &mut self,
+        family: &str,
+    ) -> BoxFuture<'_, crate::Result<OptionGreeksStream>>;
+}
+
+impl<S> SubscribeOptionGreeksService for S
+where
+    S: ExcService<SubscribeOptionGreeks> + Send,
+    S::Future: Send,
+{
+    fn subscribe_option_greeks(
+        &mut self,
+        family: &str,
+    ) -> BoxFuture<'_, crate::Result<OptionGreeksStream>> {
+        ServiceExt::<SubscribeOptionGreeks>::oneshot(
+            self.as_service(),
+            SubscribeOptionGreeks::new(family),
+        )
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    fn boxed_subscribe_option_greeks<'a, S>(svc: S) -> Box<dyn SubscribeOptionGreeksService + 'a>
+    where
+        S: SubscribeOptionGreeksService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_subscribe_option_greeks_okx() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_subscribe_option_greeks(okx));
+    }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_subscribe_option_greeks_binance() {
+        let binance = crate::Binance::european_options().connect_exc();
+        std::hint::black_box(boxed_subscribe_option_greeks(binance));
+    }
+}
@@ -0,0 +1,54 @@
+use futures::{future::BoxFuture, FutureExt, TryFutureExt};
+use time::OffsetDateTime;
+use tower::ServiceExt;
+
+use crate::{core::types::utils::FetchServerTime, ExcService};
+
+/// Server time service.
+pub trait ServerTimeService {
+    /// Fetch the current time of the exchange server.
+    fn fetch_server_time(&mut self) -> BoxFuture<'_, crate::Result<OffsetDateTime>>;
+}
+
+impl<S> ServerTimeService for S
+where
+    S: ExcService<FetchServerTime> + Send,
+    S::Future: Send,
+{
+    fn fetch_server_time(&mut self) -> BoxFuture<'_, crate::Result<OffsetDateTime>> {
+        ServiceExt::<FetchServerTime>::oneshot(self.as_service(), FetchServerTime)
+            .and_then(|fut| fut)
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    fn boxed_server_time<'a, S>(svc: S) -> Box<dyn ServerTimeService + 'a>
+    where
+        S: ServerTimeService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_server_time_okx() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_server_time(okx));
+    }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_server_time_binance() {
+        use crate::ExcExt;
+
+        let binance = crate::Binance::usd_margin_futures().connect_exc();
+        std::hint::black_box(boxed_server_time(
+            binance.into_clock_sync(Default::default(), std::time::Duration::from_secs(60)),
+        ));
+    }
+}
@@ -0,0 +1,67 @@
+use futures::{future::BoxFuture, FutureExt};
+use tower::ServiceExt;
+
+use crate::{
+    core::types::{
+        candle::{CandleUpdateStream, SubscribeCandles},
+        Period,
+    },
+    ExcService,
+};
+
+/// Subscribe candles service.
+pub trait SubscribeCandlesService {
+    /// Subscribe live candles of the given period.
+    fn subscribe_candles(
+        &mut self,
+        inst: &str,
+        period: Period,
+    ) -> BoxFuture<'_, crate::Result<CandleUpdateStream>>;
+}
+
+impl<S> SubscribeCandlesService for S
+where
+    S: ExcService<SubscribeCandles> + Send,
+    S::Future: Send,
+{
+    fn subscribe_candles(
+        &mut self,
+        inst: &str,
+        period: Period,
+    ) -> BoxFuture<'_, crate::Result<CandleUpdateStream>> {
+        ServiceExt::<SubscribeCandles>::oneshot(
+            self.as_service(),
+            SubscribeCandles::new(inst, period),
+        )
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    fn boxed_subscribe_candles<'a, S>(svc: S) -> Box<dyn SubscribeCandlesService + 'a>
+    where
+        S: SubscribeCandlesService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_subscribe_candles() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_subscribe_candles(okx));
+    }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_trade_candles() {
+        use crate::ExcExt;
+
+        let binance = crate::Binance::usd_margin_futures().connect_exc();
+        std::hint::black_box(boxed_subscribe_candles(binance.into_subscribe_candles()));
+    }
+}
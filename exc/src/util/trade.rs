@@ -1,7 +1,10 @@
+use std::ops::Bound;
+
 use crate::ExcService;
-use exc_core::types::{SubscribeTrades, TradeStream};
+use exc_core::types::{QueryTrades, SubscribeTrades, TradeHistoryStream, TradeStream};
 use futures::future::BoxFuture;
 use futures::FutureExt;
+use time::OffsetDateTime;
 use tower::ServiceExt;
 
 /// Subscribe trades service.
@@ -19,3 +22,61 @@ where
         ServiceExt::oneshot(self.as_service(), SubscribeTrades::new(inst)).boxed()
     }
 }
+
+/// Fetch trades service.
+pub trait FetchTradesService {
+    /// Fetch the trades of the instrument in the range.
+    fn fetch_trades(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<TradeHistoryStream>>;
+}
+
+impl<S> FetchTradesService for S
+where
+    S: ExcService<QueryTrades> + Send,
+    S::Future: Send,
+{
+    fn fetch_trades(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<TradeHistoryStream>> {
+        ServiceExt::<QueryTrades>::oneshot(self.as_service(), QueryTrades::new(inst, (start, end)))
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    fn boxed_fetch_trades<'a, S>(svc: S) -> Box<dyn FetchTradesService + 'a>
+    where
+        S: FetchTradesService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_fetch_trades_okx() {
+        use crate::ExcExt;
+
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_fetch_trades(okx.into_fetch_trades_backward(100)));
+    }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_fetch_trades_binance() {
+        use crate::ExcExt;
+
+        let binance = crate::Binance::usd_margin_futures().connect_exc();
+        std::hint::black_box(boxed_fetch_trades(binance.into_fetch_trades_forward(1000)));
+    }
+}
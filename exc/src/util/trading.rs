@@ -1,10 +1,17 @@
 use exc_core::{
     types::{
-        trading::PlaceOrderOptions, Canceled, OrderStream, OrderUpdate, Placed, SubscribeOrders,
+        trading::PlaceOrderOptions, AmendOrder, Amended, CancelAllAfter, CancelAllOrders,
+        CancelOrders, Canceled, CanceledBatch, FeeRates, FetchFeeRates, FillStream, ListOpenOrders,
+        OrderStream, OrderUpdate, PlaceOrders, Placed, PlacedBatch, QueryFills, QueryOrderHistory,
+        SubscribeOrders,
     },
     Str,
 };
+use std::{ops::Bound, time::Duration};
+
 use futures::{future::BoxFuture, FutureExt, TryFutureExt};
+use rust_decimal::Decimal;
+use time::OffsetDateTime;
 use tower::ServiceExt;
 
 use crate::core::types::trading::{CancelOrder, GetOrder, OrderId, Place, PlaceOrder};
@@ -75,6 +82,151 @@ where
     }
 }
 
+/// Amend order service.
+pub trait AmendOrderService {
+    /// Amend the price and size of an order.
+    /// The sign of `size` represents the side of the order.
+    fn amend(
+        &mut self,
+        inst: &str,
+        id: &OrderId,
+        price: Decimal,
+        size: Decimal,
+    ) -> BoxFuture<'_, crate::Result<Amended>>;
+}
+
+impl<S> AmendOrderService for S
+where
+    S: ExcService<AmendOrder> + Send,
+    S::Future: Send,
+{
+    fn amend(
+        &mut self,
+        inst: &str,
+        id: &OrderId,
+        price: Decimal,
+        size: Decimal,
+    ) -> BoxFuture<'_, crate::Result<Amended>> {
+        ServiceExt::oneshot(
+            self.as_service(),
+            AmendOrder::new(inst, id.clone(), price, size),
+        )
+        .try_flatten()
+        .boxed()
+    }
+}
+
+/// Batch trading service.
+pub trait BatchTradingService {
+    /// Place a batch of orders.
+    /// The results are in the same order as the requests.
+    fn place_batch(&mut self, orders: Vec<PlaceOrder>)
+        -> BoxFuture<'_, crate::Result<PlacedBatch>>;
+
+    /// Cancel a batch of orders.
+    /// The results are in the same order as the requests.
+    fn cancel_batch(
+        &mut self,
+        orders: Vec<CancelOrder>,
+    ) -> BoxFuture<'_, crate::Result<CanceledBatch>>;
+}
+
+impl<S> BatchTradingService for S
+where
+    S: ExcService<PlaceOrders> + ExcService<CancelOrders> + Send,
+    <S as ExcService<PlaceOrders>>::Future: Send,
+    <S as ExcService<CancelOrders>>::Future: Send,
+{
+    fn place_batch(
+        &mut self,
+        orders: Vec<PlaceOrder>,
+    ) -> BoxFuture<'_, crate::Result<PlacedBatch>> {
+        ServiceExt::<PlaceOrders>::oneshot(self.as_service(), PlaceOrders(orders))
+            .try_flatten()
+            .boxed()
+    }
+
+    fn cancel_batch(
+        &mut self,
+        orders: Vec<CancelOrder>,
+    ) -> BoxFuture<'_, crate::Result<CanceledBatch>> {
+        ServiceExt::<CancelOrders>::oneshot(self.as_service(), CancelOrders(orders))
+            .try_flatten()
+            .boxed()
+    }
+}
+
+/// Cancel all orders service.
+pub trait CancelAllOrdersService {
+    /// Cancel all open orders of the instrument (`None` for all instruments).
+    fn cancel_all(&mut self, inst: Option<&str>) -> BoxFuture<'_, crate::Result<()>>;
+}
+
+impl<S> CancelAllOrdersService for S
+where
+    S: ExcService<CancelAllOrders> + Send,
+    S::Future: Send,
+{
+    fn cancel_all(&mut self, inst: Option<&str>) -> BoxFuture<'_, crate::Result<()>> {
+        let req = match inst {
+            Some(inst) => CancelAllOrders::new(inst),
+            None => CancelAllOrders::all(),
+        };
+        ServiceExt::oneshot(self.as_service(), req)
+            .try_flatten()
+            .boxed()
+    }
+}
+
+/// Cancel all orders after timeout service.
+pub trait CancelAllAfterService {
+    /// Cancel all open orders of the instrument (`None` for all instruments) after the timeout.
+    /// Call it again to re-arm the countdown, or with a zero timeout to disarm it.
+    fn cancel_all_after(
+        &mut self,
+        inst: Option<&str>,
+        timeout: Duration,
+    ) -> BoxFuture<'_, crate::Result<()>>;
+}
+
+impl<S> CancelAllAfterService for S
+where
+    S: ExcService<CancelAllAfter> + Send,
+    S::Future: Send,
+{
+    fn cancel_all_after(
+        &mut self,
+        inst: Option<&str>,
+        timeout: Duration,
+    ) -> BoxFuture<'_, crate::Result<()>> {
+        let req = match inst {
+            Some(inst) => CancelAllAfter::new(inst, timeout),
+            None => CancelAllAfter::all(timeout),
+        };
+        ServiceExt::oneshot(self.as_service(), req)
+            .try_flatten()
+            .boxed()
+    }
+}
+
+/// Fee rates service.
+pub trait FeeRatesService {
+    /// Fetch the trading fee rates of the instrument for the account.
+    fn fetch_fee_rates(&mut self, inst: &str) -> BoxFuture<'_, crate::Result<FeeRates>>;
+}
+
+impl<S> FeeRatesService for S
+where
+    S: ExcService<FetchFeeRates> + Send,
+    S::Future: Send,
+{
+    fn fetch_fee_rates(&mut self, inst: &str) -> BoxFuture<'_, crate::Result<FeeRates>> {
+        ServiceExt::oneshot(self.as_service(), FetchFeeRates::new(inst))
+            .try_flatten()
+            .boxed()
+    }
+}
+
 /// Check order service.
 pub trait CheckOrderService {
     /// Check the current status of an order.
@@ -116,6 +268,75 @@ where
     }
 }
 
+/// Order history service.
+pub trait OrderHistoryService {
+    /// List the open orders of the instrument (`None` for all instruments).
+    fn open_orders(&mut self, inst: Option<&str>) -> BoxFuture<'_, crate::Result<OrderStream>>;
+
+    /// Query the orders of the instrument created in the range.
+    fn order_history(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<OrderStream>>;
+}
+
+impl<S> OrderHistoryService for S
+where
+    S: ExcService<ListOpenOrders> + ExcService<QueryOrderHistory> + Send,
+    <S as ExcService<ListOpenOrders>>::Future: Send,
+    <S as ExcService<QueryOrderHistory>>::Future: Send,
+{
+    fn open_orders(&mut self, inst: Option<&str>) -> BoxFuture<'_, crate::Result<OrderStream>> {
+        let req = match inst {
+            Some(inst) => ListOpenOrders::new(inst),
+            None => ListOpenOrders::all(),
+        };
+        ServiceExt::<ListOpenOrders>::oneshot(self.as_service(), req).boxed()
+    }
+
+    fn order_history(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<OrderStream>> {
+        ServiceExt::<QueryOrderHistory>::oneshot(
+            self.as_service(),
+            QueryOrderHistory::new(inst, (start, end)),
+        )
+        .boxed()
+    }
+}
+
+/// Fetch fills service.
+pub trait FetchFillsService {
+    /// Fetch the fills of the instrument in the range backward.
+    fn fetch_fills(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<FillStream>>;
+}
+
+impl<S> FetchFillsService for S
+where
+    S: ExcService<QueryFills> + Send,
+    S::Future: Send,
+{
+    fn fetch_fills(
+        &mut self,
+        inst: &str,
+        start: Bound<OffsetDateTime>,
+        end: Bound<OffsetDateTime>,
+    ) -> BoxFuture<'_, crate::Result<FillStream>> {
+        ServiceExt::<QueryFills>::oneshot(self.as_service(), QueryFills::new(inst, (start, end)))
+            .boxed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -135,6 +356,69 @@ mod tests {
         std::hint::black_box(boxed_trading(okx));
     }
 
+    #[allow(dead_code)]
+    fn boxed_amend_order<'a, S>(svc: S) -> Box<dyn AmendOrderService + 'a>
+    where
+        S: AmendOrderService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_amend_order() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_amend_order(okx));
+    }
+
+    #[allow(dead_code)]
+    fn boxed_batch_trading<'a, S>(svc: S) -> Box<dyn BatchTradingService + 'a>
+    where
+        S: BatchTradingService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_batch_trading() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_batch_trading(okx));
+    }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_fan_out_batch() {
+        use crate::ExcExt;
+
+        let binance = crate::Binance::spot().connect_exc();
+        std::hint::black_box(boxed_batch_trading(binance.into_fan_out_batch()));
+    }
+
+    #[allow(dead_code)]
+    fn boxed_cancel_all<'a, S>(svc: S) -> Box<dyn CancelAllOrdersService + 'a>
+    where
+        S: CancelAllOrdersService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[allow(dead_code)]
+    fn boxed_cancel_all_after<'a, S>(svc: S) -> Box<dyn CancelAllAfterService + 'a>
+    where
+        S: CancelAllAfterService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_cancel_all() {
+        let binance = crate::Binance::usd_margin_futures().connect_exc();
+        std::hint::black_box(boxed_cancel_all(binance.clone()));
+        std::hint::black_box(boxed_cancel_all_after(binance));
+    }
+
     #[allow(dead_code)]
     fn boxed_check_order<'a, S>(svc: S) -> Box<dyn CheckOrderService + 'a>
     where
@@ -150,6 +434,28 @@ mod tests {
         std::hint::black_box(boxed_check_order(okx));
     }
 
+    #[allow(dead_code)]
+    fn boxed_fee_rates<'a, S>(svc: S) -> Box<dyn FeeRatesService + 'a>
+    where
+        S: FeeRatesService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_fee_rates_okx() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_fee_rates(okx));
+    }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_fee_rates_binance() {
+        let binance = crate::Binance::usd_margin_futures().connect_exc();
+        std::hint::black_box(boxed_fee_rates(binance));
+    }
+
     #[allow(dead_code)]
     fn boxed_subscribe_order<'a, S>(svc: S) -> Box<dyn SubscribeOrdersService + 'a>
     where
@@ -164,4 +470,38 @@ mod tests {
         let okx = crate::Okx::endpoint().connect_exc();
         std::hint::black_box(boxed_subscribe_order(okx));
     }
+
+    #[allow(dead_code)]
+    fn boxed_order_history<'a, S>(svc: S) -> Box<dyn OrderHistoryService + 'a>
+    where
+        S: OrderHistoryService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_order_history() {
+        use crate::ExcExt;
+
+        let binance = crate::Binance::usd_margin_futures().connect_exc();
+        std::hint::black_box(boxed_order_history(binance.into_paginate()));
+    }
+
+    #[allow(dead_code)]
+    fn boxed_fetch_fills<'a, S>(svc: S) -> Box<dyn FetchFillsService + 'a>
+    where
+        S: FetchFillsService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_fetch_fills() {
+        use crate::ExcExt;
+
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_fetch_fills(okx.into_fetch_fills_backward(100)));
+    }
 }
@@ -0,0 +1,92 @@
+use futures::{future::BoxFuture, FutureExt, TryFutureExt};
+use rust_decimal::Decimal;
+use tower::ServiceExt;
+
+use crate::{
+    core::{
+        types::{QueryTransfer, Transfer, TransferState, Wallet},
+        Asset, Str,
+    },
+    ExcService,
+};
+
+/// Transfer service.
+pub trait TransferService {
+    /// Transfer an asset between wallets, returning the transfer id.
+    fn transfer(
+        &mut self,
+        asset: &Asset,
+        amount: Decimal,
+        from: Wallet,
+        to: Wallet,
+    ) -> BoxFuture<'_, crate::Result<Str>>;
+
+    /// Query the state of the transfer of the given id.
+    fn query_transfer(
+        &mut self,
+        id: &str,
+        transfer: &Transfer,
+    ) -> BoxFuture<'_, crate::Result<TransferState>>;
+}
+
+impl<S> TransferService for S
+where
+    S: ExcService<Transfer> + ExcService<QueryTransfer> + Send,
+    <S as ExcService<Transfer>>::Future: Send,
+    <S as ExcService<QueryTransfer>>::Future: Send,
+{
+    fn transfer(
+        &mut self,
+        asset: &Asset,
+        amount: Decimal,
+        from: Wallet,
+        to: Wallet,
+    ) -> BoxFuture<'_, crate::Result<Str>> {
+        ServiceExt::<Transfer>::oneshot(
+            ExcService::<Transfer>::as_service(self),
+            Transfer::new(asset.clone(), amount, from, to),
+        )
+        .try_flatten()
+        .boxed()
+    }
+
+    fn query_transfer(
+        &mut self,
+        id: &str,
+        transfer: &Transfer,
+    ) -> BoxFuture<'_, crate::Result<TransferState>> {
+        ServiceExt::<QueryTransfer>::oneshot(
+            ExcService::<QueryTransfer>::as_service(self),
+            QueryTransfer::new(id, transfer),
+        )
+        .try_flatten()
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    fn boxed_transfer<'a, S>(svc: S) -> Box<dyn TransferService + 'a>
+    where
+        S: TransferService + 'a,
+    {
+        Box::new(svc)
+    }
+
+    #[cfg(feature = "okx")]
+    #[tokio::test]
+    async fn test_box_transfer_okx() {
+        let okx = crate::Okx::endpoint().connect_exc();
+        std::hint::black_box(boxed_transfer(okx));
+    }
+
+    #[cfg(feature = "binance")]
+    #[tokio::test]
+    async fn test_box_transfer_binance() {
+        let binance = crate::Binance::spot().connect_exc();
+        std::hint::black_box(boxed_transfer(binance));
+    }
+}